@@ -1,6 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use ff::Field;
 use group::prime::PrimeCurveAffine;
+use group::Group;
+use halo2curves::jacobian::Jacobian;
 use halo2curves::secp256k1::Secp256k1;
 use pasta_curves::arithmetic::CurveExt;
 use rand_core::OsRng;
@@ -48,5 +50,26 @@ fn criterion_benchmark<G: CurveExt>(c: &mut Criterion) {
     }
 }
 
-criterion_group!(benches, criterion_benchmark<Secp256k1>);
+fn jacobian_benchmark(c: &mut Criterion) {
+    let name = "GJacobian";
+    let p1 = Jacobian::from(Secp256k1::random(OsRng));
+    let p2 = Jacobian::from(Secp256k1::random(OsRng));
+    let p1_affine = p1.to_affine();
+    let s = <Secp256k1 as CurveExt>::ScalarExt::random(OsRng);
+
+    c.bench_function(&format!("{name} doubling"), move |b| {
+        b.iter(|| black_box(p1).double())
+    });
+    c.bench_function(&format!("{name} addition"), move |b| {
+        b.iter(|| black_box(p1) + black_box(p2))
+    });
+    c.bench_function(&format!("{name} mixed addition"), move |b| {
+        b.iter(|| black_box(p2).add_affine(&p1_affine))
+    });
+    c.bench_function(&format!("{name} scalar multiplication"), move |b| {
+        b.iter(|| black_box(p1) * black_box(s))
+    });
+}
+
+criterion_group!(benches, criterion_benchmark<Secp256k1>, jacobian_benchmark);
 criterion_main!(benches);
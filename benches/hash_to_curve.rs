@@ -31,6 +31,34 @@ fn hash_to_grumpkin(c: &mut Criterion) {
     hash_to_curve::<halo2curves::grumpkin::G1>(c, "Grumpkin");
 }
 
+fn hash_to_bn256_many(c: &mut Criterion) {
+    use halo2curves::bn256::G1;
+    use halo2curves::hash_to_curve::{hash_to_curve_many, hash_to_curve_xmd, Blake2b512};
+
+    let mut rng = OsRng;
+    let messages: Vec<[u8; 32]> = (0..1024)
+        .map(|_| {
+            let mut message = [0; 32];
+            rng.fill_bytes(&mut message);
+            message
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("Hash 1024 messages to Bn256");
+    group.bench_function("one by one", |b| {
+        b.iter(|| {
+            black_box(&messages)
+                .iter()
+                .map(|message| hash_to_curve_xmd::<G1, Blake2b512>(message, b"dst"))
+                .collect::<Vec<_>>()
+        })
+    });
+    group.bench_function("batched", |b| {
+        b.iter(|| hash_to_curve_many::<G1, Blake2b512, _>(black_box(&messages), b"dst"))
+    });
+    group.finish();
+}
+
 fn hash_to_curve<G: CurveExt>(c: &mut Criterion, name: &'static str) {
     {
         let hasher = G::hash_to_curve("test");
@@ -55,5 +83,6 @@ criterion_group!(
     hash_to_vesta,
     hash_to_bn256,
     hash_to_grumpkin,
+    hash_to_bn256_many,
 );
 criterion_main!(benches);
@@ -14,9 +14,13 @@ extern crate criterion;
 
 use criterion::{BenchmarkId, Criterion};
 use ff::Field;
-use group::prime::PrimeCurveAffine;
+use group::Curve;
 use halo2curves::bn256::{Fr as Scalar, G1Affine as Point};
-use halo2curves::msm::{best_multiexp, multiexp_serial};
+use halo2curves::cpu::Codegen;
+use halo2curves::msm::{
+    batch_mul, batch_mul_vartime, best_multiexp, best_multiexp_glv, multiexp_serial,
+    multiexp_serial_with,
+};
 use rand_core::SeedableRng;
 use rand_xorshift::XorShiftRng;
 use rayon::current_thread_index;
@@ -26,6 +30,8 @@ use std::time::SystemTime;
 const SAMPLE_SIZE: usize = 10;
 const SINGLECORE_RANGE: [u8; 6] = [3, 8, 10, 12, 14, 16];
 const MULTICORE_RANGE: [u8; 9] = [3, 8, 10, 12, 14, 16, 18, 20, 22];
+const BATCH_MUL_RANGE: [u8; 3] = [8, 12, 16];
+const CODEGEN_RANGE: [u8; 3] = [8, 12, 16];
 const SEED: [u8; 16] = [
     0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc, 0xe5,
 ];
@@ -109,8 +115,74 @@ fn msm(c: &mut Criterion) {
             })
             .sample_size(SAMPLE_SIZE);
     }
+    for k in MULTICORE_RANGE {
+        group
+            .bench_function(BenchmarkId::new("multicore-glv", k), |b| {
+                assert!(k < 64);
+                let n: usize = 1 << k;
+                b.iter(|| {
+                    best_multiexp_glv(&coeffs[..n], &bases[..n]);
+                })
+            })
+            .sample_size(SAMPLE_SIZE);
+    }
+    group.finish();
+}
+
+// Compares the loops of `multiexp_serial` compiled for the baseline target
+// and with `bmi2`/`adx`. On CPUs without them both run the baseline code.
+fn msm_codegen(c: &mut Criterion) {
+    let mut group = c.benchmark_group("msm_codegen");
+    let max_k = *CODEGEN_RANGE.iter().max().unwrap_or(&16);
+    let (coeffs, bases) = generate_coefficients_and_curvepoints(max_k);
+
+    for k in CODEGEN_RANGE {
+        for (name, codegen) in [
+            ("baseline", Codegen::Baseline),
+            ("bmi2_adx", Codegen::Bmi2Adx),
+        ] {
+            group
+                .bench_function(BenchmarkId::new(name, k), |b| {
+                    let n: usize = 1 << k;
+                    let mut acc = Point::identity().into();
+                    b.iter(|| multiexp_serial_with(codegen, &coeffs[..n], &bases[..n], &mut acc));
+                })
+                .sample_size(SAMPLE_SIZE);
+        }
+    }
+    group.finish();
+}
+
+fn batch_mul_elementwise(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_mul");
+    let (coeffs, bases) = generate_coefficients_and_curvepoints(BATCH_MUL_RANGE[2]);
+
+    for k in BATCH_MUL_RANGE {
+        let n: usize = 1 << k;
+        group
+            .bench_function(BenchmarkId::new("batch", k), |b| {
+                b.iter(|| batch_mul(&bases[..n], &coeffs[..n]))
+            })
+            .sample_size(SAMPLE_SIZE);
+        group
+            .bench_function(BenchmarkId::new("batch-vartime", k), |b| {
+                b.iter(|| batch_mul_vartime(&bases[..n], &coeffs[..n]))
+            })
+            .sample_size(SAMPLE_SIZE);
+        group
+            .bench_function(BenchmarkId::new("pointwise", k), |b| {
+                b.iter(|| {
+                    bases[..n]
+                        .iter()
+                        .zip(coeffs[..n].iter())
+                        .map(|(p, s)| (p * s).to_affine())
+                        .collect::<Vec<_>>()
+                })
+            })
+            .sample_size(SAMPLE_SIZE);
+    }
     group.finish();
 }
 
-criterion_group!(benches, msm);
+criterion_group!(benches, msm, msm_codegen, batch_mul_elementwise);
 criterion_main!(benches);
@@ -4,17 +4,89 @@ fn main() {
         eprintln!("Currently feature `asm` can only be enabled on x86_64 arch.");
         std::process::exit(1);
     }
-    #[cfg(feature = "bn256-table")]
+    #[cfg(feature = "generate-constants")]
     {
-        if std::path::Path::new("src/bn256/fr/table.rs").exists() {
-            eprintln!("Pre-computed table for BN256 scalar field exists.");
-            eprintln!("Skip pre-computation\n");
-        } else {
-            eprintln!("Generating pre-computed table for BN256 scalar field\n");
-            std::process::Command::new("python3")
-                .args(["script/bn256.py"])
+        println!("cargo:rerun-if-changed=build.rs");
+        println!("cargo:rerun-if-changed=script/constants.py");
+        let mut params: Vec<_> = std::fs::read_dir("script/params")
+            .expect("missing curve parameters in script/params")
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        params.sort();
+        for path in params {
+            println!("cargo:rerun-if-changed={}", path.display());
+            eprintln!("Generating constants from {}", path.display());
+            let output = std::process::Command::new("python3")
+                .arg("script/constants.py")
+                .arg(&path)
                 .output()
-                .expect("requires python 3 to build pre-computed table");
+                .expect("requires python 3 to generate the constants");
+            if !output.status.success() {
+                eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+                std::process::exit(1);
+            }
+        }
+    }
+    // The small-value lookup tables of the `*-table` features, as the file of
+    // the table, its field type, its name and the modulus.
+    let tables: &[(&str, &str, &str, &str)] = &[
+        #[cfg(feature = "bn256-table")]
+        (
+            "src/bn256/fr/table.rs",
+            "Fr",
+            "FR_TABLE",
+            "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001",
+        ),
+        #[cfg(feature = "grumpkin-table")]
+        (
+            "src/bn256/fq/table.rs",
+            "Fq",
+            "FQ_TABLE",
+            "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47",
+        ),
+    ];
+    let bits: u32 = match std::env::var("HALO2CURVES_TABLE_BITS") {
+        Ok(bits) => bits
+            .parse()
+            .expect("HALO2CURVES_TABLE_BITS must be an integer"),
+        Err(_) => 16,
+    };
+    if !tables.is_empty() {
+        println!("cargo:rerun-if-changed=build.rs");
+        println!("cargo:rerun-if-changed=script/table.py");
+        println!("cargo:rerun-if-env-changed=HALO2CURVES_TABLE_BITS");
+        if !(1..=20).contains(&bits) {
+            eprintln!("HALO2CURVES_TABLE_BITS must be between 1 and 20, got {bits}.");
+            std::process::exit(1);
+        }
+    }
+    for &(path, ty, name, modulus) in tables {
+        println!("cargo:rerun-if-changed={path}");
+        // The second line of the file records the size of the table.
+        let size = format!("for `x < 2^{bits}`");
+        let up_to_date = std::fs::read_to_string(path)
+            .map(|table| table.lines().nth(1).map_or(false, |l| l.contains(&size)))
+            .unwrap_or(false);
+        if up_to_date {
+            eprintln!("Pre-computed table {name} with 2^{bits} entries exists.");
+            eprintln!("Skip pre-computation\n");
+            continue;
+        }
+        eprintln!("Generating pre-computed table {name} with 2^{bits} entries\n");
+        let output = std::process::Command::new("python3")
+            .args([
+                "script/table.py",
+                path,
+                ty,
+                name,
+                modulus,
+                &bits.to_string(),
+            ])
+            .output()
+            .expect("requires python 3 to build pre-computed table");
+        if !output.status.success() {
+            eprintln!("{}", String::from_utf8_lossy(&output.stderr));
+            std::process::exit(1);
         }
     }
 }
@@ -0,0 +1,270 @@
+# This file generates the constants of fields and curves from their parameters.
+#
+# usage: python3 script/constants.py script/params/<curve>.toml
+#
+# The parameter file is TOML, or JSON if its name ends with `.json`, with:
+#
+#   output = "src/<curve>/constants.rs"
+#
+#   [fields.<module>]                 one entry per field
+#   path = "crate::<curve>::Fp"       the type of the field
+#   modulus = "0x..."
+#   multiplicative_generator = 3
+#   zeta = "0x..."                    the cube root of unity used as `ZETA`
+#   generate = true                   false to only use the field for the
+#                                     constants below, default true
+#
+#   [[constants]]                     named elements, or nested arrays of them
+#   name = "G1_B"
+#   field = "<module>"
+#   value = "3"
+#   public = false                    default false
+#   doc = "..."                       optional doc comment
+#
+#   [[endomorphisms]]                 GLV decomposition parameters
+#   name = "ENDO_PARAMS_BN"
+#   scalar = "<module>"               the scalar field
+#   lambda = "0x..."                  default: the `zeta` of the scalar field
+#
+# Every value is checked before anything is written: the generator must be a
+# quadratic non-residue, `zeta` a primitive cube root of unity and `lambda` a
+# root of `x^2 + x + 1`.
+
+import json
+import sys
+from math import isqrt
+
+HEADER = """//! Constants of the `{name}` fields and curves.
+//!
+//! Generated by `script/constants.py` from `{params}`, do not
+//! modify. Building with the `generate-constants` feature regenerates it.
+"""
+
+
+def parse(value):
+    if isinstance(value, int):
+        return value
+    value = value.replace("_", "").strip()
+    negative = value.startswith("-")
+    value = value.lstrip("-")
+    value = int(value, 16) if value.startswith("0x") else int(value)
+    return -value if negative else value
+
+
+def limbs(value, count, bits=64):
+    mask = (1 << bits) - 1
+    return [(value >> (bits * i)) & mask for i in range(count)]
+
+
+# Lays out `items` as rustfmt does for arrays of long literals: on the line of
+# `prefix` if the array is at most 60 columns wide and everything fits in 100
+# columns, one item per line otherwise.
+def array(prefix, items, suffix, indent):
+    one_line = "[" + ", ".join(items) + "]"
+    line = indent + prefix + one_line + suffix
+    if len(one_line) <= 60 and len(line) <= 100:
+        return line + "\n"
+    out = indent + prefix + "[\n"
+    for item in items:
+        out += indent + "    " + item + ",\n"
+    return out + indent + "]" + suffix + "\n"
+
+
+def hex_limbs(value, count):
+    return ["0x%016x" % l for l in limbs(value, count)]
+
+
+class Field:
+    def __init__(self, module, params):
+        self.module = module
+        self.path = params["path"]
+        self.ty = self.path.split("::")[-1]
+        self.p = parse(params["modulus"])
+        self.generate = params.get("generate", True)
+        self.limbs = (self.p.bit_length() + 63) // 64
+        if not self.generate:
+            return
+
+        p = self.p
+        assert p % 2 == 1, "%s: the modulus must be odd" % module
+        assert pow(2, p - 1, p) == 1, "%s: the modulus must be prime" % module
+
+        self.g = parse(params["multiplicative_generator"])
+        assert pow(self.g, (p - 1) // 2, p) == p - 1, (
+            "%s: the multiplicative generator must be a quadratic non-residue" % module
+        )
+
+        self.zeta = parse(params["zeta"]) % p
+        assert self.zeta != 1 and pow(self.zeta, 3, p) == 1, (
+            "%s: zeta must be a primitive cube root of unity" % module
+        )
+
+        self.s = 0
+        t = p - 1
+        while t % 2 == 0:
+            t //= 2
+            self.s += 1
+        self.t = t
+
+    def element(self, value):
+        return self.ty + "::from_raw", parse(value) % self.p
+
+    def emit(self):
+        p, n, ty = self.p, self.limbs, self.ty
+        root = pow(self.g, self.t, p)
+
+        def raw(name, value, doc):
+            out = "".join("    /// %s\n" % d for d in doc)
+            return out + array(
+                "pub(crate) const %s: %s = %s(" % (name, ty, ty), hex_limbs(value, n), ");", "    "
+            )
+
+        def canonical(name, value, doc):
+            out = "".join("    /// %s\n" % d for d in doc)
+            out += "    /// `0x%x`\n" % value
+            return out + array(
+                "pub(crate) const %s: %s = %s::from_raw(" % (name, ty, ty),
+                hex_limbs(value, n),
+                ");",
+                "    ",
+            )
+
+        out = "\npub(crate) mod %s {\n    use %s;\n\n" % (self.module, self.path)
+        out += raw("MODULUS", p, ["Constant representing the modulus", "`0x%x`" % p])
+        out += "\n    /// The modulus as u32 limbs.\n"
+        out += '    #[cfg(not(target_pointer_width = "64"))]\n'
+        out += array(
+            "pub(crate) const MODULUS_LIMBS_32: [u32; %d] = " % (2 * n),
+            ["0x%04x_%04x" % (l >> 16, l & 0xFFFF) for l in limbs(p, 2 * n, 32)],
+            ";",
+            "    ",
+        )
+        out += "\n    /// Constant representing the modulus as static str\n"
+        line = '    pub(crate) const MODULUS_STR: &str = "0x%x";\n' % p
+        out += line if len(line) <= 101 else line.replace("= ", "=\n        ")
+        out += "\n    /// INV = -(p^{-1} mod 2^64) mod 2^64\n"
+        out += "    pub(crate) const INV: u64 = 0x%016x;\n" % ((-pow(p, -1, 1 << 64)) % (1 << 64))
+        for name, k in (("R", 1), ("R2", 2), ("R3", 3)):
+            exp = 64 * n * k
+            out += "\n" + raw(name, pow(2, exp, p), ["%s = 2^%d mod p" % (name, exp)])
+        out += "\n" + canonical(
+            "MULTIPLICATIVE_GENERATOR",
+            self.g,
+            ["A generator of the multiplicative group of the field."],
+        )
+        out += "\n    /// The largest `S` such that `2^S` divides `p - 1`.\n"
+        out += "    pub(crate) const S: u32 = %d;\n" % self.s
+        out += "\n" + canonical(
+            "ROOT_OF_UNITY",
+            root,
+            ["MULTIPLICATIVE_GENERATOR^t where t * 2^S + 1 = p with t odd, a 2^S root of unity."],
+        )
+        out += "\n" + canonical("ROOT_OF_UNITY_INV", pow(root, -1, p), ["1 / ROOT_OF_UNITY mod p"])
+        out += "\n" + canonical("TWO_INV", pow(2, -1, p), ["1 / 2 mod p"])
+        out += "\n" + canonical(
+            "DELTA",
+            pow(self.g, 1 << self.s, p),
+            ["MULTIPLICATIVE_GENERATOR^{2^S}, a t root of unity."],
+        )
+        out += "\n" + canonical("ZETA", self.zeta, ["`ZETA^3 = 1 mod p` where `ZETA != 1 mod p`"])
+        return out + "}\n"
+
+
+def constant(fields, entry):
+    field = fields[entry["field"]]
+    vis = "pub" if entry.get("public", False) else "pub(crate)"
+
+    def ty(value):
+        if isinstance(value, list):
+            return "[%s; %d]" % (ty(value[0]), len(value))
+        return field.ty
+
+    # Lays out `value` as rustfmt does, starting with `prefix` and ending with
+    # `suffix` on lines indented by `indent`.
+    def block(value, prefix, suffix, indent):
+        if not isinstance(value, list):
+            ctor, v = field.element(value)
+            return array(prefix + ctor + "(", hex_limbs(v, field.limbs), ")" + suffix, indent)
+        items = [block(v, "", "", "") for v in value]
+        if all("\n" not in i.rstrip("\n") for i in items):
+            line = array(prefix, [i.strip() for i in items], suffix, indent)
+            if line.count("\n") == 1:
+                return line
+        out = indent + prefix + "[\n"
+        for v in value:
+            out += block(v, "", ",", indent + "    ")
+        return out + indent + "]" + suffix + "\n"
+
+    value = entry["value"]
+    prefix = "%s const %s: %s = " % (vis, entry["name"], ty(value))
+    doc = "".join("/// %s\n" % d for d in entry.get("doc", "").splitlines())
+    return "\n" + doc + block(value, prefix, ";", "")
+
+
+# The short basis of the lattice of `(a, b)` with `a + b * lambda = 0 mod r`,
+# found by the extended Euclidean algorithm as in
+# https://github.com/ConsenSys/gnark-crypto/blob/master/ecc/utils.go
+def endomorphism(fields, entry):
+    field = fields[entry["scalar"]]
+    r = field.p
+    lam = parse(entry["lambda"]) % r if "lambda" in entry else field.zeta
+    assert (lam * lam + lam + 1) % r == 0, "%s: lambda must be a root of x^2 + x + 1" % entry["name"]
+
+    rs, ts = [r, lam], [0, 1]
+    while rs[-1] != 0:
+        q = rs[-2] // rs[-1]
+        rs.append(rs[-2] - q * rs[-1])
+        ts.append(ts[-2] - q * ts[-1])
+    l = next(i for i, x in enumerate(rs) if x < isqrt(r)) - 1
+    v1 = (rs[l + 1], -ts[l + 1])
+    a, b = (rs[l], -ts[l]), (rs[l + 2], -ts[l + 2])
+    v2 = a if a[0] ** 2 + a[1] ** 2 <= b[0] ** 2 + b[1] ** 2 else b
+
+    b1, b2 = abs(v1[1]), abs(v2[1])
+    gamma1, gamma2 = (b1 << 256) // r, (b2 << 256) // r
+    assert max(b1, b2, gamma1, gamma2).bit_length() <= 256
+
+    out = "\n/// `gamma_i = floor(b_i * 2^256 / r)` for the short basis `b_1`, `b_2` of the\n"
+    out += "/// lattice of the decompositions of zero, with\n"
+    out += "/// `lambda = 0x%x`.\n" % lam
+    out += "pub(crate) const %s: EndoParameters = EndoParameters {\n" % entry["name"]
+    for name, value in (("gamma1", gamma1), ("gamma2", gamma2), ("b1", b1), ("b2", b2)):
+        out += array("%s: " % name, hex_limbs(value, 4), ",", "    ")
+    return out + "};\n"
+
+
+def main(path):
+    if path.endswith(".json"):
+        with open(path) as f:
+            params = json.load(f)
+    else:
+        import tomllib
+
+        with open(path, "rb") as f:
+            params = tomllib.load(f)
+
+    fields = {m: Field(m, p) for m, p in params.get("fields", {}).items()}
+    name = params["output"].split("/")[-2]
+
+    out = HEADER.format(name=name, params=path)
+    uses = sorted(
+        {fields[c["field"]].path for c in params.get("constants", [])}
+        | ({"crate::arithmetic::EndoParameters"} if params.get("endomorphisms") else set())
+    )
+    if uses:
+        out += "\n" + "".join("use %s;\n" % u for u in uses)
+    for field in fields.values():
+        if field.generate:
+            out += field.emit()
+    for entry in params.get("constants", []):
+        out += constant(fields, entry)
+    for entry in params.get("endomorphisms", []):
+        out += endomorphism(fields, entry)
+
+    with open(params["output"], "w") as f:
+        f.write(out)
+
+
+if __name__ == "__main__":
+    for path in sys.argv[1:]:
+        main(path)
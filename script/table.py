@@ -0,0 +1,55 @@
+# This file generates the Montgomery form of the integers x in [0, 2^bits),
+# for the small-value lookup table of a 4-limb field.
+#
+# usage: python3 script/table.py <output> <type> <name> <modulus> <bits>
+#
+#   output   the generated file, e.g. src/bn256/fr/table.rs
+#   type     the field type, in scope in the parent module, e.g. Fr
+#   name     the name of the table, e.g. FR_TABLE
+#   modulus  the modulus of the field, in hexadecimal
+#   bits     the table has 2^bits entries
+#
+# It is run by `build.rs` for the enabled `*-table` features.
+
+import sys
+
+verbose = False
+
+
+# @input:  field element a
+# @output: 4 u64 a0, a1, a2, a3 s.t.
+#          a = a3 * 2^192 + a2 * 2^128 + a1 * 2^64 + a0
+def decompose_field_element(a):
+    return [(a >> (64 * i)) % 2**64 for i in range(4)]
+
+
+# @input:  field element a
+# @output: a rust format string that encodes
+#          4 u64 a0, a1, a2, a3 s.t.
+#          a = a3 * 2^192 + a2 * 2^128 + a1 * 2^64 + a0
+def format_field_element(ty, a):
+    return ty + "([" + ",".join(hex(l) for l in decompose_field_element(a)) + "]),\n"
+
+
+def main(output, ty, name, modulus, bits):
+    modulus = int(modulus, 16)
+    assert modulus < 2**256, "the modulus must fit in 4 limbs"
+    R = 2**256 % modulus
+    table_size = 1 << bits
+
+    with open(output, "w") as f:
+        f.write("//! Generated by `script/table.py`, do not modify.\n")
+        f.write("//! `%s[x]` is the Montgomery form of `x`, for `x < 2^%d`.\n" % (name, bits))
+        f.write("use super::%s;\n" % ty)
+        f.write("pub const %s: &[%s] = &[\n" % (name, ty))
+        for i in range(table_size):
+            a = (i * R) % modulus
+            if verbose:
+                print(i, a, format_field_element(ty, a))
+            f.write(format_field_element(ty, a))
+        f.write("\n];")
+
+
+if __name__ == "__main__":
+    output, ty, name, modulus, bits = sys.argv[1:]
+    main(output, ty, name, modulus, int(bits))
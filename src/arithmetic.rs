@@ -4,7 +4,45 @@
 //! This module is temporary, and the extension traits defined here are expected to be
 //! upstreamed into the `ff` and `group` crates after some refactoring.
 
+#[cfg(any(
+    test,
+    all(
+        feature = "wasm-simd",
+        target_arch = "wasm32",
+        target_feature = "simd128"
+    )
+))]
+pub(crate) mod simd128;
+
+#[cfg(all(feature = "asm", target_arch = "aarch64"))]
+pub(crate) mod aarch64;
+
+#[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+pub(crate) mod ifma;
+
+#[cfg(all(feature = "asm", target_arch = "riscv64"))]
+pub(crate) mod riscv;
+
+#[cfg(any(
+    test,
+    all(
+        any(feature = "force-u32", target_pointer_width = "32"),
+        not(feature = "portable"),
+        not(all(
+            feature = "wasm-simd",
+            target_arch = "wasm32",
+            target_feature = "simd128"
+        ))
+    )
+))]
+pub(crate) mod limb32;
+
+#[cfg(any(test, feature = "portable"))]
+pub(crate) mod portable;
+
 use crate::CurveExt;
+use alloc::vec;
+use alloc::vec::Vec;
 
 pub(crate) struct EndoParameters {
     pub(crate) gamma1: [u64; 4],
@@ -13,7 +51,12 @@ pub(crate) struct EndoParameters {
     pub(crate) b2: [u64; 4],
 }
 
+/// A curve with an efficient endomorphism, `endo(P) = ZETA * P`, and the
+/// decomposition of its scalars along it.
 pub trait CurveEndo: CurveExt {
+    /// Splits `e` into `(k1, k1_neg, k2, k2_neg)`, two scalars of at most 128
+    /// bits with `e = s1 * k1 + s2 * k2 * ZETA`, where `s1` is `-1` if `k1_neg`
+    /// and `1` otherwise, and `s2` is `1` if `k2_neg` and `-1` otherwise.
     fn decompose_scalar(e: &Self::ScalarExt) -> (u128, bool, u128, bool);
 }
 
@@ -94,3 +137,89 @@ pub(crate) fn mul_512(a: [u64; 4], b: [u64; 4]) -> [u64; 8] {
 
     [r0, r1, r2, r3, r4, r5, r6, carry_out]
 }
+
+/// Decomposes the little-endian integer `bytes` into base-`base` digits in
+/// `(-base/2, base/2]`, least significant first.
+///
+/// The digits recompose to the input as `sum(d_i * base^i)`. Zero has no
+/// digits.
+pub(crate) fn balanced_digits(bytes: &[u8], base: u64) -> Vec<i64> {
+    assert!(
+        (2..=i64::MAX as u64).contains(&base),
+        "base must be in [2, i64::MAX]"
+    );
+
+    let mut limbs: Vec<u64> = bytes
+        .chunks(8)
+        .map(|chunk| {
+            let mut limb = [0u8; 8];
+            limb[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(limb)
+        })
+        .collect();
+
+    let mut digits = vec![];
+    while limbs.iter().any(|&limb| limb != 0) {
+        // Divide by `base` in place, from the most significant limb down.
+        let mut rem = 0u128;
+        for limb in limbs.iter_mut().rev() {
+            let cur = (rem << 64) | *limb as u128;
+            *limb = (cur / base as u128) as u64;
+            rem = cur % base as u128;
+        }
+        let rem = rem as u64;
+
+        if rem > base / 2 {
+            digits.push(rem as i64 - base as i64);
+            // Borrowed `base` from the quotient, so carry one back in.
+            for limb in limbs.iter_mut() {
+                let (sum, carry) = limb.overflowing_add(1);
+                *limb = sum;
+                if !carry {
+                    break;
+                }
+            }
+        } else {
+            digits.push(rem as i64);
+        }
+    }
+    digits
+}
+
+/// Compute a + b for 256-bit values, discarding the final carry.
+#[inline(always)]
+pub(crate) const fn add_256(a: [u64; 4], b: [u64; 4]) -> [u64; 4] {
+    let (r0, carry) = adc(a[0], b[0], 0);
+    let (r1, carry) = adc(a[1], b[1], carry);
+    let (r2, carry) = adc(a[2], b[2], carry);
+    let (r3, _) = adc(a[3], b[3], carry);
+    [r0, r1, r2, r3]
+}
+
+/// Compute a + b for 512-bit values, discarding the final carry.
+#[inline(always)]
+pub(crate) const fn add_512(a: [u64; 8], b: [u64; 8]) -> [u64; 8] {
+    let (r0, carry) = adc(a[0], b[0], 0);
+    let (r1, carry) = adc(a[1], b[1], carry);
+    let (r2, carry) = adc(a[2], b[2], carry);
+    let (r3, carry) = adc(a[3], b[3], carry);
+    let (r4, carry) = adc(a[4], b[4], carry);
+    let (r5, carry) = adc(a[5], b[5], carry);
+    let (r6, carry) = adc(a[6], b[6], carry);
+    let (r7, _) = adc(a[7], b[7], carry);
+    [r0, r1, r2, r3, r4, r5, r6, r7]
+}
+
+/// Compute a - b for 512-bit values, discarding the final borrow.
+#[inline(always)]
+pub(crate) const fn sub_512(a: [u64; 8], b: [u64; 8]) -> [u64; 8] {
+    let (r0, borrow) = sbb(a[0], b[0], 0);
+    let (r1, borrow) = sbb(a[1], b[1], borrow);
+    let (r2, borrow) = sbb(a[2], b[2], borrow);
+    let (r3, borrow) = sbb(a[3], b[3], borrow);
+    let (r4, borrow) = sbb(a[4], b[4], borrow);
+    let (r5, borrow) = sbb(a[5], b[5], borrow);
+    let (r6, borrow) = sbb(a[6], b[6], borrow);
+    let (r7, _) = sbb(a[7], b[7], borrow);
+    [r0, r1, r2, r3, r4, r5, r6, r7]
+}
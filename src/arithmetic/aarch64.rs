@@ -0,0 +1,200 @@
+//! Montgomery multiplication for 4-limb fields in aarch64 assembly.
+//!
+//! The product of each pair of limbs is computed with `mul`/`umulh` and
+//! accumulated with `adds`/`adc` carry chains, which keeps the accumulator in
+//! registers for the whole multiplication. It uses the same Montgomery domain
+//! `R = 2^256` as the other backends.
+
+use core::arch::asm;
+
+/// Returns `a * b / 2^256 mod modulus` for `a` and `b` in Montgomery form,
+/// where `inv = -modulus^-1 mod 2^64`.
+///
+/// This is the Coarsely Integrated Operand Scanning method, and works for any
+/// odd modulus below `2^256`.
+#[inline(always)]
+pub(crate) fn mont_mul(a: &[u64; 4], b: &[u64; 4], modulus: &[u64; 4], inv: u64) -> [u64; 4] {
+    let mut r0: u64;
+    let mut r1: u64;
+    let mut r2: u64;
+    let mut r3: u64;
+    unsafe {
+        asm!(
+            // The accumulator is t0..t4, and t5 catches the carry of t4. It
+            // stays below `2 * modulus`, so t4 is at most one between steps.
+            "2:",
+            "ldr {bi}, [{b}], #8",
+
+            // t += a * b_i
+            "mul {lo}, {a0}, {bi}",
+            "umulh {hi}, {a0}, {bi}",
+            "adds {t0}, {t0}, {lo}",
+            "adc {c}, {hi}, xzr",
+
+            "mul {lo}, {a1}, {bi}",
+            "umulh {hi}, {a1}, {bi}",
+            "adds {lo}, {lo}, {c}",
+            "adc {hi}, {hi}, xzr",
+            "adds {t1}, {t1}, {lo}",
+            "adc {c}, {hi}, xzr",
+
+            "mul {lo}, {a2}, {bi}",
+            "umulh {hi}, {a2}, {bi}",
+            "adds {lo}, {lo}, {c}",
+            "adc {hi}, {hi}, xzr",
+            "adds {t2}, {t2}, {lo}",
+            "adc {c}, {hi}, xzr",
+
+            "mul {lo}, {a3}, {bi}",
+            "umulh {hi}, {a3}, {bi}",
+            "adds {lo}, {lo}, {c}",
+            "adc {hi}, {hi}, xzr",
+            "adds {t3}, {t3}, {lo}",
+            "adc {c}, {hi}, xzr",
+
+            "adds {t4}, {t4}, {c}",
+            "adc {t5}, xzr, xzr",
+
+            // m = t0 * -modulus^-1 mod 2^64
+            "mul {m}, {t0}, {inv}",
+
+            // t = (t + m * modulus) / 2^64, the low limb cancelling out
+            "mul {lo}, {m}, {p0}",
+            "umulh {hi}, {m}, {p0}",
+            "adds {lo}, {lo}, {t0}",
+            "adc {c}, {hi}, xzr",
+
+            "mul {lo}, {m}, {p1}",
+            "umulh {hi}, {m}, {p1}",
+            "adds {lo}, {lo}, {c}",
+            "adc {hi}, {hi}, xzr",
+            "adds {t0}, {t1}, {lo}",
+            "adc {c}, {hi}, xzr",
+
+            "mul {lo}, {m}, {p2}",
+            "umulh {hi}, {m}, {p2}",
+            "adds {lo}, {lo}, {c}",
+            "adc {hi}, {hi}, xzr",
+            "adds {t1}, {t2}, {lo}",
+            "adc {c}, {hi}, xzr",
+
+            "mul {lo}, {m}, {p3}",
+            "umulh {hi}, {m}, {p3}",
+            "adds {lo}, {lo}, {c}",
+            "adc {hi}, {hi}, xzr",
+            "adds {t2}, {t3}, {lo}",
+            "adc {c}, {hi}, xzr",
+
+            "adds {t3}, {t4}, {c}",
+            "adc {t4}, {t5}, xzr",
+
+            "subs {n}, {n}, #1",
+            "b.ne 2b",
+
+            // Subtract the modulus if the result is not below it. The carry
+            // flag is clear if the subtraction borrowed.
+            "subs {a0}, {t0}, {p0}",
+            "sbcs {a1}, {t1}, {p1}",
+            "sbcs {a2}, {t2}, {p2}",
+            "sbcs {a3}, {t3}, {p3}",
+            "sbcs xzr, {t4}, xzr",
+            "csel {t0}, {a0}, {t0}, hs",
+            "csel {t1}, {a1}, {t1}, hs",
+            "csel {t2}, {a2}, {t2}, hs",
+            "csel {t3}, {a3}, {t3}, hs",
+
+            a0 = inout(reg) a[0] => _,
+            a1 = inout(reg) a[1] => _,
+            a2 = inout(reg) a[2] => _,
+            a3 = inout(reg) a[3] => _,
+            b = inout(reg) b.as_ptr() => _,
+            n = inout(reg) 4u64 => _,
+            p0 = in(reg) modulus[0],
+            p1 = in(reg) modulus[1],
+            p2 = in(reg) modulus[2],
+            p3 = in(reg) modulus[3],
+            inv = in(reg) inv,
+            t0 = inout(reg) 0u64 => r0,
+            t1 = inout(reg) 0u64 => r1,
+            t2 = inout(reg) 0u64 => r2,
+            t3 = inout(reg) 0u64 => r3,
+            t4 = inout(reg) 0u64 => _,
+            t5 = out(reg) _,
+            bi = out(reg) _,
+            m = out(reg) _,
+            c = out(reg) _,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            options(pure, readonly, nostack)
+        );
+    }
+    [r0, r1, r2, r3]
+}
+
+#[cfg(test)]
+mod test {
+    use super::mont_mul;
+    use crate::serde::SerdeObject;
+    use ff::PrimeField;
+    use num_bigint::BigUint;
+    use rand_core::OsRng;
+
+    fn limbs<F: SerdeObject>(e: &F) -> [u64; 4] {
+        let bytes = e.to_raw_bytes();
+        core::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap()))
+    }
+
+    fn to_big(limbs: &[u64; 4]) -> BigUint {
+        BigUint::from_slice(
+            &limbs
+                .iter()
+                .flat_map(|l| [*l as u32, (l >> 32) as u32])
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn check<F: PrimeField + SerdeObject>() {
+        // The modulus is one more than the canonical form of -1.
+        let mut modulus = [0u64; 4];
+        for (m, b) in modulus
+            .iter_mut()
+            .zip((-F::ONE).to_repr().as_ref().chunks(8))
+        {
+            *m = u64::from_le_bytes(b.try_into().unwrap());
+        }
+        modulus[0] += 1;
+        // -modulus^-1 mod 2^64 by Newton iteration.
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        }
+        let inv = inv.wrapping_neg();
+
+        // Checked against integer arithmetic, as the field multiplication may
+        // itself be this routine.
+        let p = to_big(&modulus);
+        let r_inv = (BigUint::from(1u8) << 256u32).modpow(&(&p - 2u32), &p);
+        let edge = [F::ZERO, F::ONE, -F::ONE];
+        for a in edge
+            .iter()
+            .copied()
+            .chain((0..1000).map(|_| F::random(OsRng)))
+        {
+            for b in edge.iter().copied().chain([F::random(OsRng)]) {
+                let (a, b) = (limbs(&a), limbs(&b));
+                let expected = to_big(&a) * to_big(&b) * &r_inv % &p;
+                assert_eq!(to_big(&mont_mul(&a, &b, &modulus, inv)), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_mont_mul() {
+        check::<crate::bn256::Fr>();
+        check::<crate::bn256::Fq>();
+        check::<crate::secp256k1::Fp>();
+        check::<crate::secp256k1::Fq>();
+        check::<crate::secp256r1::Fp>();
+        check::<crate::secp256r1::Fq>();
+    }
+}
@@ -0,0 +1,331 @@
+//! Montgomery multiplication of eight elements of a 4-limb field at once with
+//! AVX-512 IFMA.
+//!
+//! Each element is split into five 52-bit limbs, and the limbs of eight
+//! elements fill the lanes of five 512-bit registers. `vpmadd52{l,h}uq`
+//! accumulate the low and high halves of eight 52 x 52 bit products into
+//! 64-bit lanes, which leaves enough headroom to postpone carries until the
+//! end of the multiplication.
+//!
+//! The reduction runs four steps of 52 bits and a last one of 48 bits, so that
+//! the result is divided by `2^256` and stays in the Montgomery domain of the
+//! other backends.
+//!
+//! The code is written with inline assembly, as the AVX-512 intrinsics are not
+//! available on the toolchain of this crate, and is selected at runtime with
+//! [`backend`](crate::cpu::backend).
+
+use core::arch::asm;
+
+const MASK_52: u64 = (1 << 52) - 1;
+const MASK_48: u64 = (1 << 48) - 1;
+
+/// Number of elements multiplied at once.
+pub(crate) const LANES: usize = 8;
+
+/// Returns whether the running CPU supports this backend.
+#[inline]
+pub(crate) fn is_available() -> bool {
+    crate::cpu::backend() == crate::cpu::Backend::Avx512Ifma
+}
+
+#[inline(always)]
+fn to_52_bit_limbs(a: &[u64; 4]) -> [u64; 5] {
+    [
+        a[0] & MASK_52,
+        (a[0] >> 52 | a[1] << 12) & MASK_52,
+        (a[1] >> 40 | a[2] << 24) & MASK_52,
+        (a[2] >> 28 | a[3] << 36) & MASK_52,
+        a[3] >> 16,
+    ]
+}
+
+// Converts normalized limbs holding `t * 2^48` for some `t < 2 * modulus` to
+// `t mod modulus`.
+#[inline(always)]
+fn from_52_bit_limbs(l: [u64; 6], modulus: &[u64; 4]) -> [u64; 4] {
+    let w = [
+        l[0] | l[1] << 52,
+        l[1] >> 12 | l[2] << 40,
+        l[2] >> 24 | l[3] << 28,
+        l[3] >> 36 | l[4] << 16,
+        l[4] >> 48 | l[5] << 4,
+    ];
+    let t: [u64; 4] = core::array::from_fn(|i| w[i] >> 48 | w[i + 1] << 16);
+    let overflow = w[4] >> 48;
+
+    let mut r = [0u64; 4];
+    let mut borrow = 0u64;
+    for ((r_i, t_i), m_i) in r.iter_mut().zip(t).zip(modulus) {
+        let (d, b1) = t_i.overflowing_sub(*m_i);
+        let (d, b2) = d.overflowing_sub(borrow);
+        *r_i = d;
+        borrow = (b1 | b2) as u64;
+    }
+    // Keep `t` if the subtraction underflowed without an overflow bit.
+    let keep = 0u64.wrapping_sub(borrow & !overflow & 1);
+    for (r_i, t_i) in r.iter_mut().zip(t) {
+        *r_i = (t_i & keep) | (*r_i & !keep);
+    }
+    r
+}
+
+/// Returns `a[k] * b[k] / 2^256 mod modulus` for every `k`, for `a[k]` and
+/// `b[k]` in Montgomery form, where `inv = -modulus^-1 mod 2^64`.
+///
+/// # Safety
+///
+/// The CPU must support `avx512f` and `avx512ifma`, see [`is_available`].
+pub(crate) unsafe fn mont_mul(
+    a: &[[u64; 4]; LANES],
+    b: &[[u64; 4]; LANES],
+    modulus: &[u64; 4],
+    inv: u64,
+) -> [[u64; 4]; LANES] {
+    // Transpose to one array of lanes per limb.
+    let mut a_limbs = [[0u64; LANES]; 5];
+    let mut b_limbs = [[0u64; LANES]; 5];
+    for k in 0..LANES {
+        for (i, (a_i, b_i)) in to_52_bit_limbs(&a[k])
+            .into_iter()
+            .zip(to_52_bit_limbs(&b[k]))
+            .enumerate()
+        {
+            a_limbs[i][k] = a_i;
+            b_limbs[i][k] = b_i;
+        }
+    }
+    let p_limbs = to_52_bit_limbs(modulus);
+    let mut t_limbs = [[0u64; LANES]; 6];
+
+    asm!(
+        // rsi: limbs of b, rcx: loop counter,
+        // zmm0-4: limbs of a, zmm5-10: accumulator, zmm11: limb of b,
+        // zmm12: Montgomery factor, zmm13: -modulus^-1, zmm14: 52-bit mask.
+        "vmovdqu64 zmm0, zmmword ptr [{a}]",
+        "vmovdqu64 zmm1, zmmword ptr [{a} + 64]",
+        "vmovdqu64 zmm2, zmmword ptr [{a} + 128]",
+        "vmovdqu64 zmm3, zmmword ptr [{a} + 192]",
+        "vmovdqu64 zmm4, zmmword ptr [{a} + 256]",
+        "vpxorq zmm5, zmm5, zmm5",
+        "vpxorq zmm6, zmm6, zmm6",
+        "vpxorq zmm7, zmm7, zmm7",
+        "vpxorq zmm8, zmm8, zmm8",
+        "vpxorq zmm9, zmm9, zmm9",
+        "vpxorq zmm10, zmm10, zmm10",
+        "vpbroadcastq zmm13, {inv}",
+        "vpbroadcastq zmm14, {mask_52}",
+
+        // Four reduction steps of 52 bits.
+        "2:",
+        // t += a * b_i
+        "vmovdqu64 zmm11, zmmword ptr [rsi]",
+        "vpmadd52luq zmm5, zmm0, zmm11",
+        "vpmadd52huq zmm6, zmm0, zmm11",
+        "vpmadd52luq zmm6, zmm1, zmm11",
+        "vpmadd52huq zmm7, zmm1, zmm11",
+        "vpmadd52luq zmm7, zmm2, zmm11",
+        "vpmadd52huq zmm8, zmm2, zmm11",
+        "vpmadd52luq zmm8, zmm3, zmm11",
+        "vpmadd52huq zmm9, zmm3, zmm11",
+        "vpmadd52luq zmm9, zmm4, zmm11",
+        "vpmadd52huq zmm10, zmm4, zmm11",
+        // m = t_0 * -modulus^-1 mod 2^52
+        "vpxorq zmm12, zmm12, zmm12",
+        "vpmadd52luq zmm12, zmm5, zmm13",
+        // t += m * modulus
+        "vpmadd52luq zmm5, zmm12, qword ptr [{p}]{{1to8}}",
+        "vpmadd52huq zmm6, zmm12, qword ptr [{p}]{{1to8}}",
+        "vpmadd52luq zmm6, zmm12, qword ptr [{p} + 8]{{1to8}}",
+        "vpmadd52huq zmm7, zmm12, qword ptr [{p} + 8]{{1to8}}",
+        "vpmadd52luq zmm7, zmm12, qword ptr [{p} + 16]{{1to8}}",
+        "vpmadd52huq zmm8, zmm12, qword ptr [{p} + 16]{{1to8}}",
+        "vpmadd52luq zmm8, zmm12, qword ptr [{p} + 24]{{1to8}}",
+        "vpmadd52huq zmm9, zmm12, qword ptr [{p} + 24]{{1to8}}",
+        "vpmadd52luq zmm9, zmm12, qword ptr [{p} + 32]{{1to8}}",
+        "vpmadd52huq zmm10, zmm12, qword ptr [{p} + 32]{{1to8}}",
+        // t /= 2^52, the low limb being zero except for its carry
+        "vpsrlq zmm15, zmm5, 52",
+        "vpaddq zmm5, zmm6, zmm15",
+        "vmovdqa64 zmm6, zmm7",
+        "vmovdqa64 zmm7, zmm8",
+        "vmovdqa64 zmm8, zmm9",
+        "vmovdqa64 zmm9, zmm10",
+        "vpxorq zmm10, zmm10, zmm10",
+        "add rsi, 64",
+        "dec rcx",
+        "jnz 2b",
+
+        // A last reduction step of 48 bits, leaving the division to the
+        // caller.
+        "vmovdqu64 zmm11, zmmword ptr [rsi]",
+        "vpmadd52luq zmm5, zmm0, zmm11",
+        "vpmadd52huq zmm6, zmm0, zmm11",
+        "vpmadd52luq zmm6, zmm1, zmm11",
+        "vpmadd52huq zmm7, zmm1, zmm11",
+        "vpmadd52luq zmm7, zmm2, zmm11",
+        "vpmadd52huq zmm8, zmm2, zmm11",
+        "vpmadd52luq zmm8, zmm3, zmm11",
+        "vpmadd52huq zmm9, zmm3, zmm11",
+        "vpmadd52luq zmm9, zmm4, zmm11",
+        "vpmadd52huq zmm10, zmm4, zmm11",
+        "vpxorq zmm12, zmm12, zmm12",
+        "vpmadd52luq zmm12, zmm5, zmm13",
+        "vpbroadcastq zmm15, {mask_48}",
+        "vpandq zmm12, zmm12, zmm15",
+        "vpmadd52luq zmm5, zmm12, qword ptr [{p}]{{1to8}}",
+        "vpmadd52huq zmm6, zmm12, qword ptr [{p}]{{1to8}}",
+        "vpmadd52luq zmm6, zmm12, qword ptr [{p} + 8]{{1to8}}",
+        "vpmadd52huq zmm7, zmm12, qword ptr [{p} + 8]{{1to8}}",
+        "vpmadd52luq zmm7, zmm12, qword ptr [{p} + 16]{{1to8}}",
+        "vpmadd52huq zmm8, zmm12, qword ptr [{p} + 16]{{1to8}}",
+        "vpmadd52luq zmm8, zmm12, qword ptr [{p} + 24]{{1to8}}",
+        "vpmadd52huq zmm9, zmm12, qword ptr [{p} + 24]{{1to8}}",
+        "vpmadd52luq zmm9, zmm12, qword ptr [{p} + 32]{{1to8}}",
+        "vpmadd52huq zmm10, zmm12, qword ptr [{p} + 32]{{1to8}}",
+
+        // Propagate the carries.
+        "vpsrlq zmm15, zmm5, 52",
+        "vpandq zmm5, zmm5, zmm14",
+        "vpaddq zmm6, zmm6, zmm15",
+        "vpsrlq zmm15, zmm6, 52",
+        "vpandq zmm6, zmm6, zmm14",
+        "vpaddq zmm7, zmm7, zmm15",
+        "vpsrlq zmm15, zmm7, 52",
+        "vpandq zmm7, zmm7, zmm14",
+        "vpaddq zmm8, zmm8, zmm15",
+        "vpsrlq zmm15, zmm8, 52",
+        "vpandq zmm8, zmm8, zmm14",
+        "vpaddq zmm9, zmm9, zmm15",
+        "vpsrlq zmm15, zmm9, 52",
+        "vpandq zmm9, zmm9, zmm14",
+        "vpaddq zmm10, zmm10, zmm15",
+
+        "vmovdqu64 zmmword ptr [{t}], zmm5",
+        "vmovdqu64 zmmword ptr [{t} + 64], zmm6",
+        "vmovdqu64 zmmword ptr [{t} + 128], zmm7",
+        "vmovdqu64 zmmword ptr [{t} + 192], zmm8",
+        "vmovdqu64 zmmword ptr [{t} + 256], zmm9",
+        "vmovdqu64 zmmword ptr [{t} + 320], zmm10",
+        "vzeroupper",
+
+        a = in(reg) a_limbs.as_ptr(),
+        inout("rsi") b_limbs.as_ptr() => _,
+        p = in(reg) p_limbs.as_ptr(),
+        t = in(reg) t_limbs.as_mut_ptr(),
+        inout("rcx") 4u64 => _,
+        inv = in(reg) inv & MASK_52,
+        mask_52 = in(reg) MASK_52,
+        mask_48 = in(reg) MASK_48,
+        // The upper halves of the vector registers are never live in code
+        // compiled without AVX-512, so clobbering xmm0-15 covers them.
+        clobber_abi("C"),
+        options(nostack)
+    );
+
+    core::array::from_fn(|k| from_52_bit_limbs(core::array::from_fn(|i| t_limbs[i][k]), modulus))
+}
+
+/// Multiplies `lhs` by `rhs` elementwise with [`mont_mul`] while at least
+/// [`LANES`] elements remain, returning how many elements were multiplied.
+/// Nothing is multiplied if the backend is not available.
+pub(crate) fn mont_mul_assign(
+    lhs: &mut [[u64; 4]],
+    rhs: &[[u64; 4]],
+    modulus: &[u64; 4],
+    inv: u64,
+) -> usize {
+    if !is_available() {
+        return 0;
+    }
+    let mut done = 0;
+    for (a, b) in lhs.chunks_exact_mut(LANES).zip(rhs.chunks_exact(LANES)) {
+        let a: &mut [[u64; 4]; LANES] = a.try_into().unwrap();
+        // SAFETY: the CPU supports the backend.
+        *a = unsafe { mont_mul(a, b.try_into().unwrap(), modulus, inv) };
+        done += LANES;
+    }
+    done
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ff_ext::BatchMul;
+    use crate::serde::SerdeObject;
+    use ff::PrimeField;
+    use rand_core::OsRng;
+
+    fn limbs<F: SerdeObject>(e: &F) -> [u64; 4] {
+        let bytes = e.to_raw_bytes();
+        core::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap()))
+    }
+
+    fn check<F: PrimeField + SerdeObject + BatchMul>() {
+        // The modulus is one more than the canonical form of -1.
+        let mut modulus = [0u64; 4];
+        for (m, b) in modulus
+            .iter_mut()
+            .zip((-F::ONE).to_repr().as_ref().chunks(8))
+        {
+            *m = u64::from_le_bytes(b.try_into().unwrap());
+        }
+        modulus[0] += 1;
+        // -modulus^-1 mod 2^64 by Newton iteration.
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        }
+        let inv = inv.wrapping_neg();
+
+        let edge = [
+            F::ZERO,
+            F::ONE,
+            -F::ONE,
+            -F::ONE,
+            F::ONE,
+            F::ZERO,
+            -F::ONE,
+            F::ONE,
+        ];
+        for i in 0..100 {
+            let a: [F; LANES] = if i == 0 {
+                edge
+            } else {
+                core::array::from_fn(|_| F::random(OsRng))
+            };
+            let b: [F; LANES] = core::array::from_fn(|_| F::random(OsRng));
+            let expected: [[u64; 4]; LANES] = core::array::from_fn(|k| limbs(&(a[k] * b[k])));
+            let a_limbs = a.map(|e| limbs(&e));
+            let b_limbs = b.map(|e| limbs(&e));
+            // SAFETY: the CPU supports the backend.
+            let res = unsafe { mont_mul(&a_limbs, &b_limbs, &modulus, inv) };
+            assert_eq!(res, expected);
+            let res = unsafe { mont_mul(&a_limbs, &a_limbs, &modulus, inv) };
+            assert_eq!(res, a.map(|e| limbs(&e.square())));
+        }
+
+        // The batch multiplication of the field, with a remainder that does
+        // not fill the lanes.
+        let a: Vec<F> = (0..21).map(|_| F::random(OsRng)).collect();
+        let b: Vec<F> = (0..21).map(|_| F::random(OsRng)).collect();
+        let mut c = a.clone();
+        F::batch_mul_assign(&mut c, &b);
+        for ((a, b), c) in a.iter().zip(b.iter()).zip(c.iter()) {
+            assert_eq!(*a * b, *c);
+        }
+    }
+
+    #[test]
+    fn test_ifma_mont_mul() {
+        if !is_available() {
+            return;
+        }
+        check::<crate::bn256::Fr>();
+        check::<crate::bn256::Fq>();
+        check::<crate::secp256k1::Fp>();
+        check::<crate::secp256k1::Fq>();
+        check::<crate::secp256r1::Fp>();
+        check::<crate::secp256r1::Fq>();
+    }
+}
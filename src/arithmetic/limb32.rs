@@ -0,0 +1,261 @@
+//! Montgomery multiplication for 4-limb fields over 32-bit limbs.
+//!
+//! 32-bit targets have no 64 x 64 -> 128 bit multiplication, so every limb
+//! product of the portable routines is emulated with four 32-bit products and
+//! their carries. This backend splits the operands into eight 32-bit limbs,
+//! whose products are a single `u64` widening multiplication: a `mul`/`mulhu`
+//! pair on rv32, an `i64.mul` on wasm32. The carries are kept in the upper
+//! half of the `u64` accumulator, so no carry chain needs a comparison.
+//!
+//! It is used on targets whose pointers are 32 bits wide, except on wasm32
+//! with the `wasm-simd` feature, and on any target with the `force-u32`
+//! feature. It uses the same Montgomery domain `R = 2^256` as the other
+//! backends, so constants and serialized elements are shared.
+
+#[inline(always)]
+const fn to_u32_limbs(a: &[u64; 4]) -> [u32; 8] {
+    let mut r = [0u32; 8];
+    let mut i = 0;
+    while i < 8 {
+        r[i] = (a[i / 2] >> (32 * (i % 2))) as u32;
+        i += 1;
+    }
+    r
+}
+
+// Returns `t mod modulus` as 64-bit limbs for `t < 2 * modulus`, where `top`
+// is the ninth limb of `t`, which is at most one.
+#[inline(always)]
+const fn reduce_once(t: &[u32; 8], top: u32, p: &[u32; 8]) -> [u64; 4] {
+    let mut d = [0u32; 8];
+    let mut borrow = 0u32;
+    let mut j = 0;
+    while j < 8 {
+        let (s, b1) = t[j].overflowing_sub(p[j]);
+        let (s, b2) = s.overflowing_sub(borrow);
+        d[j] = s;
+        borrow = (b1 | b2) as u32;
+        j += 1;
+    }
+    // Keep `t` if the subtraction underflowed without an overflow limb.
+    let keep = 0u32.wrapping_sub(borrow & !top & 1);
+    let mut r = [0u64; 4];
+    let mut j = 0;
+    while j < 8 {
+        let l = (t[j] & keep) | (d[j] & !keep);
+        r[j / 2] |= (l as u64) << (32 * (j % 2));
+        j += 1;
+    }
+    r
+}
+
+/// Returns `a * b / 2^256 mod modulus` for `a` and `b` in Montgomery form,
+/// where `inv = -modulus^-1 mod 2^64`.
+///
+/// This is the Coarsely Integrated Operand Scanning method, and works for any
+/// odd modulus below `2^256`.
+#[inline(always)]
+pub(crate) const fn mont_mul(a: &[u64; 4], b: &[u64; 4], modulus: &[u64; 4], inv: u64) -> [u64; 4] {
+    let a = to_u32_limbs(a);
+    let b = to_u32_limbs(b);
+    let p = to_u32_limbs(modulus);
+    // `-modulus^-1 mod 2^32`.
+    let inv = inv as u32;
+
+    // The accumulator stays below `2 * modulus`, so `t[9]` is at most one.
+    let mut t = [0u32; 10];
+    let mut i = 0;
+    while i < 8 {
+        // t += a * b_i
+        let b_i = b[i] as u64;
+        let mut carry = 0u64;
+        let mut j = 0;
+        while j < 8 {
+            let s = t[j] as u64 + a[j] as u64 * b_i + carry;
+            t[j] = s as u32;
+            carry = s >> 32;
+            j += 1;
+        }
+        let s = t[8] as u64 + carry;
+        t[8] = s as u32;
+        t[9] = (s >> 32) as u32;
+
+        // t = (t + m * modulus) / 2^32, where m cancels the lowest limb.
+        let m = t[0].wrapping_mul(inv) as u64;
+        let mut carry = (t[0] as u64 + m * p[0] as u64) >> 32;
+        let mut j = 1;
+        while j < 8 {
+            let s = t[j] as u64 + m * p[j] as u64 + carry;
+            t[j - 1] = s as u32;
+            carry = s >> 32;
+            j += 1;
+        }
+        let s = t[8] as u64 + carry;
+        t[7] = s as u32;
+        t[8] = t[9] + (s >> 32) as u32;
+        i += 1;
+    }
+
+    let mut low = [0u32; 8];
+    let mut j = 0;
+    while j < 8 {
+        low[j] = t[j];
+        j += 1;
+    }
+    reduce_once(&low, t[8], &p)
+}
+
+/// Returns `a^2 / 2^256 mod modulus` for `a` in Montgomery form, where
+/// `inv = -modulus^-1 mod 2^64`.
+///
+/// The off-diagonal products are computed once and doubled, which saves 28
+/// of the 64 limb products of [`mont_mul`], before a separate Montgomery
+/// reduction of the 16-limb square.
+#[inline(always)]
+pub(crate) const fn mont_square(a: &[u64; 4], modulus: &[u64; 4], inv: u64) -> [u64; 4] {
+    let a = to_u32_limbs(a);
+    let p = to_u32_limbs(modulus);
+    let inv = inv as u32;
+
+    // The products a_i * a_j with i < j.
+    let mut t = [0u32; 16];
+    let mut i = 0;
+    while i < 7 {
+        let a_i = a[i] as u64;
+        let mut carry = 0u64;
+        let mut j = i + 1;
+        while j < 8 {
+            let s = t[i + j] as u64 + a_i * a[j] as u64 + carry;
+            t[i + j] = s as u32;
+            carry = s >> 32;
+            j += 1;
+        }
+        t[i + 8] = carry as u32;
+        i += 1;
+    }
+
+    // Doubled, which cannot overflow as they sum to less than 2^511.
+    let mut j = 15;
+    while j > 0 {
+        t[j] = (t[j] << 1) | (t[j - 1] >> 31);
+        j -= 1;
+    }
+    t[0] <<= 1;
+
+    // Plus the squares a_i^2 on the diagonal.
+    let mut carry = 0u64;
+    let mut i = 0;
+    while i < 8 {
+        let sq = a[i] as u64 * a[i] as u64;
+        let s = t[2 * i] as u64 + (sq & 0xffff_ffff) + carry;
+        t[2 * i] = s as u32;
+        let s = t[2 * i + 1] as u64 + (sq >> 32) + (s >> 32);
+        t[2 * i + 1] = s as u32;
+        carry = s >> 32;
+        i += 1;
+    }
+
+    // Montgomery reduction, where `top` carries into the limb above the
+    // current window.
+    let mut top = 0u64;
+    let mut i = 0;
+    while i < 8 {
+        let m = t[i].wrapping_mul(inv) as u64;
+        let mut carry = 0u64;
+        let mut j = 0;
+        while j < 8 {
+            let s = t[i + j] as u64 + m * p[j] as u64 + carry;
+            t[i + j] = s as u32;
+            carry = s >> 32;
+            j += 1;
+        }
+        let s = t[i + 8] as u64 + carry + top;
+        t[i + 8] = s as u32;
+        top = s >> 32;
+        i += 1;
+    }
+
+    let mut high = [0u32; 8];
+    let mut j = 0;
+    while j < 8 {
+        high[j] = t[8 + j];
+        j += 1;
+    }
+    reduce_once(&high, top as u32, &p)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{mont_mul, mont_square};
+    use crate::serde::SerdeObject;
+    use ff::PrimeField;
+    use num_bigint::BigUint;
+    use rand_core::OsRng;
+
+    fn limbs<F: SerdeObject>(e: &F) -> [u64; 4] {
+        let bytes = e.to_raw_bytes();
+        core::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap()))
+    }
+
+    fn to_big(limbs: &[u64; 4]) -> BigUint {
+        BigUint::from_slice(
+            &limbs
+                .iter()
+                .flat_map(|l| [*l as u32, (l >> 32) as u32])
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn check<F: PrimeField + SerdeObject>() {
+        // The modulus is one more than the canonical form of -1.
+        let mut modulus = [0u64; 4];
+        for (m, b) in modulus
+            .iter_mut()
+            .zip((-F::ONE).to_repr().as_ref().chunks(8))
+        {
+            *m = u64::from_le_bytes(b.try_into().unwrap());
+        }
+        modulus[0] += 1;
+        // -modulus^-1 mod 2^64 by Newton iteration.
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        }
+        let inv = inv.wrapping_neg();
+
+        // Checked against integer arithmetic, as the field multiplication may
+        // itself be one of these routines.
+        let p = to_big(&modulus);
+        let r_inv = (BigUint::from(1u8) << 256u32).modpow(&(&p - 2u32), &p);
+        let edge = [F::ZERO, F::ONE, -F::ONE];
+        for a in edge
+            .iter()
+            .copied()
+            .chain((0..1000).map(|_| F::random(OsRng)))
+        {
+            let a = limbs(&a);
+            let expected = to_big(&a) * to_big(&a) * &r_inv % &p;
+            assert_eq!(to_big(&mont_square(&a, &modulus, inv)), expected);
+            for b in edge.iter().copied().chain([F::random(OsRng)]) {
+                let b = limbs(&b);
+                let expected = to_big(&a) * to_big(&b) * &r_inv % &p;
+                assert_eq!(to_big(&mont_mul(&a, &b, &modulus, inv)), expected);
+                #[cfg(all(feature = "asm", target_arch = "riscv64"))]
+                assert_eq!(
+                    to_big(&super::super::riscv::mont_mul(&a, &b, &modulus, inv)),
+                    expected
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mont_mul() {
+        check::<crate::bn256::Fr>();
+        check::<crate::bn256::Fq>();
+        check::<crate::secp256k1::Fp>();
+        check::<crate::secp256k1::Fq>();
+        check::<crate::secp256r1::Fp>();
+        check::<crate::secp256r1::Fq>();
+    }
+}
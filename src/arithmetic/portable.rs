@@ -0,0 +1,213 @@
+//! Portable Montgomery multiplication for 4-limb fields.
+//!
+//! Every limb product is a `u128` widening multiplication, and every carry is
+//! propagated to the end, so the routines make no assumption on the modulus
+//! beyond it being odd and below `2^256`: unlike the generic arithmetic, they
+//! do not skip carries when the top bit of the modulus is clear. They use no
+//! assembly and no target intrinsics, and run in constant time as long as the
+//! target's `u128` multiplication does.
+//!
+//! With the `portable` feature, the 4-limb fields built with the generic
+//! arithmetic use them instead of the target specific backends. They are also
+//! the reference the other backends are tested against. They use the same
+//! Montgomery domain `R = 2^256` as the other backends.
+
+// Returns `a + b * c + carry` as its low and high limbs.
+#[inline(always)]
+const fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 * c as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+// Returns `t mod modulus` for `t < 2 * modulus`, where `top` is the fifth limb
+// of `t`, which is at most one.
+#[inline(always)]
+const fn reduce_once(t: &[u64; 4], top: u64, p: &[u64; 4]) -> [u64; 4] {
+    let mut d = [0u64; 4];
+    let mut borrow = 0u64;
+    let mut j = 0;
+    while j < 4 {
+        let s = (t[j] as u128).wrapping_sub(p[j] as u128 + borrow as u128);
+        d[j] = s as u64;
+        borrow = (s >> 127) as u64;
+        j += 1;
+    }
+    // Keep `t` if the subtraction underflowed without an overflow limb.
+    let keep = 0u64.wrapping_sub(borrow & !top & 1);
+    let mut r = [0u64; 4];
+    let mut j = 0;
+    while j < 4 {
+        r[j] = (t[j] & keep) | (d[j] & !keep);
+        j += 1;
+    }
+    r
+}
+
+/// Returns `a * b / 2^256 mod modulus` for `a` and `b` in Montgomery form,
+/// where `inv = -modulus^-1 mod 2^64`.
+///
+/// This is the Coarsely Integrated Operand Scanning method, and works for any
+/// odd modulus below `2^256`.
+#[inline(always)]
+pub(crate) const fn mont_mul(a: &[u64; 4], b: &[u64; 4], modulus: &[u64; 4], inv: u64) -> [u64; 4] {
+    // The accumulator stays below `2 * modulus`, so `t[5]` is at most one.
+    let mut t = [0u64; 6];
+    let mut i = 0;
+    while i < 4 {
+        // t += a * b_i
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 4 {
+            (t[j], carry) = mac(t[j], a[j], b[i], carry);
+            j += 1;
+        }
+        (t[4], t[5]) = mac(t[4], 1, carry, 0);
+
+        // t = (t + m * modulus) / 2^64, where m cancels the lowest limb.
+        let m = t[0].wrapping_mul(inv);
+        let (_, mut carry) = mac(t[0], m, modulus[0], 0);
+        let mut j = 1;
+        while j < 4 {
+            (t[j - 1], carry) = mac(t[j], m, modulus[j], carry);
+            j += 1;
+        }
+        let (s, c) = mac(t[4], 1, carry, 0);
+        t[3] = s;
+        t[4] = t[5] + c;
+        i += 1;
+    }
+
+    reduce_once(&[t[0], t[1], t[2], t[3]], t[4], modulus)
+}
+
+/// Returns `a^2 / 2^256 mod modulus` for `a` in Montgomery form, where
+/// `inv = -modulus^-1 mod 2^64`.
+///
+/// The off-diagonal products are computed once and doubled, before a
+/// separate Montgomery reduction of the 8-limb square.
+#[inline(always)]
+pub(crate) const fn mont_square(a: &[u64; 4], modulus: &[u64; 4], inv: u64) -> [u64; 4] {
+    // The products a_i * a_j with i < j.
+    let mut t = [0u64; 8];
+    let mut i = 0;
+    while i < 3 {
+        let mut carry = 0;
+        let mut j = i + 1;
+        while j < 4 {
+            (t[i + j], carry) = mac(t[i + j], a[i], a[j], carry);
+            j += 1;
+        }
+        t[i + 4] = carry;
+        i += 1;
+    }
+
+    // Doubled, which cannot overflow as they sum to less than 2^511.
+    let mut j = 7;
+    while j > 0 {
+        t[j] = (t[j] << 1) | (t[j - 1] >> 63);
+        j -= 1;
+    }
+    t[0] <<= 1;
+
+    // Plus the squares a_i^2 on the diagonal.
+    let mut carry = 0;
+    let mut i = 0;
+    while i < 4 {
+        let (lo, hi) = mac(0, a[i], a[i], 0);
+        let (s, c) = mac(t[2 * i], 1, lo, carry);
+        t[2 * i] = s;
+        (t[2 * i + 1], carry) = mac(t[2 * i + 1], 1, hi, c);
+        i += 1;
+    }
+
+    // Montgomery reduction, where `top` carries into the limb above the
+    // current window.
+    let mut top = 0;
+    let mut i = 0;
+    while i < 4 {
+        let m = t[i].wrapping_mul(inv);
+        let mut carry = 0;
+        let mut j = 0;
+        while j < 4 {
+            (t[i + j], carry) = mac(t[i + j], m, modulus[j], carry);
+            j += 1;
+        }
+        (t[i + 4], top) = mac(t[i + 4], 1, carry, top);
+        i += 1;
+    }
+
+    reduce_once(&[t[4], t[5], t[6], t[7]], top, modulus)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{mont_mul, mont_square};
+    use crate::serde::SerdeObject;
+    use ff::PrimeField;
+    use num_bigint::BigUint;
+    use rand_core::OsRng;
+
+    fn limbs<F: SerdeObject>(e: &F) -> [u64; 4] {
+        let bytes = e.to_raw_bytes();
+        core::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap()))
+    }
+
+    fn to_big(limbs: &[u64; 4]) -> BigUint {
+        BigUint::from_slice(
+            &limbs
+                .iter()
+                .flat_map(|l| [*l as u32, (l >> 32) as u32])
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    fn check<F: PrimeField + SerdeObject>() {
+        // The modulus is one more than the canonical form of -1.
+        let mut modulus = [0u64; 4];
+        for (m, b) in modulus
+            .iter_mut()
+            .zip((-F::ONE).to_repr().as_ref().chunks(8))
+        {
+            *m = u64::from_le_bytes(b.try_into().unwrap());
+        }
+        modulus[0] += 1;
+        // -modulus^-1 mod 2^64 by Newton iteration.
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        }
+        let inv = inv.wrapping_neg();
+
+        let p = to_big(&modulus);
+        let r_inv = (BigUint::from(1u8) << 256u32).modpow(&(&p - 2u32), &p);
+        let edge = [F::ZERO, F::ONE, -F::ONE];
+        for x in edge
+            .iter()
+            .copied()
+            .chain((0..1000).map(|_| F::random(OsRng)))
+        {
+            let a = limbs(&x);
+            let square = mont_square(&a, &modulus, inv);
+            assert_eq!(to_big(&square), to_big(&a) * to_big(&a) * &r_inv % &p);
+            // Against the backend the field is compiled with, whether
+            // assembly, fiat-crypto or another of the portable routines.
+            assert_eq!(square, limbs(&x.square()));
+            for y in edge.iter().copied().chain([F::random(OsRng)]) {
+                let b = limbs(&y);
+                let product = mont_mul(&a, &b, &modulus, inv);
+                assert_eq!(to_big(&product), to_big(&a) * to_big(&b) * &r_inv % &p);
+                assert_eq!(product, limbs(&(x * y)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_mont_mul() {
+        check::<crate::bn256::Fr>();
+        check::<crate::bn256::Fq>();
+        check::<crate::secp256k1::Fp>();
+        check::<crate::secp256k1::Fq>();
+        check::<crate::secp256r1::Fp>();
+        check::<crate::secp256r1::Fq>();
+    }
+}
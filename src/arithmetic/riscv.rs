@@ -0,0 +1,179 @@
+//! Montgomery multiplication for 4-limb fields tuned for rv64.
+//!
+//! RISC-V has no carry flag, so the carry chains of the portable routines are
+//! rebuilt from `sltu` comparisons. With the `asm` feature, the multiplication
+//! is written in inline assembly that keeps the accumulator in registers and
+//! pairs each `mul` with `mulhu`. rv32 uses the 32-bit limb backend of
+//! [`super::limb32`].
+//!
+//! It uses the same Montgomery domain `R = 2^256` as the other backends.
+
+/// Returns `a * b / 2^256 mod modulus` for `a` and `b` in Montgomery form,
+/// where `inv = -modulus^-1 mod 2^64`.
+///
+/// This is the Coarsely Integrated Operand Scanning method, and works for any
+/// odd modulus below `2^256`.
+#[inline(always)]
+pub(crate) fn mont_mul(a: &[u64; 4], b: &[u64; 4], modulus: &[u64; 4], inv: u64) -> [u64; 4] {
+    use core::arch::asm;
+
+    let mut r0: u64;
+    let mut r1: u64;
+    let mut r2: u64;
+    let mut r3: u64;
+    unsafe {
+        asm!(
+            // The accumulator is t0..t4, and t5 catches the carry of t4. It
+            // stays below `2 * modulus`, so t4 is at most one between steps.
+            "2:",
+            "ld {bi}, 0({b})",
+            "addi {b}, {b}, 8",
+
+            // t += a * b_i
+            "mul {lo}, {a0}, {bi}",
+            "mulhu {hi}, {a0}, {bi}",
+            "add {t0}, {t0}, {lo}",
+            "sltu {x}, {t0}, {lo}",
+            "add {c}, {hi}, {x}",
+
+            "mul {lo}, {a1}, {bi}",
+            "mulhu {hi}, {a1}, {bi}",
+            "add {lo}, {lo}, {c}",
+            "sltu {x}, {lo}, {c}",
+            "add {hi}, {hi}, {x}",
+            "add {t1}, {t1}, {lo}",
+            "sltu {x}, {t1}, {lo}",
+            "add {c}, {hi}, {x}",
+
+            "mul {lo}, {a2}, {bi}",
+            "mulhu {hi}, {a2}, {bi}",
+            "add {lo}, {lo}, {c}",
+            "sltu {x}, {lo}, {c}",
+            "add {hi}, {hi}, {x}",
+            "add {t2}, {t2}, {lo}",
+            "sltu {x}, {t2}, {lo}",
+            "add {c}, {hi}, {x}",
+
+            "mul {lo}, {a3}, {bi}",
+            "mulhu {hi}, {a3}, {bi}",
+            "add {lo}, {lo}, {c}",
+            "sltu {x}, {lo}, {c}",
+            "add {hi}, {hi}, {x}",
+            "add {t3}, {t3}, {lo}",
+            "sltu {x}, {t3}, {lo}",
+            "add {c}, {hi}, {x}",
+
+            "add {t4}, {t4}, {c}",
+            "sltu {t5}, {t4}, {c}",
+
+            // m = t0 * -modulus^-1 mod 2^64
+            "mul {m}, {t0}, {inv}",
+
+            // t = (t + m * modulus) / 2^64, the low limb cancelling out
+            "mul {lo}, {m}, {p0}",
+            "mulhu {hi}, {m}, {p0}",
+            "add {lo}, {lo}, {t0}",
+            "sltu {x}, {lo}, {t0}",
+            "add {c}, {hi}, {x}",
+
+            "mul {lo}, {m}, {p1}",
+            "mulhu {hi}, {m}, {p1}",
+            "add {lo}, {lo}, {c}",
+            "sltu {x}, {lo}, {c}",
+            "add {hi}, {hi}, {x}",
+            "add {t0}, {t1}, {lo}",
+            "sltu {x}, {t0}, {lo}",
+            "add {c}, {hi}, {x}",
+
+            "mul {lo}, {m}, {p2}",
+            "mulhu {hi}, {m}, {p2}",
+            "add {lo}, {lo}, {c}",
+            "sltu {x}, {lo}, {c}",
+            "add {hi}, {hi}, {x}",
+            "add {t1}, {t2}, {lo}",
+            "sltu {x}, {t1}, {lo}",
+            "add {c}, {hi}, {x}",
+
+            "mul {lo}, {m}, {p3}",
+            "mulhu {hi}, {m}, {p3}",
+            "add {lo}, {lo}, {c}",
+            "sltu {x}, {lo}, {c}",
+            "add {hi}, {hi}, {x}",
+            "add {t2}, {t3}, {lo}",
+            "sltu {x}, {t2}, {lo}",
+            "add {c}, {hi}, {x}",
+
+            "add {t3}, {t4}, {c}",
+            "sltu {x}, {t3}, {c}",
+            "add {t4}, {t5}, {x}",
+
+            "addi {n}, {n}, -1",
+            "bnez {n}, 2b",
+
+            // d = t - modulus, with the borrow in c
+            "sltu {c}, {t0}, {p0}",
+            "sub {a0}, {t0}, {p0}",
+
+            "sltu {x}, {t1}, {p1}",
+            "sub {a1}, {t1}, {p1}",
+            "sltu {hi}, {a1}, {c}",
+            "sub {a1}, {a1}, {c}",
+            "or {c}, {x}, {hi}",
+
+            "sltu {x}, {t2}, {p2}",
+            "sub {a2}, {t2}, {p2}",
+            "sltu {hi}, {a2}, {c}",
+            "sub {a2}, {a2}, {c}",
+            "or {c}, {x}, {hi}",
+
+            "sltu {x}, {t3}, {p3}",
+            "sub {a3}, {t3}, {p3}",
+            "sltu {hi}, {a3}, {c}",
+            "sub {a3}, {a3}, {c}",
+            "or {c}, {x}, {hi}",
+
+            // Keep t if the subtraction borrowed and t4 is zero, through
+            // the mask -(t4 < borrow).
+            "sltu {c}, {t4}, {c}",
+            "neg {c}, {c}",
+            "xor {x}, {t0}, {a0}",
+            "and {x}, {x}, {c}",
+            "xor {t0}, {a0}, {x}",
+            "xor {x}, {t1}, {a1}",
+            "and {x}, {x}, {c}",
+            "xor {t1}, {a1}, {x}",
+            "xor {x}, {t2}, {a2}",
+            "and {x}, {x}, {c}",
+            "xor {t2}, {a2}, {x}",
+            "xor {x}, {t3}, {a3}",
+            "and {x}, {x}, {c}",
+            "xor {t3}, {a3}, {x}",
+
+            a0 = inout(reg) a[0] => _,
+            a1 = inout(reg) a[1] => _,
+            a2 = inout(reg) a[2] => _,
+            a3 = inout(reg) a[3] => _,
+            b = inout(reg) b.as_ptr() => _,
+            n = inout(reg) 4u64 => _,
+            p0 = in(reg) modulus[0],
+            p1 = in(reg) modulus[1],
+            p2 = in(reg) modulus[2],
+            p3 = in(reg) modulus[3],
+            inv = in(reg) inv,
+            t0 = inout(reg) 0u64 => r0,
+            t1 = inout(reg) 0u64 => r1,
+            t2 = inout(reg) 0u64 => r2,
+            t3 = inout(reg) 0u64 => r3,
+            t4 = inout(reg) 0u64 => _,
+            t5 = out(reg) _,
+            bi = out(reg) _,
+            m = out(reg) _,
+            c = out(reg) _,
+            lo = out(reg) _,
+            hi = out(reg) _,
+            x = out(reg) _,
+            options(pure, readonly, nostack)
+        );
+    }
+    [r0, r1, r2, r3]
+}
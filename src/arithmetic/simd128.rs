@@ -0,0 +1,200 @@
+//! Montgomery multiplication for 4-limb fields with wasm SIMD128.
+//!
+//! wasm32 has no 64 x 64 -> 128 bit multiplication, so every limb product of
+//! the portable routines costs several 64-bit multiplications. This backend
+//! works on 32-bit limbs instead, whose products are native, and computes two
+//! of them per `i64x2.extmul_{low,high}_i32x4_u` instruction. It uses the same
+//! Montgomery domain `R = 2^256` as the other backends, so constants and
+//! serialized elements are shared.
+//!
+//! Other targets use a scalar model of the lanes, which lets the algorithm be
+//! tested natively.
+
+// Four 32-bit lanes, as in a `v128`.
+#[derive(Clone, Copy)]
+struct U32x4(
+    #[cfg(all(target_arch = "wasm32", target_feature = "simd128"))] core::arch::wasm32::v128,
+    #[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))] [u32; 4],
+);
+
+#[cfg(all(target_arch = "wasm32", target_feature = "simd128"))]
+impl U32x4 {
+    #[inline(always)]
+    fn new(x: &[u32]) -> Self {
+        Self(core::arch::wasm32::u32x4(x[0], x[1], x[2], x[3]))
+    }
+
+    #[inline(always)]
+    fn splat(x: u32) -> Self {
+        Self(core::arch::wasm32::u32x4_splat(x))
+    }
+
+    // Returns the lanewise `self * rhs + acc` as 64-bit integers, which
+    // cannot overflow.
+    #[inline(always)]
+    fn mul_add_wide(self, rhs: Self, acc: Self) -> [u64; 4] {
+        use core::arch::wasm32::*;
+
+        let lo = u64x2_add(
+            u64x2_extmul_low_u32x4(self.0, rhs.0),
+            u64x2_extend_low_u32x4(acc.0),
+        );
+        let hi = u64x2_add(
+            u64x2_extmul_high_u32x4(self.0, rhs.0),
+            u64x2_extend_high_u32x4(acc.0),
+        );
+        [
+            u64x2_extract_lane::<0>(lo),
+            u64x2_extract_lane::<1>(lo),
+            u64x2_extract_lane::<0>(hi),
+            u64x2_extract_lane::<1>(hi),
+        ]
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_feature = "simd128")))]
+impl U32x4 {
+    #[inline(always)]
+    fn new(x: &[u32]) -> Self {
+        Self([x[0], x[1], x[2], x[3]])
+    }
+
+    #[inline(always)]
+    fn splat(x: u32) -> Self {
+        Self([x; 4])
+    }
+
+    #[inline(always)]
+    fn mul_add_wide(self, rhs: Self, acc: Self) -> [u64; 4] {
+        core::array::from_fn(|i| self.0[i] as u64 * rhs.0[i] as u64 + acc.0[i] as u64)
+    }
+}
+
+#[inline(always)]
+fn to_u32_limbs(a: &[u64; 4]) -> [u32; 8] {
+    core::array::from_fn(|i| (a[i / 2] >> (32 * (i % 2))) as u32)
+}
+
+/// Returns `a * b / 2^256 mod modulus` for `a` and `b` in Montgomery form,
+/// where `inv = -modulus^-1 mod 2^64`.
+///
+/// This is the Coarsely Integrated Operand Scanning method over 32-bit limbs,
+/// and works for any odd modulus below `2^256`.
+#[inline]
+pub(crate) fn mont_mul(a: &[u64; 4], b: &[u64; 4], modulus: &[u64; 4], inv: u64) -> [u64; 4] {
+    let a = to_u32_limbs(a);
+    let b = to_u32_limbs(b);
+    let p = to_u32_limbs(modulus);
+    // `-modulus^-1 mod 2^32`.
+    let inv = inv as u32;
+
+    let (a_lo, a_hi) = (U32x4::new(&a[..4]), U32x4::new(&a[4..]));
+    let (p_lo, p_hi) = (U32x4::new(&p[..4]), U32x4::new(&p[4..]));
+
+    // The accumulator stays below `2 * modulus`, so `t[9]` is at most one.
+    let mut t = [0u32; 10];
+    for b_i in b {
+        // t += a * b_i
+        let b_i = U32x4::splat(b_i);
+        let (t_lo, t_hi) = (U32x4::new(&t[..4]), U32x4::new(&t[4..8]));
+        let lo = a_lo.mul_add_wide(b_i, t_lo);
+        let hi = a_hi.mul_add_wide(b_i, t_hi);
+        let mut carry = 0u64;
+        for (t_j, s) in t.iter_mut().zip(lo.into_iter().chain(hi)) {
+            let s = s + carry;
+            *t_j = s as u32;
+            carry = s >> 32;
+        }
+        let s = t[8] as u64 + carry;
+        t[8] = s as u32;
+        t[9] = (s >> 32) as u32;
+
+        // t = (t + m * modulus) / 2^32, where m cancels the lowest limb.
+        let m = U32x4::splat(t[0].wrapping_mul(inv));
+        let (t_lo, t_hi) = (U32x4::new(&t[..4]), U32x4::new(&t[4..8]));
+        let lo = p_lo.mul_add_wide(m, t_lo);
+        let hi = p_hi.mul_add_wide(m, t_hi);
+        let mut carry = lo[0] >> 32;
+        for (j, s) in lo.into_iter().chain(hi).enumerate().skip(1) {
+            let s = s + carry;
+            t[j - 1] = s as u32;
+            carry = s >> 32;
+        }
+        let s = t[8] as u64 + carry;
+        t[7] = s as u32;
+        t[8] = t[9] + (s >> 32) as u32;
+    }
+
+    // Subtract the modulus if the result is not below it.
+    let mut r = [0u64; 4];
+    let mut borrow = 0u64;
+    for (i, r_i) in r.iter_mut().enumerate() {
+        let t_i = t[2 * i] as u64 | (t[2 * i + 1] as u64) << 32;
+        let (d, b1) = t_i.overflowing_sub(modulus[i]);
+        let (d, b2) = d.overflowing_sub(borrow);
+        *r_i = d;
+        borrow = (b1 | b2) as u64;
+    }
+    // Keep `t` if the subtraction underflowed without an overflow limb.
+    let keep = 0u64.wrapping_sub(borrow & !(t[8] as u64) & 1);
+    for (i, r_i) in r.iter_mut().enumerate() {
+        let t_i = t[2 * i] as u64 | (t[2 * i + 1] as u64) << 32;
+        *r_i = (t_i & keep) | (*r_i & !keep);
+    }
+    r
+}
+
+#[cfg(test)]
+mod test {
+    use super::mont_mul;
+    use crate::serde::SerdeObject;
+    use ff::PrimeField;
+    use rand_core::OsRng;
+
+    fn limbs<F: SerdeObject>(e: &F) -> [u64; 4] {
+        let bytes = e.to_raw_bytes();
+        core::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * i + 8].try_into().unwrap()))
+    }
+
+    fn check<F: PrimeField + SerdeObject>() {
+        // The modulus is one more than the canonical form of -1.
+        let mut modulus = [0u64; 4];
+        for (m, b) in modulus
+            .iter_mut()
+            .zip((-F::ONE).to_repr().as_ref().chunks(8))
+        {
+            *m = u64::from_le_bytes(b.try_into().unwrap());
+        }
+        modulus[0] += 1;
+        // -modulus^-1 mod 2^64 by Newton iteration.
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(modulus[0].wrapping_mul(inv)));
+        }
+        let inv = inv.wrapping_neg();
+
+        let edge = [F::ZERO, F::ONE, -F::ONE];
+        for a in edge
+            .iter()
+            .copied()
+            .chain((0..1000).map(|_| F::random(OsRng)))
+        {
+            for b in edge.iter().copied().chain([F::random(OsRng)]) {
+                assert_eq!(
+                    mont_mul(&limbs(&a), &limbs(&b), &modulus, inv),
+                    limbs(&(a * b))
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_mont_mul() {
+        check::<crate::bn256::Fr>();
+        check::<crate::bn256::Fq>();
+        check::<crate::secp256k1::Fp>();
+        check::<crate::secp256k1::Fq>();
+        check::<crate::secp256r1::Fp>();
+        check::<crate::secp256r1::Fq>();
+    }
+}
@@ -0,0 +1,207 @@
+//! Conversions between the types of this module and their counterparts in
+//! `ark-bn254`.
+//!
+//! Field elements convert in both directions with `From`. Points convert to
+//! arkworks with `From` and back with `TryFrom`, as arkworks allows
+//! constructing points that are not on the curve or not in the prime order
+//! subgroup.
+
+use super::{Fq, Fq2, Fr, G1Affine, G2Affine};
+use crate::group::prime::PrimeCurveAffine;
+use crate::subgroup::TorsionFreeAffine;
+use crate::CurveAffine;
+use ark_ff::{BigInt, PrimeField};
+
+/// Error returned when converting an arkworks point that is not a valid
+/// element of the corresponding group.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArkConversionError {
+    /// The coordinates do not define a point on the curve.
+    NotOnCurve,
+    /// The point is on the curve but not in the prime order subgroup.
+    NotInSubgroup,
+}
+
+impl core::fmt::Display for ArkConversionError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::NotOnCurve => write!(f, "point is not on the curve"),
+            Self::NotInSubgroup => {
+                write!(f, "point is not in the prime order subgroup of the curve")
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArkConversionError {}
+
+macro_rules! impl_ark_prime_field {
+    ($field:ident, $ark:ty) => {
+        impl From<$field> for $ark {
+            fn from(e: $field) -> Self {
+                let limbs: [u64; 4] = e.into();
+                <$ark>::from_bigint(BigInt::new(limbs)).unwrap()
+            }
+        }
+
+        impl From<$ark> for $field {
+            fn from(e: $ark) -> Self {
+                $field::from_raw(e.into_bigint().0)
+            }
+        }
+    };
+}
+
+impl_ark_prime_field!(Fr, ark_bn254::Fr);
+impl_ark_prime_field!(Fq, ark_bn254::Fq);
+
+impl From<Fq2> for ark_bn254::Fq2 {
+    fn from(e: Fq2) -> Self {
+        ark_bn254::Fq2::new(e.c0.into(), e.c1.into())
+    }
+}
+
+impl From<ark_bn254::Fq2> for Fq2 {
+    fn from(e: ark_bn254::Fq2) -> Self {
+        Fq2 {
+            c0: e.c0.into(),
+            c1: e.c1.into(),
+        }
+    }
+}
+
+macro_rules! impl_ark_affine {
+    ($affine:ident, $ark:ty) => {
+        impl From<$affine> for $ark {
+            fn from(p: $affine) -> Self {
+                if bool::from(p.is_identity()) {
+                    <$ark>::identity()
+                } else {
+                    <$ark>::new_unchecked(p.x.into(), p.y.into())
+                }
+            }
+        }
+
+        impl TryFrom<$ark> for $affine {
+            type Error = ArkConversionError;
+
+            fn try_from(p: $ark) -> Result<Self, Self::Error> {
+                if p.infinity {
+                    return Ok($affine::identity());
+                }
+                let p = Option::<$affine>::from($affine::from_xy(p.x.into(), p.y.into()))
+                    .ok_or(ArkConversionError::NotOnCurve)?;
+                if bool::from(TorsionFreeAffine::is_torsion_free(&p)) {
+                    Ok(p)
+                } else {
+                    Err(ArkConversionError::NotInSubgroup)
+                }
+            }
+        }
+    };
+}
+
+impl_ark_affine!(G1Affine, ark_bn254::G1Affine);
+impl_ark_affine!(G2Affine, ark_bn254::G2Affine);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{G1, G2};
+    use crate::ff::Field;
+    use crate::group::Curve;
+    use crate::serde::arkworks::{ArkworksSerde, Compress};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_serialize::CanonicalSerialize;
+    use rand_core::OsRng;
+
+    fn ark_bytes<T: CanonicalSerialize>(t: &T, compress: Compress) -> Vec<u8> {
+        let mut bytes = vec![];
+        match compress {
+            Compress::Yes => t.serialize_compressed(&mut bytes).unwrap(),
+            Compress::No => t.serialize_uncompressed(&mut bytes).unwrap(),
+        }
+        bytes
+    }
+
+    #[test]
+    fn test_ark_fields() {
+        for _ in 0..100 {
+            let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+            let (ark_a, ark_b) = (ark_bn254::Fr::from(a), ark_bn254::Fr::from(b));
+            assert_eq!(Fr::from(ark_a * ark_b), a * b);
+            assert_eq!(Fr::from(ark_a + ark_b), a + b);
+
+            let e = Fq2::random(OsRng);
+            let ark_e = ark_bn254::Fq2::from(e);
+            assert_eq!(Fq2::from(ark_e * ark_e), e.square());
+            assert_eq!(
+                ark_bytes(&ark_e, Compress::Yes),
+                e.to_arkworks_bytes(Compress::Yes)
+            );
+        }
+        assert_eq!(ark_bn254::Fq::from(Fq::ONE), ark_bn254::Fq::from(1u64));
+        assert_eq!(Fq::from(-ark_bn254::Fq::from(1u64)), -Fq::ONE);
+    }
+
+    #[test]
+    fn test_ark_points() {
+        assert_eq!(
+            ark_bn254::G1Affine::from(G1Affine::generator()),
+            ark_bn254::G1Affine::generator()
+        );
+        assert_eq!(
+            ark_bn254::G2Affine::from(G2Affine::generator()),
+            ark_bn254::G2Affine::generator()
+        );
+        assert!(ark_bn254::G1Affine::from(G1Affine::identity()).infinity);
+        assert!(bool::from(
+            G2Affine::try_from(ark_bn254::G2Affine::identity())
+                .unwrap()
+                .is_identity()
+        ));
+
+        for _ in 0..10 {
+            let k = Fr::random(OsRng);
+            let p = (G1::generator() * k).to_affine();
+            let q = (G2::generator() * k).to_affine();
+            let ark_k = ark_bn254::Fr::from(k);
+            let ark_p = (ark_bn254::G1Affine::generator() * ark_k).into_affine();
+            let ark_q = (ark_bn254::G2Affine::generator() * ark_k).into_affine();
+
+            assert_eq!(ark_bn254::G1Affine::from(p), ark_p);
+            assert_eq!(ark_bn254::G2Affine::from(q), ark_q);
+            assert_eq!(G1Affine::try_from(ark_p).unwrap(), p);
+            assert_eq!(G2Affine::try_from(ark_q).unwrap(), q);
+
+            for compress in [Compress::Yes, Compress::No] {
+                assert_eq!(ark_bytes(&ark_p, compress), p.to_arkworks_bytes(compress));
+                assert_eq!(ark_bytes(&ark_q, compress), q.to_arkworks_bytes(compress));
+            }
+        }
+
+        let invalid = ark_bn254::G1Affine::new_unchecked(1u64.into(), 3u64.into());
+        assert_eq!(
+            G1Affine::try_from(invalid),
+            Err(ArkConversionError::NotOnCurve)
+        );
+
+        // A point on the twist outside the prime order subgroup.
+        let q = loop {
+            let x = Fq2::random(OsRng);
+            if let Some(y) = Option::<Fq2>::from((x.square() * x + G2Affine::b()).sqrt()) {
+                let q = G2Affine { x, y };
+                if !bool::from(q.is_torsion_free()) {
+                    break q;
+                }
+            }
+        };
+        let ark_q = ark_bn254::G2Affine::from(q);
+        assert!(ark_q.is_on_curve());
+        assert_eq!(
+            G2Affine::try_from(ark_q),
+            Err(ArkConversionError::NotInSubgroup)
+        );
+    }
+}
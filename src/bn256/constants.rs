@@ -0,0 +1,293 @@
+//! Constants of the `bn256` fields and curves.
+//!
+//! Generated by `script/constants.py` from `script/params/bn256.toml`, do not
+//! modify. Building with the `generate-constants` feature regenerates it.
+
+use crate::arithmetic::EndoParameters;
+use crate::bn256::Fq;
+
+pub(crate) mod fq {
+    use crate::bn256::Fq;
+
+    /// Constant representing the modulus
+    /// `0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47`
+    pub(crate) const MODULUS: Fq = Fq([
+        0x3c208c16d87cfd47,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+
+    /// The modulus as u32 limbs.
+    #[cfg(not(target_pointer_width = "64"))]
+    pub(crate) const MODULUS_LIMBS_32: [u32; 8] = [
+        0xd87c_fd47,
+        0x3c20_8c16,
+        0x6871_ca8d,
+        0x9781_6a91,
+        0x8181_585d,
+        0xb850_45b6,
+        0xe131_a029,
+        0x3064_4e72,
+    ];
+
+    /// Constant representing the modulus as static str
+    pub(crate) const MODULUS_STR: &str =
+        "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
+
+    /// INV = -(p^{-1} mod 2^64) mod 2^64
+    pub(crate) const INV: u64 = 0x87d20782e4866389;
+
+    /// R = 2^256 mod p
+    pub(crate) const R: Fq = Fq([
+        0xd35d438dc58f0d9d,
+        0x0a78eb28f5c70b3d,
+        0x666ea36f7879462c,
+        0x0e0a77c19a07df2f,
+    ]);
+
+    /// R2 = 2^512 mod p
+    pub(crate) const R2: Fq = Fq([
+        0xf32cfc5b538afa89,
+        0xb5e71911d44501fb,
+        0x47ab1eff0a417ff6,
+        0x06d89f71cab8351f,
+    ]);
+
+    /// R3 = 2^768 mod p
+    pub(crate) const R3: Fq = Fq([
+        0xb1cd6dafda1530df,
+        0x62f210e6a7283db6,
+        0xef7f0b0c0ada0afb,
+        0x20fd6e902d592544,
+    ]);
+
+    /// A generator of the multiplicative group of the field.
+    /// `0x3`
+    pub(crate) const MULTIPLICATIVE_GENERATOR: Fq = Fq::from_raw([
+        0x0000000000000003,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// The largest `S` such that `2^S` divides `p - 1`.
+    pub(crate) const S: u32 = 1;
+
+    /// MULTIPLICATIVE_GENERATOR^t where t * 2^S + 1 = p with t odd, a 2^S root of unity.
+    /// `0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46`
+    pub(crate) const ROOT_OF_UNITY: Fq = Fq::from_raw([
+        0x3c208c16d87cfd46,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+
+    /// 1 / ROOT_OF_UNITY mod p
+    /// `0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46`
+    pub(crate) const ROOT_OF_UNITY_INV: Fq = Fq::from_raw([
+        0x3c208c16d87cfd46,
+        0x97816a916871ca8d,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+
+    /// 1 / 2 mod p
+    /// `0x183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea4`
+    pub(crate) const TWO_INV: Fq = Fq::from_raw([
+        0x9e10460b6c3e7ea4,
+        0xcbc0b548b438e546,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+
+    /// MULTIPLICATIVE_GENERATOR^{2^S}, a t root of unity.
+    /// `0x9`
+    pub(crate) const DELTA: Fq = Fq::from_raw([
+        0x0000000000000009,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// `ZETA^3 = 1 mod p` where `ZETA != 1 mod p`
+    /// `0x30644e72e131a0295e6dd9e7e0acccb0c28f069fbb966e3de4bd44e5607cfd48`
+    pub(crate) const ZETA: Fq = Fq::from_raw([
+        0xe4bd44e5607cfd48,
+        0xc28f069fbb966e3d,
+        0x5e6dd9e7e0acccb0,
+        0x30644e72e131a029,
+    ]);
+}
+
+pub(crate) mod fr {
+    use crate::bn256::Fr;
+
+    /// Constant representing the modulus
+    /// `0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001`
+    pub(crate) const MODULUS: Fr = Fr([
+        0x43e1f593f0000001,
+        0x2833e84879b97091,
+        0xb85045b68181585d,
+        0x30644e72e131a029,
+    ]);
+
+    /// The modulus as u32 limbs.
+    #[cfg(not(target_pointer_width = "64"))]
+    pub(crate) const MODULUS_LIMBS_32: [u32; 8] = [
+        0xf000_0001,
+        0x43e1_f593,
+        0x79b9_7091,
+        0x2833_e848,
+        0x8181_585d,
+        0xb850_45b6,
+        0xe131_a029,
+        0x3064_4e72,
+    ];
+
+    /// Constant representing the modulus as static str
+    pub(crate) const MODULUS_STR: &str =
+        "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+
+    /// INV = -(p^{-1} mod 2^64) mod 2^64
+    pub(crate) const INV: u64 = 0xc2e1f593efffffff;
+
+    /// R = 2^256 mod p
+    pub(crate) const R: Fr = Fr([
+        0xac96341c4ffffffb,
+        0x36fc76959f60cd29,
+        0x666ea36f7879462e,
+        0x0e0a77c19a07df2f,
+    ]);
+
+    /// R2 = 2^512 mod p
+    pub(crate) const R2: Fr = Fr([
+        0x1bb8e645ae216da7,
+        0x53fe3ab1e35c59e3,
+        0x8c49833d53bb8085,
+        0x0216d0b17f4e44a5,
+    ]);
+
+    /// R3 = 2^768 mod p
+    pub(crate) const R3: Fr = Fr([
+        0x5e94d8e1b4bf0040,
+        0x2a489cbe1cfbb6b8,
+        0x893cc664a19fcfed,
+        0x0cf8594b7fcc657c,
+    ]);
+
+    /// A generator of the multiplicative group of the field.
+    /// `0x7`
+    pub(crate) const MULTIPLICATIVE_GENERATOR: Fr = Fr::from_raw([
+        0x0000000000000007,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// The largest `S` such that `2^S` divides `p - 1`.
+    pub(crate) const S: u32 = 28;
+
+    /// MULTIPLICATIVE_GENERATOR^t where t * 2^S + 1 = p with t odd, a 2^S root of unity.
+    /// `0x3ddb9f5166d18b798865ea93dd31f743215cf6dd39329c8d34f1ed960c37c9c`
+    pub(crate) const ROOT_OF_UNITY: Fr = Fr::from_raw([
+        0xd34f1ed960c37c9c,
+        0x3215cf6dd39329c8,
+        0x98865ea93dd31f74,
+        0x03ddb9f5166d18b7,
+    ]);
+
+    /// 1 / ROOT_OF_UNITY mod p
+    /// `0x48127174daabc261bbe587180f34361b22625f59115aba70ed3e50a414e6dba`
+    pub(crate) const ROOT_OF_UNITY_INV: Fr = Fr::from_raw([
+        0x0ed3e50a414e6dba,
+        0xb22625f59115aba7,
+        0x1bbe587180f34361,
+        0x048127174daabc26,
+    ]);
+
+    /// 1 / 2 mod p
+    /// `0x183227397098d014dc2822db40c0ac2e9419f4243cdcb848a1f0fac9f8000001`
+    pub(crate) const TWO_INV: Fr = Fr::from_raw([
+        0xa1f0fac9f8000001,
+        0x9419f4243cdcb848,
+        0xdc2822db40c0ac2e,
+        0x183227397098d014,
+    ]);
+
+    /// MULTIPLICATIVE_GENERATOR^{2^S}, a t root of unity.
+    /// `0x9226b6e22c6f0ca64ec26aad4c86e715b5f898e5e963f25870e56bbe533e9a2`
+    pub(crate) const DELTA: Fr = Fr::from_raw([
+        0x870e56bbe533e9a2,
+        0x5b5f898e5e963f25,
+        0x64ec26aad4c86e71,
+        0x09226b6e22c6f0ca,
+    ]);
+
+    /// `ZETA^3 = 1 mod p` where `ZETA != 1 mod p`
+    /// `0x30644e72e131a029048b6e193fd84104cc37a73fec2bc5e9b8ca0b2d36636f23`
+    pub(crate) const ZETA: Fr = Fr::from_raw([
+        0xb8ca0b2d36636f23,
+        0xcc37a73fec2bc5e9,
+        0x048b6e193fd84104,
+        0x30644e72e131a029,
+    ]);
+}
+
+pub(crate) const G1_GENERATOR_X: Fq = Fq::from_raw([
+    0x0000000000000001,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const G1_GENERATOR_Y: Fq = Fq::from_raw([
+    0x0000000000000002,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const G1_A: Fq = Fq::from_raw([
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const G1_B: Fq = Fq::from_raw([
+    0x0000000000000003,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+/// `gamma_i = floor(b_i * 2^256 / r)` for the short basis `b_1`, `b_2` of the
+/// lattice of the decompositions of zero, with
+/// `lambda = 0x30644e72e131a029048b6e193fd84104cc37a73fec2bc5e9b8ca0b2d36636f23`.
+pub(crate) const ENDO_PARAMS_BN: EndoParameters = EndoParameters {
+    gamma1: [
+        0xd91d232ec7e0b3d7,
+        0x0000000000000002,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    gamma2: [
+        0x5398fd0300ff6565,
+        0x4ccef014a773d2d2,
+        0x0000000000000002,
+        0x0000000000000000,
+    ],
+    b1: [
+        0x89d3256894d213e3,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    b2: [
+        0x0be4e1541221250b,
+        0x6f4d8248eeb859fd,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+};
@@ -5,15 +5,19 @@ use crate::bn256::fq12::*;
 use crate::bn256::fq2::*;
 use crate::bn256::fq6::FROBENIUS_COEFF_FQ6_C1;
 use crate::bn256::fr::*;
+use crate::bn256::torus::{GtCompressed, GtTorus};
 use crate::ff::{Field, PrimeField};
 use crate::group::cofactor::CofactorCurveAffine;
-use crate::group::Group;
+use crate::group::{Group, GroupEncoding};
+use crate::impl_pairing_engine;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::iter::Sum;
-use core::ops::{Add, Mul, MulAssign, Neg, Sub};
-use pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub};
+use pairing::MillerLoopResult;
 use rand_core::RngCore;
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 pub const BN_X: u64 = 4965661367192848881;
 
@@ -39,29 +43,20 @@ pub const XI_TO_Q_MINUS_1_OVER_2: Fq2 = Fq2 {
     ]),
 };
 
-impl PairingCurveAffine for G1Affine {
-    type Pair = G2Affine;
-    type PairingResult = Gt;
-
-    fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
-        pairing(self, other)
-    }
-}
-
-impl PairingCurveAffine for G2Affine {
-    type Pair = G1Affine;
-    type PairingResult = Gt;
+#[derive(Copy, Clone, Debug)]
+pub struct Gt(pub(crate) Fq12);
 
-    fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
-        pairing(other, self)
+/// The default element is the identity, not zero.
+impl Default for Gt {
+    fn default() -> Self {
+        Self::identity()
     }
 }
 
-#[derive(Copy, Clone, Debug, Default)]
-pub struct Gt(pub(crate) Fq12);
+crate::__impl_zeroize!(Gt);
 
-impl std::fmt::Display for Gt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Gt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{self:?}")
     }
 }
@@ -88,13 +83,93 @@ impl PartialEq for Gt {
 
 impl Gt {
     /// Returns the group identity, which is $1$.
-    pub fn identity() -> Gt {
+    pub const fn identity() -> Gt {
         Gt(Fq12::ONE)
     }
 
+    /// Returns the fixed generator of the group, `e(G1, G2)` for the
+    /// generators of G1 and G2.
+    pub fn generator() -> Gt {
+        *GT_GENERATOR
+    }
+
+    /// Returns whether `self` is the identity.
+    pub fn is_identity(&self) -> Choice {
+        self.ct_eq(&Self::identity())
+    }
+
     /// Doubles this group element.
+    ///
+    /// Uses cyclotomic squaring, which is valid since `Gt` lies in the
+    /// cyclotomic subgroup of Fq12. Miller loop outputs, which do not, are
+    /// kept apart as [`MillerLoopOutput`].
     pub fn double(&self) -> Gt {
-        Gt(self.0.square())
+        let mut res = self.0;
+        res.cyclotomic_square();
+        Gt(res)
+    }
+
+    /// Returns whether `self` lies in the order `r` subgroup of the
+    /// multiplicative group of Fq12, i.e. `self^r = 1`.
+    pub fn is_torsion_free(&self) -> Choice {
+        let r_minus_one: [u64; 4] = (-Fr::ONE).into();
+        (self.0.pow_vartime(r_minus_one) * self.0).ct_eq(&Fq12::ONE)
+    }
+}
+
+lazy_static::lazy_static! {
+    static ref GT_GENERATOR: Gt = pairing(&G1Affine::generator(), &G2Affine::generator());
+}
+
+impl GroupEncoding for Gt {
+    type Repr = GtCompressed;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        Self::from_bytes_unchecked(bytes).and_then(|f| CtOption::new(f, f.is_torsion_free()))
+    }
+
+    /// Decodes a compressed element without checking that it lies in the
+    /// order `r` subgroup.
+    ///
+    /// The result is always unitary, but it may be of the wrong order if the
+    /// input was not produced by [`GroupEncoding::to_bytes`].
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        GtTorus::from_bytes(bytes).map(|g| g.decompress())
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        GtTorus::from(*self).to_bytes()
+    }
+}
+
+impl crate::serde::SerdeObject for Gt {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        Gt(Fq12::from_raw_bytes_unchecked(bytes))
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        Fq12::from_raw_bytes(bytes)
+            .map(Gt)
+            .filter(|f| bool::from(f.is_torsion_free()))
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        self.0.to_raw_bytes()
+    }
+    #[cfg(feature = "std")]
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        Gt(Fq12::read_raw_unchecked(reader))
+    }
+    #[cfg(feature = "std")]
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let res = Gt(Fq12::read_raw(reader)?);
+        bool::from(res.is_torsion_free())
+            .then_some(res)
+            .ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::InvalidData, "element is not in Gt")
+            })
+    }
+    #[cfg(feature = "std")]
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.0.write_raw(writer)
     }
 }
 
@@ -110,14 +185,7 @@ impl<'a> Neg for &'a Gt {
     }
 }
 
-impl Neg for Gt {
-    type Output = Gt;
-
-    #[inline]
-    fn neg(self) -> Gt {
-        -&self
-    }
-}
+crate::impl_neg!(Gt);
 
 impl<'a, 'b> Add<&'b Gt> for &'a Gt {
     type Output = Gt;
@@ -177,11 +245,13 @@ where
     }
 }
 
+crate::impl_standard_distribution!(Gt, crate::group::Group);
+
 impl Group for Gt {
     type Scalar = Fr;
 
-    fn random(_: impl RngCore) -> Self {
-        unimplemented!();
+    fn random(rng: impl RngCore) -> Self {
+        Self::generator() * Fr::random(rng)
     }
 
     fn identity() -> Self {
@@ -189,11 +259,11 @@ impl Group for Gt {
     }
 
     fn generator() -> Self {
-        unimplemented!();
+        Self::generator()
     }
 
     fn is_identity(&self) -> Choice {
-        self.ct_eq(&Self::identity())
+        self.is_identity()
     }
 
     #[must_use]
@@ -202,6 +272,13 @@ impl Group for Gt {
     }
 }
 
+/// A G2 point with the line function coefficients of its Miller loop
+/// precomputed.
+///
+/// Preparing a point does all the G2 arithmetic of the Miller loop, so a
+/// verifier that pairs repeatedly against the same point, such as a fixed
+/// verification key, should prepare it once and reuse it with
+/// [`pairing_prepared`] or [`multi_miller_loop`].
 #[derive(Clone, Debug)]
 pub struct G2Prepared {
     pub(crate) coeffs: Vec<(Fq2, Fq2, Fq2)>,
@@ -209,10 +286,12 @@ pub struct G2Prepared {
 }
 
 impl G2Prepared {
+    /// Returns whether the prepared point is the identity.
     pub fn is_zero(&self) -> bool {
         self.infinity
     }
 
+    /// Precomputes the line function coefficients of `q`.
     pub fn from_affine(q: G2Affine) -> Self {
         if bool::from(q.is_identity()) {
             return G2Prepared {
@@ -454,9 +533,75 @@ impl From<G2Affine> for G2Prepared {
     }
 }
 
-impl MillerLoopResult for Gt {
-    type Gt = Self;
-    // pub fn final_exponentiation(r: &Fq12) -> CtOption<Fq12> {
+impl<'a> From<&'a G2Affine> for G2Prepared {
+    fn from(q: &'a G2Affine) -> G2Prepared {
+        G2Prepared::from_affine(*q)
+    }
+}
+
+/// The output of a Miller loop, an element of Fq12 that only lands in [`Gt`]
+/// after [`final_exponentiation`].
+///
+/// Outputs are outside of the cyclotomic subgroup, so they only support
+/// multiplication, which accumulates several Miller loops for a single
+/// final exponentiation. The `pairing` crate writes that product additively,
+/// so `+` multiplies as well.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MillerLoopOutput(pub(crate) Fq12);
+
+/// The default output is one, the output of an empty Miller loop.
+impl Default for MillerLoopOutput {
+    fn default() -> Self {
+        MillerLoopOutput(Fq12::ONE)
+    }
+}
+
+impl<'b> Mul<&'b MillerLoopOutput> for &MillerLoopOutput {
+    type Output = MillerLoopOutput;
+
+    #[inline]
+    fn mul(self, rhs: &'b MillerLoopOutput) -> MillerLoopOutput {
+        MillerLoopOutput(self.0 * rhs.0)
+    }
+}
+
+impl_binops_multiplicative!(MillerLoopOutput, MillerLoopOutput);
+
+impl Add for MillerLoopOutput {
+    type Output = MillerLoopOutput;
+
+    #[inline]
+    fn add(self, rhs: MillerLoopOutput) -> MillerLoopOutput {
+        self * rhs
+    }
+}
+
+impl<'b> Add<&'b MillerLoopOutput> for MillerLoopOutput {
+    type Output = MillerLoopOutput;
+
+    #[inline]
+    fn add(self, rhs: &'b MillerLoopOutput) -> MillerLoopOutput {
+        self * rhs
+    }
+}
+
+impl AddAssign for MillerLoopOutput {
+    #[inline]
+    fn add_assign(&mut self, rhs: MillerLoopOutput) {
+        self.mul_assign(rhs);
+    }
+}
+
+impl<'b> AddAssign<&'b MillerLoopOutput> for MillerLoopOutput {
+    #[inline]
+    fn add_assign(&mut self, rhs: &'b MillerLoopOutput) {
+        self.mul_assign(rhs);
+    }
+}
+
+impl MillerLoopResult for MillerLoopOutput {
+    type Gt = Gt;
+
     fn final_exponentiation(&self) -> Gt {
         fn exp_by_x(f: &mut Fq12) {
             let x = BN_X;
@@ -559,7 +704,14 @@ impl MillerLoopResult for Gt {
     }
 }
 
-pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Gt {
+/// Computes the product of the Miller loops of `terms`.
+///
+/// The result is not yet a pairing: call
+/// [`MillerLoopResult::final_exponentiation`] once on the returned value to
+/// obtain the product of the pairings `e(p_i, q_i)`. Verifiers evaluating a
+/// product of pairings should prefer this over repeated calls to [`pairing`],
+/// which pay for a final exponentiation each time.
+pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> MillerLoopOutput {
     let mut pairs = vec![];
     for &(p, q) in terms {
         if !bool::from(p.is_identity()) && !q.is_zero() {
@@ -619,46 +771,81 @@ pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Gt {
         assert_eq!(coeffs.next(), None);
     }
 
-    Gt(f)
+    MillerLoopOutput(f)
+}
+
+/// Computes the Miller loop of a single pair of points.
+///
+/// Like [`multi_miller_loop`], the result still has to go through
+/// [`final_exponentiation`]. Miller loop outputs can be multiplied
+/// beforehand, so that several of them share one final exponentiation.
+pub fn miller_loop(g1: &G1Affine, g2: &G2Prepared) -> MillerLoopOutput {
+    multi_miller_loop(&[(g1, g2)])
+}
+
+/// Maps a Miller loop output to the pairing target group.
+pub fn final_exponentiation(f: &MillerLoopOutput) -> Gt {
+    MillerLoopResult::final_exponentiation(f)
 }
 
 pub fn pairing(g1: &G1Affine, g2: &G2Affine) -> Gt {
-    let g2 = G2Prepared::from_affine(*g2);
-    let terms: &[(&G1Affine, &G2Prepared)] = &[(g1, &g2)];
+    pairing_prepared(g1, &G2Prepared::from_affine(*g2))
+}
+
+/// Computes the pairing of `g1` with an already prepared G2 point.
+pub fn pairing_prepared(g1: &G1Affine, g2: &G2Prepared) -> Gt {
+    let terms: &[(&G1Affine, &G2Prepared)] = &[(g1, g2)];
     let u = multi_miller_loop(terms);
     u.final_exponentiation()
 }
 
-#[derive(Clone, Debug)]
-pub struct Bn256;
-
-impl Engine for Bn256 {
-    type Fr = Fr;
-    type G1 = G1;
-    type G1Affine = G1Affine;
-    type G2 = G2;
-    type G2Affine = G2Affine;
-    type Gt = Gt;
-
-    fn pairing(p: &Self::G1Affine, q: &Self::G2Affine) -> Self::Gt {
-        pairing(p, q)
-    }
+/// Checks whether the product of the pairings `e(p_i, q_i)` equals one.
+///
+/// All terms share a single Miller loop and a single final exponentiation.
+pub fn pairing_check(terms: &[(&G1Affine, &G2Affine)]) -> bool {
+    let prepared: Vec<G2Prepared> = terms.iter().map(|(_, q)| G2Prepared::from(**q)).collect();
+    let terms: Vec<(&G1Affine, &G2Prepared)> = terms
+        .iter()
+        .zip(prepared.iter())
+        .map(|((p, _), q)| (*p, q))
+        .collect();
+    bool::from(
+        multi_miller_loop(&terms)
+            .final_exponentiation()
+            .is_identity(),
+    )
 }
 
-impl MultiMillerLoop for Bn256 {
-    type G2Prepared = G2Prepared;
-    type Result = Gt;
+#[derive(Clone, Debug)]
+pub struct Bn256;
 
-    fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
-        multi_miller_loop(terms)
-    }
-}
+impl_pairing_engine!(
+    Bn256,
+    Fr,
+    G1,
+    G1Affine,
+    G2,
+    G2Affine,
+    G2Prepared,
+    Gt,
+    MillerLoopOutput,
+    multi_miller_loop
+);
 
+#[cfg(test)]
+use pairing::{Engine, PairingCurveAffine};
 #[cfg(test)]
 use rand::SeedableRng;
 #[cfg(test)]
 use rand_xorshift::XorShiftRng;
 
+#[test]
+fn test_gt_identity() {
+    const IDENTITY: Gt = Gt::identity();
+    assert_eq!(Gt::default(), IDENTITY);
+    assert_eq!(<Gt as Group>::identity(), IDENTITY);
+}
+
 #[test]
 fn test_pairing() {
     let g1 = G1::generator();
@@ -836,3 +1023,189 @@ fn random_miller_loop_tests() {
         assert_eq!(abcd, abcd_with_double_loop);
     }
 }
+
+#[test]
+fn test_pairing_prepared() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let q = G2Affine::from(G2::random(&mut rng));
+    let prepared = G2Prepared::from(&q);
+    for _ in 0..5 {
+        let p = G1Affine::from(G1::random(&mut rng));
+        assert_eq!(pairing_prepared(&p, &prepared), pairing(&p, &q));
+    }
+
+    let identity = G2Prepared::from(&G2Affine::identity());
+    assert!(identity.is_zero());
+    assert_eq!(
+        pairing_prepared(&G1Affine::generator(), &identity),
+        Gt::identity()
+    );
+}
+
+#[test]
+fn test_multi_miller_loop_product() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let g1s: Vec<G1Affine> = (0..4).map(|_| G1::random(&mut rng).into()).collect();
+    let g2s: Vec<G2Affine> = (0..4).map(|_| G2::random(&mut rng).into()).collect();
+    let prepared: Vec<G2Prepared> = g2s.iter().map(G2Prepared::from).collect();
+    let terms: Vec<(&G1Affine, &G2Prepared)> = g1s.iter().zip(prepared.iter()).collect();
+
+    let expected = g1s
+        .iter()
+        .zip(g2s.iter())
+        .fold(Gt::identity(), |acc, (p, q)| acc + pairing(p, q));
+    assert_eq!(multi_miller_loop(&terms).final_exponentiation(), expected);
+    assert_eq!(
+        multi_miller_loop(&[]).final_exponentiation(),
+        Gt::identity()
+    );
+
+    // e(s * P, Q) * e(-P, s * Q) == 1
+    let s = Fr::random(&mut rng);
+    let p = g1s[0];
+    let q = g2s[0];
+    let sp = G1Affine::from(p * s);
+    let sq = G2Prepared::from(G2Affine::from(q * s));
+    let neg_p = -p;
+    let result = multi_miller_loop(&[(&sp, &prepared[0]), (&neg_p, &sq)]);
+    assert!(bool::from(result.final_exponentiation().is_identity()));
+}
+
+#[test]
+fn test_pairing_check() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let p = G1Affine::from(G1::random(&mut rng));
+    let q = G2Affine::from(G2::random(&mut rng));
+    let s = Fr::random(&mut rng);
+    let sp = G1Affine::from(p * s);
+    let sq = G2Affine::from(q * s);
+
+    assert!(pairing_check(&[]));
+    assert!(pairing_check(&[(&sp, &q), (&-p, &sq)]));
+    assert!(pairing_check(&[(&G1Affine::identity(), &q)]));
+    assert!(!pairing_check(&[(&p, &q)]));
+    assert!(!pairing_check(&[(&sp, &q), (&p, &sq)]));
+}
+
+#[test]
+fn test_miller_loop_accumulation() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let a = G1Affine::from(G1::random(&mut rng));
+    let b = G2Affine::from(G2::random(&mut rng));
+    let c = G1Affine::from(G1::random(&mut rng));
+    let d = G2Affine::from(G2::random(&mut rng));
+    let b_prepared = G2Prepared::from(&b);
+    let d_prepared = G2Prepared::from(&d);
+
+    assert_eq!(
+        final_exponentiation(&miller_loop(&a, &b_prepared)),
+        pairing(&a, &b)
+    );
+
+    let accumulated = miller_loop(&a, &b_prepared) * miller_loop(&c, &d_prepared);
+    assert_eq!(
+        accumulated,
+        multi_miller_loop(&[(&a, &b_prepared), (&c, &d_prepared)])
+    );
+    assert_eq!(
+        final_exponentiation(&accumulated),
+        pairing(&a, &b) + pairing(&c, &d)
+    );
+    // `MillerLoopResult` writes the same product additively.
+    assert_eq!(
+        miller_loop(&a, &b_prepared) + miller_loop(&c, &d_prepared),
+        accumulated
+    );
+    assert_eq!(MillerLoopOutput::default(), multi_miller_loop(&[]));
+}
+
+#[test]
+fn test_gt_api() {
+    use crate::serde::SerdeObject;
+
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let generator = Gt::generator();
+    assert_eq!(
+        generator,
+        pairing(&G1Affine::generator(), &G2Affine::generator())
+    );
+    assert!(!bool::from(generator.is_identity()));
+    assert!(bool::from(generator.is_torsion_free()));
+    assert!(bool::from(Gt::identity().is_identity()));
+
+    // The identity is encoded as zero.
+    assert!(Gt::identity().to_bytes().as_ref().iter().all(|&b| b == 0));
+    assert_eq!(
+        Gt::from_bytes(&GtCompressed::default()).unwrap(),
+        Gt::identity()
+    );
+
+    for _ in 0..10 {
+        let a = Gt::random(&mut rng);
+        assert!(bool::from(a.is_torsion_free()));
+
+        let bytes = a.to_bytes();
+        assert_eq!(Gt::from_bytes(&bytes).unwrap(), a);
+        assert_eq!(
+            <Gt as GroupEncoding>::from_bytes_unchecked(&bytes).unwrap(),
+            a
+        );
+
+        let raw = a.to_raw_bytes();
+        assert_eq!(raw.len(), 384);
+        assert_eq!(Gt::from_raw_bytes(&raw).unwrap(), a);
+        assert_eq!(Gt::from_raw_bytes_unchecked(&raw), a);
+        let mut buf = vec![];
+        a.write_raw(&mut buf).unwrap();
+        assert_eq!(Gt::read_raw(&mut &buf[..]).unwrap(), a);
+
+        // Exponentiation by Fr agrees with the pairing.
+        let s = Fr::random(&mut rng);
+        assert_eq!(
+            generator * s,
+            pairing(&G1Affine::from(G1::generator() * s), &G2Affine::generator())
+        );
+    }
+
+    // `Gt` samples through rand's `Standard` distribution, which delegates to
+    // `Gt::random`.
+    {
+        use rand::Rng;
+        let a: Gt = rng.gen();
+        assert!(bool::from(a.is_torsion_free()));
+    }
+
+    // Encodings of unitary elements outside of Gt are rejected.
+    let mut bytes = Gt::random(&mut rng).to_bytes();
+    bytes.as_mut()[0] ^= 1;
+    assert!(bool::from(
+        <Gt as GroupEncoding>::from_bytes_unchecked(&bytes).is_some()
+    ));
+    assert!(bool::from(Gt::from_bytes(&bytes).is_none()));
+
+    // So are raw Fq12 elements outside of Gt.
+    let f = Gt(Fq12::random(&mut rng));
+    assert!(!bool::from(f.is_torsion_free()));
+    assert!(Gt::from_raw_bytes(&f.to_raw_bytes()).is_none());
+    assert!(Gt::read_raw(&mut &f.to_raw_bytes()[..]).is_err());
+}
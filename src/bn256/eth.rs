@@ -0,0 +1,178 @@
+//! Encodings of BN254 points used by the Ethereum precompiles of
+//! [EIP-196](https://eips.ethereum.org/EIPS/eip-196) and
+//! [EIP-197](https://eips.ethereum.org/EIPS/eip-197).
+//!
+//! Base field elements are 32-byte big-endian integers. A G1 point is encoded
+//! as `x || y` and a G2 point as `x.c1 || x.c0 || y.c1 || y.c0`, i.e. with the
+//! imaginary part of each Fq2 coordinate first. The identity is encoded as all
+//! zeros.
+
+use crate::bn256::{Fq, Fq2, G1Affine, G2Affine, G2};
+use crate::group::cofactor::CofactorGroup;
+use crate::CurveAffine;
+use subtle::CtOption;
+
+pub(super) fn fq_to_be_bytes(e: &Fq) -> [u8; 32] {
+    e.to_bytes_be()
+}
+
+pub(super) fn fq_from_be_bytes(bytes: &[u8]) -> CtOption<Fq> {
+    Fq::from_bytes_be(bytes.try_into().unwrap())
+}
+
+// The big-endian encoding of an Fq2 element is `c1 || c0`.
+pub(super) fn fq2_to_be_bytes(e: &Fq2) -> [u8; 64] {
+    e.to_bytes_be()
+}
+
+pub(super) fn fq2_from_be_bytes(bytes: &[u8]) -> CtOption<Fq2> {
+    Fq2::from_bytes_be(bytes.try_into().unwrap())
+}
+
+impl G1Affine {
+    /// Encodes the point as the 64-byte `x || y` expected by the EIP-196
+    /// precompiles.
+    pub fn to_eip196_bytes(&self) -> [u8; 64] {
+        let mut res = [0u8; 64];
+        res[0..32].copy_from_slice(&fq_to_be_bytes(&self.x));
+        res[32..64].copy_from_slice(&fq_to_be_bytes(&self.y));
+        res
+    }
+
+    /// Decodes a point in the EIP-196 format, failing if a coordinate is not
+    /// canonical or the point is not on the curve.
+    pub fn from_eip196_bytes(bytes: &[u8; 64]) -> CtOption<Self> {
+        fq_from_be_bytes(&bytes[0..32]).and_then(|x| {
+            fq_from_be_bytes(&bytes[32..64]).and_then(|y| {
+                // The identity is (0, 0), which `from_xy` accepts.
+                G1Affine::from_xy(x, y)
+            })
+        })
+    }
+}
+
+impl G2Affine {
+    /// Encodes the point as the 128-byte `x.c1 || x.c0 || y.c1 || y.c0`
+    /// expected by the EIP-197 pairing precompile.
+    pub fn to_eip197_bytes(&self) -> [u8; 128] {
+        let mut res = [0u8; 128];
+        res[0..64].copy_from_slice(&fq2_to_be_bytes(&self.x));
+        res[64..128].copy_from_slice(&fq2_to_be_bytes(&self.y));
+        res
+    }
+
+    /// Decodes a point in the EIP-197 format, failing if a coordinate is not
+    /// canonical, or the point is not on the twist or not in the prime order
+    /// subgroup.
+    pub fn from_eip197_bytes(bytes: &[u8; 128]) -> CtOption<Self> {
+        fq2_from_be_bytes(&bytes[0..64]).and_then(|x| {
+            fq2_from_be_bytes(&bytes[64..128]).and_then(|y| {
+                G2Affine::from_xy(x, y)
+                    .and_then(|p| CtOption::new(p, G2::from(p).is_torsion_free()))
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::G1;
+    use crate::ff::Field;
+    use crate::group::prime::PrimeCurveAffine;
+    use crate::group::{Curve, Group};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_eip196_encoding() {
+        let mut expected = [0u8; 64];
+        expected[31] = 1;
+        expected[63] = 2;
+        assert_eq!(G1Affine::generator().to_eip196_bytes(), expected);
+        assert_eq!(
+            G1Affine::from_eip196_bytes(&expected).unwrap(),
+            G1Affine::generator()
+        );
+
+        assert_eq!(G1Affine::identity().to_eip196_bytes(), [0u8; 64]);
+        assert!(bool::from(
+            G1Affine::from_eip196_bytes(&[0u8; 64])
+                .unwrap()
+                .is_identity()
+        ));
+
+        for _ in 0..100 {
+            let p = G1::random(OsRng).to_affine();
+            assert_eq!(
+                G1Affine::from_eip196_bytes(&p.to_eip196_bytes()).unwrap(),
+                p
+            );
+        }
+
+        // Off the curve.
+        expected[63] = 3;
+        assert!(bool::from(G1Affine::from_eip196_bytes(&expected).is_none()));
+
+        // Non-canonical coordinate, (1 + p, 2).
+        let mut bytes = G1Affine::generator().to_eip196_bytes();
+        bytes[0..32].copy_from_slice(
+            &hex::decode("30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd48")
+                .unwrap(),
+        );
+        assert!(bool::from(G1Affine::from_eip196_bytes(&bytes).is_none()));
+    }
+
+    #[test]
+    fn test_eip197_encoding() {
+        // The G2 generator as listed in EIP-197.
+        let expected = hex::decode(
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed\
+             090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b\
+             12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+        )
+        .unwrap();
+        let expected: [u8; 128] = expected.try_into().unwrap();
+        assert_eq!(G2Affine::generator().to_eip197_bytes(), expected);
+        assert_eq!(
+            G2Affine::from_eip197_bytes(&expected).unwrap(),
+            G2Affine::generator()
+        );
+
+        assert_eq!(G2Affine::identity().to_eip197_bytes(), [0u8; 128]);
+        assert!(bool::from(
+            G2Affine::from_eip197_bytes(&[0u8; 128])
+                .unwrap()
+                .is_identity()
+        ));
+
+        for _ in 0..100 {
+            let p = G2::random(OsRng).to_affine();
+            assert_eq!(
+                G2Affine::from_eip197_bytes(&p.to_eip197_bytes()).unwrap(),
+                p
+            );
+        }
+
+        // Swapping the Fq2 limbs yields a point off the twist.
+        let mut swapped = expected;
+        swapped[0..32].copy_from_slice(&expected[32..64]);
+        swapped[32..64].copy_from_slice(&expected[0..32]);
+        assert!(bool::from(G2Affine::from_eip197_bytes(&swapped).is_none()));
+
+        // A point on the twist outside the prime order subgroup.
+        let p = loop {
+            let x = Fq2::random(OsRng);
+            let y2 = x.square() * x + G2Affine::b();
+            if let Some(y) = Option::<Fq2>::from(y2.sqrt()) {
+                let p = G2Affine { x, y };
+                if !bool::from(G2::from(p).is_torsion_free()) {
+                    break p;
+                }
+            }
+        };
+        assert!(bool::from(
+            G2Affine::from_eip197_bytes(&p.to_eip197_bytes()).is_none()
+        ));
+    }
+}
@@ -1,15 +1,29 @@
-#[cfg(feature = "asm")]
-use crate::bn256::assembly::field_arithmetic_asm;
-#[cfg(not(feature = "asm"))]
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+use crate::derive::asm::field_arithmetic_asm;
+#[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
 use crate::{arithmetic::macx, field_arithmetic, field_specific};
 
+#[cfg(feature = "grumpkin-table")]
+#[rustfmt::skip]
+mod table;
+#[cfg(feature = "grumpkin-table")]
+#[cfg(test)]
+mod table_tests;
+
+#[cfg(feature = "grumpkin-table")]
+// This table should have been generated by `build.rs`;
+// and stored in `src/bn256/fq/table.rs`.
+pub use table::FQ_TABLE;
+
+use super::constants::fq::*;
 use crate::arithmetic::{adc, bigint_geq, mac, sbb};
 use crate::extend_field_legendre;
 use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
 use crate::{
     field_bits, field_common, impl_add_binop_specify_output, impl_binops_additive,
     impl_binops_additive_specify_output, impl_binops_multiplicative,
-    impl_binops_multiplicative_mixed, impl_from_u64, impl_sub_binop_specify_output, impl_sum_prod,
+    impl_binops_multiplicative_mixed, impl_from_signed, impl_from_u128,
+    impl_sub_binop_specify_output, impl_sum_prod,
 };
 use core::convert::TryInto;
 use core::fmt;
@@ -25,61 +39,22 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 // The internal representation of this type is four 64-bit unsigned
 // integers in little-endian order. `Fq` values are always in
 // Montgomery form; i.e., Fq(a) = aR mod q, with R = 2^256.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Fq(pub(crate) [u64; 4]);
 
+#[cfg(target_endian = "little")]
+// SAFETY: `Fq` is `#[repr(transparent)]` over its Montgomery form in
+// little-endian limbs.
+unsafe impl crate::serde::montgomery::MontgomeryLimbs for Fq {
+    fn is_reduced(&self) -> bool {
+        self.debug_validate()
+    }
+}
+
 #[cfg(feature = "derive_serde")]
 crate::serialize_deserialize_32_byte_primefield!(Fq);
 
-/// Constant representing the modulus
-/// q = 0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47
-const MODULUS: Fq = Fq([
-    0x3c208c16d87cfd47,
-    0x97816a916871ca8d,
-    0xb85045b68181585d,
-    0x30644e72e131a029,
-]);
-
-/// The modulus as u32 limbs.
-#[cfg(not(target_pointer_width = "64"))]
-const MODULUS_LIMBS_32: [u32; 8] = [
-    0xd87c_fd47,
-    0x3c20_8c16,
-    0x6871_ca8d,
-    0x9781_6a91,
-    0x8181_585d,
-    0xb850_45b6,
-    0xe131_a029,
-    0x3064_4e72,
-];
-
-/// INV = -(q^{-1} mod 2^64) mod 2^64
-const INV: u64 = 0x87d20782e4866389;
-
-/// R = 2^256 mod q
-const R: Fq = Fq([
-    0xd35d438dc58f0d9d,
-    0x0a78eb28f5c70b3d,
-    0x666ea36f7879462c,
-    0x0e0a77c19a07df2f,
-]);
-
-/// R^2 = 2^512 mod q
-const R2: Fq = Fq([
-    0xf32cfc5b538afa89,
-    0xb5e71911d44501fb,
-    0x47ab1eff0a417ff6,
-    0x06d89f71cab8351f,
-]);
-
-/// R^3 = 2^768 mod q
-const R3: Fq = Fq([
-    0xb1cd6dafda1530df,
-    0x62f210e6a7283db6,
-    0xef7f0b0c0ada0afb,
-    0x20fd6e902d592544,
-]);
-
 pub const NEGATIVE_ONE: Fq = Fq([
     0x68c3488912edefaa,
     0x8d087f6872aabf4f,
@@ -87,46 +62,6 @@ pub const NEGATIVE_ONE: Fq = Fq([
     0x2259d6b14729c0fa,
 ]);
 
-const MODULUS_STR: &str = "0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47";
-
-/// Obtained with:
-/// `sage: GF(0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47).primitive_element()`
-const MULTIPLICATIVE_GENERATOR: Fq = Fq::from_raw([0x03, 0x0, 0x0, 0x0]);
-
-const TWO_INV: Fq = Fq::from_raw([
-    0x9e10460b6c3e7ea4,
-    0xcbc0b548b438e546,
-    0xdc2822db40c0ac2e,
-    0x183227397098d014,
-]);
-
-/// `0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46`
-const ROOT_OF_UNITY: Fq = Fq::from_raw([
-    0x3c208c16d87cfd46,
-    0x97816a916871ca8d,
-    0xb85045b68181585d,
-    0x30644e72e131a029,
-]);
-
-/// `0x30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd46`
-const ROOT_OF_UNITY_INV: Fq = Fq::from_raw([
-    0x3c208c16d87cfd46,
-    0x97816a916871ca8d,
-    0xb85045b68181585d,
-    0x30644e72e131a029,
-]);
-
-// `0x9`
-const DELTA: Fq = Fq::from_raw([0x9, 0, 0, 0]);
-
-/// `ZETA^3 = 1 mod r` where `ZETA^2 != 1 mod r`
-const ZETA: Fq = Fq::from_raw([
-    0xe4bd44e5607cfd48,
-    0xc28f069fbb966e3d,
-    0x5e6dd9e7e0acccb0,
-    0x30644e72e131a029,
-]);
-
 impl_binops_additive!(Fq, Fq);
 impl_binops_multiplicative!(Fq, Fq);
 field_common!(
@@ -143,12 +78,21 @@ field_common!(
     R3
 );
 impl_sum_prod!(Fq);
-impl_from_u64!(Fq, R2);
-
-#[cfg(not(feature = "asm"))]
+#[cfg(not(feature = "grumpkin-table"))]
+crate::impl_from_u64!(Fq, R2);
+// The scalar field of grumpkin, whose witnesses hold small values as often as
+// those of bn256, so it gets the same table as `Fr` with `grumpkin-table`.
+#[cfg(feature = "grumpkin-table")]
+crate::impl_from_u64_table!(Fq, R2, FQ_TABLE);
+impl_from_u128!(Fq, R2);
+impl_from_signed!(Fq);
+#[cfg(feature = "crypto-bigint")]
+crate::derive::bigint::impl_crypto_bigint_conversions!(Fq, U256);
+
+#[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
 field_arithmetic!(Fq, MODULUS, INV, sparse);
-#[cfg(feature = "asm")]
-field_arithmetic_asm!(Fq, MODULUS, INV);
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+field_arithmetic_asm!(Fq, MODULUS, INV, sparse);
 
 #[cfg(target_pointer_width = "64")]
 field_bits!(Fq, MODULUS);
@@ -217,7 +161,7 @@ impl ff::PrimeField for Fq {
     const ROOT_OF_UNITY_INV: Self = ROOT_OF_UNITY_INV;
     const TWO_INV: Self = TWO_INV;
     const DELTA: Self = DELTA;
-    const S: u32 = 0;
+    const S: u32 = S;
 
     fn from_repr(repr: Self::Repr) -> CtOption<Self> {
         let mut tmp = Fq([0, 0, 0, 0]);
@@ -240,7 +184,7 @@ impl ff::PrimeField for Fq {
 
         // Convert to Montgomery form by computing
         // (a.R^0 * R^2) / R = a.R
-        tmp *= &R2;
+        let tmp = Self::mul(&tmp, &R2);
 
         CtOption::new(tmp, Choice::from(is_some))
     }
@@ -286,11 +230,20 @@ impl WithSmallOrderMulGroup<3> for Fq {
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq, "field_arithmetic");
+    #[cfg(feature = "zeroize")]
+    crate::field_testing_suite!(Fq, "zeroize");
     crate::field_testing_suite!(Fq, "conversion");
+    crate::field_testing_suite!(Fq, "str");
+    #[cfg(feature = "crypto-bigint")]
+    crate::field_testing_suite!(Fq, "crypto_bigint", U256);
     crate::field_testing_suite!(Fq, "serialization");
     crate::field_testing_suite!(Fq, "quadratic_residue");
     crate::field_testing_suite!(Fq, "bits");
     crate::field_testing_suite!(Fq, "serialization_check");
+    crate::field_testing_suite!(Fq, "from_be_slice");
+    crate::field_testing_suite!(Fq, "domain");
+    crate::field_testing_suite!(Fq, "debug_validate");
+    crate::field_testing_suite!(Fq, "balanced_digits");
     crate::field_testing_suite!(Fq, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fq, "sqrt");
     crate::field_testing_suite!(Fq, "zeta");
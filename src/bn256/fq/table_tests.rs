@@ -0,0 +1,9 @@
+use crate::bn256::{Fq, FQ_TABLE};
+
+#[test]
+fn test_table() {
+    for (i, e) in FQ_TABLE.iter().enumerate() {
+        assert_eq!(Fq::from(i as u64), *e);
+        assert_eq!(Fq::from_raw([i as u64, 0, 0, 0]), *e);
+    }
+}
@@ -2,8 +2,11 @@ use super::fq::Fq;
 use super::fq2::Fq2;
 use super::fq6::Fq6;
 use crate::ff::Field;
+use alloc::vec::Vec;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// -GAMMA is a quadratic non-residue in Fp6. Fp12 = Fp6[X]/(X^2 + GAMMA)
@@ -11,12 +14,15 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 // GAMMA = - v
 
 /// An element of Fq12, represented by c0 + c1 * w.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
 pub struct Fq12 {
     pub c0: Fq6,
     pub c1: Fq6,
 }
 
+crate::__impl_zeroize!(Fq12);
+
 impl ConditionallySelectable for Fq12 {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Fq12 {
@@ -32,15 +38,18 @@ impl ConstantTimeEq for Fq12 {
     }
 }
 
-impl Neg for Fq12 {
-    type Output = Fq12;
-
+// Compares all the coefficients, in constant time like `ct_eq`.
+impl PartialEq for Fq12 {
     #[inline]
-    fn neg(self) -> Fq12 {
-        -&self
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
     }
 }
 
+impl Eq for Fq12 {}
+
+crate::impl_neg!(Fq12);
+
 impl<'a> Neg for &'a Fq12 {
     type Output = Fq12;
 
@@ -87,6 +96,54 @@ impl_binops_multiplicative!(Fq12, Fq12);
 impl_sum_prod!(Fq12);
 
 impl Fq12 {
+    /// Checks that all coefficients are fully reduced.
+    pub const fn debug_validate(&self) -> bool {
+        self.c0.debug_validate() && self.c1.debug_validate()
+    }
+
+    pub const fn size() -> usize {
+        384
+    }
+
+    /// Attempts to convert a little-endian byte representation of
+    /// an element into a `Fq12`, failing if a coefficient is not canonical.
+    pub fn from_bytes(bytes: &[u8; 384]) -> CtOption<Fq12> {
+        let [c0, c1] = [0, 192].map(|i| Fq6::from_bytes(bytes[i..i + 192].try_into().unwrap()));
+        CtOption::new(
+            Fq12 {
+                c0: c0.unwrap_or(Fq6::ZERO),
+                c1: c1.unwrap_or(Fq6::ZERO),
+            },
+            c0.is_some() & c1.is_some(),
+        )
+    }
+
+    /// Converts an element of `Fq12` into a byte representation in
+    /// little-endian byte order, with the coefficients in order.
+    pub fn to_bytes(&self) -> [u8; 384] {
+        let mut res = [0u8; 384];
+        res[0..192].copy_from_slice(&self.c0.to_bytes());
+        res[192..384].copy_from_slice(&self.c1.to_bytes());
+        res
+    }
+
+    /// Attempts to convert a big-endian byte representation of an element
+    /// into a `Fq12`, the reverse of [`to_bytes`](Self::to_bytes), failing if
+    /// a coefficient is not canonical.
+    pub fn from_bytes_be(bytes: &[u8; 384]) -> CtOption<Fq12> {
+        let mut repr = *bytes;
+        repr.reverse();
+        Self::from_bytes(&repr)
+    }
+
+    /// Converts an element of `Fq12` into a byte representation in
+    /// big-endian byte order, the reverse of [`to_bytes`](Self::to_bytes).
+    pub fn to_bytes_be(&self) -> [u8; 384] {
+        let mut res = self.to_bytes();
+        res.reverse();
+        res
+    }
+
     #[inline]
     pub const fn zero() -> Self {
         Fq12 {
@@ -192,6 +249,8 @@ impl Fq12 {
     //     }
     // }
 
+    /// Raises `self` to the `p^power`-th power in place, using
+    /// [`FROBENIUS_COEFF_FQ12_C1`].
     pub fn frobenius_map(&mut self, power: usize) {
         self.c0.frobenius_map(power);
         self.c1.frobenius_map(power);
@@ -201,6 +260,7 @@ impl Fq12 {
         self.c1.c2.mul_assign(&FROBENIUS_COEFF_FQ12_C1[power % 12]);
     }
 
+    /// Multiplies `self` by the sparse element `c0 + c1 * v + c4 * v * w`.
     pub fn mul_by_014(&mut self, c0: &Fq2, c1: &Fq2, c4: &Fq2) {
         let mut aa = self.c0;
         aa.mul_by_01(c0, c1);
@@ -216,6 +276,8 @@ impl Fq12 {
         self.c0 += &aa;
     }
 
+    /// Multiplies `self` by the sparse element `c0 + (c3 + c4 * v) * w`, the
+    /// shape of the line functions evaluated in the Miller loop.
     pub fn mul_by_034(&mut self, c0: &Fq2, c3: &Fq2, c4: &Fq2) {
         let t0 = Fq6 {
             c0: self.c0.c0 * c0,
@@ -298,6 +360,48 @@ impl Fq12 {
     }
 }
 
+crate::impl_standard_distribution!(Fq12, crate::ff::Field);
+crate::impl_arkworks_field!(Fq12, Fq6, c0, c1);
+crate::impl_extension_field!(Fq12, Fq6, c0, c1);
+
+impl crate::serde::SerdeObject for Fq12 {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 384);
+        let [c0, c1] = [0, 192].map(|i| Fq6::from_raw_bytes_unchecked(&bytes[i..i + 192]));
+        Self { c0, c1 }
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 384 {
+            return None;
+        }
+        let [c0, c1] = [0, 192].map(|i| Fq6::from_raw_bytes(&bytes[i..i + 192]));
+        c0.zip(c1).map(|(c0, c1)| Self { c0, c1 })
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(384);
+        for c in [&self.c0, &self.c1] {
+            res.extend_from_slice(&c.to_raw_bytes());
+        }
+        res
+    }
+    #[cfg(feature = "std")]
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        let [c0, c1] = [(); 2].map(|_| Fq6::read_raw_unchecked(reader));
+        Self { c0, c1 }
+    }
+    #[cfg(feature = "std")]
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let c0 = Fq6::read_raw(reader)?;
+        let c1 = Fq6::read_raw(reader)?;
+        Ok(Self { c0, c1 })
+    }
+    #[cfg(feature = "std")]
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.c0.write_raw(writer)?;
+        self.c1.write_raw(writer)
+    }
+}
+
 impl Field for Fq12 {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
@@ -334,7 +438,8 @@ impl Field for Fq12 {
     }
 }
 
-// non_residue^((modulus^i-1)/6) for i=0,...,11
+/// `(u + 9)^((p^i - 1) / 6)` for `i = 0, ..., 11`, the Frobenius coefficients
+/// of `w`.
 pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
     // Fq2(u + 9)**(((q^0) - 1) / 6)
     // Fq points are represented in Montgomery form with R = 2^256
@@ -493,6 +598,9 @@ pub const FROBENIUS_COEFF_FQ12_C1: [Fq2; 12] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq12, "field_arithmetic");
+    #[cfg(feature = "zeroize")]
+    crate::field_testing_suite!(Fq12, "zeroize");
+    crate::field_testing_suite!(Fq12, "serialization");
     // extension field-specific
     crate::field_testing_suite!(Fq12, "f12_tests", Fq6, Fq2);
     crate::field_testing_suite!(
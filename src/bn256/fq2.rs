@@ -1,17 +1,20 @@
-use super::fq::{Fq, NEGATIVE_ONE};
+use super::fq::Fq;
+#[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
+use crate::arithmetic::{add_256, add_512, mul_512, sub_512};
 use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
-use crate::ff_ext::Legendre;
+use crate::ff_ext::{BatchMul, Legendre};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::convert::TryInto;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
-use std::cmp::Ordering;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
 /// An element of Fq2, represented by c0 + c1 * u; where u^2 = -1.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
 pub struct Fq2 {
     pub c0: Fq,
@@ -52,6 +55,16 @@ impl ConstantTimeEq for Fq2 {
     }
 }
 
+// Compares all the coefficients, in constant time like `ct_eq`.
+impl PartialEq for Fq2 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Eq for Fq2 {}
+
 impl Default for Fq2 {
     #[inline]
     fn default() -> Self {
@@ -59,6 +72,8 @@ impl Default for Fq2 {
     }
 }
 
+crate::__impl_zeroize!(Fq2);
+
 impl From<Fq2> for [u8; 64] {
     fn from(value: Fq2) -> [u8; 64] {
         value.to_bytes()
@@ -71,14 +86,7 @@ impl<'a> From<&'a Fq2> for [u8; 64] {
     }
 }
 
-impl Neg for Fq2 {
-    type Output = Fq2;
-
-    #[inline]
-    fn neg(self) -> Fq2 {
-        -&self
-    }
-}
+crate::impl_neg!(Fq2);
 
 impl<'a> Neg for &'a Fq2 {
     type Output = Fq2;
@@ -125,7 +133,25 @@ impl_binops_additive!(Fq2, Fq2);
 impl_binops_multiplicative!(Fq2, Fq2);
 impl_sum_prod!(Fq2);
 
+/// The square of the modulus of Fq, `p^2`, as a 512-bit integer.
+#[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
+const MODULUS_SQUARED: [u64; 8] = [
+    0x3b5458a2275d69b1,
+    0xa602072d09eac101,
+    0x4a50189c6d96cadc,
+    0x04689e957a1242c8,
+    0x26edfa5c34c6b38d,
+    0xb00b855116375606,
+    0x599a6f7c0348d21c,
+    0x0925c4b8763cbf9c,
+];
+
 impl Fq2 {
+    /// Checks that all coefficients are fully reduced.
+    pub const fn debug_validate(&self) -> bool {
+        self.c0.debug_validate() && self.c1.debug_validate()
+    }
+
     #[inline]
     pub const fn zero() -> Fq2 {
         Fq2 {
@@ -156,8 +182,8 @@ impl Fq2 {
         let c1 = Fq::from_bytes(bytes[32..64].try_into().unwrap());
         CtOption::new(
             Fq2 {
-                c0: c0.unwrap(),
-                c1: c1.unwrap(),
+                c0: c0.unwrap_or(Fq::zero()),
+                c1: c1.unwrap_or(Fq::zero()),
             },
             c0.is_some() & c1.is_some(),
         )
@@ -174,6 +200,43 @@ impl Fq2 {
         res
     }
 
+    /// Attempts to convert a big-endian byte representation of an element
+    /// into a `Fq2`, failing if a coefficient is not canonical. It is the
+    /// reverse of the little-endian representation, so the coefficients are
+    /// in reverse order, which is the `c1 || c0` order of Ethereum.
+    pub fn from_bytes_be(bytes: &[u8; 64]) -> CtOption<Fq2> {
+        let mut repr = *bytes;
+        repr.reverse();
+        Self::from_bytes(&repr)
+    }
+
+    /// Converts an element of `Fq2` into a byte representation in
+    /// big-endian byte order, with the coefficients in reverse order.
+    pub fn to_bytes_be(&self) -> [u8; 64] {
+        let mut res = self.to_bytes();
+        res.reverse();
+        res
+    }
+
+    #[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
+    pub fn mul_assign(&mut self, other: &Self) {
+        // Karatsuba with lazy reduction: the three products are kept as
+        // unreduced 512-bit integers and each coefficient is reduced once.
+        // Both reductions take inputs below 2p^2 < pR, as required.
+        let a0b0 = mul_512(self.c0.0, other.c0.0);
+        let a1b1 = mul_512(self.c1.0, other.c1.0);
+        // The sums are below 2p < 2^256, so they need no reduction.
+        let a = add_256(self.c0.0, self.c1.0);
+        let b = add_256(other.c0.0, other.c1.0);
+        let ab = mul_512(a, b);
+
+        // c0 = a0 b0 - a1 b1, offset by p^2 to stay non-negative.
+        self.c0 = Fq::montgomery_reduce(&sub_512(add_512(a0b0, MODULUS_SQUARED), a1b1));
+        // c1 = (a0 + a1)(b0 + b1) - a0 b0 - a1 b1 = a0 b1 + a1 b0
+        self.c1 = Fq::montgomery_reduce(&sub_512(sub_512(ab, a0b0), a1b1));
+    }
+
+    #[cfg(all(feature = "asm", target_arch = "x86_64"))]
     pub fn mul_assign(&mut self, other: &Self) {
         let mut t0 = self.c0 + self.c1;
         let mut t1 = self.c0 * other.c0;
@@ -247,6 +310,9 @@ impl Fq2 {
         self.c1 = -self.c1;
     }
 
+    /// Raises `self` to the `p^power`-th power in place.
+    ///
+    /// On Fq2 this is conjugation for odd powers and the identity otherwise.
     pub fn frobenius_map(&mut self, power: usize) {
         if power % 2 != 0 {
             self.conjugate()
@@ -311,6 +377,12 @@ impl Legendre for Fq2 {
     }
 }
 
+impl BatchMul for Fq2 {}
+
+crate::impl_standard_distribution!(Fq2, crate::ff::Field);
+crate::impl_arkworks_field!(Fq2, Fq, c0, c1);
+crate::impl_extension_field!(Fq2, Fq, c0, c1);
+
 impl Field for Fq2 {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
@@ -335,59 +407,42 @@ impl Field for Fq2 {
     }
 
     fn sqrt(&self) -> CtOption<Self> {
-        // Algorithm 9, https://eprint.iacr.org/2012/685.pdf
-
-        if self.is_zero().into() {
-            CtOption::new(Self::ZERO, Choice::from(1))
-        } else {
-            // a1 = self^((q - 3) / 4)
-            // 0xc19139cb84c680a6e14116da060561765e05aa45a1c72a34f082305b61f3f51
-            let u: [u64; 4] = [
-                0x4f082305b61f3f51,
-                0x65e05aa45a1c72a3,
-                0x6e14116da0605617,
-                0x0c19139cb84c680a,
-            ];
-            let mut a1 = self.pow(u);
-            let mut alpha = a1;
-
-            alpha.square_assign();
-            alpha.mul_assign(self);
-            let mut a0 = alpha;
-            a0.frobenius_map(1);
-            a0.mul_assign(&alpha);
-
-            let neg1 = Fq2 {
-                c0: NEGATIVE_ONE,
-                c1: Fq::zero(),
-            };
-
-            if a0 == neg1 {
-                CtOption::new(a0, Choice::from(0))
-            } else {
-                a1.mul_assign(self);
-
-                if alpha == neg1 {
-                    a1.mul_assign(&Fq2 {
-                        c0: Fq::zero(),
-                        c1: Fq::one(),
-                    });
-                } else {
-                    alpha += &Fq2::ONE;
-                    // alpha = alpha^((q - 1) / 2)
-                    // 0x183227397098d014dc2822db40c0ac2ecbc0b548b438e5469e10460b6c3e7ea3
-                    let u: [u64; 4] = [
-                        0x9e10460b6c3e7ea3,
-                        0xcbc0b548b438e546,
-                        0xdc2822db40c0ac2e,
-                        0x183227397098d014,
-                    ];
-                    alpha = alpha.pow(u);
-                    a1.mul_assign(&alpha);
-                }
-                CtOption::new(a1, Choice::from(1))
-            }
-        }
+        // Complex method, Algorithm 8 of https://eprint.iacr.org/2012/685.pdf.
+        // It reduces the square root to square roots and an inversion in Fq,
+        // instead of two exponentiations in Fq2. Both cases are computed and
+        // selected between, so that the running time does not depend on the
+        // input, which may be secret, e.g. when decompressing a point.
+
+        // If `c1` is zero, either `c0` or `-c0` is a square in Fq, as -1 is not.
+        let sqrt_c0 = self.c0.sqrt();
+        let sqrt_neg_c0 = (-self.c0).sqrt();
+        let real = Fq2::conditional_select(
+            &Fq2::new(Fq::zero(), sqrt_neg_c0.unwrap_or(Fq::zero())),
+            &Fq2::new(sqrt_c0.unwrap_or(Fq::zero()), Fq::zero()),
+            sqrt_c0.is_some(),
+        );
+
+        // Otherwise `self` is a square iff its norm c0^2 + c1^2 is.
+        let alpha = (self.c0.square() + self.c1.square()).sqrt();
+        let is_square = alpha.is_some();
+        let alpha = alpha.unwrap_or(Fq::zero());
+
+        // The product of (c0 + alpha) / 2 and (c0 - alpha) / 2 is -c1^2 / 4,
+        // a non-square, so exactly one of them is a square.
+        let delta = (self.c0 + alpha) * Fq::TWO_INV;
+        let sqrt_delta = delta.sqrt();
+        let x0 = Fq::conditional_select(
+            &(delta - alpha).sqrt().unwrap_or(Fq::zero()),
+            &sqrt_delta.unwrap_or(Fq::zero()),
+            sqrt_delta.is_some(),
+        );
+        let x1 = self.c1 * crate::ff_ext::invert_ct(&x0.double()).unwrap_or(Fq::zero());
+
+        let c1_is_zero = self.c1.is_zero();
+        CtOption::new(
+            Fq2::conditional_select(&Fq2::new(x0, x1), &real, c1_is_zero),
+            c1_is_zero | is_square,
+        )
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
@@ -401,11 +456,7 @@ impl Field for Fq2 {
 
 impl From<bool> for Fq2 {
     fn from(bit: bool) -> Fq2 {
-        if bit {
-            Fq2::ONE
-        } else {
-            Fq2::ZERO
-        }
+        Fq2::conditional_select(&Fq2::ZERO, &Fq2::ONE, Choice::from(bit as u8))
     }
 }
 
@@ -418,6 +469,42 @@ impl From<u64> for Fq2 {
     }
 }
 
+impl From<u128> for Fq2 {
+    fn from(val: u128) -> Self {
+        Fq2 {
+            c0: Fq::from(val),
+            c1: Fq::zero(),
+        }
+    }
+}
+
+impl From<i32> for Fq2 {
+    fn from(val: i32) -> Self {
+        Fq2 {
+            c0: Fq::from(val),
+            c1: Fq::zero(),
+        }
+    }
+}
+
+impl From<i64> for Fq2 {
+    fn from(val: i64) -> Self {
+        Fq2 {
+            c0: Fq::from(val),
+            c1: Fq::zero(),
+        }
+    }
+}
+
+impl From<i128> for Fq2 {
+    fn from(val: i128) -> Self {
+        Fq2 {
+            c0: Fq::from(val),
+            c1: Fq::zero(),
+        }
+    }
+}
+
 // This trait is only implemented to satisfy the requirement of CurveExt
 impl PrimeField for Fq2 {
     type Repr = Fq2Bytes;
@@ -452,10 +539,8 @@ impl PrimeField for Fq2 {
     };
 
     fn from_repr(repr: Self::Repr) -> CtOption<Self> {
-        let c0 = Fq::from_bytes(&repr.0[..32].try_into().unwrap());
-        let c1 = Fq::from_bytes(&repr.0[32..].try_into().unwrap());
-        // Disallow overflow representation
-        CtOption::new(Fq2::new(c0.unwrap(), c1.unwrap()), Choice::from(1))
+        // Fails, without panicking, if either coefficient is not canonical.
+        Self::from_bytes(&repr.0)
     }
 
     fn to_repr(&self) -> Self::Repr {
@@ -513,15 +598,18 @@ impl crate::serde::SerdeObject for Fq2 {
         }
         res
     }
+    #[cfg(feature = "std")]
     fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
         let [c0, c1] = [(); 2].map(|_| Fq::read_raw_unchecked(reader));
         Self { c0, c1 }
     }
+    #[cfg(feature = "std")]
     fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let c0 = Fq::read_raw(reader)?;
         let c1 = Fq::read_raw(reader)?;
         Ok(Self { c0, c1 })
     }
+    #[cfg(feature = "std")]
     fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         self.c0.write_raw(writer)?;
         self.c1.write_raw(writer)
@@ -545,6 +633,8 @@ impl WithSmallOrderMulGroup<3> for Fq2 {
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq2, "field_arithmetic");
+    #[cfg(feature = "zeroize")]
+    crate::field_testing_suite!(Fq2, "zeroize");
     crate::field_testing_suite!(Fq2, "conversion");
     crate::field_testing_suite!(Fq2, "serialization");
     crate::field_testing_suite!(Fq2, "quadratic_residue");
@@ -617,4 +707,38 @@ mod test {
             assert_eq!(a, b);
         }
     }
+
+    #[test]
+    fn test_fq2_sqrt_of_base_field() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        // Every element of Fq is a square in Fq2.
+        for _ in 0..1000 {
+            let a = Fq2::new(Fq::random(&mut rng), Fq::zero());
+            let root = a.sqrt().unwrap();
+            assert_eq!(root.square(), a);
+        }
+
+        // sqrt(-1) = ±u
+        let root = Fq2::new(-Fq::one(), Fq::zero()).sqrt().unwrap();
+        assert!(
+            root == Fq2::new(Fq::zero(), Fq::one()) || root == Fq2::new(Fq::zero(), -Fq::one())
+        );
+    }
+
+    #[test]
+    fn test_fq2_from_repr_non_canonical() {
+        // The modulus of Fq in the first coefficient, then in the second.
+        let modulus = (-Fq::one()).to_repr();
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&modulus);
+        bytes[0] += 1;
+        assert!(bool::from(Fq2::from_repr(Fq2Bytes(bytes)).is_none()));
+        bytes.rotate_left(32);
+        assert!(bool::from(Fq2::from_repr(Fq2Bytes(bytes)).is_none()));
+        assert!(bool::from(Fq2::from_repr(Fq2Bytes([0xff; 64])).is_none()));
+    }
 }
@@ -1,8 +1,11 @@
 use super::fq::Fq;
 use super::fq2::Fq2;
 use crate::ff::Field;
+use alloc::vec::Vec;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// -BETA is a cubic non-residue in Fp2. Fp6 = Fp2[X]/(X^3 + BETA)
@@ -10,13 +13,16 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 // BETA = - (u + 9)
 
 /// An element of Fq6, represented by c0 + c1 * v + c2 * v^2.
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
 pub struct Fq6 {
     pub c0: Fq2,
     pub c1: Fq2,
     pub c2: Fq2,
 }
 
+crate::__impl_zeroize!(Fq6);
+
 impl ConditionallySelectable for Fq6 {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Fq6 {
@@ -33,15 +39,18 @@ impl ConstantTimeEq for Fq6 {
     }
 }
 
-impl Neg for Fq6 {
-    type Output = Fq6;
-
+// Compares all the coefficients, in constant time like `ct_eq`.
+impl PartialEq for Fq6 {
     #[inline]
-    fn neg(self) -> Fq6 {
-        -&self
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
     }
 }
 
+impl Eq for Fq6 {}
+
+crate::impl_neg!(Fq6);
+
 impl<'a> Neg for &'a Fq6 {
     type Output = Fq6;
 
@@ -88,6 +97,57 @@ impl_binops_multiplicative!(Fq6, Fq6);
 impl_sum_prod!(Fq6);
 
 impl Fq6 {
+    /// Checks that all coefficients are fully reduced.
+    pub const fn debug_validate(&self) -> bool {
+        self.c0.debug_validate() && self.c1.debug_validate() && self.c2.debug_validate()
+    }
+
+    pub const fn size() -> usize {
+        192
+    }
+
+    /// Attempts to convert a little-endian byte representation of
+    /// an element into a `Fq6`, failing if a coefficient is not canonical.
+    pub fn from_bytes(bytes: &[u8; 192]) -> CtOption<Fq6> {
+        let [c0, c1, c2] =
+            [0, 64, 128].map(|i| Fq2::from_bytes(bytes[i..i + 64].try_into().unwrap()));
+        CtOption::new(
+            Fq6 {
+                c0: c0.unwrap_or(Fq2::ZERO),
+                c1: c1.unwrap_or(Fq2::ZERO),
+                c2: c2.unwrap_or(Fq2::ZERO),
+            },
+            c0.is_some() & c1.is_some() & c2.is_some(),
+        )
+    }
+
+    /// Converts an element of `Fq6` into a byte representation in
+    /// little-endian byte order, with the coefficients in order.
+    pub fn to_bytes(&self) -> [u8; 192] {
+        let mut res = [0u8; 192];
+        res[0..64].copy_from_slice(&self.c0.to_bytes());
+        res[64..128].copy_from_slice(&self.c1.to_bytes());
+        res[128..192].copy_from_slice(&self.c2.to_bytes());
+        res
+    }
+
+    /// Attempts to convert a big-endian byte representation of an element
+    /// into a `Fq6`, the reverse of [`to_bytes`](Self::to_bytes), failing if
+    /// a coefficient is not canonical.
+    pub fn from_bytes_be(bytes: &[u8; 192]) -> CtOption<Fq6> {
+        let mut repr = *bytes;
+        repr.reverse();
+        Self::from_bytes(&repr)
+    }
+
+    /// Converts an element of `Fq6` into a byte representation in
+    /// big-endian byte order, the reverse of [`to_bytes`](Self::to_bytes).
+    pub fn to_bytes_be(&self) -> [u8; 192] {
+        let mut res = self.to_bytes();
+        res.reverse();
+        res
+    }
+
     #[inline]
     pub const fn zero() -> Self {
         Fq6 {
@@ -250,6 +310,8 @@ impl Fq6 {
         }
     }
 
+    /// Raises `self` to the `p^power`-th power in place, using
+    /// [`FROBENIUS_COEFF_FQ6_C1`] and [`FROBENIUS_COEFF_FQ6_C2`].
     pub fn frobenius_map(&mut self, power: usize) {
         self.c0.frobenius_map(power);
         self.c1.frobenius_map(power);
@@ -261,13 +323,14 @@ impl Fq6 {
 
     /// Multiply by cubic nonresidue v.
     pub fn mul_by_nonresidue(&mut self) {
-        use std::mem::swap;
+        use core::mem::swap;
         swap(&mut self.c0, &mut self.c1);
         swap(&mut self.c0, &mut self.c2);
         // c0, c1, c2 -> c2, c0, c1
         self.c0.mul_by_nonresidue();
     }
 
+    /// Multiplies `self` by the sparse element `c1 * v`.
     pub fn mul_by_1(&mut self, c1: &Fq2) {
         let mut b_b = self.c1;
         b_b *= c1;
@@ -296,6 +359,7 @@ impl Fq6 {
         self.c2 = b_b;
     }
 
+    /// Multiplies `self` by the sparse element `c0 + c1 * v`.
     pub fn mul_by_01(&mut self, c0: &Fq2, c1: &Fq2) {
         let mut a_a = self.c0;
         let mut b_b = self.c1;
@@ -390,6 +454,50 @@ impl Fq6 {
     }
 }
 
+crate::impl_standard_distribution!(Fq6, crate::ff::Field);
+crate::impl_arkworks_field!(Fq6, Fq2, c0, c1, c2);
+crate::impl_extension_field!(Fq6, Fq2, c0, c1, c2);
+
+impl crate::serde::SerdeObject for Fq6 {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 192);
+        let [c0, c1, c2] = [0, 64, 128].map(|i| Fq2::from_raw_bytes_unchecked(&bytes[i..i + 64]));
+        Self { c0, c1, c2 }
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 192 {
+            return None;
+        }
+        let [c0, c1, c2] = [0, 64, 128].map(|i| Fq2::from_raw_bytes(&bytes[i..i + 64]));
+        c0.zip(c1).zip(c2).map(|((c0, c1), c2)| Self { c0, c1, c2 })
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(192);
+        for c in [&self.c0, &self.c1, &self.c2] {
+            res.extend_from_slice(&c.to_raw_bytes());
+        }
+        res
+    }
+    #[cfg(feature = "std")]
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        let [c0, c1, c2] = [(); 3].map(|_| Fq2::read_raw_unchecked(reader));
+        Self { c0, c1, c2 }
+    }
+    #[cfg(feature = "std")]
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let c0 = Fq2::read_raw(reader)?;
+        let c1 = Fq2::read_raw(reader)?;
+        let c2 = Fq2::read_raw(reader)?;
+        Ok(Self { c0, c1, c2 })
+    }
+    #[cfg(feature = "std")]
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.c0.write_raw(writer)?;
+        self.c1.write_raw(writer)?;
+        self.c2.write_raw(writer)
+    }
+}
+
 impl Field for Fq6 {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
@@ -427,6 +535,8 @@ impl Field for Fq6 {
     }
 }
 
+/// `(u + 9)^((p^i - 1) / 3)` for `i = 0, ..., 5`, the Frobenius coefficients
+/// of `v`.
 pub const FROBENIUS_COEFF_FQ6_C1: [Fq2; 6] = [
     // Fq2(u + 9)**(((q^0) - 1) / 3)
     Fq2 {
@@ -507,6 +617,8 @@ pub const FROBENIUS_COEFF_FQ6_C1: [Fq2; 6] = [
     },
 ];
 
+/// `(u + 9)^((2p^i - 2) / 3)` for `i = 0, ..., 5`, the Frobenius coefficients
+/// of `v^2`.
 pub const FROBENIUS_COEFF_FQ6_C2: [Fq2; 6] = [
     // Fq2(u + 9)**(((2q^0) - 2) / 3)
     Fq2 {
@@ -589,6 +701,7 @@ pub const FROBENIUS_COEFF_FQ6_C2: [Fq2; 6] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq6, "field_arithmetic");
+    crate::field_testing_suite!(Fq6, "serialization");
     // extension field-specific
     crate::field_testing_suite!(Fq6, "f6_tests", Fq2);
     crate::field_testing_suite!(
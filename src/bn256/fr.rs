@@ -1,6 +1,6 @@
-#[cfg(feature = "asm")]
-use crate::bn256::assembly::field_arithmetic_asm;
-#[cfg(not(feature = "asm"))]
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+use crate::derive::asm::field_arithmetic_asm;
+#[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
 use crate::{arithmetic::macx, field_arithmetic, field_specific};
 
 #[cfg(feature = "bn256-table")]
@@ -11,13 +11,15 @@ mod table;
 mod table_tests;
 
 #[cfg(feature = "bn256-table")]
-// This table should have being generated by `build.rs`;
+// This table should have been generated by `build.rs`;
 // and stored in `src/bn256/fr/table.rs`.
 pub use table::FR_TABLE;
 
 #[cfg(not(feature = "bn256-table"))]
 use crate::impl_from_u64;
+use crate::{impl_from_signed, impl_from_u128};
 
+use super::constants::fr::*;
 use crate::arithmetic::{adc, bigint_geq, mac, sbb};
 use crate::extend_field_legendre;
 use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
@@ -40,116 +42,22 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 // The internal representation of this type is four 64-bit unsigned
 // integers in little-endian order. `Fr` values are always in
 // Montgomery form; i.e., Fr(a) = aR mod r, with R = 2^256.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Fr(pub(crate) [u64; 4]);
 
+#[cfg(target_endian = "little")]
+// SAFETY: `Fr` is `#[repr(transparent)]` over its Montgomery form in
+// little-endian limbs.
+unsafe impl crate::serde::montgomery::MontgomeryLimbs for Fr {
+    fn is_reduced(&self) -> bool {
+        self.debug_validate()
+    }
+}
+
 #[cfg(feature = "derive_serde")]
 crate::serialize_deserialize_32_byte_primefield!(Fr);
 
-/// Constant representing the modulus
-/// r = 0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001
-const MODULUS: Fr = Fr([
-    0x43e1f593f0000001,
-    0x2833e84879b97091,
-    0xb85045b68181585d,
-    0x30644e72e131a029,
-]);
-
-/// The modulus as u32 limbs.
-#[cfg(not(target_pointer_width = "64"))]
-const MODULUS_LIMBS_32: [u32; 8] = [
-    0xf000_0001,
-    0x43e1_f593,
-    0x79b9_7091,
-    0x2833_e848,
-    0x8181_585d,
-    0xb850_45b6,
-    0xe131_a029,
-    0x3064_4e72,
-];
-
-const MODULUS_STR: &str = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
-
-/// INV = -(r^{-1} mod 2^64) mod 2^64
-const INV: u64 = 0xc2e1f593efffffff;
-
-/// `R = 2^256 mod r`
-/// `0xe0a77c19a07df2f666ea36f7879462e36fc76959f60cd29ac96341c4ffffffb`
-const R: Fr = Fr([
-    0xac96341c4ffffffb,
-    0x36fc76959f60cd29,
-    0x666ea36f7879462e,
-    0x0e0a77c19a07df2f,
-]);
-
-/// `R^2 = 2^512 mod r`
-/// `0x216d0b17f4e44a58c49833d53bb808553fe3ab1e35c59e31bb8e645ae216da7`
-const R2: Fr = Fr([
-    0x1bb8e645ae216da7,
-    0x53fe3ab1e35c59e3,
-    0x8c49833d53bb8085,
-    0x0216d0b17f4e44a5,
-]);
-
-/// `R^3 = 2^768 mod r`
-/// `0xcf8594b7fcc657c893cc664a19fcfed2a489cbe1cfbb6b85e94d8e1b4bf0040`
-const R3: Fr = Fr([
-    0x5e94d8e1b4bf0040,
-    0x2a489cbe1cfbb6b8,
-    0x893cc664a19fcfed,
-    0x0cf8594b7fcc657c,
-]);
-
-/// `GENERATOR = 7 mod r` is a generator of the `r - 1` order multiplicative
-/// subgroup, or in other words a primitive root of the field.
-const GENERATOR: Fr = Fr::from_raw([0x07, 0x00, 0x00, 0x00]);
-
-const S: u32 = 28;
-
-/// GENERATOR^t where t * 2^s + 1 = r
-/// with t odd. In other words, this
-/// is a 2^s root of unity.
-/// `0x3ddb9f5166d18b798865ea93dd31f743215cf6dd39329c8d34f1ed960c37c9c`
-const ROOT_OF_UNITY: Fr = Fr::from_raw([
-    0xd34f1ed960c37c9c,
-    0x3215cf6dd39329c8,
-    0x98865ea93dd31f74,
-    0x03ddb9f5166d18b7,
-]);
-
-/// 1 / 2 mod r
-const TWO_INV: Fr = Fr::from_raw([
-    0xa1f0fac9f8000001,
-    0x9419f4243cdcb848,
-    0xdc2822db40c0ac2e,
-    0x183227397098d014,
-]);
-
-/// 1 / ROOT_OF_UNITY mod r
-const ROOT_OF_UNITY_INV: Fr = Fr::from_raw([
-    0x0ed3e50a414e6dba,
-    0xb22625f59115aba7,
-    0x1bbe587180f34361,
-    0x048127174daabc26,
-]);
-
-/// GENERATOR^{2^s} where t * 2^s + 1 = r with t odd. In other words, this is a t root of unity.
-/// 0x09226b6e22c6f0ca64ec26aad4c86e715b5f898e5e963f25870e56bbe533e9a2
-const DELTA: Fr = Fr::from_raw([
-    0x870e56bbe533e9a2,
-    0x5b5f898e5e963f25,
-    0x64ec26aad4c86e71,
-    0x09226b6e22c6f0ca,
-]);
-
-/// `ZETA^3 = 1 mod r` where `ZETA^2 != 1 mod r`
-const ZETA: Fr = Fr::from_raw([
-    0xb8ca0b2d36636f23,
-    0xcc37a73fec2bc5e9,
-    0x048b6e193fd84104,
-    0x30644e72e131a029,
-]);
-
 impl_binops_additive!(Fr, Fr);
 impl_binops_multiplicative!(Fr, Fr);
 field_common!(
@@ -179,21 +87,17 @@ impl_from_u64!(Fr, R2);
 // When the "bn256-table" feature is enabled, we read the Fr element directly from the table.
 // This avoids a big integer multiplication.
 //
-// We use a table with 2^16 entries when the element is smaller than 2^16.
-impl From<u64> for Fr {
-    fn from(val: u64) -> Fr {
-        if val < 65536 {
-            FR_TABLE[val as usize]
-        } else {
-            Fr([val, 0, 0, 0]) * R2
-        }
-    }
-}
-
-#[cfg(not(feature = "asm"))]
+// We use a table with 2^16 entries by default, see `HALO2CURVES_TABLE_BITS` in `build.rs`.
+crate::impl_from_u64_table!(Fr, R2, FR_TABLE);
+impl_from_u128!(Fr, R2);
+impl_from_signed!(Fr);
+#[cfg(feature = "crypto-bigint")]
+crate::derive::bigint::impl_crypto_bigint_conversions!(Fr, U256);
+
+#[cfg(not(all(feature = "asm", target_arch = "x86_64")))]
 field_arithmetic!(Fr, MODULUS, INV, sparse);
-#[cfg(feature = "asm")]
-field_arithmetic_asm!(Fr, MODULUS, INV);
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+field_arithmetic_asm!(Fr, MODULUS, INV, sparse);
 
 #[cfg(target_pointer_width = "64")]
 field_bits!(Fr, MODULUS);
@@ -260,7 +164,7 @@ impl ff::PrimeField for Fr {
     const NUM_BITS: u32 = 254;
     const CAPACITY: u32 = 253;
     const MODULUS: &'static str = MODULUS_STR;
-    const MULTIPLICATIVE_GENERATOR: Self = GENERATOR;
+    const MULTIPLICATIVE_GENERATOR: Self = MULTIPLICATIVE_GENERATOR;
     const ROOT_OF_UNITY: Self = ROOT_OF_UNITY;
     const ROOT_OF_UNITY_INV: Self = ROOT_OF_UNITY_INV;
     const TWO_INV: Self = TWO_INV;
@@ -288,7 +192,7 @@ impl ff::PrimeField for Fr {
 
         // Convert to Montgomery form by computing
         // (a.R^0 * R^2) / R = a.R
-        tmp *= &R2;
+        let tmp = Self::mul(&tmp, &R2);
 
         CtOption::new(tmp, Choice::from(is_some))
     }
@@ -334,11 +238,20 @@ impl WithSmallOrderMulGroup<3> for Fr {
 mod test {
     use super::*;
     crate::field_testing_suite!(Fr, "field_arithmetic");
+    #[cfg(feature = "zeroize")]
+    crate::field_testing_suite!(Fr, "zeroize");
     crate::field_testing_suite!(Fr, "conversion");
+    crate::field_testing_suite!(Fr, "str");
+    #[cfg(feature = "crypto-bigint")]
+    crate::field_testing_suite!(Fr, "crypto_bigint", U256);
     crate::field_testing_suite!(Fr, "serialization");
     crate::field_testing_suite!(Fr, "quadratic_residue");
     crate::field_testing_suite!(Fr, "bits");
     crate::field_testing_suite!(Fr, "serialization_check");
+    crate::field_testing_suite!(Fr, "from_be_slice");
+    crate::field_testing_suite!(Fr, "domain");
+    crate::field_testing_suite!(Fr, "debug_validate");
+    crate::field_testing_suite!(Fr, "balanced_digits");
     crate::field_testing_suite!(Fr, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fr, "sqrt");
     crate::field_testing_suite!(Fr, "zeta");
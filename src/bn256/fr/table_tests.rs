@@ -4,5 +4,6 @@ use crate::bn256::{Fr, FR_TABLE};
 fn test_table() {
     for (i, e) in FR_TABLE.iter().enumerate() {
         assert_eq!(Fr::from(i as u64), *e);
+        assert_eq!(Fr::from_raw([i as u64, 0, 0, 0]), *e);
     }
 }
@@ -0,0 +1,246 @@
+//! Compressed encodings of BN254 points compatible with
+//! [gnark-crypto](https://github.com/Consensys/gnark-crypto).
+//!
+//! The x-coordinate is written in big-endian order, with Fq2 coordinates
+//! written as `c1 || c0`, and the two most significant bits of the first byte
+//! hold the flags:
+//!
+//! - `0b10`: the y-coordinate is the lexicographically smallest root,
+//! - `0b11`: the y-coordinate is the lexicographically largest root,
+//! - `0b01`: the point is the identity and all other bits are zero.
+//!
+//! Here `y` is the largest root if `y > -y` when both are compared as
+//! integers, with Fq2 elements compared `c1` first.
+
+use super::eth::{fq2_from_be_bytes, fq2_to_be_bytes, fq_from_be_bytes, fq_to_be_bytes};
+use crate::bn256::{Fq, Fq2, G1Affine, G2Affine, G2};
+use crate::ff::Field;
+use crate::group::cofactor::CofactorGroup;
+use crate::group::prime::PrimeCurveAffine;
+use crate::CurveAffine;
+use core::ops::Neg;
+use subtle::{Choice, CtOption};
+
+const FLAG_MASK: u8 = 0b11 << 6;
+const FLAG_SMALLEST: u8 = 0b10 << 6;
+const FLAG_LARGEST: u8 = 0b11 << 6;
+const FLAG_INFINITY: u8 = 0b01 << 6;
+
+fn is_lexicographically_largest<F: Copy + Ord + Neg<Output = F>>(y: F) -> bool {
+    y > -y
+}
+
+// Splits the flags off the first byte of `bytes`.
+fn split_flags<const N: usize>(bytes: &[u8; N]) -> (u8, [u8; N]) {
+    let mut x = *bytes;
+    x[0] &= !FLAG_MASK;
+    (bytes[0] & FLAG_MASK, x)
+}
+
+// Selects the root `y` or `-y` according to `flag`, failing on flags other
+// than the two compressed ones.
+fn select_root<F: Copy + Ord + Neg<Output = F>>(y: F, flag: u8) -> Option<F> {
+    let largest = match flag {
+        FLAG_SMALLEST => false,
+        FLAG_LARGEST => true,
+        _ => return None,
+    };
+    Some(if is_lexicographically_largest(y) == largest {
+        y
+    } else {
+        -y
+    })
+}
+
+impl G1Affine {
+    /// Encodes the point in gnark-crypto's 32-byte compressed format.
+    pub fn to_gnark_compressed(&self) -> [u8; 32] {
+        if bool::from(self.is_identity()) {
+            let mut res = [0u8; 32];
+            res[0] = FLAG_INFINITY;
+            return res;
+        }
+        let mut res = fq_to_be_bytes(&self.x);
+        res[0] |= if is_lexicographically_largest(self.y) {
+            FLAG_LARGEST
+        } else {
+            FLAG_SMALLEST
+        };
+        res
+    }
+
+    /// Decodes a point in gnark-crypto's 32-byte compressed format, failing
+    /// if the flags are invalid, the x-coordinate is not canonical or is not
+    /// the abscissa of a point on the curve.
+    pub fn from_gnark_compressed(bytes: &[u8; 32]) -> CtOption<Self> {
+        let (flag, x) = split_flags(bytes);
+        if flag == FLAG_INFINITY {
+            let is_zero = x.iter().all(|b| *b == 0);
+            return CtOption::new(Self::identity(), Choice::from(is_zero as u8));
+        }
+        fq_from_be_bytes(&x).and_then(|x| {
+            (x.square() * x + G1Affine::b()).sqrt().and_then(|y| {
+                let y = select_root(y, flag);
+                CtOption::new(
+                    G1Affine {
+                        x,
+                        y: y.unwrap_or(Fq::ZERO),
+                    },
+                    Choice::from(y.is_some() as u8),
+                )
+            })
+        })
+    }
+}
+
+impl G2Affine {
+    /// Encodes the point in gnark-crypto's 64-byte compressed format.
+    pub fn to_gnark_compressed(&self) -> [u8; 64] {
+        if bool::from(self.is_identity()) {
+            let mut res = [0u8; 64];
+            res[0] = FLAG_INFINITY;
+            return res;
+        }
+        let mut res = fq2_to_be_bytes(&self.x);
+        res[0] |= if is_lexicographically_largest(self.y) {
+            FLAG_LARGEST
+        } else {
+            FLAG_SMALLEST
+        };
+        res
+    }
+
+    /// Decodes a point in gnark-crypto's 64-byte compressed format, failing
+    /// if the flags are invalid, the x-coordinate is not canonical or is not
+    /// the abscissa of a point on the twist, or the point is not in the prime
+    /// order subgroup.
+    pub fn from_gnark_compressed(bytes: &[u8; 64]) -> CtOption<Self> {
+        let (flag, x) = split_flags(bytes);
+        if flag == FLAG_INFINITY {
+            let is_zero = x.iter().all(|b| *b == 0);
+            return CtOption::new(Self::identity(), Choice::from(is_zero as u8));
+        }
+        fq2_from_be_bytes(&x).and_then(|x| {
+            (x.square() * x + G2Affine::b()).sqrt().and_then(|y| {
+                let y = select_root(y, flag);
+                let p = G2Affine {
+                    x,
+                    y: y.unwrap_or(Fq2::ZERO),
+                };
+                CtOption::new(
+                    p,
+                    Choice::from(y.is_some() as u8) & G2::from(p).is_torsion_free(),
+                )
+            })
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::G1;
+    use crate::group::{Curve, Group};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_gnark_g1_compressed() {
+        let mut expected = [0u8; 32];
+        expected[0] = 0x80;
+        expected[31] = 1;
+        assert_eq!(G1Affine::generator().to_gnark_compressed(), expected);
+        assert_eq!(
+            G1Affine::from_gnark_compressed(&expected).unwrap(),
+            G1Affine::generator()
+        );
+        expected[0] = 0xc0;
+        assert_eq!(
+            G1Affine::from_gnark_compressed(&expected).unwrap(),
+            -G1Affine::generator()
+        );
+
+        let mut identity = [0u8; 32];
+        identity[0] = 0x40;
+        assert_eq!(G1Affine::identity().to_gnark_compressed(), identity);
+        assert!(bool::from(
+            G1Affine::from_gnark_compressed(&identity)
+                .unwrap()
+                .is_identity()
+        ));
+        identity[31] = 1;
+        assert!(bool::from(
+            G1Affine::from_gnark_compressed(&identity).is_none()
+        ));
+
+        for _ in 0..100 {
+            let p = G1::random(OsRng).to_affine();
+            let bytes = p.to_gnark_compressed();
+            assert_eq!(G1Affine::from_gnark_compressed(&bytes).unwrap(), p);
+            assert_eq!((-p).to_gnark_compressed()[0] ^ bytes[0], 0x40);
+        }
+
+        // The uncompressed flag is rejected.
+        expected[0] = 0x00;
+        assert!(bool::from(
+            G1Affine::from_gnark_compressed(&expected).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_gnark_g2_compressed() {
+        // The generator with the smallest-root flag set on its x-coordinate.
+        let expected: [u8; 64] = hex::decode(
+            "998e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2\
+             1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+        )
+        .unwrap()
+        .try_into()
+        .unwrap();
+        assert_eq!(G2Affine::generator().to_gnark_compressed(), expected);
+        assert_eq!(
+            G2Affine::from_gnark_compressed(&expected).unwrap(),
+            G2Affine::generator()
+        );
+
+        let mut identity = [0u8; 64];
+        identity[0] = 0x40;
+        assert_eq!(G2Affine::identity().to_gnark_compressed(), identity);
+        assert!(bool::from(
+            G2Affine::from_gnark_compressed(&identity)
+                .unwrap()
+                .is_identity()
+        ));
+
+        for _ in 0..100 {
+            let p = G2::random(OsRng).to_affine();
+            let bytes = p.to_gnark_compressed();
+            assert_eq!(G2Affine::from_gnark_compressed(&bytes).unwrap(), p);
+            assert_eq!(
+                G2Affine::from_gnark_compressed(&(-p).to_gnark_compressed()).unwrap(),
+                -p
+            );
+        }
+
+        // A root with zero imaginary part is ordered by its real part.
+        let y = Fq2 {
+            c0: Fq::from(2),
+            c1: Fq::zero(),
+        };
+        assert!(!is_lexicographically_largest(y));
+        assert!(is_lexicographically_largest(-y));
+
+        // A point on the twist outside the prime order subgroup.
+        let p = loop {
+            let x = Fq2::random(OsRng);
+            if let Some(y) = Option::<Fq2>::from((x.square() * x + G2Affine::b()).sqrt()) {
+                let p = G2Affine { x, y };
+                if !bool::from(G2::from(p).is_torsion_free()) {
+                    break p;
+                }
+            }
+        };
+        assert!(bool::from(
+            G2Affine::from_gnark_compressed(&p.to_gnark_compressed()).is_none()
+        ));
+    }
+}
@@ -1,13 +1,19 @@
+mod constants;
 mod curve;
 mod engine;
+mod eth;
 mod fq;
 mod fq12;
 mod fq2;
 mod fq6;
 mod fr;
+mod gnark;
+#[cfg(feature = "std")]
+pub mod srs;
+mod torus;
 
-#[cfg(feature = "asm")]
-mod assembly;
+#[cfg(feature = "ark")]
+mod ark;
 
 pub use curve::*;
 pub use engine::*;
@@ -16,6 +22,10 @@ pub use fq12::*;
 pub use fq2::*;
 pub use fq6::*;
 pub use fr::*;
+pub use torus::*;
+
+#[cfg(feature = "ark")]
+pub use ark::*;
 
 #[cfg(test)]
 mod test {
@@ -45,7 +55,7 @@ mod test {
         ];
 
         let mut seeded_rng = rand_chacha::ChaChaRng::seed_from_u64(0u64);
-        let uniform_bytes = std::iter::from_fn(|| {
+        let uniform_bytes = core::iter::from_fn(|| {
             let mut bytes = [0u8; 32];
             seeded_rng.fill_bytes(&mut bytes);
             Some(bytes)
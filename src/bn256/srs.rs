@@ -0,0 +1,796 @@
+//! Readers for the BN254 powers-of-tau files of public trusted setups.
+//!
+//! Three formats are supported:
+//!
+//! - The `challenge` and `response` files of the
+//!   [Perpetual Powers of Tau](https://github.com/privacy-scaling-explorations/perpetualpowersoftau)
+//!   ceremony, whose points are encoded in big-endian order as in
+//!   [EIP-196](https://eips.ethereum.org/EIPS/eip-196) and
+//!   [EIP-197](https://eips.ethereum.org/EIPS/eip-197), uncompressed in
+//!   challenges and compressed in responses, see [`read_ppot`].
+//! - The transcripts of Aztec's Ignition ceremony, see [`read_ignition`].
+//! - The `.ptau` files of [snarkjs](https://github.com/iden3/snarkjs), see
+//!   [`read_ptau`].
+//!
+//! The points are read in chunks, which are decoded and validated in
+//! parallel: the coordinates must be canonical, the points on the curve, and
+//! the G2 points in the prime order subgroup.
+
+use std::io::{self, Read};
+
+use ff::{Field, PrimeField};
+
+use super::{Fq, Fq2, G1Affine, G2Affine};
+use crate::serde::{PointFormat, SerdeObject};
+use crate::CurveAffine;
+
+// Number of points decoded in parallel at a time.
+const CHUNK_POINTS: usize = 1 << 16;
+
+/// Error returned when reading a powers-of-tau file.
+#[derive(Debug)]
+pub enum SrsError {
+    /// Reading the file failed, or it ended early.
+    Io(io::Error),
+    /// The file is not in the expected format, or is for another curve.
+    InvalidFormat(&'static str),
+    /// More points were requested than the file holds.
+    TooManyPoints,
+    /// The G1 point of the given index is invalid.
+    InvalidG1Point(usize),
+    /// The G2 point of the given index is invalid.
+    InvalidG2Point(usize),
+}
+
+impl core::fmt::Display for SrsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "{err}"),
+            Self::InvalidFormat(reason) => write!(f, "invalid file: {reason}"),
+            Self::TooManyPoints => write!(f, "more points requested than the file holds"),
+            Self::InvalidG1Point(index) => write!(f, "invalid G1 point at index {index}"),
+            Self::InvalidG2Point(index) => write!(f, "invalid G2 point at index {index}"),
+        }
+    }
+}
+
+impl std::error::Error for SrsError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SrsError {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
+}
+
+/// The points read from a powers-of-tau file.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PowersOfTau {
+    /// The G1 points, `tau^i G1` for `i` from `0` unless documented otherwise
+    /// by the reader.
+    pub g1: Vec<G1Affine>,
+    /// The G2 points, `tau^i G2` for `i` from `0` unless documented otherwise
+    /// by the reader.
+    pub g2: Vec<G2Affine>,
+}
+
+/// Reads the first `num_g1` G1 and `num_g2` G2 powers of tau of a Perpetual
+/// Powers of Tau file for `2^power` powers, a challenge if `format` is
+/// [`PointFormat::Uncompressed`] or a response if it is
+/// [`PointFormat::Compressed`].
+///
+/// The file holds `2^(power + 1) - 1` G1 and `2^power` G2 powers, followed
+/// by the `alpha` and `beta` powers of the Groth16 setup, which are not read.
+/// The identity is flagged by the second most significant bit of the first
+/// byte, and in the compressed encoding the most significant bit is set when
+/// `y` is the lexicographically largest root.
+pub fn read_ppot<R: Read>(
+    mut reader: R,
+    power: u32,
+    format: PointFormat,
+    num_g1: usize,
+    num_g2: usize,
+) -> Result<PowersOfTau, SrsError> {
+    let len_g1 = (1usize << (power + 1)) - 1;
+    let len_g2 = 1usize << power;
+    if num_g1 > len_g1 || num_g2 > len_g2 {
+        return Err(SrsError::TooManyPoints);
+    }
+
+    // The file starts with the BLAKE2b hash of the previous contribution.
+    skip(&mut reader, 64)?;
+    let (g1, g2) = match format {
+        PointFormat::Uncompressed => {
+            let g1 = read_points(&mut reader, num_g1, 64, ppot_g1_uncompressed)
+                .map_err(|e| e.map_index(SrsError::InvalidG1Point))?;
+            skip(&mut reader, (len_g1 - num_g1) as u64 * 64)?;
+            let g2 = read_points(&mut reader, num_g2, 128, ppot_g2_uncompressed)
+                .map_err(|e| e.map_index(SrsError::InvalidG2Point))?;
+            (g1, g2)
+        }
+        PointFormat::Compressed => {
+            let g1 = read_points(&mut reader, num_g1, 32, ppot_g1_compressed)
+                .map_err(|e| e.map_index(SrsError::InvalidG1Point))?;
+            skip(&mut reader, (len_g1 - num_g1) as u64 * 32)?;
+            let g2 = read_points(&mut reader, num_g2, 64, ppot_g2_compressed)
+                .map_err(|e| e.map_index(SrsError::InvalidG2Point))?;
+            (g1, g2)
+        }
+    };
+    Ok(PowersOfTau { g1, g2 })
+}
+
+/// The manifest at the start of an Ignition transcript.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct IgnitionManifest {
+    /// The index of the transcript.
+    pub transcript_number: u32,
+    /// The number of transcripts of the ceremony.
+    pub total_transcripts: u32,
+    /// The number of G1 points over all transcripts.
+    pub total_g1_points: u32,
+    /// The number of G2 points over all transcripts.
+    pub total_g2_points: u32,
+    /// The number of G1 points in this transcript.
+    pub num_g1_points: u32,
+    /// The number of G2 points in this transcript.
+    pub num_g2_points: u32,
+    /// The index of the first G1 point of this transcript over all
+    /// transcripts.
+    pub start_from: u32,
+}
+
+/// Reads an Ignition transcript: its manifest, and all its points.
+///
+/// The transcripts do not store the generators: the `j`-th G1 point of a
+/// transcript is `tau^(start_from + j + 1) G1`, and the G2 points, only found
+/// in the first transcript, start at `tau G2`. The manifest is seven
+/// big-endian `u32`, and each coordinate four 64-bit limbs, least significant
+/// first, each in big-endian order. The checksum at the end of the
+/// transcript is not read.
+pub fn read_ignition<R: Read>(mut reader: R) -> Result<(IgnitionManifest, PowersOfTau), SrsError> {
+    let manifest = IgnitionManifest {
+        transcript_number: read_u32_be(&mut reader)?,
+        total_transcripts: read_u32_be(&mut reader)?,
+        total_g1_points: read_u32_be(&mut reader)?,
+        total_g2_points: read_u32_be(&mut reader)?,
+        num_g1_points: read_u32_be(&mut reader)?,
+        num_g2_points: read_u32_be(&mut reader)?,
+        start_from: read_u32_be(&mut reader)?,
+    };
+
+    let g1 = read_points(&mut reader, manifest.num_g1_points as usize, 64, |bytes| {
+        let x = ignition_fq(&bytes[..32])?;
+        let y = ignition_fq(&bytes[32..])?;
+        Option::from(G1Affine::from_xy(x, y))
+    })
+    .map_err(|e| e.map_index(SrsError::InvalidG1Point))?;
+    let g2 = read_points(&mut reader, manifest.num_g2_points as usize, 128, |bytes| {
+        let x = Fq2::new(ignition_fq(&bytes[..32])?, ignition_fq(&bytes[32..64])?);
+        let y = Fq2::new(ignition_fq(&bytes[64..96])?, ignition_fq(&bytes[96..])?);
+        g2_from_xy(x, y)
+    })
+    .map_err(|e| e.map_index(SrsError::InvalidG2Point))?;
+    Ok((manifest, PowersOfTau { g1, g2 }))
+}
+
+/// Reads the first `num_g1` G1 and `num_g2` G2 powers of tau of a snarkjs
+/// `.ptau` file.
+///
+/// The file is a sequence of sections. The header section must come first
+/// and be for BN254, the `tauG1` and `tauG2` sections are read, and the
+/// other sections are skipped. The coordinates are in little-endian
+/// Montgomery form, as in [`SerdeObject::to_raw_bytes`], with Fq2 elements
+/// written as `c0 || c1`, and the identity is encoded as all zeros.
+pub fn read_ptau<R: Read>(
+    mut reader: R,
+    num_g1: usize,
+    num_g2: usize,
+) -> Result<PowersOfTau, SrsError> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != b"ptau" {
+        return Err(SrsError::InvalidFormat("missing ptau magic"));
+    }
+    if read_u32_le(&mut reader)? != 1 {
+        return Err(SrsError::InvalidFormat("unsupported ptau version"));
+    }
+    let num_sections = read_u32_le(&mut reader)?;
+
+    let mut power = None;
+    let mut g1 = None;
+    let mut g2 = None;
+    for _ in 0..num_sections {
+        let section = read_u32_le(&mut reader)?;
+        let size = read_u64_le(&mut reader)?;
+        match (section, power) {
+            (1, None) => {
+                if size != 4 + 32 + 4 + 4 || read_u32_le(&mut reader)? != 32 {
+                    return Err(SrsError::InvalidFormat("unsupported field size"));
+                }
+                let mut modulus = [0u8; 32];
+                reader.read_exact(&mut modulus)?;
+                if modulus != ptau_modulus() {
+                    return Err(SrsError::InvalidFormat("not a BN254 file"));
+                }
+                let p = read_u32_le(&mut reader)?;
+                if p >= usize::BITS - 1 {
+                    return Err(SrsError::InvalidFormat("invalid power"));
+                }
+                // The power of the ceremony the file was prepared from.
+                read_u32_le(&mut reader)?;
+                power = Some(p);
+            }
+            (1, Some(_)) => return Err(SrsError::InvalidFormat("duplicate header section")),
+            (2 | 3, None) => return Err(SrsError::InvalidFormat("missing header section")),
+            (2, Some(p)) => {
+                let len = (1usize << (p + 1)) - 1;
+                let expected = (len as u64)
+                    .checked_mul(64)
+                    .ok_or(SrsError::InvalidFormat("invalid power"))?;
+                if size != expected {
+                    return Err(SrsError::InvalidFormat("invalid tauG1 section size"));
+                }
+                if num_g1 > len {
+                    return Err(SrsError::TooManyPoints);
+                }
+                let points = read_points(&mut reader, num_g1, 64, |bytes| {
+                    let x = Fq::from_raw_bytes(&bytes[..32])?;
+                    let y = Fq::from_raw_bytes(&bytes[32..])?;
+                    Option::from(G1Affine::from_xy(x, y))
+                })
+                .map_err(|e| e.map_index(SrsError::InvalidG1Point))?;
+                skip(&mut reader, size - num_g1 as u64 * 64)?;
+                g1 = Some(points);
+            }
+            (3, Some(p)) => {
+                let len = 1usize << p;
+                let expected = (len as u64)
+                    .checked_mul(128)
+                    .ok_or(SrsError::InvalidFormat("invalid power"))?;
+                if size != expected {
+                    return Err(SrsError::InvalidFormat("invalid tauG2 section size"));
+                }
+                if num_g2 > len {
+                    return Err(SrsError::TooManyPoints);
+                }
+                let points = read_points(&mut reader, num_g2, 128, |bytes| {
+                    let x = Fq2::new(
+                        Fq::from_raw_bytes(&bytes[..32])?,
+                        Fq::from_raw_bytes(&bytes[32..64])?,
+                    );
+                    let y = Fq2::new(
+                        Fq::from_raw_bytes(&bytes[64..96])?,
+                        Fq::from_raw_bytes(&bytes[96..])?,
+                    );
+                    g2_from_xy(x, y)
+                })
+                .map_err(|e| e.map_index(SrsError::InvalidG2Point))?;
+                skip(&mut reader, size - num_g2 as u64 * 128)?;
+                g2 = Some(points);
+            }
+            _ => skip(&mut reader, size)?,
+        }
+        if let (Some(g1), Some(g2)) = (&mut g1, &mut g2) {
+            return Ok(PowersOfTau {
+                g1: core::mem::take(g1),
+                g2: core::mem::take(g2),
+            });
+        }
+    }
+    Err(SrsError::InvalidFormat("missing tauG1 or tauG2 section"))
+}
+
+// The error of `read_points`, before the group of the point is known.
+enum ReadError {
+    Io(io::Error),
+    InvalidPoint(usize),
+}
+
+impl ReadError {
+    fn map_index(self, f: fn(usize) -> SrsError) -> SrsError {
+        match self {
+            Self::Io(err) => SrsError::Io(err),
+            Self::InvalidPoint(index) => f(index),
+        }
+    }
+}
+
+// Reads `count` points of `size` bytes, decoding them in parallel chunks.
+fn read_points<R, P, D>(
+    reader: &mut R,
+    count: usize,
+    size: usize,
+    decode: D,
+) -> Result<Vec<P>, ReadError>
+where
+    R: Read,
+    P: Send,
+    D: Fn(&[u8]) -> Option<P> + Send + Sync,
+{
+    use crate::multicore::*;
+
+    let mut points = Vec::with_capacity(count);
+    let mut buf = vec![0u8; CHUNK_POINTS.min(count) * size];
+    while points.len() < count {
+        let n = CHUNK_POINTS.min(count - points.len());
+        let bytes = &mut buf[..n * size];
+        reader.read_exact(bytes).map_err(ReadError::Io)?;
+        let decoded: Vec<Option<P>> = bytes.par_chunks(size).map(&decode).collect();
+        for point in decoded {
+            let point = point.ok_or(ReadError::InvalidPoint(points.len()))?;
+            points.push(point);
+        }
+    }
+    Ok(points)
+}
+
+fn skip<R: Read>(reader: &mut R, len: u64) -> io::Result<()> {
+    let skipped = io::copy(&mut reader.take(len), &mut io::sink())?;
+    if skipped != len {
+        return Err(io::ErrorKind::UnexpectedEof.into());
+    }
+    Ok(())
+}
+
+fn read_u32_be<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_be_bytes(bytes))
+}
+
+fn read_u32_le<R: Read>(reader: &mut R) -> io::Result<u32> {
+    let mut bytes = [0u8; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64_le<R: Read>(reader: &mut R) -> io::Result<u64> {
+    let mut bytes = [0u8; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+// The little-endian modulus, as written in the header of `.ptau` files.
+fn ptau_modulus() -> [u8; 32] {
+    // The modulus is odd, so adding one to `p - 1` does not carry.
+    let mut modulus = (-Fq::ONE).to_repr();
+    modulus[0] += 1;
+    modulus
+}
+
+fn g2_from_xy(x: Fq2, y: Fq2) -> Option<G2Affine> {
+    use group::cofactor::CofactorGroup;
+
+    let p = Option::<G2Affine>::from(G2Affine::from_xy(x, y))?;
+    bool::from(super::G2::from(p).is_torsion_free()).then_some(p)
+}
+
+// Parses an Ignition coordinate: four big-endian limbs, least significant
+// first.
+fn ignition_fq(bytes: &[u8]) -> Option<Fq> {
+    let mut repr = [0u8; 32];
+    for (limb, out) in bytes.chunks(8).zip(repr.chunks_mut(8)) {
+        out.copy_from_slice(limb);
+        out.reverse();
+    }
+    Fq::from_repr(repr).into()
+}
+
+const PPOT_INFINITY: u8 = 0b01 << 6;
+const PPOT_LARGEST: u8 = 0b10 << 6;
+const PPOT_FLAG_MASK: u8 = 0b11 << 6;
+
+// Returns whether `bytes` is the identity with the infinity flag, or `None`
+// if the flags are invalid.
+fn ppot_is_identity(bytes: &[u8], allowed: u8) -> Option<bool> {
+    match bytes[0] & PPOT_FLAG_MASK {
+        PPOT_INFINITY => {
+            (bytes[0] == PPOT_INFINITY && bytes[1..].iter().all(|b| *b == 0)).then_some(true)
+        }
+        flag if flag & !allowed == 0 => Some(false),
+        _ => None,
+    }
+}
+
+fn ppot_g1_uncompressed(bytes: &[u8]) -> Option<G1Affine> {
+    if ppot_is_identity(bytes, 0)? {
+        return Some(G1Affine::identity());
+    }
+    G1Affine::from_eip196_bytes(bytes.try_into().unwrap()).into()
+}
+
+fn ppot_g2_uncompressed(bytes: &[u8]) -> Option<G2Affine> {
+    if ppot_is_identity(bytes, 0)? {
+        return Some(G2Affine::identity());
+    }
+    G2Affine::from_eip197_bytes(bytes.try_into().unwrap()).into()
+}
+
+// The compressed encoding is gnark-crypto's with a different flag for each
+// root: convert the flags and let the gnark decoder do the rest.
+fn ppot_to_gnark_flags(bytes: &mut [u8]) {
+    bytes[0] |= if bytes[0] & PPOT_LARGEST != 0 {
+        0b11 << 6
+    } else {
+        0b10 << 6
+    };
+}
+
+fn ppot_g1_compressed(bytes: &[u8]) -> Option<G1Affine> {
+    if ppot_is_identity(bytes, PPOT_LARGEST)? {
+        return Some(G1Affine::identity());
+    }
+    let mut bytes: [u8; 32] = bytes.try_into().unwrap();
+    ppot_to_gnark_flags(&mut bytes);
+    G1Affine::from_gnark_compressed(&bytes).into()
+}
+
+fn ppot_g2_compressed(bytes: &[u8]) -> Option<G2Affine> {
+    if ppot_is_identity(bytes, PPOT_LARGEST)? {
+        return Some(G2Affine::identity());
+    }
+    let mut bytes: [u8; 64] = bytes.try_into().unwrap();
+    ppot_to_gnark_flags(&mut bytes);
+    G2Affine::from_gnark_compressed(&bytes).into()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{Fr, G1, G2};
+    use group::prime::PrimeCurveAffine;
+    use group::Curve;
+    use rand_core::OsRng;
+
+    fn powers(tau: Fr, power: u32) -> PowersOfTau {
+        let mut g1 = vec![G1::generator()];
+        for _ in 1..(1 << (power + 1)) - 1 {
+            g1.push(g1[g1.len() - 1] * tau);
+        }
+        let mut g2 = vec![G2::generator()];
+        for _ in 1..1 << power {
+            g2.push(g2[g2.len() - 1] * tau);
+        }
+        PowersOfTau {
+            g1: g1.iter().map(|p| p.to_affine()).collect(),
+            g2: g2.iter().map(|p| p.to_affine()).collect(),
+        }
+    }
+
+    fn ppot_file(srs: &PowersOfTau, format: PointFormat) -> Vec<u8> {
+        let mut file = vec![0xab; 64];
+        let ppot_flags = |bytes: &mut [u8]| {
+            // gnark sets the top bit for both roots, and the next one for the
+            // largest, which PPoT flags with the top bit alone.
+            let largest = bytes[0] & (0b01 << 6) != 0;
+            bytes[0] &= !PPOT_FLAG_MASK;
+            if largest {
+                bytes[0] |= PPOT_LARGEST;
+            }
+        };
+        for p in &srs.g1 {
+            match format {
+                PointFormat::Uncompressed => file.extend(p.to_eip196_bytes()),
+                PointFormat::Compressed => {
+                    let mut bytes = p.to_gnark_compressed();
+                    ppot_flags(&mut bytes);
+                    file.extend(bytes);
+                }
+            }
+        }
+        for p in &srs.g2 {
+            match format {
+                PointFormat::Uncompressed => file.extend(p.to_eip197_bytes()),
+                PointFormat::Compressed => {
+                    let mut bytes = p.to_gnark_compressed();
+                    ppot_flags(&mut bytes);
+                    file.extend(bytes);
+                }
+            }
+        }
+        // The alpha and beta powers.
+        file.extend(vec![0xcd; 1000]);
+        file
+    }
+
+    #[test]
+    fn test_read_ppot() {
+        let srs = powers(Fr::random(OsRng), 3);
+        for format in [PointFormat::Uncompressed, PointFormat::Compressed] {
+            let file = ppot_file(&srs, format);
+            assert_eq!(read_ppot(&file[..], 3, format, 15, 8).unwrap(), srs);
+            let prefix = read_ppot(&file[..], 3, format, 5, 2).unwrap();
+            assert_eq!(prefix.g1, srs.g1[..5]);
+            assert_eq!(prefix.g2, srs.g2[..2]);
+            assert!(matches!(
+                read_ppot(&file[..], 3, format, 16, 8),
+                Err(SrsError::TooManyPoints)
+            ));
+            assert!(matches!(
+                read_ppot(&file[..100], 3, format, 15, 8),
+                Err(SrsError::Io(_))
+            ));
+        }
+
+        // Invalid points.
+        let mut file = ppot_file(&srs, PointFormat::Uncompressed);
+        file[64 + 2 * 64 + 63] ^= 1;
+        assert!(matches!(
+            read_ppot(&file[..], 3, PointFormat::Uncompressed, 15, 8),
+            Err(SrsError::InvalidG1Point(2))
+        ));
+        let mut file = ppot_file(&srs, PointFormat::Compressed);
+        file[64 + 15 * 32 + 64] |= 0b11 << 6;
+        assert!(matches!(
+            read_ppot(&file[..], 3, PointFormat::Compressed, 15, 8),
+            Err(SrsError::InvalidG2Point(1))
+        ));
+
+        // The identity.
+        let mut identity = [0u8; 32];
+        identity[0] = PPOT_INFINITY;
+        assert_eq!(ppot_g1_compressed(&identity), Some(G1Affine::identity()));
+        let mut identity = [0u8; 128];
+        identity[0] = PPOT_INFINITY;
+        assert_eq!(ppot_g2_uncompressed(&identity), Some(G2Affine::identity()));
+        identity[127] = 1;
+        assert_eq!(ppot_g2_uncompressed(&identity), None);
+    }
+
+    #[test]
+    fn test_read_ignition() {
+        let srs = powers(Fr::random(OsRng), 3);
+        let to_ignition = |x: &Fq| -> Vec<u8> {
+            x.to_repr()
+                .chunks(8)
+                .flat_map(|limb| limb.iter().rev().copied().collect::<Vec<_>>())
+                .collect()
+        };
+
+        let manifest = IgnitionManifest {
+            transcript_number: 0,
+            total_transcripts: 1,
+            total_g1_points: 14,
+            total_g2_points: 1,
+            num_g1_points: 14,
+            num_g2_points: 1,
+            start_from: 0,
+        };
+        let mut file = Vec::new();
+        for word in [0, 1, 14, 1, 14, 1, 0u32] {
+            file.extend(word.to_be_bytes());
+        }
+        for p in &srs.g1[1..] {
+            file.extend(to_ignition(&p.x));
+            file.extend(to_ignition(&p.y));
+        }
+        let p = srs.g2[1];
+        for c in [p.x.c0, p.x.c1, p.y.c0, p.y.c1] {
+            file.extend(to_ignition(&c));
+        }
+        file.extend([0u8; 64]);
+
+        let (read_manifest, read) = read_ignition(&file[..]).unwrap();
+        assert_eq!(read_manifest, manifest);
+        assert_eq!(read.g1, srs.g1[1..]);
+        assert_eq!(read.g2, srs.g2[1..2]);
+
+        // The coordinates are in 64-bit limbs, least significant first, so
+        // the G1 generator `(1, 2)` is stored as below.
+        let generator = hex::decode(concat!(
+            // One transcript of one G1 point and no G2 point.
+            "00000000000000010000000100000000000000010000000000000000",
+            "0000000000000001000000000000000000000000000000000000000000000000",
+            "0000000000000002000000000000000000000000000000000000000000000000",
+        ))
+        .unwrap();
+        let (_, read) = read_ignition(&generator[..]).unwrap();
+        assert_eq!(read.g1, vec![G1Affine::generator()]);
+        assert!(read.g2.is_empty());
+
+        // An invalid G2 point.
+        let len = file.len();
+        file[len - 64 - 1] ^= 1;
+        assert!(matches!(
+            read_ignition(&file[..]),
+            Err(SrsError::InvalidG2Point(0))
+        ));
+    }
+
+    #[test]
+    fn test_read_ptau() {
+        let power = 3;
+        let srs = powers(Fr::random(OsRng), power);
+        let section = |file: &mut Vec<u8>, id: u32, data: Vec<u8>| {
+            file.extend(id.to_le_bytes());
+            file.extend((data.len() as u64).to_le_bytes());
+            file.extend(data);
+        };
+        let mut header = 32u32.to_le_bytes().to_vec();
+        header.extend(ptau_modulus());
+        header.extend(power.to_le_bytes());
+        header.extend(power.to_le_bytes());
+        let mut tau_g1 = Vec::new();
+        for p in &srs.g1 {
+            tau_g1.extend(p.x.to_raw_bytes());
+            tau_g1.extend(p.y.to_raw_bytes());
+        }
+        let mut tau_g2 = Vec::new();
+        for p in &srs.g2 {
+            for c in [p.x.c0, p.x.c1, p.y.c0, p.y.c1] {
+                tau_g2.extend(c.to_raw_bytes());
+            }
+        }
+
+        let mut file = b"ptau".to_vec();
+        file.extend(1u32.to_le_bytes());
+        file.extend(4u32.to_le_bytes());
+        section(&mut file, 1, header.clone());
+        section(&mut file, 2, tau_g1.clone());
+        // Sections that are skipped may come in between.
+        section(&mut file, 4, vec![0xcd; 100]);
+        section(&mut file, 3, tau_g2.clone());
+
+        assert_eq!(read_ptau(&file[..], 15, 8).unwrap(), srs);
+        let prefix = read_ptau(&file[..], 4, 2).unwrap();
+        assert_eq!(prefix.g1, srs.g1[..4]);
+        assert_eq!(prefix.g2, srs.g2[..2]);
+        assert!(matches!(
+            read_ptau(&file[..], 15, 9),
+            Err(SrsError::TooManyPoints)
+        ));
+
+        // The identity is all zeros.
+        let mut zeros = file.clone();
+        let start = 4 + 4 + 4 + 12 + header.len() + 12;
+        zeros[start..start + 64].fill(0);
+        assert!(bool::from(
+            read_ptau(&zeros[..], 1, 0).unwrap().g1[0].is_identity()
+        ));
+
+        // Invalid points or header.
+        let mut invalid = file.clone();
+        invalid[start + 64 + 1] ^= 1;
+        assert!(matches!(
+            read_ptau(&invalid[..], 15, 8),
+            Err(SrsError::InvalidG1Point(1))
+        ));
+        let mut other_curve = file.clone();
+        other_curve[4 + 4 + 4 + 12 + 4] ^= 2;
+        assert!(matches!(
+            read_ptau(&other_curve[..], 15, 8),
+            Err(SrsError::InvalidFormat(_))
+        ));
+        let mut no_header = b"ptau".to_vec();
+        no_header.extend(1u32.to_le_bytes());
+        no_header.extend(1u32.to_le_bytes());
+        section(&mut no_header, 2, tau_g1);
+        assert!(matches!(
+            read_ptau(&no_header[..], 15, 8),
+            Err(SrsError::InvalidFormat(_))
+        ));
+    }
+
+    // The generators as the files encode them, written out by hand rather
+    // than with the encoders of this crate: as big-endian coordinates, with
+    // the imaginary part first, in Perpetual Powers of Tau, and in
+    // little-endian Montgomery form, real part first, in `.ptau` files.
+    const PPOT_G1_GENERATOR: &str = concat!(
+        "0000000000000000000000000000000000000000000000000000000000000001",
+        "0000000000000000000000000000000000000000000000000000000000000002",
+    );
+    const PPOT_G2_GENERATOR: &str = concat!(
+        "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+        "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+        "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+        "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+    );
+    const PTAU_G1_GENERATOR: &str = concat!(
+        "9d0d8fc58d435dd33d0bc7f528eb780a2c4679786fa36e662fdf079ac1770a0e",
+        "3a1b1e8b1b87baa67b168eeb51d6f114588cf2f0de46ddcc5ebe0f3483ef141c",
+    );
+    const PTAU_G2_GENERATOR: &str = concat!(
+        "2620bc02d1b5838e72017b493519ebdcdf1a81974726b8fb3b5096af41385719",
+        "40614ca87d73b4afc4d802585add4360862fa052fc50e9096b7bea3a83f0fe14",
+        "f6e96b889dfa9d61789b9ef597d27ffefe7d1b23621a9eff06429eaeeb7efd28",
+        "ee5618c7565b0964bb3c7d3222f957dc76103533be35f9558264fd93e6a0a40d",
+    );
+
+    fn repeat(point: &str, count: usize) -> Vec<u8> {
+        hex::decode(point.repeat(count)).unwrap()
+    }
+
+    #[test]
+    fn test_read_ppot_new() {
+        // The challenge of `new_constrained` for `2^1` powers, before any
+        // contribution: the BLAKE2b hash of the empty string, then every
+        // power of tau, alpha and beta set to the generators.
+        let mut file = hex::decode(concat!(
+            "786a02f742015903c6c6fd852552d272912f4740e15847618a86e217f71f5419",
+            "d25e1031afee585313896444934eb04b903a685b1448b755d56f701afe9be2ce",
+        ))
+        .unwrap();
+        file.extend(repeat(PPOT_G1_GENERATOR, 3));
+        file.extend(repeat(PPOT_G2_GENERATOR, 2));
+        file.extend(repeat(PPOT_G1_GENERATOR, 4));
+        file.extend(repeat(PPOT_G2_GENERATOR, 1));
+
+        let srs = read_ppot(&file[..], 1, PointFormat::Uncompressed, 3, 2).unwrap();
+        assert_eq!(srs.g1, vec![G1Affine::generator(); 3]);
+        assert_eq!(srs.g2, vec![G2Affine::generator(); 2]);
+
+        // The response compresses the points to `x`. `y` is the smallest
+        // root for both generators, so no flag is set.
+        let mut file = file[..64].to_vec();
+        file.extend(repeat(&PPOT_G1_GENERATOR[..64], 3));
+        file.extend(repeat(&PPOT_G2_GENERATOR[..128], 2));
+        let srs = read_ppot(&file[..], 1, PointFormat::Compressed, 3, 2).unwrap();
+        assert_eq!(srs.g1, vec![G1Affine::generator(); 3]);
+        assert_eq!(srs.g2, vec![G2Affine::generator(); 2]);
+    }
+
+    #[test]
+    fn test_read_ptau_new() {
+        // The file of `snarkjs powersoftau new bn128 1`, before any
+        // contribution: the header, then the sections of the powers of tau,
+        // alpha and beta, all set to the generators, and of the empty list
+        // of contributions.
+        let mut file = hex::decode(concat!(
+            // Magic, version and number of sections.
+            "70746175",
+            "01000000",
+            "07000000",
+            // The header: field size, modulus, power and ceremony power.
+            "01000000",
+            "2c00000000000000",
+            "20000000",
+            "47fd7cd8168c203c8dca7168916a81975d588181b64550b829a031e1724e6430",
+            "01000000",
+            "01000000",
+        ))
+        .unwrap();
+        let mut section = |id: u32, data: Vec<u8>| {
+            file.extend(id.to_le_bytes());
+            file.extend((data.len() as u64).to_le_bytes());
+            file.extend(data);
+        };
+        section(2, repeat(PTAU_G1_GENERATOR, 3));
+        section(3, repeat(PTAU_G2_GENERATOR, 2));
+        section(4, repeat(PTAU_G1_GENERATOR, 2));
+        section(5, repeat(PTAU_G1_GENERATOR, 2));
+        section(6, repeat(PTAU_G2_GENERATOR, 1));
+        section(7, vec![0; 4]);
+
+        let srs = read_ptau(&file[..], 3, 2).unwrap();
+        assert_eq!(srs.g1, vec![G1Affine::generator(); 3]);
+        assert_eq!(srs.g2, vec![G2Affine::generator(); 2]);
+    }
+
+    #[test]
+    fn test_read_ptau_power_overflow() {
+        // The largest power the header accepts, whose tauG1 section size
+        // does not fit in a `u64` on 64-bit targets.
+        let mut file = b"ptau".to_vec();
+        file.extend(1u32.to_le_bytes());
+        file.extend(2u32.to_le_bytes());
+        file.extend(1u32.to_le_bytes());
+        file.extend(44u64.to_le_bytes());
+        file.extend(32u32.to_le_bytes());
+        file.extend(ptau_modulus());
+        file.extend((usize::BITS - 2).to_le_bytes());
+        file.extend(0u32.to_le_bytes());
+        file.extend(2u32.to_le_bytes());
+        file.extend(0u64.to_le_bytes());
+        assert!(matches!(
+            read_ptau(&file[..], 1, 1),
+            Err(SrsError::InvalidFormat(_))
+        ));
+    }
+}
@@ -0,0 +1,238 @@
+use super::engine::Gt;
+use super::fq12::Fq12;
+use super::fq2::Fq2;
+use super::fq6::Fq6;
+use crate::ff::Field;
+use core::borrow::Borrow;
+use core::iter::Sum;
+use core::ops::{Add, Neg, Sub};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// `v`, the generator of Fq6 over Fq2. Fq12 = Fq6[w]/(w^2 - v).
+const V: Fq6 = Fq6 {
+    c0: Fq2::zero(),
+    c1: Fq2::one(),
+    c2: Fq2::zero(),
+};
+
+/// An element of [`Gt`] compressed to half its size on the algebraic torus
+/// T2.
+///
+/// Elements of Gt are unitary, `f = c0 + c1 * w` with `f * conj(f) = 1`, so
+/// they are determined by the single Fq6 element `g = (1 + c0) / c1`, and
+/// `f = (g + w) / (g - w)`. The identity, the only element of Gt with
+/// `c1 = 0`, is represented by `g = 0`.
+///
+/// The group law can be evaluated on the compressed form directly, which
+/// avoids decompressing when aggregating many pairing results.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct GtTorus(Fq6);
+
+crate::__impl_zeroize!(GtTorus);
+
+impl GtTorus {
+    /// Returns the group identity.
+    pub const fn identity() -> Self {
+        GtTorus(Fq6::zero())
+    }
+
+    /// Returns whether `self` is the identity.
+    pub fn is_identity(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    /// Recovers the uncompressed element.
+    pub fn decompress(&self) -> Gt {
+        // `v` is not a square in Fq6, so `g^2 - v` never vanishes.
+        let g2 = self.0.square();
+        let denominator = (g2 - V).invert().unwrap();
+        let f = Fq12 {
+            c0: (g2 + V) * denominator,
+            c1: self.0.double() * denominator,
+        };
+        Gt(Fq12::conditional_select(&f, &Fq12::ONE, self.is_identity()))
+    }
+
+    /// Doubles this group element.
+    pub fn double(&self) -> Self {
+        // g^2 = (g^2 + v) / 2g. The identity maps to itself since zero has no
+        // inverse.
+        let denominator = self.0.double().invert().unwrap_or(Fq6::zero());
+        GtTorus((self.0.square() + V) * denominator)
+    }
+
+    /// Encodes `self` into bytes.
+    pub fn to_bytes(&self) -> GtCompressed {
+        let mut res = [0u8; 192];
+        res[0..64].copy_from_slice(&self.0.c0.to_bytes());
+        res[64..128].copy_from_slice(&self.0.c1.to_bytes());
+        res[128..192].copy_from_slice(&self.0.c2.to_bytes());
+        GtCompressed(res)
+    }
+
+    /// Decodes bytes produced by [`GtTorus::to_bytes`], failing if any of the
+    /// coefficients is not canonical.
+    ///
+    /// No subgroup check is performed, see [`group::GroupEncoding`] for `Gt`
+    /// for a checked decoding.
+    pub fn from_bytes(bytes: &GtCompressed) -> CtOption<Self> {
+        let [c0, c1, c2] =
+            [0, 64, 128].map(|i| Fq2::from_bytes(&bytes.0[i..i + 64].try_into().unwrap()));
+        let is_some = c0.is_some() & c1.is_some() & c2.is_some();
+        let g = Fq6 {
+            c0: c0.unwrap_or(Fq2::zero()),
+            c1: c1.unwrap_or(Fq2::zero()),
+            c2: c2.unwrap_or(Fq2::zero()),
+        };
+        CtOption::new(GtTorus(g), is_some)
+    }
+}
+
+impl From<Gt> for GtTorus {
+    fn from(f: Gt) -> Self {
+        let c1_inv = f.0.c1.invert().unwrap_or(Fq6::zero());
+        GtTorus((f.0.c0 + Fq6::one()) * c1_inv)
+    }
+}
+
+impl From<GtTorus> for Gt {
+    fn from(g: GtTorus) -> Self {
+        g.decompress()
+    }
+}
+
+impl ConstantTimeEq for GtTorus {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl ConditionallySelectable for GtTorus {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        GtTorus(Fq6::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl Neg for &GtTorus {
+    type Output = GtTorus;
+
+    #[inline]
+    fn neg(self) -> GtTorus {
+        // Conjugation maps g to -g.
+        GtTorus(-self.0)
+    }
+}
+
+crate::impl_neg!(GtTorus);
+
+impl<'b> Add<&'b GtTorus> for &GtTorus {
+    type Output = GtTorus;
+
+    #[inline]
+    fn add(self, rhs: &'b GtTorus) -> GtTorus {
+        // g1 * g2 = (g1 g2 + v) / (g1 + g2). The sum vanishes exactly when
+        // the operands are inverse to each other, which yields the identity.
+        let denominator = (self.0 + rhs.0).invert().unwrap_or(Fq6::zero());
+        let res = GtTorus((self.0 * rhs.0 + V) * denominator);
+
+        let res = GtTorus::conditional_select(&res, rhs, self.is_identity());
+        GtTorus::conditional_select(&res, self, rhs.is_identity())
+    }
+}
+
+impl<'b> Sub<&'b GtTorus> for &GtTorus {
+    type Output = GtTorus;
+
+    #[inline]
+    fn sub(self, rhs: &'b GtTorus) -> GtTorus {
+        self + (-rhs)
+    }
+}
+
+use crate::{
+    impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
+    impl_sub_binop_specify_output,
+};
+impl_binops_additive!(GtTorus, GtTorus);
+
+impl<T> Sum<T> for GtTorus
+where
+    T: Borrow<GtTorus>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item.borrow())
+    }
+}
+
+/// Byte encoding of a [`GtTorus`], and the compressed encoding of [`Gt`].
+#[derive(Clone, Copy, Debug)]
+pub struct GtCompressed(pub(crate) [u8; 192]);
+
+impl Default for GtCompressed {
+    fn default() -> Self {
+        Self([0u8; 192])
+    }
+}
+
+impl AsMut<[u8]> for GtCompressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for GtCompressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::Fr;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    #[test]
+    fn test_torus_arithmetic() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let identity = GtTorus::identity();
+        assert_eq!(GtTorus::from(Gt::identity()), identity);
+        assert_eq!(identity.decompress(), Gt::identity());
+        assert_eq!(identity.double(), identity);
+        assert_eq!(identity + identity, identity);
+
+        let generator = Gt::generator();
+        let elements: Vec<Gt> = (0..10).map(|_| generator * Fr::random(&mut rng)).collect();
+        let compressed: Vec<GtTorus> = elements.iter().copied().map(GtTorus::from).collect();
+
+        for (a, ca) in elements.iter().zip(compressed.iter()) {
+            assert_eq!(ca.decompress(), *a);
+            assert_eq!(GtTorus::from_bytes(&ca.to_bytes()).unwrap(), *ca);
+
+            assert_eq!(ca + identity, *ca);
+            assert_eq!(identity + ca, *ca);
+            assert_eq!(ca - ca, identity);
+            assert_eq!((-ca).decompress(), -a);
+            assert_eq!(ca.double().decompress(), a.double());
+        }
+
+        for (a, ca) in elements.iter().zip(compressed.iter()) {
+            for (b, cb) in elements.iter().zip(compressed.iter()) {
+                assert_eq!((ca + cb).decompress(), a + b);
+                assert_eq!((ca - cb).decompress(), a - b);
+            }
+        }
+
+        let sum: Gt = elements.iter().sum();
+        let compressed_sum: GtTorus = compressed.iter().sum();
+        assert_eq!(compressed_sum.decompress(), sum);
+    }
+}
@@ -0,0 +1,135 @@
+//! Runtime detection of CPU features.
+//!
+//! The `asm` feature fixes the field arithmetic at compile time, so a prebuilt
+//! binary has to target the least capable machine it may be deployed on. The
+//! features of the running CPU are detected once and used in two ways.
+//!
+//! [`codegen`] is a code generation hint. Coarse grained routines
+//! (multiexponentiation, FFT butterflies, serial batch multiplication) have a
+//! copy of their loops compiled with `bmi2` and `adx` enabled, which they run
+//! when the CPU supports them. Only the inlined loop bodies are recompiled: the
+//! field and curve operations they call are separate functions built for the
+//! baseline target, so the arithmetic itself is the one selected at compile
+//! time. The `msm` benchmark compares both variants of `multiexp_serial`.
+//!
+//! [`backend`] selects the field multiplication kernel of
+//! [`BatchMul`](crate::ff_ext::BatchMul), which switches to AVX-512 IFMA on
+//! CPUs supporting it. The multiplications of MSM bucket accumulation are
+//! chained inside point additions, and the FFT butterflies multiply elements
+//! of a generic `FftGroup` rather than of a field, so neither has independent
+//! field products to hand to it.
+
+/// Instruction set extensions relevant to the arithmetic backends.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CpuFeatures {
+    /// Multi-precision add-carry instructions (`adcx`/`adox`).
+    pub adx: bool,
+    /// Flag-preserving multiplication (`mulx`).
+    pub bmi2: bool,
+    /// AVX-512 foundation.
+    pub avx512f: bool,
+    /// AVX-512 integer fused multiply-add.
+    pub avx512ifma: bool,
+}
+
+impl CpuFeatures {
+    /// Queries the running CPU. Prefer [`cpu_features`], which caches the
+    /// result.
+    ///
+    /// Without the `std` feature the CPU cannot be queried, and this returns
+    /// the features enabled at compile time instead.
+    #[allow(unused_mut)]
+    pub fn detect() -> Self {
+        let mut features = Self::default();
+        #[cfg(not(feature = "std"))]
+        {
+            features.adx = cfg!(target_feature = "adx");
+            features.bmi2 = cfg!(target_feature = "bmi2");
+            features.avx512f = cfg!(target_feature = "avx512f");
+            features.avx512ifma = cfg!(target_feature = "avx512ifma");
+        }
+        #[cfg(all(feature = "std", target_arch = "x86_64"))]
+        {
+            features.adx = std::is_x86_feature_detected!("adx");
+            features.bmi2 = std::is_x86_feature_detected!("bmi2");
+            features.avx512f = std::is_x86_feature_detected!("avx512f");
+            features.avx512ifma = std::is_x86_feature_detected!("avx512ifma");
+        }
+        features
+    }
+}
+
+/// Instruction set the loops of the dispatching routines are compiled for,
+/// from the least to the most capable.
+///
+/// This does not change the field arithmetic, see the [module](self)
+/// documentation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Codegen {
+    /// The baseline target.
+    Baseline,
+    /// x86_64 with `bmi2` and `adx` enabled.
+    Bmi2Adx,
+}
+
+/// Field multiplication kernel of [`BatchMul`](crate::ff_ext::BatchMul).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Backend {
+    /// One multiplication at a time, with the arithmetic selected at compile
+    /// time.
+    Portable,
+    /// Eight multiplications at once with AVX-512 IFMA, for the 4-limb fields.
+    Avx512Ifma,
+}
+
+lazy_static::lazy_static! {
+    static ref CPU_FEATURES: CpuFeatures = CpuFeatures::detect();
+}
+
+/// Returns the features of the running CPU, detected on first use.
+pub fn cpu_features() -> CpuFeatures {
+    *CPU_FEATURES
+}
+
+/// Returns the most capable [`Codegen`] supported by the running CPU.
+pub fn codegen() -> Codegen {
+    let features = cpu_features();
+    if features.bmi2 && features.adx {
+        Codegen::Bmi2Adx
+    } else {
+        Codegen::Baseline
+    }
+}
+
+/// Returns the best [`Backend`] available on the running CPU.
+pub fn backend() -> Backend {
+    let features = cpu_features();
+    if features.avx512f && features.avx512ifma {
+        Backend::Avx512Ifma
+    } else {
+        Backend::Portable
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_backend_matches_features() {
+        let features = cpu_features();
+        assert_eq!(features, CpuFeatures::detect());
+        assert_eq!(codegen() == Codegen::Bmi2Adx, features.bmi2 && features.adx);
+        assert_eq!(
+            backend() == Backend::Avx512Ifma,
+            features.avx512f && features.avx512ifma
+        );
+        #[cfg(not(target_arch = "x86_64"))]
+        {
+            assert_eq!(codegen(), Codegen::Baseline);
+            assert_eq!(backend(), Backend::Portable);
+        }
+    }
+}
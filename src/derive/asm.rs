@@ -1,10 +1,20 @@
+/// Implements the arithmetic of a 4-limb Montgomery field in x86_64 assembly,
+/// using the `bmi2` and `adx` instructions.
+///
+/// `sparse` moduli leave the top bit of the last limb free, which the
+/// multiplication and addition rely on to skip carries, e.g. the `bn256`
+/// fields. `dense` moduli use all 256 bits, e.g. the `secp256k1` and
+/// `secp256r1` fields.
 macro_rules! field_arithmetic_asm {
     (
         $field:ident,
         $modulus:ident,
-        $inv:ident
+        $inv:ident,
+        sparse
     ) => {
-        use std::arch::asm;
+        use core::arch::asm;
+
+        $crate::derive::asm::field_arithmetic_asm!(@common $field, $modulus);
 
         impl $field {
             /// Doubles this field element.
@@ -62,12 +72,6 @@ macro_rules! field_arithmetic_asm {
                 $field([r0, r1, r2, r3])
             }
 
-            /// Squares this element.
-            #[inline]
-            pub fn square(&self) -> $field {
-                self.mul(self)
-            }
-
             #[inline(always)]
             pub(crate) fn montgomery_reduce_256(&self) -> $field {
                 let mut r0: u64;
@@ -438,52 +442,48 @@ macro_rules! field_arithmetic_asm {
                 $field([r0, r1, r2, r3])
             }
 
-            /// Subtracts `rhs` from `self`, returning the result.
+            /// Adds `rhs` to `self`, returning the result.
             #[inline]
-            pub fn sub(&self, rhs: &Self) -> $field {
+            pub fn add(&self, rhs: &Self) -> $field {
                 let mut r0: u64;
                 let mut r1: u64;
                 let mut r2: u64;
                 let mut r3: u64;
                 unsafe {
                     asm!(
-                        // init modulus area
-                        "mov r12, qword ptr [{m_ptr} + 0]",
-                        "mov r13, qword ptr [{m_ptr} + 8]",
-                        "mov r14, qword ptr [{m_ptr} + 16]",
-                        "mov r15, qword ptr [{m_ptr} + 24]",
-
                         // load a array to former registers
                         "mov r8, qword ptr [{a_ptr} + 0]",
                         "mov r9, qword ptr [{a_ptr} + 8]",
                         "mov r10, qword ptr [{a_ptr} + 16]",
                         "mov r11, qword ptr [{a_ptr} + 24]",
 
-                        // sub a array and b array with borrow
-                        "sub r8, qword ptr [{b_ptr} + 0]",
-                        "sbb r9, qword ptr [{b_ptr} + 8]",
-                        "sbb r10, qword ptr [{b_ptr} + 16]",
-                        "sbb r11, qword ptr [{b_ptr} + 24]",
+                        // add a array and b array with carry
+                        "add r8, qword ptr [{b_ptr} + 0]",
+                        "adc r9, qword ptr [{b_ptr} + 8]",
+                        "adc r10, qword ptr [{b_ptr} + 16]",
+                        "adc r11, qword ptr [{b_ptr} + 24]",
 
-                        // Mask: rax contains 0xFFFF if < m or 0x0000 otherwise
-                        "sbb rax, rax",
+                        // copy result array to latter registers
+                        "mov r12, r8",
+                        "mov r13, r9",
+                        "mov r14, r10",
+                        "mov r15, r11",
 
-                        // Zero-out the modulus if a-b < m or leave as-is otherwise
-                        "and r12, rax",
-                        "and r13, rax",
-                        "and r14, rax",
-                        "and r15, rax",
+                        // mod reduction
+                        "sub r12, qword ptr [{m_ptr} + 0]",
+                        "sbb r13, qword ptr [{m_ptr} + 8]",
+                        "sbb r14, qword ptr [{m_ptr} + 16]",
+                        "sbb r15, qword ptr [{m_ptr} + 24]",
 
-                        // Add zero if a-b < m or a-b+m otherwise
-                        "add  r12, r8",
-                        "adc  r13, r9",
-                        "adc  r14, r10",
-                        "adc  r15, r11",
+                        // if carry copy former registers to out areas
+                        "cmovc r12, r8",
+                        "cmovc r13, r9",
+                        "cmovc r14, r10",
+                        "cmovc r15, r11",
 
                         m_ptr = in(reg) $modulus.0.as_ptr(),
                         a_ptr = in(reg) self.0.as_ptr(),
                         b_ptr = in(reg) rhs.0.as_ptr(),
-                        out("rax") _,
                         out("r8") _,
                         out("r9") _,
                         out("r10") _,
@@ -497,6 +497,342 @@ macro_rules! field_arithmetic_asm {
                 }
                 $field([r0, r1, r2, r3])
             }
+        }
+    };
+    (
+        $field:ident,
+        $modulus:ident,
+        $inv:ident,
+        dense
+    ) => {
+        use core::arch::asm;
+
+        $crate::derive::asm::field_arithmetic_asm!(@common $field, $modulus);
+
+        impl $field {
+            /// Doubles this field element.
+            #[inline]
+            pub fn double(&self) -> $field {
+                self.add(self)
+            }
+
+            #[inline(always)]
+            pub(crate) fn montgomery_reduce_256(&self) -> $field {
+                // (a.R) * 1 / R = a
+                self.mul(&$field([1, 0, 0, 0]))
+            }
+
+            /// Multiplies `rhs` by `self`, returning the result.
+            #[inline]
+            pub fn mul(&self, rhs: &Self) -> $field {
+                // The modulus followed by m0ninv, read from memory as all
+                // general purpose registers are taken.
+                const MODULUS_INV: [u64; 5] = [
+                    $modulus.0[0],
+                    $modulus.0[1],
+                    $modulus.0[2],
+                    $modulus.0[3],
+                    $inv,
+                ];
+
+                let mut r0: u64;
+                let mut r1: u64;
+                let mut r2: u64;
+                let mut r3: u64;
+                unsafe {
+                    asm!(
+                        // Coarsely Integrated Operand Scanning without the
+                        // no-carry optimization, as the modulus has no spare
+                        // bit. The accumulator t[0..5] lives in r8-r12, and
+                        // r13 catches the carry of t[4].
+                        //
+                        // for i=0 to N-1
+                        //   (A,t[j])  := t[j] + a[j]*b[i] + A
+                        //   (t[N+1],t[N]) := t[N] + A
+                        //   m := t[0]*m0ninv mod W
+                        //   C,_ := t[0] + m*M[0]
+                        //   for j=1 to N-1
+                        //     (C,t[j-1]) := t[j] + m*M[j] + C
+                        //   (t[N],t[N-1]) := t[N] + t[N+1]*W + C
+
+                        // Outer loop i = 0
+                        //   t += a * b[i]
+                        "mov  rdx, qword ptr [{b_ptr} + 0]",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 0]",
+                        "add  r8, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 8]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r9, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 16]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r10, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 24]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r11, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "add  r12, r15",
+                        "mov  r13, 0",
+                        "adc  r13, 0",
+                        //   m = t[0] * m0ninv mod 2^w
+                        "mov  rdx, r8",
+                        "imul rdx, qword ptr [{m_ptr} + 32]",
+                        //   t = (t + m * M) / 2^w
+                        "mulx r14, rax, qword ptr [{m_ptr} + 0]",
+                        "add  rax, r8",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 8]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r9",
+                        "adc  r14, 0",
+                        "mov  r8, rax",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 16]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r10",
+                        "adc  r14, 0",
+                        "mov  r9, rax",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 24]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r11",
+                        "adc  r14, 0",
+                        "mov  r10, rax",
+                        "add  r14, r12",
+                        "mov  r11, r14",
+                        "adc  r13, 0",
+                        "mov  r12, r13",
+
+                        // Outer loop i = 1
+                        //   t += a * b[i]
+                        "mov  rdx, qword ptr [{b_ptr} + 8]",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 0]",
+                        "add  r8, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 8]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r9, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 16]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r10, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 24]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r11, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "add  r12, r15",
+                        "mov  r13, 0",
+                        "adc  r13, 0",
+                        //   m = t[0] * m0ninv mod 2^w
+                        "mov  rdx, r8",
+                        "imul rdx, qword ptr [{m_ptr} + 32]",
+                        //   t = (t + m * M) / 2^w
+                        "mulx r14, rax, qword ptr [{m_ptr} + 0]",
+                        "add  rax, r8",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 8]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r9",
+                        "adc  r14, 0",
+                        "mov  r8, rax",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 16]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r10",
+                        "adc  r14, 0",
+                        "mov  r9, rax",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 24]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r11",
+                        "adc  r14, 0",
+                        "mov  r10, rax",
+                        "add  r14, r12",
+                        "mov  r11, r14",
+                        "adc  r13, 0",
+                        "mov  r12, r13",
+
+                        // Outer loop i = 2
+                        //   t += a * b[i]
+                        "mov  rdx, qword ptr [{b_ptr} + 16]",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 0]",
+                        "add  r8, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 8]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r9, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 16]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r10, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 24]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r11, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "add  r12, r15",
+                        "mov  r13, 0",
+                        "adc  r13, 0",
+                        //   m = t[0] * m0ninv mod 2^w
+                        "mov  rdx, r8",
+                        "imul rdx, qword ptr [{m_ptr} + 32]",
+                        //   t = (t + m * M) / 2^w
+                        "mulx r14, rax, qword ptr [{m_ptr} + 0]",
+                        "add  rax, r8",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 8]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r9",
+                        "adc  r14, 0",
+                        "mov  r8, rax",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 16]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r10",
+                        "adc  r14, 0",
+                        "mov  r9, rax",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 24]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r11",
+                        "adc  r14, 0",
+                        "mov  r10, rax",
+                        "add  r14, r12",
+                        "mov  r11, r14",
+                        "adc  r13, 0",
+                        "mov  r12, r13",
+
+                        // Outer loop i = 3
+                        //   t += a * b[i]
+                        "mov  rdx, qword ptr [{b_ptr} + 24]",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 0]",
+                        "add  r8, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 8]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r9, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 16]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r10, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{a_ptr} + 24]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  r11, rax",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "add  r12, r15",
+                        "mov  r13, 0",
+                        "adc  r13, 0",
+                        //   m = t[0] * m0ninv mod 2^w
+                        "mov  rdx, r8",
+                        "imul rdx, qword ptr [{m_ptr} + 32]",
+                        //   t = (t + m * M) / 2^w
+                        "mulx r14, rax, qword ptr [{m_ptr} + 0]",
+                        "add  rax, r8",
+                        "adc  r14, 0",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 8]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r9",
+                        "adc  r14, 0",
+                        "mov  r8, rax",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 16]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r10",
+                        "adc  r14, 0",
+                        "mov  r9, rax",
+                        "mov  r15, r14",
+                        "mulx r14, rax, qword ptr [{m_ptr} + 24]",
+                        "add  rax, r15",
+                        "adc  r14, 0",
+                        "add  rax, r11",
+                        "adc  r14, 0",
+                        "mov  r10, rax",
+                        "add  r14, r12",
+                        "mov  r11, r14",
+                        "adc  r13, 0",
+                        "mov  r12, r13",
+
+                        //   Final subtraction, with the carry word in r12
+                        "mov  rax, r8",
+                        "sub  rax, qword ptr [{m_ptr} + 0]",
+                        "mov  rdx, r9",
+                        "sbb  rdx, qword ptr [{m_ptr} + 8]",
+                        "mov  r14, r10",
+                        "sbb  r14, qword ptr [{m_ptr} + 16]",
+                        "mov  r15, r11",
+                        "sbb  r15, qword ptr [{m_ptr} + 24]",
+                        "sbb  r12, 0",
+                        "cmovnc r8, rax",
+                        "cmovnc r9, rdx",
+                        "cmovnc r10, r14",
+                        "cmovnc r11, r15",
+
+                        m_ptr = in(reg) MODULUS_INV.as_ptr(),
+                        a_ptr = in(reg) self.0.as_ptr(),
+                        b_ptr = in(reg) rhs.0.as_ptr(),
+                        inout("r8") 0u64 => r0,
+                        inout("r9") 0u64 => r1,
+                        inout("r10") 0u64 => r2,
+                        inout("r11") 0u64 => r3,
+                        inout("r12") 0u64 => _,
+                        out("r13") _,
+                        out("rax") _,
+                        out("rdx") _,
+                        out("r14") _,
+                        out("r15") _,
+                        options(pure, readonly, nostack)
+                    )
+                }
+
+                $field([r0, r1, r2, r3])
+            }
 
             /// Adds `rhs` to `self`, returning the result.
             #[inline]
@@ -513,11 +849,14 @@ macro_rules! field_arithmetic_asm {
                         "mov r10, qword ptr [{a_ptr} + 16]",
                         "mov r11, qword ptr [{a_ptr} + 24]",
 
-                        // add a array and b array with carry
+                        // add a array and b array with carry, keeping the
+                        // carry out of the last limb in rax
                         "add r8, qword ptr [{b_ptr} + 0]",
                         "adc r9, qword ptr [{b_ptr} + 8]",
                         "adc r10, qword ptr [{b_ptr} + 16]",
                         "adc r11, qword ptr [{b_ptr} + 24]",
+                        "mov rax, 0",
+                        "adc rax, 0",
 
                         // copy result array to latter registers
                         "mov r12, r8",
@@ -525,13 +864,14 @@ macro_rules! field_arithmetic_asm {
                         "mov r14, r10",
                         "mov r15, r11",
 
-                        // mod reduction
+                        // mod reduction, borrowing from the carry
                         "sub r12, qword ptr [{m_ptr} + 0]",
                         "sbb r13, qword ptr [{m_ptr} + 8]",
                         "sbb r14, qword ptr [{m_ptr} + 16]",
                         "sbb r15, qword ptr [{m_ptr} + 24]",
+                        "sbb rax, 0",
 
-                        // if carry copy former registers to out areas
+                        // if borrow copy former registers to out areas
                         "cmovc r12, r8",
                         "cmovc r13, r9",
                         "cmovc r14, r10",
@@ -540,6 +880,76 @@ macro_rules! field_arithmetic_asm {
                         m_ptr = in(reg) $modulus.0.as_ptr(),
                         a_ptr = in(reg) self.0.as_ptr(),
                         b_ptr = in(reg) rhs.0.as_ptr(),
+                        out("rax") _,
+                        out("r8") _,
+                        out("r9") _,
+                        out("r10") _,
+                        out("r11") _,
+                        out("r12") r0,
+                        out("r13") r1,
+                        out("r14") r2,
+                        out("r15") r3,
+                        options(pure, readonly, nostack)
+                    );
+                }
+                $field([r0, r1, r2, r3])
+            }
+        }
+    };
+    (@common $field:ident, $modulus:ident) => {
+        impl $field {
+            /// Squares this element.
+            #[inline]
+            pub fn square(&self) -> $field {
+                self.mul(self)
+            }
+
+            /// Subtracts `rhs` from `self`, returning the result.
+            #[inline]
+            pub fn sub(&self, rhs: &Self) -> $field {
+                let mut r0: u64;
+                let mut r1: u64;
+                let mut r2: u64;
+                let mut r3: u64;
+                unsafe {
+                    asm!(
+                        // init modulus area
+                        "mov r12, qword ptr [{m_ptr} + 0]",
+                        "mov r13, qword ptr [{m_ptr} + 8]",
+                        "mov r14, qword ptr [{m_ptr} + 16]",
+                        "mov r15, qword ptr [{m_ptr} + 24]",
+
+                        // load a array to former registers
+                        "mov r8, qword ptr [{a_ptr} + 0]",
+                        "mov r9, qword ptr [{a_ptr} + 8]",
+                        "mov r10, qword ptr [{a_ptr} + 16]",
+                        "mov r11, qword ptr [{a_ptr} + 24]",
+
+                        // sub a array and b array with borrow
+                        "sub r8, qword ptr [{b_ptr} + 0]",
+                        "sbb r9, qword ptr [{b_ptr} + 8]",
+                        "sbb r10, qword ptr [{b_ptr} + 16]",
+                        "sbb r11, qword ptr [{b_ptr} + 24]",
+
+                        // Mask: rax contains 0xFFFF if < m or 0x0000 otherwise
+                        "sbb rax, rax",
+
+                        // Zero-out the modulus if a-b < m or leave as-is otherwise
+                        "and r12, rax",
+                        "and r13, rax",
+                        "and r14, rax",
+                        "and r15, rax",
+
+                        // Add zero if a-b < m or a-b+m otherwise
+                        "add  r12, r8",
+                        "adc  r13, r9",
+                        "adc  r14, r10",
+                        "adc  r15, r11",
+
+                        m_ptr = in(reg) $modulus.0.as_ptr(),
+                        a_ptr = in(reg) self.0.as_ptr(),
+                        b_ptr = in(reg) rhs.0.as_ptr(),
+                        out("rax") _,
                         out("r8") _,
                         out("r9") _,
                         out("r10") _,
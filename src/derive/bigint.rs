@@ -0,0 +1,35 @@
+/// Implements conversions between the field `$field` and the `crypto-bigint`
+/// integer `$uint` of the same width, and the reduction of a `U512` into the
+/// field.
+///
+/// The integers hold the canonical value of the elements, not their Montgomery
+/// form.
+macro_rules! impl_crypto_bigint_conversions {
+    ($field:ident, $uint:ident) => {
+        impl From<$field> for ::crypto_bigint::$uint {
+            fn from(f: $field) -> Self {
+                ::crypto_bigint::$uint::from_le_slice($crate::ff::PrimeField::to_repr(&f).as_ref())
+            }
+        }
+
+        impl $field {
+            /// Converts an integer into a field element, failing if it is not
+            /// below the modulus.
+            pub fn from_uint(n: &::crypto_bigint::$uint) -> CtOption<Self> {
+                let mut repr = <Self as $crate::ff::PrimeField>::Repr::default();
+                repr.as_mut()
+                    .copy_from_slice(&::crypto_bigint::Encoding::to_le_bytes(n));
+                <Self as $crate::ff::PrimeField>::from_repr(repr)
+            }
+
+            /// Reduces a 512-bit integer modulo the modulus.
+            pub fn reduce(n: &::crypto_bigint::U512) -> Self {
+                <Self as $crate::ff::FromUniformBytes<64>>::from_uniform_bytes(
+                    &::crypto_bigint::Encoding::to_le_bytes(n),
+                )
+            }
+        }
+    };
+}
+
+pub(crate) use impl_crypto_bigint_conversions;
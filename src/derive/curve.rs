@@ -50,11 +50,75 @@ macro_rules! endo {
 }
 
 // Sign mask for 0, 1 and 2 spare bits.
-pub(crate) const SIGN_MASK: u8 = 0b1000_0000;
-pub(crate) const SIGN_SHIFT: u8 = 7;
+pub const SIGN_MASK: u8 = 0b1000_0000;
+pub const SIGN_SHIFT: u8 = 7;
 // Identity mask for 0 and 2 spare bits (1 spare bit does not use it).
-pub(crate) const IDENTITY_MASK: u8 = 0b0100_0000;
-pub(crate) const IDENTITY_SHIFT: u8 = 6;
+pub const IDENTITY_MASK: u8 = 0b0100_0000;
+pub const IDENTITY_SHIFT: u8 = 6;
+
+/// Defines the compressed encoding `$name` of a point as a byte array of
+/// length `$size`, deriving serde support with the `derive_serde` feature.
+#[cfg(feature = "derive_serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compressed_point_struct {
+    ($name:ident, $size:expr) => {
+        #[derive(
+            Copy, Clone, $crate::__private::serde::Serialize, $crate::__private::serde::Deserialize,
+        )]
+        pub struct $name(#[serde(with = "serde_arrays")] [u8; $size]);
+    };
+}
+
+/// Defines the compressed encoding `$name` of a point as a byte array of
+/// length `$size`, deriving serde support with the `derive_serde` feature.
+#[cfg(not(feature = "derive_serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __compressed_point_struct {
+    ($name:ident, $size:expr) => {
+        #[derive(Copy, Clone)]
+        pub struct $name([u8; $size]);
+    };
+}
+
+/// Implements `zeroize::DefaultIsZeroes` for `$name` with the `zeroize`
+/// feature, which provides `Zeroize` for it and for slices and vectors of it.
+/// The value is reset to its `Default`: zero for field elements, the identity
+/// for points.
+#[cfg(feature = "zeroize")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_zeroize {
+    ($name:ident) => {
+        impl $crate::__private::zeroize::DefaultIsZeroes for $name {}
+    };
+}
+
+/// Implements `zeroize::DefaultIsZeroes` for `$name` with the `zeroize`
+/// feature, which provides `Zeroize` for it and for slices and vectors of it.
+/// The value is reset to its `Default`: zero for field elements, the identity
+/// for points.
+#[cfg(not(feature = "zeroize"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __impl_zeroize {
+    ($name:ident) => {};
+}
+
+/// Implements `derive_generator` for a curve that supports hash-to-curve.
+#[macro_export]
+macro_rules! impl_derive_generator {
+    ($name:ident) => {
+        impl $name {
+            /// Derives the `index`-th generator for `label`, see
+            /// [`derive_generator`]($crate::hash_to_curve::derive_generator).
+            pub fn derive_generator(label: &[u8], index: u64) -> Self {
+                $crate::hash_to_curve::derive_generator(label, index)
+            }
+        }
+    };
+}
 
 #[macro_export]
 macro_rules! new_curve_impl {
@@ -127,19 +191,42 @@ macro_rules! new_curve_impl {
                         $base::size()
                     };
 
-                #[derive(Copy, Clone, PartialEq, Eq)]
-                #[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
-                pub struct [<$name Compressed >](
-                    #[cfg_attr(feature = "derive_serde", serde(with = "serde_arrays"))]
-                    [u8; [< $name _COMPRESSED_SIZE >]]
+                $crate::__compressed_point_struct!(
+                    [<$name Compressed >],
+                    [< $name _COMPRESSED_SIZE >]
                 );
 
-                impl std::fmt::Debug for [< $name Compressed >] {
-                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                impl core::fmt::Debug for [< $name Compressed >] {
+                    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                         self.0[..].fmt(f)
                     }
                 }
 
+                impl ConstantTimeEq for [< $name Compressed >] {
+                    fn ct_eq(&self, other: &Self) -> Choice {
+                        self.0.ct_eq(&other.0)
+                    }
+                }
+
+                impl ConditionallySelectable for [< $name Compressed >] {
+                    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                        let mut res = *a;
+                        for (r, b) in res.0.iter_mut().zip(b.0.iter()) {
+                            r.conditional_assign(b, choice);
+                        }
+                        res
+                    }
+                }
+
+                impl Eq for [< $name Compressed >] {}
+
+                impl PartialEq for [< $name Compressed >] {
+                    #[inline]
+                    fn eq(&self, other: &Self) -> bool {
+                        bool::from(self.ct_eq(other))
+                    }
+                }
+
                 impl Default for [< $name Compressed >] {
                     fn default() -> Self {
                         [< $name Compressed >]([0; [< $name _COMPRESSED_SIZE >]])
@@ -292,6 +379,76 @@ macro_rules! new_curve_impl {
                     }
                 }
 
+                impl $name_affine {
+                    /// Decodes a compressed point, accepting only the encodings produced by
+                    /// [`GroupEncoding::to_bytes`] of points in the prime order subgroup.
+                    ///
+                    /// [`GroupEncoding::from_bytes`] ignores the remaining bits of an identity
+                    /// encoding and does not check the subgroup, so distinct encodings may
+                    /// decode to the same point. This is meant for consensus-critical code,
+                    /// where all parties must agree on which encodings are valid.
+                    ///
+                    /// Unlike `from_bytes`, this runs in variable time, and returns early on
+                    /// the first check that fails.
+                    pub fn from_bytes_strict(
+                        bytes: &[< $name Compressed >],
+                    ) -> Result<Self, $crate::serde::PointDecodingError> {
+                        use $crate::serde::PointDecodingError;
+
+                        let mut tmp = bytes.0;
+                        let flag_byte = tmp[[< $name _FLAG_BYTE_INDEX>]];
+                        let sign_flag = flag_byte & SIGN_MASK != 0;
+                        let identity_flag = ($spare_bits == 0 || $spare_bits == 2)
+                            && flag_byte & IDENTITY_MASK != 0;
+                        if $spare_bits == 0 && flag_byte & 0b0011_1111 != 0 {
+                            return Err(PointDecodingError::InvalidFlags);
+                        }
+
+                        tmp[[< $name _FLAG_BYTE_INDEX>]] &= ![< $name _FLAG_BITS >];
+                        let mut xbytes = [0u8; $base::size()];
+                        xbytes.copy_from_slice(&tmp[..$base::size()]);
+                        let x = Option::<$base>::from($base::from_bytes(&xbytes))
+                            .ok_or(PointDecodingError::NotCanonical)?;
+
+                        let is_identity = if $spare_bits == 1 {
+                            bool::from(x.is_zero())
+                        } else {
+                            identity_flag
+                        };
+                        if is_identity {
+                            // All bits but the identity flag are zero.
+                            return if sign_flag || !bool::from(x.is_zero()) {
+                                Err(PointDecodingError::InvalidFlags)
+                            } else {
+                                Ok(Self::identity())
+                            };
+                        }
+
+                        let y = Option::<$base>::from($name_affine::y2(x).sqrt())
+                            .ok_or(PointDecodingError::NotOnCurve)?;
+                        let y = if (y.to_bytes()[0] & 1 == 1) == sign_flag { y } else { -y };
+                        // A zero y-coordinate has no negative root.
+                        if (y.to_bytes()[0] & 1 == 1) != sign_flag {
+                            return Err(PointDecodingError::InvalidFlags);
+                        }
+
+                        let p = $name_affine { x, y };
+                        if !bool::from(p.is_torsion_free()) {
+                            return Err(PointDecodingError::NotInSubgroup);
+                        }
+                        Ok(p)
+                    }
+                }
+
+                impl $name {
+                    /// Decodes a compressed point, see [`$name_affine::from_bytes_strict`].
+                    pub fn from_bytes_strict(
+                        bytes: &[< $name Compressed >],
+                    ) -> Result<Self, $crate::serde::PointDecodingError> {
+                        $name_affine::from_bytes_strict(bytes).map(Self::from)
+                    }
+                }
+
 
                 }
             };
@@ -344,8 +501,8 @@ macro_rules! new_curve_impl {
 
                 #[derive(Copy, Clone)]
                 pub struct [< $name Uncompressed >]([u8; 2*$base::size()]);
-                    impl std::fmt::Debug for [< $name Uncompressed >] {
-                        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                    impl core::fmt::Debug for [< $name Uncompressed >] {
+                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
                             self.0[..].fmt(f)
                         }
                     }
@@ -374,6 +531,16 @@ macro_rules! new_curve_impl {
                         }
                     }
 
+                    impl ConditionallySelectable for [< $name Uncompressed >] {
+                        fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                            let mut res = *a;
+                            for (r, b) in res.0.iter_mut().zip(b.0.iter()) {
+                                r.conditional_assign(b, choice);
+                            }
+                            res
+                        }
+                    }
+
                     impl Eq for [< $name Uncompressed >] {}
 
                     impl PartialEq for [< $name Uncompressed >] {
@@ -387,7 +554,8 @@ macro_rules! new_curve_impl {
                         type Uncompressed = [< $name Uncompressed >];
 
                         fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
-                            Self::from_uncompressed_unchecked(bytes).and_then(|p| CtOption::new(p, p.is_on_curve()))
+                            Self::from_uncompressed_unchecked(bytes)
+                                .and_then(|p| CtOption::new(p, p.is_on_curve() & p.is_torsion_free()))
                         }
 
                         fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
@@ -451,8 +619,6 @@ macro_rules! new_curve_impl {
                                         is_identity,
                                     );
 
-                                    eprintln!("Is the point valid? {:?}", is_valid);
-
                                     CtOption::new(
                                         p,
                                         is_valid
@@ -478,69 +644,66 @@ macro_rules! new_curve_impl {
             };
         }
 
-        /// A macro to help define point serialization using the [`group::GroupEncoding`] trait
-        /// This assumes both point types ($name, $nameaffine) implement [`group::GroupEncoding`].
-        #[cfg(feature = "derive_serde")]
-        macro_rules! serialize_deserialize_to_from_bytes {
-            () => {
-                impl ::serde::Serialize for $name {
-                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                        let bytes = &self.to_bytes();
-                        if serializer.is_human_readable() {
-                            ::hex::serde::serialize(&bytes.0, serializer)
-                        } else {
-                            ::serde_arrays::serialize(&bytes.0, serializer)
-                        }
+        // Point serialization using the [`group::GroupEncoding`] trait.
+        // Human-readable formats use `0x`-prefixed big-endian hex strings.
+        $crate::__cfg_derive_serde! {
+            impl $crate::__private::serde::Serialize for $name {
+                fn serialize<S: $crate::__private::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let bytes = &self.to_bytes();
+                    if serializer.is_human_readable() {
+                        $crate::serde::hex_be::serialize(&bytes.0, serializer)
+                    } else {
+                        $crate::__private::serde_arrays::serialize(&bytes.0, serializer)
                     }
                 }
+            }
 
-                paste::paste! {
-                    impl<'de> ::serde::Deserialize<'de> for $name {
-                        fn deserialize<D: ::serde::Deserializer<'de>>(
-                            deserializer: D,
-                        ) -> Result<Self, D::Error> {
-                            use ::serde::de::Error as _;
-                            let bytes = if deserializer.is_human_readable() {
-                                ::hex::serde::deserialize(deserializer)?
-                            } else {
-                                ::serde_arrays::deserialize::<_, u8, [< $name _COMPRESSED_SIZE >]>(deserializer)?
-                            };
-                            Option::from(Self::from_bytes(&[< $name Compressed >](bytes))).ok_or_else(|| {
-                                D::Error::custom("deserialized bytes don't encode a valid field element")
-                            })
-                        }
+            paste::paste! {
+                impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+                    fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+                        deserializer: D,
+                    ) -> Result<Self, D::Error> {
+                        use $crate::__private::serde::de::Error as _;
+                        let bytes = if deserializer.is_human_readable() {
+                            $crate::serde::hex_be::deserialize(deserializer)?
+                        } else {
+                            $crate::__private::serde_arrays::deserialize::<_, u8, [< $name _COMPRESSED_SIZE >]>(deserializer)?
+                        };
+                        Option::from(Self::from_bytes(&[< $name Compressed >](bytes))).ok_or_else(|| {
+                            D::Error::custom("deserialized bytes don't encode a valid field element")
+                        })
                     }
                 }
+            }
 
-                impl ::serde::Serialize for $name_affine {
-                    fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-                        let bytes = &self.to_bytes();
-                        if serializer.is_human_readable() {
-                            ::hex::serde::serialize(&bytes.0, serializer)
-                        } else {
-                            ::serde_arrays::serialize(&bytes.0, serializer)
-                        }
+            impl $crate::__private::serde::Serialize for $name_affine {
+                fn serialize<S: $crate::__private::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    let bytes = &self.to_bytes();
+                    if serializer.is_human_readable() {
+                        $crate::serde::hex_be::serialize(&bytes.0, serializer)
+                    } else {
+                        $crate::__private::serde_arrays::serialize(&bytes.0, serializer)
                     }
                 }
+            }
 
-                paste::paste! {
-                    impl<'de> ::serde::Deserialize<'de> for $name_affine {
-                        fn deserialize<D: ::serde::Deserializer<'de>>(
-                            deserializer: D,
-                        ) -> Result<Self, D::Error> {
-                            use ::serde::de::Error as _;
-                            let bytes = if deserializer.is_human_readable() {
-                                ::hex::serde::deserialize(deserializer)?
-                            } else {
-                                ::serde_arrays::deserialize::<_, u8, [< $name _COMPRESSED_SIZE >]>(deserializer)?
-                            };
-                            Option::from(Self::from_bytes(&[< $name Compressed >](bytes))).ok_or_else(|| {
-                                D::Error::custom("deserialized bytes don't encode a valid field element")
-                            })
-                        }
+            paste::paste! {
+                impl<'de> $crate::__private::serde::Deserialize<'de> for $name_affine {
+                    fn deserialize<D: $crate::__private::serde::Deserializer<'de>>(
+                        deserializer: D,
+                    ) -> Result<Self, D::Error> {
+                        use $crate::__private::serde::de::Error as _;
+                        let bytes = if deserializer.is_human_readable() {
+                            $crate::serde::hex_be::deserialize(deserializer)?
+                        } else {
+                            $crate::__private::serde_arrays::deserialize::<_, u8, [< $name _COMPRESSED_SIZE >]>(deserializer)?
+                        };
+                        Option::from(Self::from_bytes(&[< $name Compressed >](bytes))).ok_or_else(|| {
+                            D::Error::custom("deserialized bytes don't encode a valid field element")
+                        })
                     }
                 }
-            };
+            }
         }
 
         #[derive(Copy, Clone, Debug)]
@@ -550,15 +713,12 @@ macro_rules! new_curve_impl {
             pub z: $base,
         }
 
-        #[derive(Copy, Clone, PartialEq)]
+        #[derive(Copy, Clone)]
         $($privacy)* struct $name_affine {
             pub x: $base,
             pub y: $base,
         }
 
-        #[cfg(feature = "derive_serde")]
-        serialize_deserialize_to_from_bytes!();
-
         // Base's num_bits is the number of bits for the base prime field,
         // so the computation of spare bits is correct for extensions as well.
         impl_compressed!((($base::NUM_BITS-1) / 8 +1) * 8 - $base::NUM_BITS);
@@ -567,6 +727,16 @@ macro_rules! new_curve_impl {
 
 
         impl $name {
+            /// Returns the identity of the group, which is also the `Default`
+            /// value. Unlike `Group::identity`, this can be used in constants.
+            pub const fn identity() -> Self {
+                Self {
+                    x: $base::zero(),
+                    y: $base::one(),
+                    z: $base::zero(),
+                }
+            }
+
             pub fn generator() -> Self {
                 let generator = $name_affine::generator();
                 Self {
@@ -576,24 +746,314 @@ macro_rules! new_curve_impl {
                 }
             }
 
+            /// Multiplies the generator by `scalar` using a lazily precomputed
+            /// table of generator multiples.
+            pub fn mul_by_generator(scalar: &$scalar) -> Self {
+                lazy_static::lazy_static! {
+                    static ref GENERATOR_TABLE: $crate::fixed_base::FixedBaseTable<$name_affine> =
+                        $crate::fixed_base::FixedBaseTable::new($name_affine::generator());
+                }
+                GENERATOR_TABLE.mul(scalar)
+            }
+
+            $crate::__cfg_std! {
+                /// Writes `points` in the raw projective format, prefixed with their
+                /// number as a little-endian `u64`. Points are not normalized, which
+                /// makes this suitable for checkpointing intermediate state.
+                pub fn write_raw_batch<W: std::io::Write>(points: &[Self], writer: &mut W) -> std::io::Result<()> {
+                    writer.write_all(&(points.len() as u64).to_le_bytes())?;
+                    for point in points {
+                        $crate::serde::SerdeObject::write_raw(point, writer)?;
+                    }
+                    Ok(())
+                }
+            }
+
+            $crate::__cfg_std! {
+                /// Reads points written by [`Self::write_raw_batch`], checking in
+                /// parallel that every coordinate is canonical and every point is on
+                /// the curve.
+                pub fn read_raw_batch<R: std::io::Read>(reader: &mut R) -> std::io::Result<alloc::vec::Vec<Self>> {
+                    use $crate::multicore::*;
+                    use std::io::Read;
+
+                    let mut len = [0u8; 8];
+                    reader.read_exact(&mut len)?;
+                    let point_size = 3 * $base::size();
+                    let num_bytes = u64::from_le_bytes(len)
+                        .checked_mul(point_size as u64)
+                        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "too many points"))?;
+
+                    // Do not trust the length prefix for the allocation.
+                    let mut bytes = alloc::vec::Vec::new();
+                    reader.take(num_bytes).read_to_end(&mut bytes)?;
+                    if bytes.len() as u64 != num_bytes {
+                        return Err(std::io::ErrorKind::UnexpectedEof.into());
+                    }
+
+                    bytes
+                        .par_chunks(point_size)
+                        .map(<Self as $crate::serde::SerdeObject>::from_raw_bytes)
+                        .collect::<Option<alloc::vec::Vec<_>>>()
+                        .ok_or_else(|| std::io::Error::new(std::io::ErrorKind::InvalidData, "invalid projective point"))
+                }
+            }
+
+            // Converts a chunk to affine with a single inversion, using
+            // Montgomery's trick.
+            fn batch_normalize_serial(p: &[Self], q: &mut [$name_affine]) {
+                let mut acc = $base::one();
+                for (p, q) in p.iter().zip(q.iter_mut()) {
+                    // We use the `x` field of $name_affine to store the product
+                    // of previous z-coordinates seen.
+                    q.x = acc;
+
+                    // We will end up skipping all identities in p
+                    acc = $base::conditional_select(&(acc * p.z), &acc, p.is_identity());
+                }
+
+                // This is the inverse, as all z-coordinates are nonzero and the ones
+                // that are not are skipped.
+                acc = acc.invert().unwrap();
+
+                for (p, q) in p.iter().rev().zip(q.iter_mut().rev()) {
+                    // Compute tmp = 1/z, again stored in the `x` field
+                    let tmp = q.x * acc;
+
+                    // Cancel out z-coordinate in denominator of `acc`
+                    acc = $base::conditional_select(&(acc * p.z), &acc, p.is_identity());
+
+                    q.x = tmp;
+                }
+
+                for (p, q) in p.chunks(8).zip(q.chunks_mut(8)) {
+                    let mut z_inv = [$base::zero(); 8];
+                    for (z_inv, q) in z_inv.iter_mut().zip(q.iter()) {
+                        *z_inv = q.x;
+                    }
+                    let (x, y) = $name::scale_coordinates(p, &z_inv[..p.len()]);
+                    for (i, (p, q)) in p.iter().zip(q.iter_mut()).enumerate() {
+                        let r = $name_affine { x: x[i], y: y[i] };
+                        *q = $name_affine::conditional_select(&r, &$name_affine::identity(), p.is_identity());
+                    }
+                }
+            }
+
+            // Returns the coordinates of at most eight points multiplied by
+            // the inverses of their z-coordinates, batching the products so
+            // that fields with a vectorized multiplication can use it.
+            fn scale_coordinates(p: &[Self], z_inv: &[$base]) -> ([$base; 8], [$base; 8]) {
+                use $crate::ff_ext::BatchMul;
+
+                let n = p.len();
+                let mut x = [$base::zero(); 8];
+                let mut y = [$base::zero(); 8];
+                for (p, (x, y)) in p.iter().zip(x.iter_mut().zip(y.iter_mut())) {
+                    *x = p.x;
+                    *y = p.y;
+                }
+                $base::batch_mul_assign(&mut x[..n], z_inv);
+                $base::batch_mul_assign(&mut y[..n], z_inv);
+                (x, y)
+            }
+
+            // Splits the work across threads for large inputs, while keeping
+            // chunks big enough to amortize the inversion in each of them.
+            fn batch_normalize_chunk_size(len: usize) -> usize {
+                const MIN_CHUNK_SIZE: usize = 1 << 10;
+                let num_threads = $crate::multicore::current_num_threads();
+                if len < 2 * MIN_CHUNK_SIZE || num_threads == 1 {
+                    len
+                } else {
+                    core::cmp::max(MIN_CHUNK_SIZE, (len + num_threads - 1) / num_threads)
+                }
+            }
+
+            /// Normalizes `points` in place so that every point other than the
+            /// identity has `Z = 1`, without allocating affine outputs. Large
+            /// slices are processed in parallel.
+            pub fn batch_normalize_in_place(points: &mut [Self]) {
+                use $crate::multicore::*;
+
+                let chunk_size = $name::batch_normalize_chunk_size(points.len());
+                points.par_chunks_mut(chunk_size.max(1)).for_each(|points| {
+                    let mut prefix = alloc::vec::Vec::with_capacity(points.len());
+                    let mut acc = $base::one();
+                    for p in points.iter() {
+                        prefix.push(acc);
+                        acc = $base::conditional_select(&(acc * p.z), &acc, p.is_identity());
+                    }
+
+                    acc = acc.invert().unwrap();
+
+                    for (p, prefix) in points.iter().rev().zip(prefix.iter_mut().rev()) {
+                        *prefix *= acc;
+                        acc = $base::conditional_select(&(acc * p.z), &acc, p.is_identity());
+                    }
+
+                    for (points, z_inv) in points.chunks_mut(8).zip(prefix.chunks(8)) {
+                        let (x, y) = $name::scale_coordinates(points, z_inv);
+                        for (i, p) in points.iter_mut().enumerate() {
+                            let normalized = $name {
+                                x: x[i],
+                                y: y[i],
+                                z: $base::one(),
+                            };
+                            *p = $name::conditional_select(&normalized, &$name::identity(), p.is_identity());
+                        }
+                    }
+                });
+            }
+
+            /// Checks the internal invariants of the point: reduced coordinates,
+            /// the curve equation and a well-formed point at infinity.
+            pub fn debug_validate(&self) -> bool {
+                self.x.debug_validate()
+                    && self.y.debug_validate()
+                    && self.z.debug_validate()
+                    && bool::from(self.is_valid_raw())
+            }
+
+            // Besides the curve equation, a point at infinity must have `X = 0`
+            // and `Y != 0`, which `is_on_curve` does not enforce.
+            fn is_valid_raw(&self) -> Choice {
+                self.is_on_curve() & (!self.z.is_zero() | (self.x.is_zero() & !self.y.is_zero()))
+            }
+
             #[inline]
-            fn curve_constant_3b() -> $base {
+            pub(crate) fn curve_constant_3b() -> $base {
                 lazy_static::lazy_static! {
                     static ref CONST_3B: $base = $constant_b + $constant_b + $constant_b;
                 }
                 *CONST_3B
             }
 
-            fn mul_by_3b(input: &$base) -> $base {
-                if $name::CURVE_ID == "bn256_g1"{
-                    input.double().double().double() + input
-                } else {
-                    input * $name::curve_constant_3b()
+            // Returns `|3b|` and its sign when `3b` is a small integer, so that
+            // multiplying by it can use doublings and additions instead of a
+            // full multiplication. The integer is found at run time, once.
+            fn curve_constant_3b_small() -> Option<(u64, bool)> {
+                lazy_static::lazy_static! {
+                    static ref CONST_3B_SMALL: Option<(u64, bool)> = {
+                        const BOUND: u64 = 1 << 13;
+                        let b3 = $name::curve_constant_3b();
+                        let mut k = $base::one();
+                        (1..BOUND).find_map(|i| {
+                            let res = if k == b3 {
+                                Some((i, false))
+                            } else if -k == b3 {
+                                Some((i, true))
+                            } else {
+                                None
+                            };
+                            k += $base::one();
+                            res
+                        })
+                    };
+                }
+                *CONST_3B_SMALL
+            }
+
+            pub(crate) fn mul_by_3b(input: &$base) -> $base {
+                match $name::curve_constant_3b_small() {
+                    Some((k, neg)) => {
+                        // Left-to-right double-and-add over the bits of the
+                        // public constant `k`, read in a loop at run time
+                        // rather than unrolled into a fixed chain.
+                        let mut acc = *input;
+                        for i in (0..63 - k.leading_zeros()).rev() {
+                            acc = acc.double();
+                            if (k >> i) & 1 == 1 {
+                                acc += input;
+                            }
+                        }
+                        if neg {
+                            -acc
+                        } else {
+                            acc
+                        }
+                    }
+                    None => input * $name::curve_constant_3b(),
+                }
+            }
+
+            /// Adds the affine point `rhs` to `self` with the complete mixed
+            /// formulas, which are cheaper than converting `rhs` to projective
+            /// coordinates first. This is the same as `self + rhs`.
+            pub fn add_mixed(&self, rhs: &$name_affine) -> Self {
+                self + rhs
+            }
+
+            /// Adds `rhs` to `self` with the incomplete projective formulas
+            /// (`add-1998-cmo-2`), which cost `12M + 2S` but, unlike `+`, are
+            /// only correct when neither point is the identity and
+            /// `self != ±rhs`. The result is unspecified otherwise, so this is
+            /// meant for hot loops where the inputs are known to satisfy these
+            /// conditions.
+            pub fn add_unchecked(&self, rhs: &Self) -> Self {
+                $crate::debug_validate!(self, rhs);
+                let y1z2 = self.y * rhs.z;
+                let x1z2 = self.x * rhs.z;
+                let z1z2 = self.z * rhs.z;
+                let u = rhs.y * self.z - y1z2;
+                let uu = u.square();
+                let v = rhs.x * self.z - x1z2;
+                let vv = v.square();
+                let vvv = v * vv;
+                let r = vv * x1z2;
+                let a = uu * z1z2 - vvv - r.double();
+
+                $name {
+                    x: v * a,
+                    y: u * (r - a) - vvv * y1z2,
+                    z: vvv * z1z2,
+                }
+            }
+
+            /// Adds the affine point `rhs` to `self` with the incomplete mixed
+            /// formulas (`madd-1998-cmo`), which cost `9M + 2S`. Like
+            /// [`Self::add_unchecked`], the result is unspecified when either
+            /// point is the identity or `self == ±rhs`.
+            pub fn add_mixed_unchecked(&self, rhs: &$name_affine) -> Self {
+                $crate::debug_validate!(self, rhs);
+                let u = rhs.y * self.z - self.y;
+                let uu = u.square();
+                let v = rhs.x * self.z - self.x;
+                let vv = v.square();
+                let vvv = v * vv;
+                let r = vv * self.x;
+                let a = uu * self.z - vvv - r.double();
+
+                $name {
+                    x: v * a,
+                    y: u * (r - a) - vvv * self.y,
+                    z: vvv * self.z,
                 }
             }
         }
 
         impl $name_affine {
+            /// Returns the point at infinity, which is also the `Default` value.
+            /// Unlike `PrimeCurveAffine::identity`, this can be used in
+            /// constants.
+            pub const fn identity() -> Self {
+                Self {
+                    x: $base::zero(),
+                    y: $base::zero(),
+                }
+            }
+
+            /// Checks the internal invariants of the point: reduced coordinates
+            /// and the curve equation.
+            pub fn debug_validate(&self) -> bool {
+                self.x.debug_validate() && self.y.debug_validate() && bool::from(self.is_on_curve())
+            }
+
+            /// Checks that the point lies in the prime order subgroup, using the
+            /// fastest check the curve provides.
+            pub fn is_torsion_free(&self) -> Choice {
+                group::cofactor::CofactorGroup::is_torsion_free(&$name::from(*self))
+            }
+
             pub fn generator() -> Self {
                 Self {
                     x: $generator.0,
@@ -612,6 +1072,16 @@ macro_rules! new_curve_impl {
                 }
             }
 
+            /// Samples a uniformly distributed point of the prime order subgroup.
+            ///
+            /// A uniform `x` is drawn until `x^3 + a x + b` is a square, and one of
+            /// its two square roots is picked at random, which gives a uniform
+            /// point of the curve, as no point has `y = 0`. Clearing the cofactor
+            /// then maps it to a uniform point of the subgroup. On prime order
+            /// curves the point is returned as is, without going through
+            /// projective coordinates and an inversion.
+            ///
+            /// This runs in variable time.
             pub fn random(mut rng: impl RngCore) -> Self {
                 loop {
                     let x = $base::random(&mut rng);
@@ -627,13 +1097,55 @@ macro_rules! new_curve_impl {
                             y,
                         };
 
-
                         use $crate::group::cofactor::CofactorGroup;
-                        let p = p.to_curve();
-                        return p.clear_cofactor().to_affine()
+                        let cleared = p.to_curve().clear_cofactor();
+                        // Clearing the cofactor of a prime order curve leaves `z = 1`.
+                        return if bool::from(cleared.z.ct_eq(&$base::ONE)) {
+                            $name_affine {
+                                x: cleared.x,
+                                y: cleared.y,
+                            }
+                        } else {
+                            cleared.to_affine()
+                        };
                     }
                 }
             }
+
+            /// Decompresses each of `reprs`, computing the square roots in
+            /// parallel. Each output is `None` exactly when `from_bytes` would
+            /// reject the corresponding input.
+            pub fn batch_from_bytes(reprs: &[<Self as group::GroupEncoding>::Repr]) -> alloc::vec::Vec<CtOption<Self>> {
+                use $crate::multicore::*;
+
+                reprs
+                    .par_iter()
+                    .map(<Self as group::GroupEncoding>::from_bytes)
+                    .collect()
+            }
+
+            /// Checks in parallel whether each of `points` is on the curve.
+            pub fn batch_is_on_curve(points: &[Self]) -> alloc::vec::Vec<Choice> {
+                use $crate::multicore::*;
+
+                points.par_iter().map(|p| p.is_on_curve()).collect()
+            }
+
+            /// Checks in parallel whether all of `points` are on the curve. Every
+            /// point is checked, so the running time does not reveal which
+            /// point, if any, is invalid.
+            pub fn batch_all_on_curve(points: &[Self]) -> Choice {
+                use $crate::multicore::*;
+
+                let is_on_curve = points.par_iter().map(|p| p.is_on_curve());
+                $crate::__cfg_std! {
+                    let all = is_on_curve.reduce(|| Choice::from(1), |a, b| a & b);
+                }
+                $crate::__cfg_not_std! {
+                    let all = is_on_curve.fold(Choice::from(1), |a, b| a & b);
+                }
+                all
+            }
         }
 
 
@@ -658,6 +1170,8 @@ macro_rules! new_curve_impl {
             }
         }
 
+        $crate::__impl_zeroize!($name);
+
         impl subtle::ConstantTimeEq for $name {
             fn ct_eq(&self, other: &Self) -> Choice {
                 // Is (x, y, z) equal to (x', y, z') when converted to affine?
@@ -723,7 +1237,7 @@ macro_rules! new_curve_impl {
 
 
             #[allow(clippy::redundant_closure_call)]
-            fn hash_to_curve<'a>(domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+            fn hash_to_curve<'a>(domain_prefix: &'a str) -> alloc::boxed::Box<dyn Fn(&[u8]) -> Self + 'a> {
                 $hash_to_curve($curve_id, domain_prefix)
             }
 
@@ -773,35 +1287,17 @@ macro_rules! new_curve_impl {
             type AffineRepr = $name_affine;
 
             fn batch_normalize(p: &[Self], q: &mut [Self::AffineRepr]) {
-                assert_eq!(p.len(), q.len());
-
-                let mut acc = $base::one();
-                for (p, q) in p.iter().zip(q.iter_mut()) {
-                    // We use the `x` field of $name_affine to store the product
-                    // of previous z-coordinates seen.
-                    q.x = acc;
-
-                    // We will end up skipping all identities in p
-                    acc = $base::conditional_select(&(acc * p.z), &acc, p.is_identity());
-                }
-
-                // This is the inverse, as all z-coordinates are nonzero and the ones
-                // that are not are skipped.
-                acc = acc.invert().unwrap();
-
-                for (p, q) in p.iter().rev().zip(q.iter_mut().rev()) {
-                    let skip = p.is_identity();
-
-                    // Compute tmp = 1/z
-                    let tmp = q.x * acc;
+                use $crate::multicore::*;
 
-                    // Cancel out z-coordinate in denominator of `acc`
-                    acc = $base::conditional_select(&(acc * p.z), &acc, skip);
-
-                    q.x = p.x * tmp;
-                    q.y = p.y * tmp;
+                assert_eq!(p.len(), q.len());
 
-                    *q = $name_affine::conditional_select(&q, &$name_affine::identity(), skip);
+                let chunk_size = $name::batch_normalize_chunk_size(p.len());
+                if chunk_size == p.len() {
+                    $name::batch_normalize_serial(p, q);
+                } else {
+                    p.par_chunks(chunk_size)
+                        .zip(q.par_chunks_mut(chunk_size))
+                        .for_each(|(p, q)| $name::batch_normalize_serial(p, q));
                 }
             }
 
@@ -817,6 +1313,16 @@ macro_rules! new_curve_impl {
             }
         }
 
+        $crate::impl_standard_distribution!($name, group::Group);
+
+        impl $crate::__private::rand::distributions::Distribution<$name_affine>
+            for $crate::__private::rand::distributions::Standard
+        {
+            fn sample<R: $crate::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> $name_affine {
+                $name_affine::random(rng)
+            }
+        }
+
         impl group::Group for $name {
             type Scalar = $scalar;
 
@@ -825,6 +1331,7 @@ macro_rules! new_curve_impl {
             }
 
             fn double(&self) -> Self {
+                $crate::debug_validate!(self);
                 if $constant_a == $base::ZERO {
                     // Algorithm 9, https://eprint.iacr.org/2015/1060.pdf
                     let t0 = self.y.square();
@@ -902,11 +1409,7 @@ macro_rules! new_curve_impl {
             }
 
             fn identity() -> Self {
-                Self {
-                    x: $base::zero(),
-                    y: $base::one(),
-                    z: $base::zero(),
-                }
+                $name::identity()
             }
 
             fn is_identity(&self) -> Choice {
@@ -929,29 +1432,67 @@ macro_rules! new_curve_impl {
                     [0, 1, 2].map(|i| $base::from_raw_bytes(&bytes[i * $base::size()..(i + 1) * $base::size()]));
                 x.zip(y).zip(z).and_then(|((x, y), z)| {
                     let res = Self { x, y, z };
-                    // Check that the point is on the curve.
-                    bool::from(res.is_on_curve()).then(|| res)
+                    // Check that the point is on the curve and in the subgroup.
+                    bool::from(res.is_valid_raw() & group::cofactor::CofactorGroup::is_torsion_free(&res)).then(|| res)
                 })
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(3 * $base::size());
-                Self::write_raw(self, &mut res).unwrap();
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(3 * $base::size());
+                for c in [&self.x, &self.y, &self.z] {
+                    res.extend_from_slice(&c.to_raw_bytes());
+                }
                 res
             }
-            fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
-                let [x, y, z] = [(); 3].map(|_| $base::read_raw_unchecked(reader));
-                Self { x, y, z }
+            $crate::__cfg_std! {
+                fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+                    let [x, y, z] = [(); 3].map(|_| $base::read_raw_unchecked(reader));
+                    Self { x, y, z }
+                }
+            }
+            $crate::__cfg_std! {
+                fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                    let x = $base::read_raw(reader)?;
+                    let y = $base::read_raw(reader)?;
+                    let z = $base::read_raw(reader)?;
+                    let res = Self { x, y, z };
+                    // Check that the point is on the curve and in the subgroup.
+                    bool::from(res.is_valid_raw() & group::cofactor::CofactorGroup::is_torsion_free(&res))
+                        .then(|| res)
+                        .ok_or_else(|| {
+                            std::io::Error::new(std::io::ErrorKind::InvalidData, "point is not in the group")
+                        })
+                }
+            }
+            $crate::__cfg_std! {
+                fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                    self.x.write_raw(writer)?;
+                    self.y.write_raw(writer)?;
+                    self.z.write_raw(writer)
+                }
+            }
+        }
+
+        impl $crate::serde::arkworks::ArkworksSerde for $name_affine {
+            fn to_arkworks_bytes(&self, compress: $crate::serde::arkworks::Compress) -> alloc::vec::Vec<u8> {
+                $crate::serde::arkworks::point_to_bytes(self, compress)
+            }
+            fn from_arkworks_bytes(
+                bytes: &[u8],
+                compress: $crate::serde::arkworks::Compress,
+            ) -> Option<Self> {
+                $crate::serde::arkworks::point_from_bytes(bytes, compress)
             }
-            fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-                let x = $base::read_raw(reader)?;
-                let y = $base::read_raw(reader)?;
-                let z = $base::read_raw(reader)?;
-                Ok(Self { x, y, z })
+        }
+
+        impl $crate::serde::arkworks::ArkworksSerde for $name {
+            fn to_arkworks_bytes(&self, compress: $crate::serde::arkworks::Compress) -> alloc::vec::Vec<u8> {
+                $name_affine::from(self).to_arkworks_bytes(compress)
             }
-            fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-                self.x.write_raw(writer)?;
-                self.y.write_raw(writer)?;
-                self.z.write_raw(writer)
+            fn from_arkworks_bytes(
+                bytes: &[u8],
+                compress: $crate::serde::arkworks::Compress,
+            ) -> Option<Self> {
+                $name_affine::from_arkworks_bytes(bytes, compress).map(Self::from)
             }
         }
 
@@ -967,8 +1508,8 @@ macro_rules! new_curve_impl {
 
         // Affine implementations
 
-        impl std::fmt::Debug for $name_affine {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> Result<(), std::fmt::Error> {
+        impl core::fmt::Debug for $name_affine {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> Result<(), core::fmt::Error> {
                 if self.is_identity().into() {
                     write!(f, "Infinity")
                 } else {
@@ -995,6 +1536,8 @@ macro_rules! new_curve_impl {
             }
         }
 
+        $crate::__impl_zeroize!($name_affine);
+
         impl subtle::ConstantTimeEq for $name_affine {
             fn ct_eq(&self, other: &Self) -> Choice {
                 let z1 = self.is_identity();
@@ -1013,6 +1556,12 @@ macro_rules! new_curve_impl {
             }
         }
 
+        impl PartialEq for $name_affine {
+            fn eq(&self, other: &Self) -> bool {
+                self.ct_eq(other).into()
+            }
+        }
+
         impl cmp::Eq for $name_affine {}
 
 
@@ -1030,27 +1579,41 @@ macro_rules! new_curve_impl {
                 let [x, y] = [0, $base::size()].map(|i| $base::from_raw_bytes(&bytes[i..i + $base::size()]));
                 x.zip(y).and_then(|(x, y)| {
                     let res = Self { x, y };
-                    // Check that the point is on the curve.
-                    bool::from(res.is_on_curve()).then(|| res)
+                    // Check that the point is on the curve and in the subgroup.
+                    bool::from(res.is_on_curve() & res.is_torsion_free()).then(|| res)
                 })
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(2 * $base::size());
-                Self::write_raw(self, &mut res).unwrap();
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(2 * $base::size());
+                for c in [&self.x, &self.y] {
+                    res.extend_from_slice(&c.to_raw_bytes());
+                }
                 res
             }
-            fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
-                let [x, y] = [(); 2].map(|_| $base::read_raw_unchecked(reader));
-                Self { x, y }
+            $crate::__cfg_std! {
+                fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+                    let [x, y] = [(); 2].map(|_| $base::read_raw_unchecked(reader));
+                    Self { x, y }
+                }
             }
-            fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
-                let x = $base::read_raw(reader)?;
-                let y = $base::read_raw(reader)?;
-                Ok(Self { x, y })
+            $crate::__cfg_std! {
+                fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+                    let x = $base::read_raw(reader)?;
+                    let y = $base::read_raw(reader)?;
+                    let res = Self { x, y };
+                    // Check that the point is on the curve and in the subgroup.
+                    bool::from(res.is_on_curve() & res.is_torsion_free())
+                        .then(|| res)
+                        .ok_or_else(|| {
+                            std::io::Error::new(std::io::ErrorKind::InvalidData, "point is not in the group")
+                        })
+                }
             }
-            fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
-                self.x.write_raw(writer)?;
-                self.y.write_raw(writer)
+            $crate::__cfg_std! {
+                fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+                    self.x.write_raw(writer)?;
+                    self.y.write_raw(writer)
+                }
             }
         }
 
@@ -1064,10 +1627,7 @@ macro_rules! new_curve_impl {
             }
 
             fn identity() -> Self {
-                Self {
-                    x: $base::zero(),
-                    y: $base::zero(),
-                }
+                $name_affine::identity()
             }
 
             fn is_identity(&self) -> Choice {
@@ -1163,13 +1723,7 @@ macro_rules! new_curve_impl {
             }
         }
 
-        impl Neg for $name {
-            type Output = $name;
-
-            fn neg(self) -> $name {
-                -&self
-            }
-        }
+        $crate::impl_neg!($name);
 
         impl<T> Sum<T> for $name
         where
@@ -1187,6 +1741,7 @@ macro_rules! new_curve_impl {
             type Output = $name;
 
             fn add(self, rhs: &'a $name) -> $name {
+                $crate::debug_validate!(self, rhs);
                 if $constant_a == $base::ZERO {
                     // Algorithm 7, https://eprint.iacr.org/2015/1060.pdf
                     let t0 = self.x * rhs.x;
@@ -1285,6 +1840,7 @@ macro_rules! new_curve_impl {
 
             // Mixed addition
             fn add(self, rhs: &'a $name_affine) -> $name {
+                $crate::debug_validate!(self, rhs);
                 if $constant_a == $base::ZERO {
                     // Algorithm 8, https://eprint.iacr.org/2015/1060.pdf
                     let t0 = self.x * rhs.x;
@@ -1395,6 +1951,7 @@ macro_rules! new_curve_impl {
             // significant bit of the scalar.
 
             fn mul(self, other: &'b $scalar) -> Self::Output {
+                $crate::debug_validate!(self);
                 let mut acc = $name::identity();
                 for bit in other
                     .to_repr()
@@ -1421,13 +1978,7 @@ macro_rules! new_curve_impl {
             }
         }
 
-        impl Neg for $name_affine {
-            type Output = $name_affine;
-
-            fn neg(self) -> $name_affine {
-                -&self
-            }
-        }
+        $crate::impl_neg!($name_affine);
 
         impl<'a, 'b> Add<&'a $name> for &'b $name_affine {
             type Output = $name;
@@ -1441,7 +1992,7 @@ macro_rules! new_curve_impl {
             type Output = $name;
 
             fn add(self, rhs: &'a $name_affine) -> $name {
-                rhs.to_curve() + self.to_curve()
+                rhs.to_curve().add_mixed(self)
             }
         }
 
@@ -1485,5 +2036,204 @@ macro_rules! new_curve_impl {
                 acc
             }
         }
+
+        impl<'a, 'b> Mul<&'b $crate::secret::SecretScalar<$scalar>> for &'a $name {
+            type Output = $name;
+
+            fn mul(self, other: &'b $crate::secret::SecretScalar<$scalar>) -> $name {
+                self * other.expose_secret()
+            }
+        }
+
+        impl<'b> Mul<&'b $crate::secret::SecretScalar<$scalar>> for $name {
+            type Output = $name;
+
+            fn mul(self, other: &'b $crate::secret::SecretScalar<$scalar>) -> $name {
+                &self * other.expose_secret()
+            }
+        }
+
+        impl<'a, 'b> Mul<&'b $crate::secret::SecretScalar<$scalar>> for &'a $name_affine {
+            type Output = $name;
+
+            fn mul(self, other: &'b $crate::secret::SecretScalar<$scalar>) -> $name {
+                self * other.expose_secret()
+            }
+        }
+
+        impl<'b> Mul<&'b $crate::secret::SecretScalar<$scalar>> for $name_affine {
+            type Output = $name;
+
+            fn mul(self, other: &'b $crate::secret::SecretScalar<$scalar>) -> $name {
+                &self * other.expose_secret()
+            }
+        }
+    };
+}
+
+/// Defines a short Weierstrass curve `y^2 = x^3 + a * x + b` of prime order in
+/// the module `$module`.
+///
+/// The module contains the projective point `curve`, the affine point
+/// `affine` and their compressed encoding `<curve>Compressed`. They implement
+/// [`CurveExt`](crate::CurveExt), [`CurveAffine`](crate::CurveAffine), the
+/// `group` traits, [`SerdeObject`](crate::serde::SerdeObject), `serde` with
+/// the `derive_serde` feature, and `derive_generator`.
+///
+/// - `base` and `scalar` are field types in scope of the invocation, such as
+///   the fields of this crate or [`Fp`](crate::fp::Fp) aliases. Other fields
+///   need the same API, e.g. `size`, `from_bytes` and `to_bytes`, and the
+///   traits `WithSmallOrderMulGroup<3>`, `Legendre`, `BatchMul`,
+///   `SerdeObject` and `ArkworksField`.
+/// - `generator` is the pair of coordinates of the generator, and `a`, `b` the
+///   curve constants, all as constant expressions over `base`.
+/// - `curve_id` names the curve in the hash to curve domain separation tag.
+/// - `hash_to_curve` is a closure taking the curve id and a domain prefix,
+///   usually calling [`svdw_hash_to_curve`](crate::hash_to_curve::svdw_hash_to_curve)
+///   or [`sswu_hash_to_curve`](crate::hash_to_curve::sswu_hash_to_curve).
+///
+/// - `cofactor` must be `1`: only curves of prime order are supported, and the
+///   number of points on the curve must be the characteristic of `scalar`.
+///   Any other cofactor is rejected at compile time, as the points are
+///   neither cleared nor checked to be in the prime order subgroup.
+///
+/// With the `derive_serde` feature, the invoking crate needs to depend on
+/// `serde` and `serde_arrays` as well, which the derived implementations of the
+/// compressed encoding refer to by name.
+///
+/// # Example
+///
+/// ```
+/// use halo2curves::ff::PrimeField;
+/// use halo2curves::group::{Curve, Group};
+/// use halo2curves::hash_to_curve::svdw_hash_to_curve;
+/// use halo2curves::secp256k1::{Fp, Fq};
+/// use halo2curves::CurveExt;
+///
+/// const GENERATOR_X: Fp = Fp::from_raw([
+///     0x59F2815B16F81798,
+///     0x029BFCDB2DCE28D9,
+///     0x55A06295CE870B07,
+///     0x79BE667EF9DCBBAC,
+/// ]);
+/// const GENERATOR_Y: Fp = Fp::from_raw([
+///     0x9C47D08FFB10D4B8,
+///     0xFD17B448A6855419,
+///     0x5DA4FBFC0E1108A8,
+///     0x483ADA7726A3C465,
+/// ]);
+///
+/// halo2curves::define_curve!(
+///     /// secp256k1, defined again.
+///     pub mod k1 {
+///         curve: K1,
+///         affine: K1Affine,
+///         base: Fp,
+///         scalar: Fq,
+///         generator: (GENERATOR_X, GENERATOR_Y),
+///         a: Fp::zero(),
+///         b: Fp::from_raw([7, 0, 0, 0]),
+///         cofactor: 1,
+///         curve_id: "k1",
+///         hash_to_curve: |curve_id, domain_prefix| {
+///             svdw_hash_to_curve(curve_id, domain_prefix, Fp::one())
+///         },
+///     }
+/// );
+///
+/// fn main() {
+///     let k = Fq::from(42);
+///     let p = k1::K1::generator() * k;
+///     let q = halo2curves::secp256k1::Secp256k1::generator() * k;
+///     assert_eq!(p.to_affine().x, q.to_affine().x);
+///
+///     let h = k1::K1::hash_to_curve("example")(b"message");
+///     assert!(bool::from(h.is_on_curve()));
+/// }
+/// ```
+#[macro_export]
+macro_rules! define_curve {
+    (
+        $(#[$attr:meta])*
+        $vis:vis mod $module:ident {
+            curve: $name:ident,
+            affine: $name_affine:ident,
+            base: $base:ident,
+            scalar: $scalar:ident,
+            generator: ($generator_x:expr, $generator_y:expr),
+            a: $constant_a:expr,
+            b: $constant_b:expr,
+            cofactor: $cofactor:literal,
+            curve_id: $curve_id:literal,
+            hash_to_curve: $hash_to_curve:expr $(,)?
+        }
+    ) => {
+        $(#[$attr])*
+        $vis mod $module {
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[allow(unused_imports)]
+            use $crate::__private::{
+                alloc, lazy_static, paste, rand::RngCore, subtle, IDENTITY_MASK,
+                IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT,
+            };
+            #[allow(unused_imports)]
+            use $crate::ff::{self, Field, PrimeField, WithSmallOrderMulGroup};
+            #[allow(unused_imports)]
+            use $crate::group::{self, prime::PrimeCurveAffine, Curve, Group as _, GroupEncoding};
+            #[allow(unused_imports)]
+            use $crate::__private::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+            #[allow(unused_imports)]
+            use $crate::{
+                impl_add_binop_specify_output, impl_binops_additive,
+                impl_binops_additive_specify_output, impl_binops_multiplicative,
+                impl_binops_multiplicative_mixed, impl_sub_binop_specify_output, Coordinates,
+                CurveAffine, CurveExt,
+            };
+            #[allow(unused_imports)]
+            use core::{
+                cmp,
+                fmt::Debug,
+                iter::Sum,
+                ops::{Add, Mul, Neg, Sub},
+            };
+
+            const _: () = assert!(
+                $cofactor == 1,
+                "define_curve! only supports curves of prime order, with `cofactor: 1`"
+            );
+
+            $crate::new_curve_impl!(
+                (pub),
+                $name,
+                $name_affine,
+                $base,
+                $scalar,
+                ($generator_x, $generator_y),
+                $constant_a,
+                $constant_b,
+                $curve_id,
+                $hash_to_curve,
+            );
+
+            $crate::impl_derive_generator!($name);
+
+            impl $crate::group::cofactor::CofactorGroup for $name {
+                type Subgroup = $name;
+
+                fn clear_cofactor(&self) -> Self {
+                    *self
+                }
+
+                fn into_subgroup(self) -> CtOption<Self::Subgroup> {
+                    CtOption::new(self, 1.into())
+                }
+
+                fn is_torsion_free(&self) -> Choice {
+                    1.into()
+                }
+            }
+        }
     };
 }
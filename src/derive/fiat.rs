@@ -0,0 +1,84 @@
+/// Implements the arithmetic of a 4-limb Montgomery field on top of the
+/// formally verified routines generated by
+/// [fiat-crypto](https://github.com/mit-plv/fiat-crypto).
+///
+/// `$backend` names the fiat-crypto module, without the `_64` suffix, e.g.
+/// `secp256k1_montgomery`. The generated code must use the same Montgomery
+/// domain as this crate, i.e. `R = 2^256`, so that constants and serialized
+/// forms are shared with the default backend.
+macro_rules! field_arithmetic_fiat {
+    ($field:ident, $backend:ident) => {
+        paste::paste! {
+            use ::fiat_crypto::[<$backend _64>]::{
+                [<fiat_ $backend _add>] as fiat_add,
+                [<fiat_ $backend _from_montgomery>] as fiat_from_montgomery,
+                [<fiat_ $backend _montgomery_domain_field_element>] as FiatMontgomery,
+                [<fiat_ $backend _mul>] as fiat_mul,
+                [<fiat_ $backend _non_montgomery_domain_field_element>] as FiatNonMontgomery,
+                [<fiat_ $backend _opp>] as fiat_opp,
+                [<fiat_ $backend _square>] as fiat_square,
+                [<fiat_ $backend _sub>] as fiat_sub,
+            };
+        }
+
+        impl $field {
+            /// Doubles this field element.
+            #[inline]
+            pub fn double(&self) -> $field {
+                self.add(self)
+            }
+
+            /// Squares this element.
+            #[inline]
+            pub fn square(&self) -> $field {
+                let mut out = FiatMontgomery([0; 4]);
+                fiat_square(&mut out, &FiatMontgomery(self.0));
+                $field(out.0)
+            }
+
+            /// Multiplies `rhs` by `self`, returning the result.
+            #[inline]
+            pub fn mul(&self, rhs: &Self) -> $field {
+                let mut out = FiatMontgomery([0; 4]);
+                fiat_mul(&mut out, &FiatMontgomery(self.0), &FiatMontgomery(rhs.0));
+                $field(out.0)
+            }
+
+            /// Subtracts `rhs` from `self`, returning the result.
+            #[inline]
+            pub fn sub(&self, rhs: &Self) -> $field {
+                let mut out = FiatMontgomery([0; 4]);
+                fiat_sub(&mut out, &FiatMontgomery(self.0), &FiatMontgomery(rhs.0));
+                $field(out.0)
+            }
+
+            /// Adds `rhs` to `self`, returning the result.
+            #[inline]
+            pub fn add(&self, rhs: &Self) -> $field {
+                let mut out = FiatMontgomery([0; 4]);
+                fiat_add(&mut out, &FiatMontgomery(self.0), &FiatMontgomery(rhs.0));
+                $field(out.0)
+            }
+
+            /// Negates `self`.
+            #[inline]
+            pub fn neg(&self) -> $field {
+                let mut out = FiatMontgomery([0; 4]);
+                fiat_opp(&mut out, &FiatMontgomery(self.0));
+                $field(out.0)
+            }
+        }
+
+        impl From<$field> for [u64; 4] {
+            fn from(elt: $field) -> [u64; 4] {
+                // Turn into canonical form by computing
+                // (a.R) / R = a
+                let mut out = FiatNonMontgomery([0; 4]);
+                fiat_from_montgomery(&mut out, &FiatMontgomery(elt.0));
+                out.0
+            }
+        }
+    };
+}
+
+pub(crate) use field_arithmetic_fiat;
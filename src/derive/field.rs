@@ -9,6 +9,93 @@ macro_rules! impl_from_u64 {
     };
 }
 
+/// Implements `From<u64>` reading the values below the length of `$table`,
+/// the Montgomery forms of the small integers, from it.
+#[macro_export]
+macro_rules! impl_from_u64_table {
+    ($field:ident, $r2:ident, $table:ident) => {
+        impl From<u64> for $field {
+            fn from(val: u64) -> $field {
+                if val < $table.len() as u64 {
+                    $table[val as usize]
+                } else {
+                    $field([val, 0, 0, 0]) * $r2
+                }
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_from_u128 {
+    ($field:ident, $r2:ident) => {
+        impl From<u128> for $field {
+            fn from(val: u128) -> $field {
+                $field([val as u64, (val >> 64) as u64, 0, 0]) * $r2
+            }
+        }
+    };
+}
+
+/// Implements `From<i32>`, `From<i64>` and `From<i128>` from `From<u64>` and
+/// `From<u128>`, negative values mapping to the negation of their absolute
+/// value.
+///
+/// `From<i32>` keeps `$field::from(2)` compiling: integer literals default to
+/// `i32` once several integer conversions apply.
+#[macro_export]
+macro_rules! impl_from_signed {
+    ($field:ident) => {
+        impl From<i32> for $field {
+            fn from(val: i32) -> $field {
+                $field::from(val as i64)
+            }
+        }
+
+        impl From<i64> for $field {
+            fn from(val: i64) -> $field {
+                let abs = $field::from(val.unsigned_abs());
+                $field::conditional_select(&abs, &-abs, ((val < 0) as u8).into())
+            }
+        }
+
+        impl From<i128> for $field {
+            fn from(val: i128) -> $field {
+                let abs = $field::from(val.unsigned_abs());
+                $field::conditional_select(&abs, &-abs, ((val < 0) as u8).into())
+            }
+        }
+    };
+}
+
+/// Implements `Display` and `LowerHex`, printing the canonical value of the
+/// elements in decimal and hexadecimal, and `FromStr`, parsing a canonical
+/// decimal or `0x`-prefixed hexadecimal integer.
+#[macro_export]
+macro_rules! impl_field_str {
+    ($field:ident) => {
+        impl core::fmt::Display for $field {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::ff_ext::radix::fmt_decimal(self, f)
+            }
+        }
+
+        impl core::fmt::LowerHex for $field {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                $crate::ff_ext::radix::fmt_lower_hex(self, f)
+            }
+        }
+
+        impl core::str::FromStr for $field {
+            type Err = $crate::ff_ext::radix::ParseFieldError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $crate::ff_ext::radix::parse_canonical(s)
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! field_common {
     (
@@ -29,6 +116,10 @@ macro_rules! field_common {
         const BYINVERTOR: $crate::ff_ext::inverse::BYInverter<6> =
             $crate::ff_ext::inverse::BYInverter::<6>::new(&$modulus.0, &$r2.0);
 
+        $crate::impl_standard_distribution!($field, $crate::ff::Field);
+        $crate::impl_arkworks_field!($field);
+        $crate::impl_extension_field!($field);
+
         impl $field {
             /// Returns zero, the additive identity.
             #[inline]
@@ -44,6 +135,9 @@ macro_rules! field_common {
 
             /// Returns the multiplicative inverse of the
             /// element. If it is zero, the method fails.
+            ///
+            /// This runs in variable time, see
+            /// [`invert_ct`](crate::ff_ext::invert_ct) for secret elements.
             #[inline(always)]
             pub fn invert(&self) -> CtOption<Self> {
                 if let Some(inverse) = BYINVERTOR.invert(&self.0) {
@@ -68,7 +162,15 @@ macro_rules! field_common {
                 // If `val` represents a 256 bit value then `r` should be R^2,
                 // if `val` represents the 256 MSB of a 512 bit value, then `r` should be R^3.
 
-                #[cfg(feature = "asm")]
+                #[cfg(any(
+                    feature = "asm",
+                    feature = "fiat-backend",
+                    all(
+                        feature = "wasm-simd",
+                        target_arch = "wasm32",
+                        target_feature = "simd128"
+                    )
+                ))]
                 {
                     let (r0, carry) = mac(0, val[0], r.0[0], 0);
                     let (r1, carry) = mac(0, val[0], r.0[1], carry);
@@ -133,7 +235,15 @@ macro_rules! field_common {
                     $field([d0, d1, d2, d3])
                 }
 
-                #[cfg(not(feature = "asm"))]
+                #[cfg(not(any(
+                    feature = "asm",
+                    feature = "fiat-backend",
+                    all(
+                        feature = "wasm-simd",
+                        target_arch = "wasm32",
+                        target_feature = "simd128"
+                    )
+                )))]
                 {
                     let mut val = val;
                     if bigint_geq(&val, &$modulus.0) {
@@ -176,6 +286,8 @@ macro_rules! field_common {
 
             /// Attempts to convert a little-endian byte representation of
             /// a scalar into a `Fr`, failing if the input is not canonical.
+            ///
+            /// This runs in constant time, including when it fails.
             pub fn from_bytes(bytes: &[u8; 32]) -> CtOption<$field> {
                 <Self as ff::PrimeField>::from_repr(*bytes)
             }
@@ -186,6 +298,87 @@ macro_rules! field_common {
                 <Self as ff::PrimeField>::to_repr(self)
             }
 
+            /// Attempts to convert a big-endian byte representation of
+            /// a scalar into a `$field`, failing if the input is not canonical.
+            pub fn from_bytes_be(bytes: &[u8; 32]) -> CtOption<$field> {
+                let mut repr = *bytes;
+                repr.reverse();
+                Self::from_bytes(&repr)
+            }
+
+            /// Converts an element of `$field` into a byte representation in
+            /// big-endian byte order.
+            pub fn to_bytes_be(&self) -> [u8; 32] {
+                let mut res = self.to_bytes();
+                res.reverse();
+                res
+            }
+
+            /// Attempts to convert a big-endian byte slice into a `$field`, see
+            /// [`from_be_slice`]($crate::ff_ext::radix::from_be_slice).
+            pub fn from_be_slice(
+                bytes: &[u8],
+            ) -> Result<$field, $crate::serde::FieldDecodingError> {
+                $crate::ff_ext::radix::from_be_slice(bytes)
+            }
+
+            /// Reduces a little-endian integer of any length modulo the
+            /// modulus.
+            pub fn from_bytes_mod_order(bytes: &[u8]) -> $field {
+                $crate::ff_ext::radix::reduce_le_bytes(bytes)
+            }
+
+            /// Reduces a big-endian integer of any length modulo the modulus.
+            pub fn from_be_bytes_mod_order(bytes: &[u8]) -> $field {
+                $crate::ff_ext::radix::reduce_be_bytes(bytes)
+            }
+
+            /// Parses a decimal, or `0x`-prefixed hexadecimal, integer of any
+            /// length and reduces it modulo the modulus. `FromStr` instead
+            /// rejects integers that are not below the modulus.
+            pub fn from_str_mod_order(
+                s: &str,
+            ) -> Result<$field, $crate::ff_ext::radix::ParseFieldError> {
+                $crate::ff_ext::radix::parse_reduced(s)
+            }
+
+            /// Returns the size of the largest radix-2 evaluation domain of the
+            /// field, `2^S`.
+            pub const fn max_fft_size() -> u64 {
+                1 << <Self as ff::PrimeField>::S
+            }
+
+            /// Returns whether the field has a radix-2 evaluation domain of size
+            /// `n`.
+            pub const fn supports_domain(n: u64) -> bool {
+                n.is_power_of_two() && n <= Self::max_fft_size()
+            }
+
+            /// Returns the size of the smallest radix-2 evaluation domain holding
+            /// at least `n` elements, if the field has one.
+            pub const fn smallest_domain_at_least(n: u64) -> Option<u64> {
+                match n.checked_next_power_of_two() {
+                    Some(size) if size <= Self::max_fft_size() => Some(size),
+                    _ => None,
+                }
+            }
+
+            /// Decomposes `self` into base-`base` digits in `(-base/2, base/2]`,
+            /// least significant first, such that `sum(d_i * base^i) == self`
+            /// over the integers.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `base` is not in `[2, i64::MAX]`.
+            pub fn to_balanced_digits(&self, base: u64) -> alloc::vec::Vec<i64> {
+                $crate::arithmetic::balanced_digits(&self.to_bytes(), base)
+            }
+
+            /// Checks that the Montgomery form of `self` is fully reduced.
+            pub const fn debug_validate(&self) -> bool {
+                Self::is_less_than(&self.0, &$modulus.0)
+            }
+
             /// Lexicographic comparison of Montgomery forms.
             #[inline(always)]
             const fn is_less_than(x: &[u64; 4], y: &[u64; 4]) -> bool {
@@ -208,6 +401,9 @@ macro_rules! field_common {
             }
         }
 
+        $crate::impl_field_str!($field);
+        $crate::__impl_zeroize!($field);
+
         impl Default for $field {
             #[inline]
             fn default() -> Self {
@@ -217,11 +413,7 @@ macro_rules! field_common {
 
         impl From<bool> for $field {
             fn from(bit: bool) -> $field {
-                if bit {
-                    $field::one()
-                } else {
-                    $field::zero()
-                }
+                $field::conditional_select(&$field::zero(), &$field::one(), Choice::from(bit as u8))
             }
         }
 
@@ -234,6 +426,23 @@ macro_rules! field_common {
             }
         }
 
+        // Equality compares every limb, so that it runs in constant time like
+        // `ct_eq` instead of stopping at the first difference.
+        impl PartialEq for $field {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                bool::from(self.ct_eq(other))
+            }
+        }
+
+        impl Eq for $field {}
+
+        impl core::hash::Hash for $field {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
         impl core::cmp::Ord for $field {
             fn cmp(&self, other: &Self) -> core::cmp::Ordering {
                 let left = self.to_repr();
@@ -271,24 +480,19 @@ macro_rules! field_common {
 
             #[inline]
             fn neg(self) -> $field {
+                $crate::debug_validate!(self);
                 self.neg()
             }
         }
 
-        impl Neg for $field {
-            type Output = $field;
-
-            #[inline]
-            fn neg(self) -> $field {
-                -&self
-            }
-        }
+        $crate::impl_neg!($field);
 
         impl<'a, 'b> Sub<&'b $field> for &'a $field {
             type Output = $field;
 
             #[inline]
             fn sub(self, rhs: &'b $field) -> $field {
+                $crate::debug_validate!(self, rhs);
                 self.sub(rhs)
             }
         }
@@ -298,6 +502,7 @@ macro_rules! field_common {
 
             #[inline]
             fn add(self, rhs: &'b $field) -> $field {
+                $crate::debug_validate!(self, rhs);
                 self.add(rhs)
             }
         }
@@ -307,6 +512,7 @@ macro_rules! field_common {
 
             #[inline]
             fn mul(self, rhs: &'b $field) -> $field {
+                $crate::debug_validate!(self, rhs);
                 self.mul(rhs)
             }
         }
@@ -329,6 +535,37 @@ macro_rules! field_common {
             }
         }
 
+        impl $crate::ff_ext::BatchMul for $field {
+            fn batch_mul_assign(lhs: &mut [Self], rhs: &[Self]) {
+                assert_eq!(lhs.len(), rhs.len());
+                #[cfg(all(target_arch = "x86_64", not(feature = "portable")))]
+                let done = {
+                    // The cast below is only sound if `$field` has the layout
+                    // of its limbs, which this checks at compile time for any
+                    // type the macro is invoked on.
+                    const _: () = assert!(
+                        core::mem::size_of::<$field>() == core::mem::size_of::<[u64; 4]>()
+                            && core::mem::align_of::<$field>() == core::mem::align_of::<[u64; 4]>()
+                    );
+                    // SAFETY: `$field` has the size and alignment of `[u64; 4]`,
+                    // and any limbs are a valid value of both types.
+                    let (lhs, rhs) = unsafe {
+                        (
+                            core::slice::from_raw_parts_mut(
+                                lhs.as_mut_ptr() as *mut [u64; 4],
+                                lhs.len(),
+                            ),
+                            core::slice::from_raw_parts(rhs.as_ptr() as *const [u64; 4], rhs.len()),
+                        )
+                    };
+                    $crate::arithmetic::ifma::mont_mul_assign(lhs, rhs, &$modulus.0, $inv)
+                };
+                #[cfg(not(all(target_arch = "x86_64", not(feature = "portable"))))]
+                let done = 0;
+                $crate::ff_ext::mul_assign_serial(&mut lhs[done..], &rhs[done..]);
+            }
+        }
+
         impl $crate::serde::SerdeObject for $field {
             fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
                 debug_assert_eq!(bytes.len(), 32);
@@ -343,13 +580,14 @@ macro_rules! field_common {
                 let elt = Self::from_raw_bytes_unchecked(bytes);
                 Self::is_less_than(&elt.0, &$modulus.0).then(|| elt)
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(32);
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(32);
                 for limb in self.0.iter() {
                     res.extend_from_slice(&limb.to_le_bytes());
                 }
                 res
             }
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
                 let inner = [(); 4].map(|_| {
                     let mut buf = [0; 8];
@@ -358,6 +596,7 @@ macro_rules! field_common {
                 });
                 Self(inner)
             }
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
                 let mut inner = [0u64; 4];
                 for limb in inner.iter_mut() {
@@ -375,6 +614,7 @@ macro_rules! field_common {
                         )
                     })
             }
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
                 for limb in self.0.iter() {
                     writer.write_all(&limb.to_le_bytes())?;
@@ -398,6 +638,49 @@ macro_rules! field_arithmetic {
             }
 
             /// Squares this element.
+            #[cfg(all(
+                any(feature = "force-u32", target_pointer_width = "32"),
+                not(all(
+                    feature = "wasm-simd",
+                    target_arch = "wasm32",
+                    target_feature = "simd128"
+                )),
+                not(feature = "portable")
+            ))]
+            #[inline]
+            pub const fn square(&self) -> $field {
+                // The 64-bit limb products below would be emulated, so the
+                // squaring works on 32-bit limbs.
+                $field($crate::arithmetic::limb32::mont_square(
+                    &self.0,
+                    &$modulus.0,
+                    $inv,
+                ))
+            }
+
+            /// Squares this element.
+            #[cfg(feature = "portable")]
+            #[inline]
+            pub const fn square(&self) -> $field {
+                $field($crate::arithmetic::portable::mont_square(
+                    &self.0,
+                    &$modulus.0,
+                    $inv,
+                ))
+            }
+
+            /// Squares this element.
+            #[cfg(not(any(
+                all(
+                    any(feature = "force-u32", target_pointer_width = "32"),
+                    not(all(
+                        feature = "wasm-simd",
+                        target_arch = "wasm32",
+                        target_feature = "simd128"
+                    ))
+                ),
+                feature = "portable"
+            )))]
             #[inline]
             pub const fn square(&self) -> $field {
                 let (r1, carry) = mac(0, self.0[0], self.0[1], 0);
@@ -430,6 +713,99 @@ macro_rules! field_arithmetic {
             }
 
             /// Multiplies `rhs` by `self`, returning the result.
+            #[cfg(all(
+                feature = "wasm-simd",
+                target_arch = "wasm32",
+                target_feature = "simd128",
+                not(feature = "portable")
+            ))]
+            #[inline(always)]
+            pub fn mul(&self, rhs: &Self) -> Self {
+                $field($crate::arithmetic::simd128::mont_mul(
+                    &self.0,
+                    &rhs.0,
+                    &$modulus.0,
+                    $inv,
+                ))
+            }
+
+            /// Multiplies `rhs` by `self`, returning the result.
+            #[cfg(all(
+                feature = "asm",
+                target_arch = "aarch64",
+                not(any(feature = "force-u32", feature = "portable"))
+            ))]
+            #[inline(always)]
+            pub fn mul(&self, rhs: &Self) -> Self {
+                $field($crate::arithmetic::aarch64::mont_mul(
+                    &self.0,
+                    &rhs.0,
+                    &$modulus.0,
+                    $inv,
+                ))
+            }
+
+            /// Multiplies `rhs` by `self`, returning the result.
+            #[cfg(all(
+                feature = "asm",
+                target_arch = "riscv64",
+                not(any(feature = "force-u32", feature = "portable"))
+            ))]
+            #[inline(always)]
+            pub fn mul(&self, rhs: &Self) -> Self {
+                $field($crate::arithmetic::riscv::mont_mul(
+                    &self.0,
+                    &rhs.0,
+                    &$modulus.0,
+                    $inv,
+                ))
+            }
+
+            /// Multiplies `rhs` by `self`, returning the result.
+            #[cfg(all(
+                any(feature = "force-u32", target_pointer_width = "32"),
+                not(all(
+                    feature = "wasm-simd",
+                    target_arch = "wasm32",
+                    target_feature = "simd128"
+                )),
+                not(feature = "portable")
+            ))]
+            #[inline(always)]
+            pub const fn mul(&self, rhs: &Self) -> Self {
+                $field($crate::arithmetic::limb32::mont_mul(
+                    &self.0,
+                    &rhs.0,
+                    &$modulus.0,
+                    $inv,
+                ))
+            }
+
+            /// Multiplies `rhs` by `self`, returning the result.
+            #[cfg(feature = "portable")]
+            #[inline(always)]
+            pub const fn mul(&self, rhs: &Self) -> Self {
+                $field($crate::arithmetic::portable::mont_mul(
+                    &self.0,
+                    &rhs.0,
+                    &$modulus.0,
+                    $inv,
+                ))
+            }
+
+            /// Multiplies `rhs` by `self`, returning the result.
+            #[cfg(not(any(
+                all(feature = "asm", target_arch = "aarch64"),
+                all(feature = "asm", target_arch = "riscv64"),
+                feature = "force-u32",
+                target_pointer_width = "32",
+                feature = "portable",
+                all(
+                    feature = "wasm-simd",
+                    target_arch = "wasm32",
+                    target_feature = "simd128"
+                )
+            )))]
             #[inline(always)]
             #[unroll::unroll_for_loops]
             #[allow(unused_assignments)]
@@ -772,7 +1148,7 @@ macro_rules! field_bits {
 
 /// A macro to help define serialization and deserialization for prime field implementations
 /// that use 32-byte representations. This assumes the concerned type implements PrimeField
-/// (for from_repr, to_repr).
+/// (for from_repr, to_repr). Human-readable formats use `0x`-prefixed big-endian hex strings.
 #[macro_export]
 macro_rules! serialize_deserialize_32_byte_primefield {
     ($type:ty) => {
@@ -780,7 +1156,7 @@ macro_rules! serialize_deserialize_32_byte_primefield {
             fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
                 let bytes = &self.to_repr();
                 if serializer.is_human_readable() {
-                    hex::serde::serialize(bytes, serializer)
+                    $crate::serde::hex_be::serialize(bytes, serializer)
                 } else {
                     bytes.serialize(serializer)
                 }
@@ -793,7 +1169,7 @@ macro_rules! serialize_deserialize_32_byte_primefield {
                 deserializer: D,
             ) -> Result<Self, D::Error> {
                 let bytes = if deserializer.is_human_readable() {
-                    ::hex::serde::deserialize(deserializer)?
+                    $crate::serde::hex_be::deserialize(deserializer)?
                 } else {
                     <[u8; 32]>::deserialize(deserializer)?
                 };
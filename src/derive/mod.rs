@@ -1,7 +1,17 @@
+#[cfg(all(feature = "asm", target_arch = "x86_64"))]
+pub(crate) mod asm;
+#[cfg(feature = "crypto-bigint")]
+pub(crate) mod bigint;
 #[macro_use]
 pub mod curve;
+#[cfg(feature = "fiat-backend")]
+pub(crate) mod fiat;
 #[macro_use]
 pub mod field;
+#[macro_use]
+pub mod pairing;
+#[cfg(any(feature = "k256", feature = "p256"))]
+pub(crate) mod rustcrypto;
 
 #[macro_export]
 macro_rules! impl_add_binop_specify_output {
@@ -67,6 +77,20 @@ macro_rules! impl_sub_binop_specify_output {
     };
 }
 
+#[macro_export]
+macro_rules! impl_neg {
+    ($t:ident) => {
+        impl ::core::ops::Neg for $t {
+            type Output = $t;
+
+            #[inline]
+            fn neg(self) -> $t {
+                -&self
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! impl_binops_additive_specify_output {
     ($lhs:ident, $rhs:ident, $output:ident) => {
@@ -181,3 +205,96 @@ macro_rules! impl_sum_prod {
         }
     };
 }
+
+/// Implements sampling of `$type` with `rand::Rng::gen` by delegating to the
+/// `random` method of `$trait`.
+#[macro_export]
+macro_rules! impl_standard_distribution {
+    ($type:ident, $trait:path) => {
+        impl $crate::__private::rand::distributions::Distribution<$type>
+            for $crate::__private::rand::distributions::Standard
+        {
+            fn sample<R: $crate::__private::rand::Rng + ?Sized>(&self, rng: &mut R) -> $type {
+                <$type as $trait>::random(rng)
+            }
+        }
+    };
+}
+
+/// Asserts that each of the values passes `debug_validate`, in debug builds
+/// with the `debug-validate` feature enabled.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! debug_validate {
+    ($($value:expr),+) => {
+        if $crate::DEBUG_VALIDATE {
+            $(
+                assert!(
+                    $value.debug_validate(),
+                    "`{}` is not a valid element: {:?}",
+                    stringify!($value),
+                    $value
+                );
+            )+
+        }
+    };
+}
+
+/// Expands to its input when `halo2curves` is built with the `std` feature.
+///
+/// `#[cfg(feature = "std")]` in an exported macro would check the features of
+/// the crate invoking it instead.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_std {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+/// Expands to its input when `halo2curves` is built with the `std` feature.
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_std {
+    ($($tt:tt)*) => {};
+}
+
+/// Expands to its input when `halo2curves` is built without the `std` feature.
+#[cfg(feature = "std")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_not_std {
+    ($($tt:tt)*) => {};
+}
+
+/// Expands to its input when `halo2curves` is built without the `std` feature.
+#[cfg(not(feature = "std"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_not_std {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+/// Expands to its input when `halo2curves` is built with the `derive_serde`
+/// feature.
+#[cfg(feature = "derive_serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_derive_serde {
+    ($($tt:tt)*) => {
+        $($tt)*
+    };
+}
+
+/// Expands to its input when `halo2curves` is built with the `derive_serde`
+/// feature.
+#[cfg(not(feature = "derive_serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __cfg_derive_serde {
+    ($($tt:tt)*) => {};
+}
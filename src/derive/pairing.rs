@@ -0,0 +1,69 @@
+/// Implements the `pairing` crate traits for a pairing engine.
+///
+/// Curves defined outside of this crate only need to provide a Miller loop
+/// over prepared G2 points and the final exponentiation, as
+/// `MillerLoopResult` for `$miller_loop_output`, a type distinct from `$gt`
+/// since Miller loop outputs are not in the target group. The macro generates
+/// the `Engine`, `MultiMillerLoop` and `PairingCurveAffine` glue on top of
+/// them, exactly as for the engines of this crate.
+///
+/// `$g2_prepared` must implement `From<$g2_affine>`, and `$multi_miller_loop`
+/// must be a function with the signature of
+/// `MultiMillerLoop::multi_miller_loop`.
+#[macro_export]
+macro_rules! impl_pairing_engine {
+    (
+        $engine:ident,
+        $scalar:ident,
+        $g1:ident,
+        $g1_affine:ident,
+        $g2:ident,
+        $g2_affine:ident,
+        $g2_prepared:ident,
+        $gt:ident,
+        $miller_loop_output:ident,
+        $multi_miller_loop:path
+    ) => {
+        impl $crate::pairing::PairingCurveAffine for $g1_affine {
+            type Pair = $g2_affine;
+            type PairingResult = $gt;
+
+            fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
+                <$engine as $crate::pairing::Engine>::pairing(self, other)
+            }
+        }
+
+        impl $crate::pairing::PairingCurveAffine for $g2_affine {
+            type Pair = $g1_affine;
+            type PairingResult = $gt;
+
+            fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
+                <$engine as $crate::pairing::Engine>::pairing(other, self)
+            }
+        }
+
+        impl $crate::pairing::Engine for $engine {
+            type Fr = $scalar;
+            type G1 = $g1;
+            type G1Affine = $g1_affine;
+            type G2 = $g2;
+            type G2Affine = $g2_affine;
+            type Gt = $gt;
+
+            fn pairing(p: &Self::G1Affine, q: &Self::G2Affine) -> Self::Gt {
+                let q = $g2_prepared::from(*q);
+                let terms: &[(&$g1_affine, &$g2_prepared)] = &[(p, &q)];
+                $crate::pairing::MillerLoopResult::final_exponentiation(&$multi_miller_loop(terms))
+            }
+        }
+
+        impl $crate::pairing::MultiMillerLoop for $engine {
+            type G2Prepared = $g2_prepared;
+            type Result = $miller_loop_output;
+
+            fn multi_miller_loop(terms: &[(&Self::G1Affine, &Self::G2Prepared)]) -> Self::Result {
+                $multi_miller_loop(terms)
+            }
+        }
+    };
+}
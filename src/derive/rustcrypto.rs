@@ -0,0 +1,95 @@
+/// Implements conversions between a curve of this crate and its counterpart
+/// in the RustCrypto crate `$rc`, whose curve marker type is `$marker`.
+///
+/// Scalars and points convert with `From` in both directions, as both sides
+/// only hold valid elements. The affine type also implements the SEC1
+/// `ToEncodedPoint` and `FromEncodedPoint` traits of `elliptic-curve`, which
+/// is what the point conversions go through.
+macro_rules! impl_rustcrypto_conversions {
+    ($rc:ident, $marker:ident, $base:ident, $scalar:ident, $curve:ident, $affine:ident) => {
+        impl From<$scalar> for ::$rc::Scalar {
+            fn from(s: $scalar) -> Self {
+                // RustCrypto scalars are encoded in big-endian order.
+                let mut bytes = $crate::ff::PrimeField::to_repr(&s);
+                bytes.reverse();
+                <::$rc::Scalar as $crate::ff::PrimeField>::from_repr(bytes.into()).unwrap()
+            }
+        }
+
+        impl From<::$rc::Scalar> for $scalar {
+            fn from(s: ::$rc::Scalar) -> Self {
+                let mut bytes: [u8; 32] = $crate::ff::PrimeField::to_repr(&s).into();
+                bytes.reverse();
+                $scalar::from_bytes(&bytes).unwrap()
+            }
+        }
+
+        impl ::$rc::elliptic_curve::sec1::ToEncodedPoint<::$rc::$marker> for $affine {
+            fn to_encoded_point(&self, compress: bool) -> ::$rc::EncodedPoint {
+                if bool::from(self.is_identity()) {
+                    return ::$rc::EncodedPoint::identity();
+                }
+                let be_bytes = |e: &$base| {
+                    let mut bytes = $crate::ff::PrimeField::to_repr(e);
+                    bytes.reverse();
+                    ::$rc::FieldBytes::from(bytes)
+                };
+                ::$rc::EncodedPoint::from_affine_coordinates(
+                    &be_bytes(&self.x),
+                    &be_bytes(&self.y),
+                    compress,
+                )
+            }
+        }
+
+        impl ::$rc::elliptic_curve::sec1::FromEncodedPoint<::$rc::$marker> for $affine {
+            fn from_encoded_point(encoded: &::$rc::EncodedPoint) -> CtOption<Self> {
+                <::$rc::AffinePoint as ::$rc::elliptic_curve::sec1::FromEncodedPoint<
+                    ::$rc::$marker,
+                >>::from_encoded_point(encoded)
+                .map(Self::from)
+            }
+        }
+
+        impl From<::$rc::AffinePoint> for $affine {
+            fn from(p: ::$rc::AffinePoint) -> Self {
+                use ::$rc::elliptic_curve::sec1::{Coordinates, ToEncodedPoint};
+
+                let from_be_bytes = |bytes: &::$rc::FieldBytes| {
+                    let mut bytes: [u8; 32] = (*bytes).into();
+                    bytes.reverse();
+                    $base::from_bytes(&bytes).unwrap()
+                };
+                match p.to_encoded_point(false).coordinates() {
+                    Coordinates::Uncompressed { x, y } => $affine {
+                        x: from_be_bytes(x),
+                        y: from_be_bytes(y),
+                    },
+                    _ => $affine::identity(),
+                }
+            }
+        }
+
+        impl From<$affine> for ::$rc::AffinePoint {
+            fn from(p: $affine) -> Self {
+                use ::$rc::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+
+                Self::from_encoded_point(&p.to_encoded_point(false)).unwrap()
+            }
+        }
+
+        impl From<$curve> for ::$rc::ProjectivePoint {
+            fn from(p: $curve) -> Self {
+                ::$rc::AffinePoint::from(p.to_affine()).into()
+            }
+        }
+
+        impl From<::$rc::ProjectivePoint> for $curve {
+            fn from(p: ::$rc::ProjectivePoint) -> Self {
+                $affine::from($crate::group::Curve::to_affine(&p)).into()
+            }
+        }
+    };
+}
+
+pub(crate) use impl_rustcrypto_conversions;
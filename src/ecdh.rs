@@ -0,0 +1,126 @@
+//! Elliptic curve Diffie–Hellman.
+//!
+//! This is the cofactor Diffie–Hellman primitive of SEC 1 v2 section 3.3.2,
+//! whose shared secret is the big-endian `x` coordinate of `h d Q`, `h` being
+//! the cofactor, `d` the private key and `Q` the peer's public key. Clearing
+//! the cofactor keeps a public key of small order from revealing bits of the
+//! private key. It is a no-op for the prime order curves
+//! [`secp256k1`](crate::secp256k1) and [`secp256r1`](crate::secp256r1).
+//!
+//! The private key is a [`SecretScalar`], which the point is multiplied by
+//! with the constant-time double-and-add, and the shared point is normalized
+//! with the constant-time [`invert_ct`]. The shared secret is the raw `x`
+//! coordinate, which is not uniformly distributed and should be passed
+//! through a key derivation function before use.
+
+use ff::{Field, PrimeField};
+use group::cofactor::CofactorGroup;
+use group::Group;
+use subtle::CtOption;
+
+use crate::ff_ext::invert_ct;
+use crate::secret::SecretScalar;
+use crate::{CurveAffine, CurveExt};
+
+/// Returns the big-endian `x` coordinate of the shared point of the private
+/// key `secret` and the peer's `public_key`, or `None` if the shared point is
+/// the identity, which happens if `secret` is zero or `public_key` has small
+/// order.
+///
+/// The public key is not validated beyond that: it is a point of the curve by
+/// construction of `C`.
+pub fn diffie_hellman<C>(secret: &SecretScalar<C::ScalarExt>, public_key: &C) -> CtOption<[u8; 32]>
+where
+    C: CurveAffine,
+    <C::CurveExt as CurveExt>::Base: PrimeField<Repr = [u8; 32]>,
+    C::CurveExt: CofactorGroup<Subgroup = C::CurveExt>,
+    for<'a> &'a C: core::ops::Mul<&'a SecretScalar<C::ScalarExt>, Output = C::CurveExt>,
+{
+    let shared = (public_key * secret).clear_cofactor();
+    // `to_affine` inverts `Z` in variable time, which would leak the secret
+    // dependent `Z` here, so the affine `x` is computed with `invert_ct`.
+    let (x, _, z) = shared.jacobian_coordinates();
+    let x = x * invert_ct(&z).unwrap_or(Field::ZERO).square();
+    let mut x = x.to_repr();
+    x.reverse();
+    CtOption::new(x, !shared.is_identity())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use group::Curve;
+    use rand_core::OsRng;
+
+    fn check<C>()
+    where
+        C: CurveAffine,
+        C::Base: PrimeField<Repr = [u8; 32]>,
+        <C::CurveExt as CurveExt>::Base: PrimeField<Repr = [u8; 32]>,
+        C::CurveExt: CofactorGroup<Subgroup = C::CurveExt>,
+        for<'a> &'a C: core::ops::Mul<&'a SecretScalar<C::ScalarExt>, Output = C::CurveExt>,
+    {
+        for _ in 0..20 {
+            let alice = SecretScalar::<C::ScalarExt>::random(OsRng);
+            let bob = SecretScalar::<C::ScalarExt>::random(OsRng);
+            let alice_pk = (C::generator() * alice.expose_secret()).to_affine();
+            let bob_pk = (C::generator() * bob.expose_secret()).to_affine();
+
+            let shared = diffie_hellman(&alice, &bob_pk).unwrap();
+            assert_eq!(diffie_hellman(&bob, &alice_pk).unwrap(), shared);
+
+            // The shared secret is the x coordinate of `alice * bob * G`.
+            let point =
+                (C::generator() * (*alice.expose_secret() * bob.expose_secret())).to_affine();
+            let mut x = point.coordinates().unwrap().x().to_repr();
+            x.reverse();
+            assert_eq!(shared, x);
+        }
+
+        let secret = SecretScalar::<C::ScalarExt>::random(OsRng);
+        assert!(bool::from(
+            diffie_hellman(&secret, &C::identity()).is_none()
+        ));
+        let zero = SecretScalar::new(C::ScalarExt::ZERO);
+        assert!(bool::from(diffie_hellman(&zero, &C::generator()).is_none()));
+    }
+
+    #[test]
+    fn test_diffie_hellman() {
+        check::<crate::secp256k1::Secp256k1Affine>();
+        check::<crate::secp256r1::Secp256r1Affine>();
+    }
+
+    // Test vector from the CAVP ECC CDH primitive test vectors, P-256, COUNT = 0.
+    #[test]
+    fn test_diffie_hellman_p256_vector() {
+        use crate::secp256r1::{Fp, Fq, Secp256r1Affine};
+
+        let from_hex = |s: &str| {
+            let mut bytes: [u8; 32] = hex::decode(s).unwrap().try_into().unwrap();
+            bytes.reverse();
+            bytes
+        };
+        let x = Fp::from_repr(from_hex(
+            "700c48f77f56584c5cc632ca65640db91b6bacce3a4df6b42ce7cc838833d287",
+        ))
+        .unwrap();
+        let y = Fp::from_repr(from_hex(
+            "db71e509e3fd9b060ddb20ba5c51dcc5948d46fbf640dfe0441782cab85fa4ac",
+        ))
+        .unwrap();
+        let public_key = Secp256r1Affine::from_xy(x, y).unwrap();
+        let secret = SecretScalar::new(
+            Fq::from_repr(from_hex(
+                "7d7dc5f71eb29ddaf80d6214632eeae03d9058af1fb6d22ed80badb62bc1a534",
+            ))
+            .unwrap(),
+        );
+        let expected: [u8; 32] =
+            hex::decode("46fc62106420ff012e54a434fbdd2d25ccc5852060561e68040dd7778997bd7b")
+                .unwrap()
+                .try_into()
+                .unwrap();
+        assert_eq!(diffie_hellman(&secret, &public_key).unwrap(), expected);
+    }
+}
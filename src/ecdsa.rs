@@ -0,0 +1,799 @@
+//! ECDSA signature verification.
+//!
+//! This is the verification of SEC 1 v2 section 4.1.4, generic over the
+//! curve, with the public key encodings of section 2.3.3. It is meant for the
+//! prime order curves with 32-byte fields, [`secp256k1`](crate::secp256k1) and
+//! [`secp256r1`](crate::secp256r1), and serves as the native reference of
+//! circuits verifying ECDSA over the same field and curve types.
+//!
+//! Signatures are verified against the hash of the message, which is the
+//! caller's to compute. The public key can also be recovered from a signature
+//! and a [`RecoveryId`], as Ethereum's `ecrecover` does. Everything here
+//! handles public data and runs in variable time, except the codec of
+//! compressed points, which the constant time SEC 1 encoding of
+//! [`Secp256k1Affine`](crate::secp256k1::Secp256k1Affine) shares.
+
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::fmt;
+
+use ff::{Field, PrimeField};
+use group::Curve;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::ff_ext::radix::{from_be_slice, reduce_be_bytes, reduce_le_bytes};
+use crate::msm::multi_mul2;
+use crate::{Coordinates, CurveAffine};
+
+/// Error returned when parsing or verifying a signature.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EcdsaError {
+    /// The public key encoding is malformed, or does not encode a point of
+    /// the curve other than the identity.
+    InvalidPublicKey,
+    /// The signature encoding is malformed, or `r` or `s` is zero or not
+    /// smaller than the group order. When recovering a public key, the
+    /// signature and recovery id do not determine a valid point.
+    InvalidSignature,
+    /// `s` is greater than half the group order, and [`HighS::Reject`] was
+    /// requested.
+    HighS,
+    /// The signature does not match the public key and message hash.
+    VerificationFailed,
+}
+
+impl fmt::Display for EcdsaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidPublicKey => write!(f, "invalid public key"),
+            Self::InvalidSignature => write!(f, "invalid signature encoding"),
+            Self::HighS => write!(f, "signature s is not low"),
+            Self::VerificationFailed => write!(f, "signature verification failed"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EcdsaError {}
+
+/// Whether verification accepts signatures whose `s` is greater than half the
+/// group order.
+///
+/// Both `(r, s)` and `(r, -s)` are valid signatures of the same message. Some
+/// protocols, such as Bitcoin and Ethereum, only accept the low one to make
+/// signatures non-malleable.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HighS {
+    /// Accept both `s` and `-s`, as SEC 1 does.
+    Accept,
+    /// Reject `s` greater than half the group order.
+    Reject,
+}
+
+/// An ECDSA signature `(r, s)` over the scalar field `F`, where neither
+/// scalar is zero.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<F> {
+    r: F,
+    s: F,
+}
+
+impl<F: PrimeField> Signature<F> {
+    /// Returns the signature `(r, s)`, or `None` if either is zero.
+    pub fn new(r: F, s: F) -> Option<Self> {
+        (!bool::from(r.is_zero() | s.is_zero())).then_some(Self { r, s })
+    }
+
+    /// Returns `r`.
+    pub fn r(&self) -> &F {
+        &self.r
+    }
+
+    /// Returns `s`.
+    pub fn s(&self) -> &F {
+        &self.s
+    }
+
+    /// Parses the concatenation of `r` and `s` as big-endian integers of the
+    /// size of the field, 64 bytes for the bundled curves.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, EcdsaError> {
+        let len = F::Repr::default().as_ref().len();
+        if bytes.len() != 2 * len {
+            return Err(EcdsaError::InvalidSignature);
+        }
+        let r = from_be_slice::<F>(&bytes[..len]).ok();
+        let s = from_be_slice::<F>(&bytes[len..]).ok();
+        r.zip(s)
+            .and_then(|(r, s)| Self::new(r, s))
+            .ok_or(EcdsaError::InvalidSignature)
+    }
+
+    /// Returns the concatenation of `r` and `s` as big-endian integers.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = to_be_bytes(&self.r);
+        bytes.extend(to_be_bytes(&self.s));
+        bytes
+    }
+
+    /// Returns whether `s` is at most half the group order.
+    pub fn is_low_s(&self) -> bool {
+        // As the order is odd, exactly one of `s` and `-s` is below half of it.
+        cmp_canonical(&self.s, &-self.s) == Ordering::Less
+    }
+
+    /// Returns the signature with `s` replaced by `-s` if it is not low. It is
+    /// valid for the same public key and message.
+    pub fn normalize_s(&self) -> Self {
+        if self.is_low_s() {
+            *self
+        } else {
+            Self {
+                r: self.r,
+                s: -self.s,
+            }
+        }
+    }
+}
+
+/// The information needed to recover a public key from a signature, besides
+/// the signature itself: the parity of the `y` coordinate of the point `R`
+/// whose `x` coordinate gave `r`, and whether that `x` was reduced, that is,
+/// whether it is `r + n` rather than `r`, `n` being the group order.
+///
+/// The `x` coordinate of `R` is smaller than the base field modulus, which
+/// exceeds `n` by less than `2^129` for the bundled curves, so it is reduced
+/// with a negligible probability.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RecoveryId(u8);
+
+impl RecoveryId {
+    /// Returns the recovery id of a point `R` with the given `y` parity and
+    /// reduction of `x`.
+    pub const fn new(is_y_odd: bool, is_x_reduced: bool) -> Self {
+        Self(is_y_odd as u8 | (is_x_reduced as u8) << 1)
+    }
+
+    /// Parses the recovery id `0` to `3`, whose low bit is the parity of `y`
+    /// and high bit whether `x` was reduced.
+    pub const fn from_byte(byte: u8) -> Option<Self> {
+        if byte < 4 {
+            Some(Self(byte))
+        } else {
+            None
+        }
+    }
+
+    /// Returns the recovery id as a byte, see [`RecoveryId::from_byte`].
+    pub const fn to_byte(self) -> u8 {
+        self.0
+    }
+
+    /// Parses the `v` value of an Ethereum signature: `27` or `28` as used by
+    /// `ecrecover` and legacy transactions, or `chain_id * 2 + 35` or `36` as
+    /// used by EIP-155 transactions. It cannot express a reduced `x`.
+    pub const fn from_ethereum_v(v: u64) -> Option<Self> {
+        match v {
+            27 | 28 => Some(Self::new(v == 28, false)),
+            35.. => Some(Self::new((v - 35) % 2 == 1, false)),
+            _ => None,
+        }
+    }
+
+    /// Returns whether the `y` coordinate of `R` is odd.
+    pub const fn is_y_odd(self) -> bool {
+        self.0 & 1 == 1
+    }
+
+    /// Returns whether the `x` coordinate of `R` is `r + n`.
+    pub const fn is_x_reduced(self) -> bool {
+        self.0 & 2 == 2
+    }
+}
+
+/// Parses a SEC 1 encoded public key: `0x02` or `0x03` followed by the
+/// big-endian `x` coordinate for a compressed key, or `0x04` followed by `x`
+/// and `y` for an uncompressed one.
+///
+/// The point must be on the curve and not the identity. Points are not
+/// checked to be in a prime order subgroup, which all points of the bundled
+/// curves are.
+pub fn parse_public_key<C: CurveAffine>(bytes: &[u8]) -> Result<C, EcdsaError> {
+    let len = <C::Base as PrimeField>::Repr::default().as_ref().len();
+    let point = match bytes.split_first() {
+        Some((0x02 | 0x03, _)) if bytes.len() == len + 1 => decode_compressed(bytes).into(),
+        Some((0x04, xy)) if xy.len() == 2 * len => {
+            let x = from_be_slice::<C::Base>(&xy[..len]).ok();
+            let y = from_be_slice::<C::Base>(&xy[len..]).ok();
+            x.zip(y).and_then(|(x, y)| C::from_xy(x, y).into())
+        }
+        _ => None,
+    };
+    point
+        .filter(|point: &C| !bool::from(point.is_identity()))
+        .ok_or(EcdsaError::InvalidPublicKey)
+}
+
+/// Returns the SEC 1 encoding of a public key, compressed or not, see
+/// [`parse_public_key`].
+///
+/// # Panics
+///
+/// Panics if `public_key` is the identity, which has no encoding.
+pub fn encode_public_key<C: CurveAffine>(public_key: &C, compress: bool) -> Vec<u8> {
+    assert!(
+        !bool::from(public_key.is_identity()),
+        "the identity has no encoding"
+    );
+    let coords: Coordinates<C> = public_key.coordinates().unwrap();
+    let (x, y) = (coords.x(), coords.y());
+    let mut bytes = Vec::new();
+    if compress {
+        bytes.resize(x.to_repr().as_ref().len() + 1, 0);
+        encode_compressed(public_key, &mut bytes);
+    } else {
+        bytes.push(0x04);
+        bytes.extend(to_be_bytes(x));
+        bytes.extend(to_be_bytes(y));
+    }
+    bytes
+}
+
+/// Writes the SEC 1 compressed encoding of `point` to `bytes`, which is one
+/// byte longer than a base field element, and returns whether `point` has
+/// one. The identity has no encoding, and leaves `bytes` zeroed.
+///
+/// Unlike the rest of this module, this runs in constant time, so that the
+/// encodings of secret points can share it.
+pub(crate) fn encode_compressed<C: CurveAffine>(point: &C, bytes: &mut [u8]) -> Choice {
+    let coords = point.coordinates();
+    let is_some = coords.is_some() & !point.is_identity();
+    let x = coords.map(|c| *c.x()).unwrap_or(C::Base::ZERO);
+    let y = coords.map(|c| *c.y()).unwrap_or(C::Base::ZERO);
+    let prefix = u8::conditional_select(&0x02, &0x03, y.is_odd());
+    bytes[0] = u8::conditional_select(&0, &prefix, is_some);
+    for (byte, x) in bytes[1..].iter_mut().zip(x.to_repr().as_ref().iter().rev()) {
+        *byte = u8::conditional_select(&0, x, is_some);
+    }
+    is_some
+}
+
+/// Decodes a SEC 1 compressed encoding as written by [`encode_compressed`],
+/// failing if the prefix is not `0x02` or `0x03`, or `x` is not canonical or
+/// not the `x` coordinate of a point. This runs in constant time.
+pub(crate) fn decode_compressed<C: CurveAffine>(bytes: &[u8]) -> CtOption<C> {
+    let mut repr = <C::Base as PrimeField>::Repr::default();
+    for (x, byte) in repr.as_mut().iter_mut().zip(bytes[1..].iter().rev()) {
+        *x = *byte;
+    }
+    let is_odd = bytes[0].ct_eq(&0x03);
+    let valid_prefix = bytes[0].ct_eq(&0x02) | is_odd;
+    C::Base::from_repr(repr)
+        .and_then(|x| {
+            let y2 = (x.square() + C::a()) * x + C::b();
+            y2.sqrt().and_then(|y| {
+                let y = C::Base::conditional_select(&y, &-y, y.is_odd() ^ is_odd);
+                C::from_xy(x, y)
+            })
+        })
+        .and_then(|point| CtOption::new(point, valid_prefix))
+}
+
+/// Converts a message hash to a scalar: the leftmost bits of the hash, as many
+/// as the group order has, reduced modulo the order.
+///
+/// This is the conversion of SEC 1 section 4.1.3, step 5.
+pub fn prehash_to_scalar<F: PrimeField>(msg_hash: &[u8]) -> F {
+    let num_bits = F::NUM_BITS as usize;
+    let num_bytes = msg_hash.len().min((num_bits + 7) / 8);
+    let mut bytes = msg_hash[..num_bytes].to_vec();
+    // Drop the trailing bits of the last byte kept.
+    let excess = (8 * num_bytes).saturating_sub(num_bits);
+    if excess > 0 {
+        for i in (0..bytes.len()).rev() {
+            let carry = if i > 0 {
+                bytes[i - 1] << (8 - excess)
+            } else {
+                0
+            };
+            bytes[i] = (bytes[i] >> excess) | carry;
+        }
+    }
+    reduce_be_bytes(&bytes)
+}
+
+/// Verifies `signature` of the message whose hash is `msg_hash` under
+/// `public_key`.
+pub fn verify_prehash<C: CurveAffine>(
+    public_key: &C,
+    msg_hash: &[u8],
+    signature: &Signature<C::ScalarExt>,
+    high_s: HighS,
+) -> Result<(), EcdsaError> {
+    if bool::from(public_key.is_identity()) {
+        return Err(EcdsaError::InvalidPublicKey);
+    }
+    if high_s == HighS::Reject && !signature.is_low_s() {
+        return Err(EcdsaError::HighS);
+    }
+
+    let e = prehash_to_scalar::<C::ScalarExt>(msg_hash);
+    // `s` is not zero.
+    let w = signature.s.invert().unwrap();
+    let point = multi_mul2(
+        &(e * w),
+        &C::generator().to_curve(),
+        &(signature.r * w),
+        &public_key.to_curve(),
+    )
+    .to_affine();
+
+    let x = Option::from(point.coordinates()).map(|coords: Coordinates<C>| *coords.x());
+    match x {
+        Some(x) if reduce_le_bytes::<C::ScalarExt>(x.to_repr().as_ref()) == signature.r => Ok(()),
+        _ => Err(EcdsaError::VerificationFailed),
+    }
+}
+
+/// Recovers the public key under which `signature` of the message whose hash is
+/// `msg_hash` is valid, as in SEC 1 v2 section 4.1.6.
+///
+/// The signature verifies under the returned key with [`verify_prehash`]. Any
+/// valid signature recovers some key, so the caller must check that it is the
+/// expected one, for instance by comparing the address derived from it.
+pub fn recover_prehash<C: CurveAffine>(
+    msg_hash: &[u8],
+    signature: &Signature<C::ScalarExt>,
+    recovery_id: RecoveryId,
+    high_s: HighS,
+) -> Result<C, EcdsaError> {
+    if high_s == HighS::Reject && !signature.is_low_s() {
+        return Err(EcdsaError::HighS);
+    }
+
+    // R is the point with x coordinate r, or r + n, and the given parity.
+    let x = if recovery_id.is_x_reduced() {
+        add_order(&signature.r)
+    } else {
+        signature.r.to_repr().as_ref().to_vec()
+    };
+    let mut repr = <C::Base as PrimeField>::Repr::default();
+    if repr.as_ref().len() != x.len() {
+        return Err(EcdsaError::InvalidSignature);
+    }
+    repr.as_mut().copy_from_slice(&x);
+    let point = Option::<C::Base>::from(C::Base::from_repr(repr)).and_then(|x| {
+        let y2 = (x.square() + C::a()) * x + C::b();
+        let y = Option::<C::Base>::from(y2.sqrt())?;
+        let y = if bool::from(y.is_odd()) == recovery_id.is_y_odd() {
+            y
+        } else {
+            -y
+        };
+        Option::<C>::from(C::from_xy(x, y))
+    });
+    let point = point.ok_or(EcdsaError::InvalidSignature)?;
+
+    // Q = r^-1 (s R - e G)
+    let e = prehash_to_scalar::<C::ScalarExt>(msg_hash);
+    // `r` is not zero.
+    let r_inv = signature.r.invert().unwrap();
+    let public_key = multi_mul2(
+        &-(e * r_inv),
+        &C::generator().to_curve(),
+        &(signature.s * r_inv),
+        &point.to_curve(),
+    )
+    .to_affine();
+    if bool::from(public_key.is_identity()) {
+        return Err(EcdsaError::InvalidSignature);
+    }
+    Ok(public_key)
+}
+
+// Returns the little-endian bytes of `r + n`, with an extra byte for the
+// carry, which is too large for any field of the same size if it is set.
+fn add_order<F: PrimeField>(r: &F) -> Vec<u8> {
+    // n = (n - 1) + 1, where n - 1 is the canonical form of -1.
+    let (r, n_minus_one) = (r.to_repr(), (-F::ONE).to_repr());
+    let mut sum = Vec::with_capacity(r.as_ref().len() + 1);
+    let mut carry = 1u16;
+    for (a, b) in r.as_ref().iter().zip(n_minus_one.as_ref()) {
+        let t = *a as u16 + *b as u16 + carry;
+        sum.push(t as u8);
+        carry = t >> 8;
+    }
+    if carry != 0 {
+        sum.push(carry as u8);
+    }
+    sum
+}
+
+fn to_be_bytes<F: PrimeField>(x: &F) -> Vec<u8> {
+    let mut bytes = x.to_repr().as_ref().to_vec();
+    bytes.reverse();
+    bytes
+}
+
+// Compares the canonical values of `a` and `b`.
+fn cmp_canonical<F: PrimeField>(a: &F, b: &F) -> Ordering {
+    let (a, b) = (a.to_repr(), b.to_repr());
+    a.as_ref().iter().rev().cmp(b.as_ref().iter().rev())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand_core::{OsRng, RngCore};
+
+    // Signs with the textbook algorithm, returning a signature whose `s` may
+    // be high, and its recovery id.
+    fn sign<C: CurveAffine>(
+        sk: &C::ScalarExt,
+        msg_hash: &[u8],
+    ) -> (Signature<C::ScalarExt>, RecoveryId) {
+        loop {
+            let k = C::ScalarExt::random(OsRng);
+            let point = (C::generator() * k).to_affine();
+            let coords = point.coordinates().unwrap();
+            let r = reduce_le_bytes::<C::ScalarExt>(coords.x().to_repr().as_ref());
+            let s = k.invert().unwrap() * (prehash_to_scalar::<C::ScalarExt>(msg_hash) + r * sk);
+            let recovery_id = RecoveryId::new(
+                bool::from(coords.y().is_odd()),
+                coords.x().to_repr().as_ref() != r.to_repr().as_ref(),
+            );
+            if let Some(sig) = Signature::new(r, s) {
+                return (sig, recovery_id);
+            }
+        }
+    }
+
+    fn check<C: CurveAffine>() {
+        for _ in 0..100 {
+            let sk = C::ScalarExt::random(OsRng);
+            let pk = (C::generator() * sk).to_affine();
+            let mut msg_hash = [0u8; 32];
+            OsRng.fill_bytes(&mut msg_hash);
+
+            let (sig, recovery_id) = sign::<C>(&sk, &msg_hash);
+            assert_eq!(
+                recover_prehash::<C>(&msg_hash, &sig, recovery_id, HighS::Accept),
+                Ok(pk)
+            );
+            // Negating `s` negates `R`.
+            let flipped = RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced());
+            let negated = Signature::new(sig.r, -sig.s).unwrap();
+            assert_eq!(
+                recover_prehash::<C>(&msg_hash, &negated, flipped, HighS::Accept),
+                Ok(pk)
+            );
+            assert_ne!(
+                recover_prehash::<C>(&msg_hash, &sig, flipped, HighS::Accept),
+                Ok(pk)
+            );
+            if !sig.is_low_s() {
+                assert_eq!(
+                    recover_prehash::<C>(&msg_hash, &sig, recovery_id, HighS::Reject),
+                    Err(EcdsaError::HighS)
+                );
+            }
+
+            let (low, high) = if sig.is_low_s() {
+                (sig, Signature::new(sig.r, -sig.s).unwrap())
+            } else {
+                (sig.normalize_s(), sig)
+            };
+            assert!(low.is_low_s() && !high.is_low_s());
+            assert_eq!(high.normalize_s(), low);
+            assert_eq!(low.normalize_s(), low);
+
+            for policy in [HighS::Accept, HighS::Reject] {
+                assert_eq!(verify_prehash(&pk, &msg_hash, &low, policy), Ok(()));
+            }
+            assert_eq!(verify_prehash(&pk, &msg_hash, &high, HighS::Accept), Ok(()));
+            assert_eq!(
+                verify_prehash(&pk, &msg_hash, &high, HighS::Reject),
+                Err(EcdsaError::HighS)
+            );
+
+            // Another message, key or signature.
+            let mut other_hash = msg_hash;
+            other_hash[31] ^= 1;
+            assert_eq!(
+                verify_prehash(&pk, &other_hash, &low, HighS::Accept),
+                Err(EcdsaError::VerificationFailed)
+            );
+            let other_pk = (C::generator() * C::ScalarExt::random(OsRng)).to_affine();
+            assert_eq!(
+                verify_prehash(&other_pk, &msg_hash, &low, HighS::Accept),
+                Err(EcdsaError::VerificationFailed)
+            );
+            let other_sig = Signature::new(low.r + C::ScalarExt::ONE, low.s).unwrap();
+            assert_eq!(
+                verify_prehash(&pk, &msg_hash, &other_sig, HighS::Accept),
+                Err(EcdsaError::VerificationFailed)
+            );
+            assert_eq!(
+                verify_prehash(&C::identity(), &msg_hash, &low, HighS::Accept),
+                Err(EcdsaError::InvalidPublicKey)
+            );
+
+            // Encodings.
+            assert_eq!(Signature::from_bytes(&low.to_bytes()), Ok(low));
+            for compress in [false, true] {
+                let bytes = encode_public_key(&pk, compress);
+                assert_eq!(bytes.len(), if compress { 33 } else { 65 });
+                assert_eq!(parse_public_key::<C>(&bytes), Ok(pk));
+            }
+        }
+
+        // Invalid encodings.
+        let zero = [0u8; 32];
+        let one = to_be_bytes(&C::ScalarExt::ONE);
+        let order = {
+            // The order minus one, plus one.
+            let mut bytes = to_be_bytes(&-C::ScalarExt::ONE);
+            bytes[31] += 1;
+            bytes
+        };
+        for (r, s) in [
+            (&zero[..], &one[..]),
+            (&one, &zero),
+            (&order, &one),
+            (&one, &order),
+        ] {
+            assert_eq!(
+                Signature::<C::ScalarExt>::from_bytes(&[r, s].concat()),
+                Err(EcdsaError::InvalidSignature)
+            );
+        }
+        assert_eq!(
+            Signature::<C::ScalarExt>::from_bytes(&one),
+            Err(EcdsaError::InvalidSignature)
+        );
+
+        let pk = encode_public_key(&C::generator(), false);
+        let mut off_curve = pk.clone();
+        off_curve[64] ^= 1;
+        let mut bad_tag = encode_public_key(&C::generator(), true);
+        bad_tag[0] = 0x05;
+        let mut identity = vec![0u8; pk.len()];
+        identity[0] = 0x04;
+        for bytes in [&[][..], &[0x00], &pk[..64], &off_curve, &bad_tag, &identity] {
+            assert_eq!(
+                parse_public_key::<C>(bytes),
+                Err(EcdsaError::InvalidPublicKey)
+            );
+        }
+    }
+
+    // Recovers keys from signatures whose `R` has an `x` coordinate of at
+    // least the group order, which signing only produces with a negligible
+    // probability.
+    fn check_reduced_x<C: CurveAffine>() {
+        let base_from_le = |bytes: &[u8]| {
+            let mut repr = <C::Base as PrimeField>::Repr::default();
+            repr.as_mut().copy_from_slice(bytes);
+            Option::<C::Base>::from(C::Base::from_repr(repr))
+        };
+
+        let mut found = 0;
+        for i in 1..100u64 {
+            // x = r + n
+            let r = C::ScalarExt::from(i);
+            let x = base_from_le(&add_order(&r)).unwrap();
+            let Some(y) = Option::<C::Base>::from(((x.square() + C::a()) * x + C::b()).sqrt())
+            else {
+                continue;
+            };
+            found += 1;
+            let point = C::from_xy(x, y).unwrap();
+            let s = C::ScalarExt::random(OsRng);
+            let sig = Signature::new(r, s).unwrap();
+            let mut msg_hash = [0u8; 32];
+            OsRng.fill_bytes(&mut msg_hash);
+
+            // Q = r^-1 (s R - e G)
+            let e = prehash_to_scalar::<C::ScalarExt>(&msg_hash);
+            let pk = ((point * s - C::generator() * e) * r.invert().unwrap()).to_affine();
+            let recovery_id = RecoveryId::new(bool::from(y.is_odd()), true);
+            assert_eq!(
+                recover_prehash::<C>(&msg_hash, &sig, recovery_id, HighS::Accept),
+                Ok(pk)
+            );
+            assert_eq!(verify_prehash(&pk, &msg_hash, &sig, HighS::Accept), Ok(()));
+            let unreduced = RecoveryId::new(bool::from(y.is_odd()), false);
+            assert_ne!(
+                recover_prehash::<C>(&msg_hash, &sig, unreduced, HighS::Accept),
+                Ok(pk)
+            );
+        }
+        assert!(found > 0);
+
+        // r + n is not below the base field modulus.
+        let sig = Signature::new(-C::ScalarExt::ONE, C::ScalarExt::ONE).unwrap();
+        for is_y_odd in [false, true] {
+            assert_eq!(
+                recover_prehash::<C>(
+                    &[0u8; 32],
+                    &sig,
+                    RecoveryId::new(is_y_odd, true),
+                    HighS::Accept
+                ),
+                Err(EcdsaError::InvalidSignature)
+            );
+        }
+    }
+
+    #[test]
+    fn test_recover_reduced_x() {
+        check_reduced_x::<crate::secp256k1::Secp256k1Affine>();
+        check_reduced_x::<crate::secp256r1::Secp256r1Affine>();
+    }
+
+    #[test]
+    fn test_recovery_id() {
+        for byte in 0..4 {
+            let id = RecoveryId::from_byte(byte).unwrap();
+            assert_eq!(id.to_byte(), byte);
+            assert_eq!(RecoveryId::new(id.is_y_odd(), id.is_x_reduced()), id);
+        }
+        assert_eq!(RecoveryId::from_byte(4), None);
+
+        assert_eq!(RecoveryId::from_ethereum_v(27), RecoveryId::from_byte(0));
+        assert_eq!(RecoveryId::from_ethereum_v(28), RecoveryId::from_byte(1));
+        // EIP-155 with chain id 1.
+        assert_eq!(RecoveryId::from_ethereum_v(37), RecoveryId::from_byte(0));
+        assert_eq!(RecoveryId::from_ethereum_v(38), RecoveryId::from_byte(1));
+        for v in [0, 1, 26, 29, 34] {
+            assert_eq!(RecoveryId::from_ethereum_v(v), None);
+        }
+    }
+
+    #[test]
+    fn test_ecdsa() {
+        check::<crate::secp256k1::Secp256k1Affine>();
+        check::<crate::secp256r1::Secp256r1Affine>();
+    }
+
+    #[test]
+    fn test_verify_known_answers() {
+        use crate::secp256k1::Secp256k1Affine;
+        use crate::secp256r1::{Fq, Secp256r1Affine};
+
+        // RFC 6979, appendix A.2.5: P-256 with SHA-256, the hashes of "sample"
+        // and "test". The first signature has a high `s`.
+        let sk = from_be_slice::<Fq>(
+            &hex::decode("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721")
+                .unwrap(),
+        )
+        .unwrap();
+        let pk = parse_public_key::<Secp256r1Affine>(
+            &hex::decode(
+                "0460fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6\
+                 7903fe1008b8bc99a41ae9e95628bc64f2f1b20c2d7e9f5177a3c294d4462299",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(pk, (Secp256r1Affine::generator() * sk).to_affine());
+        for (msg_hash, signature, is_low_s) in [
+            (
+                "af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf",
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716\
+                 f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+                false,
+            ),
+            (
+                "9f86d081884c7d659a2feaa0c55ad015a3bf4f1b2b0b822cd15d6c15b0f00a08",
+                "f1abb023518351cd71d881567b1ea663ed3efcf6c5132b354f28d3b0b7d38367\
+                 019f4113742a2b14bd25926b49c649155f267e60d3814b4c0cc84250e46f0083",
+                true,
+            ),
+        ] {
+            let msg_hash = hex::decode(msg_hash).unwrap();
+            let signature = Signature::from_bytes(&hex::decode(signature).unwrap()).unwrap();
+            assert_eq!(signature.is_low_s(), is_low_s);
+            assert_eq!(
+                verify_prehash(&pk, &msg_hash, &signature, HighS::Accept),
+                Ok(())
+            );
+            assert_eq!(
+                verify_prehash(&pk, &msg_hash, &signature.normalize_s(), HighS::Reject),
+                Ok(())
+            );
+            let mut other_hash = msg_hash.clone();
+            other_hash[0] ^= 1;
+            assert_eq!(
+                verify_prehash(&pk, &other_hash, &signature, HighS::Accept),
+                Err(EcdsaError::VerificationFailed)
+            );
+        }
+
+        // secp256k1 with the secret key 1, whose public key is the generator,
+        // and the SHA-256 hash of "Satoshi Nakamoto", as signed with RFC 6979
+        // nonces by common Bitcoin libraries.
+        let pk = Secp256k1Affine::generator();
+        let msg_hash =
+            hex::decode("a0dc65ffca799873cbea0ac274015b9526505daaaed385155425f7337704883e")
+                .unwrap();
+        let signature = Signature::from_bytes(
+            &hex::decode(
+                "934b1ea10a4b3c1757e2b0c017d0b6143ce3c9a7e6a4a49860d7a6ab210ee3d8\
+                 2442ce9d2b916064108014783e923ec36b49743e2ffa1c4496f01a512aafd9e5",
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(
+            verify_prehash(&pk, &msg_hash, &signature, HighS::Reject),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn test_recover_known_answer() {
+        use crate::secp256k1::Secp256k1Affine;
+
+        // The `Ecrecover` test vector of go-ethereum: a message hash, the 65-byte
+        // signature `r || s || v` with `v` the recovery id, and the recovered
+        // uncompressed public key.
+        let msg_hash =
+            hex::decode("ce0677bb30baa8cf067c88db9811f4333d131bf8bcf12fe7065d211dce971008")
+                .unwrap();
+        let sig = hex::decode(
+            "90f27b8b488db00b00606796d2987f6a5f59ae62ea05effe84fef5b8b0e54998\
+             4a691139ad57a3f0b906637673aa2f63d1f55cb1a69199d4009eea23ceaddc93\
+             01",
+        )
+        .unwrap();
+        let expected = hex::decode(
+            "04e32df42865e97135acfb65f3bae71bdc86f4d49150ad6a440b6f15878109880a\
+             0a2b2667f7e725ceea70c673093bf67663e0312623c8e091b13cf2c0f11ef652",
+        )
+        .unwrap();
+
+        let signature = Signature::from_bytes(&sig[..64]).unwrap();
+        let recovery_id = RecoveryId::from_byte(sig[64]).unwrap();
+        assert_eq!(Some(recovery_id), RecoveryId::from_ethereum_v(28));
+        let pk =
+            recover_prehash::<Secp256k1Affine>(&msg_hash, &signature, recovery_id, HighS::Reject)
+                .unwrap();
+        assert_eq!(encode_public_key(&pk, false), expected);
+        assert_eq!(
+            verify_prehash(&pk, &msg_hash, &signature, HighS::Reject),
+            Ok(())
+        );
+
+        // The other parity recovers another key.
+        let flipped = RecoveryId::new(false, false);
+        assert_ne!(
+            recover_prehash::<Secp256k1Affine>(&msg_hash, &signature, flipped, HighS::Reject),
+            Ok(pk)
+        );
+    }
+
+    #[test]
+    fn test_prehash_to_scalar() {
+        use crate::secp256k1::Fq;
+
+        // Short hashes are integers as they are, long ones are truncated.
+        assert_eq!(prehash_to_scalar::<Fq>(&[1, 2]), Fq::from(0x0102));
+        let mut long = [0u8; 64];
+        long[31] = 7;
+        long[32] = 0xff;
+        assert_eq!(prehash_to_scalar::<Fq>(&long), Fq::from(7));
+        // The order is below 2^256, so the top value is reduced.
+        assert_eq!(
+            prehash_to_scalar::<Fq>(&[0xff; 32]),
+            Fq::from_str_mod_order(&format!("0x{}", "ff".repeat(32))).unwrap()
+        );
+
+        // A 254-bit order keeps the leftmost 254 bits.
+        let mut bytes = [0u8; 32];
+        bytes[31] = 0b1100;
+        assert_eq!(
+            prehash_to_scalar::<crate::bn256::Fr>(&bytes),
+            crate::bn256::Fr::from(0b11)
+        );
+    }
+}
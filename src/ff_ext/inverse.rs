@@ -1,5 +1,5 @@
 use core::cmp::PartialEq;
-use std::ops::{Add, Mul, Neg, Sub};
+use core::ops::{Add, Mul, Neg, Sub};
 
 /// Big signed (B * L)-bit integer type, whose variables store
 /// numbers in the two's complement code as arrays of B-bit chunks.
@@ -1,5 +1,6 @@
 pub mod inverse;
 pub mod jacobi;
+pub mod radix;
 use subtle::{Choice, ConstantTimeEq};
 
 pub trait Legendre {
@@ -19,6 +20,85 @@ pub trait Legendre {
     }
 }
 
+/// Elementwise multiplication of slices of field elements.
+///
+/// The default implementation multiplies one pair at a time. Fields with a
+/// vectorized multiplication override it to process several independent
+/// products at once, which batch routines such as the normalization of points
+/// take advantage of.
+pub trait BatchMul: ff::Field {
+    /// Sets `lhs[i] *= rhs[i]` for every `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slices have different lengths.
+    fn batch_mul_assign(lhs: &mut [Self], rhs: &[Self]) {
+        assert_eq!(lhs.len(), rhs.len());
+        mul_assign_serial(lhs, rhs)
+    }
+}
+
+/// Multiplies `lhs` by `rhs` one pair at a time, with the loop compiled for
+/// the [`codegen`](crate::cpu::codegen) of the running CPU.
+pub(crate) fn mul_assign_serial<F: ff::Field>(lhs: &mut [F], rhs: &[F]) {
+    match crate::cpu::codegen() {
+        #[cfg(target_arch = "x86_64")]
+        // Safety: `Bmi2Adx` is only selected when the CPU supports both features.
+        crate::cpu::Codegen::Bmi2Adx => unsafe { mul_assign_serial_bmi2_adx(lhs, rhs) },
+        _ => mul_assign_serial_inner(lhs, rhs),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2,adx")]
+unsafe fn mul_assign_serial_bmi2_adx<F: ff::Field>(lhs: &mut [F], rhs: &[F]) {
+    mul_assign_serial_inner(lhs, rhs)
+}
+
+#[inline(always)]
+fn mul_assign_serial_inner<F: ff::Field>(lhs: &mut [F], rhs: &[F]) {
+    for (a, b) in lhs.iter_mut().zip(rhs.iter()) {
+        *a *= b;
+    }
+}
+
+/// Returns the inverse of `x`, if it is not zero, in constant time.
+///
+/// The `invert` methods of the 4-limb fields, such as those of `bn256` and
+/// `secp256k1`, use the Bernstein-Yang algorithm, whose running time depends on
+/// the input. This computes `x^(p - 2)` instead, where `pow_vartime` only
+/// branches on the bits of the public exponent.
+///
+/// `F::Repr` must be the little-endian encoding of the canonical
+/// representative, as it is for the fields of this crate and of
+/// `pasta_curves`, since `p - 2` is read from the representation of `-1`.
+/// `PrimeField` does not guarantee this byte order; debug builds check it.
+pub fn invert_ct<F: ff::PrimeField>(x: &F) -> subtle::CtOption<F> {
+    // p - 2 from the canonical form of -1, which is p - 1.
+    let mut exp = (-F::ONE).to_repr();
+    let mut borrow = 1;
+    for b in exp.as_mut().iter_mut() {
+        let (d, underflow) = b.overflowing_sub(borrow);
+        *b = d;
+        borrow = underflow as u8;
+    }
+    let exp: alloc::vec::Vec<u64> = exp
+        .as_ref()
+        .chunks(8)
+        .map(|c| {
+            let mut limb = [0u8; 8];
+            limb[..c.len()].copy_from_slice(c);
+            u64::from_le_bytes(limb)
+        })
+        .collect();
+    debug_assert_eq!(
+        F::MULTIPLICATIVE_GENERATOR.pow_vartime(&exp) * F::MULTIPLICATIVE_GENERATOR,
+        F::ONE,
+        "invert_ct requires a little-endian `PrimeField::Repr`"
+    );
+    subtle::CtOption::new(x.pow_vartime(exp), !x.is_zero())
+}
+
 #[macro_export]
 macro_rules! extend_field_legendre {
     ($field:ident ) => {
@@ -30,3 +110,30 @@ macro_rules! extend_field_legendre {
         }
     };
 }
+
+#[cfg(test)]
+mod test {
+    use super::invert_ct;
+    use ff::PrimeField;
+    use rand_core::OsRng;
+
+    fn check<F: PrimeField>() {
+        assert!(bool::from(invert_ct(&F::ZERO).is_none()));
+        assert_eq!(invert_ct(&F::ONE).unwrap(), F::ONE);
+        assert_eq!(invert_ct(&-F::ONE).unwrap(), -F::ONE);
+        for _ in 0..100 {
+            let a = F::random(OsRng);
+            assert_eq!(invert_ct(&a).unwrap(), a.invert().unwrap());
+        }
+    }
+
+    #[test]
+    fn test_invert_ct() {
+        check::<crate::bn256::Fr>();
+        check::<crate::bn256::Fq>();
+        check::<crate::secp256k1::Fq>();
+        check::<crate::pluto_eris::Fp>();
+        // A field from outside the crate.
+        check::<pasta_curves::Fp>();
+    }
+}
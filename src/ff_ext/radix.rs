@@ -0,0 +1,199 @@
+//! Decimal and hexadecimal strings of prime field elements.
+//!
+//! The field types implement `Display` and `LowerHex` with [`fmt_decimal`]
+//! and [`fmt_lower_hex`], and `FromStr` with [`parse_canonical`]. The strings
+//! hold the canonical value of the elements, not their Montgomery form.
+//!
+//! [`parse_reduced`], [`reduce_le_bytes`] and [`reduce_be_bytes`] instead accept integers of any
+//! length and reduce them modulo the modulus, while [`from_be_slice`] decodes
+//! a canonical big-endian integer.
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt::{self, Write};
+use ff::PrimeField;
+
+use crate::serde::FieldDecodingError;
+
+/// Error returned when parsing a field element from a string.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParseFieldError {
+    /// The string has no digits.
+    Empty,
+    /// The string has a character that is not a digit of its radix.
+    InvalidDigit,
+    /// The string encodes an integer that is not smaller than the field modulus.
+    NotCanonical,
+}
+
+impl fmt::Display for ParseFieldError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse field element from empty string"),
+            Self::InvalidDigit => write!(f, "invalid digit found in string"),
+            Self::NotCanonical => write!(f, "input number is not less than field modulus"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseFieldError {}
+
+// The canonical value of `x` as little-endian limbs.
+fn to_limbs<F: PrimeField>(x: &F) -> Vec<u64> {
+    x.to_repr()
+        .as_ref()
+        .chunks(8)
+        .map(|c| {
+            let mut bytes = [0u8; 8];
+            bytes[..c.len()].copy_from_slice(c);
+            u64::from_le_bytes(bytes)
+        })
+        .collect()
+}
+
+/// Writes the canonical value of `x` in decimal, honoring the width, fill and
+/// sign flags of the formatter.
+pub fn fmt_decimal<F: PrimeField>(x: &F, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    // Chunks of 19 digits, the most a limb holds, by repeated division.
+    const CHUNK: u128 = 10_000_000_000_000_000_000;
+    let mut n = to_limbs(x);
+    let mut chunks = Vec::new();
+    while n.iter().any(|&l| l != 0) {
+        let mut rem = 0u128;
+        for l in n.iter_mut().rev() {
+            let d = (rem << 64) | *l as u128;
+            *l = (d / CHUNK) as u64;
+            rem = d % CHUNK;
+        }
+        chunks.push(rem as u64);
+    }
+
+    let mut digits = String::new();
+    match chunks.split_last() {
+        None => digits.push('0'),
+        Some((top, rest)) => {
+            write!(digits, "{top}")?;
+            for chunk in rest.iter().rev() {
+                write!(digits, "{chunk:019}")?;
+            }
+        }
+    }
+    f.pad_integral(true, "", &digits)
+}
+
+/// Writes the canonical value of `x` in lowercase hexadecimal without leading
+/// zeros, prefixed with `0x` under the alternate flag `{:#x}`.
+pub fn fmt_lower_hex<F: PrimeField>(x: &F, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    let mut digits = String::new();
+    for b in x.to_repr().as_ref().iter().rev() {
+        write!(digits, "{b:02x}")?;
+    }
+    let digits = digits.trim_start_matches('0');
+    f.pad_integral(true, "0x", if digits.is_empty() { "0" } else { digits })
+}
+
+// Parses a decimal, or `0x`-prefixed hexadecimal, integer of any length into
+// its little-endian limbs.
+fn parse_limbs(s: &str) -> Result<Vec<u64>, ParseFieldError> {
+    let (digits, radix) = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => (hex, 16),
+        None => (s, 10),
+    };
+    if digits.is_empty() {
+        return Err(ParseFieldError::Empty);
+    }
+
+    let mut n = Vec::new();
+    for c in digits.chars() {
+        // n = n * radix + digit
+        let mut carry = c.to_digit(radix).ok_or(ParseFieldError::InvalidDigit)? as u128;
+        for l in n.iter_mut() {
+            let t = *l as u128 * radix as u128 + carry;
+            *l = t as u64;
+            carry = t >> 64;
+        }
+        if carry != 0 {
+            n.push(carry as u64);
+        }
+    }
+    Ok(n)
+}
+
+/// Parses a decimal, or `0x`-prefixed hexadecimal, integer into a field
+/// element, failing if it is not smaller than the modulus.
+///
+/// Leading zeros are accepted. This is the `FromStr` implementation of the
+/// field types.
+pub fn parse_canonical<F: PrimeField>(s: &str) -> Result<F, ParseFieldError> {
+    let mut repr = F::Repr::default();
+    let bytes = repr.as_mut();
+    let len = bytes.len();
+    for (i, b) in parse_limbs(s)?
+        .iter()
+        .flat_map(|l| l.to_le_bytes())
+        .enumerate()
+    {
+        if i < len {
+            bytes[i] = b;
+        } else if b != 0 {
+            return Err(ParseFieldError::NotCanonical);
+        }
+    }
+    Option::from(F::from_repr(repr)).ok_or(ParseFieldError::NotCanonical)
+}
+
+// Returns the integer with the given little-endian limbs modulo the modulus.
+fn reduce_limbs<F: PrimeField>(limbs: impl DoubleEndedIterator<Item = u64>) -> F {
+    let two_64 = F::from(u64::MAX) + F::ONE;
+    limbs
+        .rev()
+        .fold(F::ZERO, |acc, l| acc * two_64 + F::from(l))
+}
+
+/// Parses a decimal, or `0x`-prefixed hexadecimal, integer of any length into
+/// a field element, reducing it modulo the modulus.
+///
+/// This function runs in variable time.
+pub fn parse_reduced<F: PrimeField>(s: &str) -> Result<F, ParseFieldError> {
+    Ok(reduce_limbs(parse_limbs(s)?.into_iter()))
+}
+
+/// Reduces the little-endian integer `bytes`, of any length, modulo the
+/// modulus.
+///
+/// The running time depends on the length of the input only.
+pub fn reduce_le_bytes<F: PrimeField>(bytes: &[u8]) -> F {
+    reduce_limbs(bytes.chunks(8).map(|c| {
+        let mut limb = [0u8; 8];
+        limb[..c.len()].copy_from_slice(c);
+        u64::from_le_bytes(limb)
+    }))
+}
+
+/// Decodes the big-endian integer `bytes` into a field element. Inputs shorter
+/// than the representation are zero-extended. Fails if the input is longer
+/// than the representation or is not below the modulus.
+///
+/// This is the decoder behind the `from_be_slice` constructors of the fields.
+pub fn from_be_slice<F: PrimeField>(bytes: &[u8]) -> Result<F, FieldDecodingError> {
+    let mut repr = F::Repr::default();
+    if bytes.len() > repr.as_ref().len() {
+        return Err(FieldDecodingError::InvalidLength);
+    }
+    for (dst, src) in repr.as_mut().iter_mut().zip(bytes.iter().rev()) {
+        *dst = *src;
+    }
+    Option::from(F::from_repr(repr)).ok_or(FieldDecodingError::NotCanonical)
+}
+
+/// Reduces the big-endian integer `bytes`, of any length, modulo the modulus.
+///
+/// The running time depends on the length of the input only.
+pub fn reduce_be_bytes<F: PrimeField>(bytes: &[u8]) -> F {
+    reduce_limbs(bytes.rchunks(8).map(|c| {
+        let mut limb = [0u8; 8];
+        limb[8 - c.len()..].copy_from_slice(c);
+        u64::from_be_bytes(limb)
+    }))
+}
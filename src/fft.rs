@@ -1,6 +1,9 @@
+use crate::cpu::Codegen;
 pub use crate::{CurveAffine, CurveExt};
+use alloc::vec;
+use alloc::vec::Vec;
 use ff::Field;
-use group::{GroupOpsOwned, ScalarMulOwned};
+use group::{Curve, GroupOpsOwned, ScalarMulOwned};
 
 /// This represents an element of a group with basic operations that can be
 /// performed. This allows an FFT implementation (for example) to operate
@@ -37,7 +40,7 @@ pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar,
         r
     }
 
-    let threads = rayon::current_num_threads();
+    let threads = crate::multicore::current_num_threads();
     let log_threads = threads.ilog2();
     let n = a.len();
     assert_eq!(n, 1 << log_n);
@@ -88,16 +91,86 @@ pub fn best_fft<Scalar: Field, G: FftGroup<Scalar>>(a: &mut [G], omega: Scalar,
             twiddle_chunk /= 2;
         }
     } else {
-        recursive_butterfly_arithmetic(a, n, 1, &twiddles)
+        recursive_butterfly_arithmetic_with(crate::cpu::codegen(), a, n, 1, &twiddles)
     }
 }
 
+/// Converts the points `tau^i G` of a structured reference string in the
+/// monomial basis, for `i` from $0$ to $n - 1$ with $n = 2^k$, to the points
+/// `L_i(tau) G` of its Lagrange basis over the domain of the powers of
+/// `omega`, an element of multiplicative order $n$, where `L_i` is the Lagrange
+/// polynomial that is one at $\omega^i$ and zero at the other powers.
+///
+/// This is an inverse FFT over the group: $L_i(\tau) = \frac{1}{n} \sum_j
+/// \tau^j \omega^{-ij}$, so committing to the evaluations of a polynomial with
+/// the returned points gives the same commitment as committing to its
+/// coefficients with the monomial ones.
+///
+/// This will use multithreading if beneficial.
+pub fn srs_to_lagrange<C: CurveAffine>(monomial: &[C], omega: C::Scalar, log_n: u32) -> Vec<C> {
+    let mut points: Vec<C::Curve> = monomial.iter().map(|p| p.to_curve()).collect();
+    // `omega` has order n, so its inverse is one of its powers.
+    let omega_inv = omega.pow_vartime([(monomial.len() - 1) as u64]);
+    best_fft(&mut points, omega_inv, log_n);
+
+    let n_inv = C::Scalar::from(monomial.len() as u64).invert().unwrap();
+    {
+        use crate::multicore::*;
+        points.par_iter_mut().for_each(|p| *p *= n_inv);
+    }
+    let mut lagrange = vec![C::identity(); points.len()];
+    C::Curve::batch_normalize(&points, &mut lagrange);
+    lagrange
+}
+
 /// This perform recursive butterfly arithmetic
 pub fn recursive_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
     a: &mut [G],
     n: usize,
     twiddle_chunk: usize,
     twiddles: &[Scalar],
+) {
+    recursive_butterfly_arithmetic_with(crate::cpu::codegen(), a, n, twiddle_chunk, twiddles)
+}
+
+// `recursive_butterfly_arithmetic` compiled for `codegen`, which the recursion
+// passes down rather than resolving it at every step.
+fn recursive_butterfly_arithmetic_with<Scalar: Field, G: FftGroup<Scalar>>(
+    codegen: Codegen,
+    a: &mut [G],
+    n: usize,
+    twiddle_chunk: usize,
+    twiddles: &[Scalar],
+) {
+    match codegen {
+        #[cfg(target_arch = "x86_64")]
+        // Safety: `Bmi2Adx` is only selected when the CPU supports both features.
+        Codegen::Bmi2Adx => unsafe {
+            recursive_butterfly_arithmetic_bmi2_adx(codegen, a, n, twiddle_chunk, twiddles)
+        },
+        _ => recursive_butterfly_arithmetic_inner(codegen, a, n, twiddle_chunk, twiddles),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2,adx")]
+unsafe fn recursive_butterfly_arithmetic_bmi2_adx<Scalar: Field, G: FftGroup<Scalar>>(
+    codegen: Codegen,
+    a: &mut [G],
+    n: usize,
+    twiddle_chunk: usize,
+    twiddles: &[Scalar],
+) {
+    recursive_butterfly_arithmetic_inner(codegen, a, n, twiddle_chunk, twiddles)
+}
+
+#[inline(always)]
+fn recursive_butterfly_arithmetic_inner<Scalar: Field, G: FftGroup<Scalar>>(
+    codegen: Codegen,
+    a: &mut [G],
+    n: usize,
+    twiddle_chunk: usize,
+    twiddles: &[Scalar],
 ) {
     if n == 2 {
         let t = a[1];
@@ -106,9 +179,25 @@ pub fn recursive_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
         a[1] -= &t;
     } else {
         let (left, right) = a.split_at_mut(n / 2);
-        rayon::join(
-            || recursive_butterfly_arithmetic(left, n / 2, twiddle_chunk * 2, twiddles),
-            || recursive_butterfly_arithmetic(right, n / 2, twiddle_chunk * 2, twiddles),
+        crate::multicore::join(
+            || {
+                recursive_butterfly_arithmetic_with(
+                    codegen,
+                    left,
+                    n / 2,
+                    twiddle_chunk * 2,
+                    twiddles,
+                )
+            },
+            || {
+                recursive_butterfly_arithmetic_with(
+                    codegen,
+                    right,
+                    n / 2,
+                    twiddle_chunk * 2,
+                    twiddles,
+                )
+            },
         );
 
         // case when twiddle factor is one
@@ -131,3 +220,41 @@ pub fn recursive_butterfly_arithmetic<Scalar: Field, G: FftGroup<Scalar>>(
             });
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{Fr, G1Affine, G1};
+    use ff::PrimeField;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_srs_to_lagrange() {
+        for log_n in 1..6 {
+            let n = 1u64 << log_n;
+            let omega = Fr::ROOT_OF_UNITY.pow_vartime([1 << (Fr::S - log_n)]);
+            let tau = Fr::random(OsRng);
+            let monomial: Vec<G1Affine> = (0..n)
+                .map(|i| (G1::generator() * tau.pow_vartime([i])).to_affine())
+                .collect();
+            let lagrange = srs_to_lagrange(&monomial, omega, log_n);
+
+            // L_i(tau) = omega^i (tau^n - 1) / (n (tau - omega^i))
+            let vanishing = tau.pow_vartime([n]) - Fr::ONE;
+            for (i, point) in lagrange.iter().enumerate() {
+                let omega_i = omega.pow_vartime([i as u64]);
+                let l_i = omega_i * vanishing * (Fr::from(n) * (tau - omega_i)).invert().unwrap();
+                assert_eq!(*point, (G1::generator() * l_i).to_affine());
+            }
+
+            // A polynomial committed to from its coefficients or evaluations.
+            let coeffs: Vec<Fr> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let mut evals = coeffs.clone();
+            best_fft(&mut evals, omega, log_n);
+            assert_eq!(
+                crate::msm::best_multiexp(&coeffs, &monomial),
+                crate::msm::best_multiexp(&evals, &lagrange)
+            );
+        }
+    }
+}
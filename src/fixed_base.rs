@@ -0,0 +1,78 @@
+//! Precomputed tables for fixed-base scalar multiplication.
+//!
+//! The scalar is split into 4-bit windows and, for every window, all 16
+//! multiples of the appropriately shifted base are stored in affine form.
+//! A multiplication then costs one constant-time table lookup and one mixed
+//! addition per window, with no doublings.
+
+use crate::secret::SecretScalar;
+use crate::CurveAffine;
+use alloc::vec;
+use alloc::vec::Vec;
+use ff::PrimeField;
+use group::{Curve, Group};
+use subtle::ConstantTimeEq;
+
+const WINDOW_SIZE: usize = 4;
+const WINDOW_ENTRIES: usize = 1 << WINDOW_SIZE;
+
+/// Table of multiples of a fixed base point.
+#[derive(Clone, Debug)]
+pub struct FixedBaseTable<C: CurveAffine> {
+    // Entry `i * WINDOW_ENTRIES + j` holds `j * 2^(WINDOW_SIZE * i) * base`.
+    table: Vec<C>,
+}
+
+impl<C: CurveAffine> FixedBaseTable<C> {
+    /// Precomputes the table of multiples of `base`.
+    pub fn new(base: C) -> Self {
+        let num_windows = Self::num_windows();
+
+        let mut table = vec![C::Curve::identity(); num_windows * WINDOW_ENTRIES];
+        let mut window_base = base.to_curve();
+        for window in table.chunks_mut(WINDOW_ENTRIES) {
+            for j in 1..WINDOW_ENTRIES {
+                window[j] = window[j - 1] + window_base;
+            }
+            for _ in 0..WINDOW_SIZE {
+                window_base = window_base.double();
+            }
+        }
+
+        let mut table_affine = vec![C::identity(); table.len()];
+        C::Curve::batch_normalize(&table, &mut table_affine);
+
+        Self {
+            table: table_affine,
+        }
+    }
+
+    /// Multiplies the base point by `scalar`.
+    pub fn mul(&self, scalar: &C::Scalar) -> C::Curve {
+        let repr = scalar.to_repr();
+        let repr = repr.as_ref();
+
+        let mut acc = C::Curve::identity();
+        for (i, window) in self.table.chunks(WINDOW_ENTRIES).enumerate() {
+            let digit = (repr[i / 2] >> ((i % 2) * WINDOW_SIZE)) & (WINDOW_ENTRIES as u8 - 1);
+
+            // Scan the whole window so the lookup does not depend on the digit.
+            let mut point = C::identity();
+            for (j, entry) in window.iter().enumerate() {
+                point.conditional_assign(entry, (j as u8).ct_eq(&digit));
+            }
+            acc += point;
+        }
+        acc
+    }
+
+    /// Multiplies the base point by a secret scalar.
+    pub fn mul_secret(&self, scalar: &SecretScalar<C::Scalar>) -> C::Curve {
+        self.mul(scalar.expose_secret())
+    }
+
+    fn num_windows() -> usize {
+        let repr = <C::Scalar as PrimeField>::Repr::default();
+        repr.as_ref().len() * 8 / WINDOW_SIZE
+    }
+}
@@ -0,0 +1,1472 @@
+//! A prime field generic over its number of limbs and its modulus.
+//!
+//! [`Fp`] implements the Montgomery arithmetic once for any number of 64-bit
+//! limbs, so that a new field only has to describe its modulus through
+//! [`FieldParams`]. Every other constant required by [`ff::PrimeField`] is
+//! derived from it at compile time. The scalar field of secp256r1,
+//! [`secp256r1::Fq`](crate::secp256r1::Fq), is defined this way.
+//!
+//! A field whose parameters set [`FieldParams::BARRETT`] stores its elements
+//! in canonical form instead and reduces multiplications with Barrett
+//! reduction, which makes the conversions from and to canonical limbs free,
+//! for uses that convert more often than they multiply. The derived constants
+//! follow the representation, and the modulus must then have a non-zero top
+//! limb.
+//!
+//! ```
+//! use halo2curves::ff::{Field, PrimeField};
+//! use halo2curves::fp::{FieldParams, Fp};
+//!
+//! /// The scalar field of BN254.
+//! #[derive(Clone, Copy, Debug)]
+//! pub struct FrParams;
+//!
+//! impl FieldParams<4> for FrParams {
+//!     type Repr = [u8; 32];
+//!     const MODULUS: [u64; 4] = [
+//!         0x43e1f593f0000001,
+//!         0x2833e84879b97091,
+//!         0xb85045b68181585d,
+//!         0x30644e72e131a029,
+//!     ];
+//!     const MODULUS_STR: &'static str =
+//!         "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+//!     const MULTIPLICATIVE_GENERATOR: u64 = 7;
+//! }
+//!
+//! pub type Fr = Fp<4, FrParams>;
+//!
+//! let two = Fr::from(2);
+//! assert_eq!(two * Fr::TWO_INV, Fr::ONE);
+//! assert_eq!(Fr::S, 28);
+//! ```
+
+use core::fmt;
+use core::hash::{Hash, Hasher};
+use core::iter::{Product, Sum};
+use core::marker::PhantomData;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+use crate::arithmetic::{adc, mac, sbb};
+use crate::ff_ext::{BatchMul, Legendre};
+use crate::hash_to_curve::ExtensionField;
+use crate::serde::arkworks::{ArkworksField, ArkworksSerde, Compress};
+
+/// Describes the prime field of an [`Fp`].
+pub trait FieldParams<const LIMBS: usize>: 'static + Copy + Send + Sync {
+    /// Canonical little-endian byte representation of an element, of
+    /// `8 * LIMBS` bytes.
+    type Repr: Copy + Default + Send + Sync + 'static + AsRef<[u8]> + AsMut<[u8]>;
+
+    /// The modulus as little-endian limbs. It must be an odd prime.
+    const MODULUS: [u64; LIMBS];
+
+    /// The modulus as a `0x`-prefixed big-endian hexadecimal string.
+    const MODULUS_STR: &'static str;
+
+    /// A generator of the multiplicative group of the field.
+    const MULTIPLICATIVE_GENERATOR: u64;
+
+    /// Whether elements are stored in canonical form and reduced with Barrett
+    /// reduction, instead of in Montgomery form. Barrett reduction requires
+    /// the top limb of the modulus to be non-zero.
+    const BARRETT: bool = false;
+}
+
+/// An element of the prime field described by `P`, stored in Montgomery form
+/// as `LIMBS` little-endian 64-bit limbs, i.e. `Fp(a) = aR mod p` with
+/// `R = 2^(64 * LIMBS)`, or in canonical form `Fp(a) = a` if
+/// [`FieldParams::BARRETT`] is set.
+#[repr(transparent)]
+pub struct Fp<const LIMBS: usize, P: FieldParams<LIMBS>>(pub(crate) [u64; LIMBS], PhantomData<P>);
+
+/// Returns `-m^-1 mod 2^64` for an odd `m`.
+const fn neg_inv(m: u64) -> u64 {
+    // Each Newton step doubles the number of correct low bits.
+    let mut inv = 1u64;
+    let mut i = 0;
+    while i < 6 {
+        inv = inv.wrapping_mul(2u64.wrapping_sub(m.wrapping_mul(inv)));
+        i += 1;
+    }
+    inv.wrapping_neg()
+}
+
+/// Returns `2^exp mod m`.
+const fn pow2_mod<const LIMBS: usize>(m: &[u64; LIMBS], exp: usize) -> [u64; LIMBS] {
+    let mut r = [0u64; LIMBS];
+    r[0] = 1;
+    let mut i = 0;
+    while i < exp {
+        let mut carry = 0;
+        let mut j = 0;
+        while j < LIMBS {
+            let top = r[j] >> 63;
+            r[j] = (r[j] << 1) | carry;
+            carry = top;
+            j += 1;
+        }
+        r = sub_if_geq(r, carry, m);
+        i += 1;
+    }
+    r
+}
+
+/// Returns `a + hi * 2^(64 * LIMBS) - m` if it is not negative, `a`
+/// otherwise.
+#[inline(always)]
+const fn sub_if_geq<const LIMBS: usize>(
+    a: [u64; LIMBS],
+    hi: u64,
+    m: &[u64; LIMBS],
+) -> [u64; LIMBS] {
+    let mut d = [0u64; LIMBS];
+    let mut borrow = 0;
+    let mut j = 0;
+    while j < LIMBS {
+        (d[j], borrow) = sbb(a[j], m[j], borrow);
+        j += 1;
+    }
+    let (_, borrow) = sbb(hi, 0, borrow);
+
+    // If the subtraction underflowed, borrow = 0xfff...fff, and we add the
+    // modulus back.
+    let mut carry = 0;
+    let mut j = 0;
+    while j < LIMBS {
+        (d[j], carry) = adc(d[j], m[j] & borrow, carry);
+        j += 1;
+    }
+    d
+}
+
+/// Returns the quotient and the remainder of the division of `a` by `d`.
+const fn div_rem_small<const LIMBS: usize>(a: &[u64; LIMBS], d: u64) -> ([u64; LIMBS], u64) {
+    let mut q = [0u64; LIMBS];
+    let mut r = 0u128;
+    let mut j = LIMBS;
+    while j > 0 {
+        j -= 1;
+        let n = (r << 64) | a[j] as u128;
+        q[j] = (n / d as u128) as u64;
+        r = n % d as u128;
+    }
+    (q, r as u64)
+}
+
+/// Returns `a + hi * 2^(64 * LIMBS) - m`, as its low limbs and its top limb,
+/// if it is not negative, `a + hi * 2^(64 * LIMBS)` otherwise.
+#[inline(always)]
+const fn sub_if_geq_wide<const LIMBS: usize>(
+    a: ([u64; LIMBS], u64),
+    m: &[u64; LIMBS],
+) -> ([u64; LIMBS], u64) {
+    let mut d = [0u64; LIMBS];
+    let mut borrow = 0;
+    let mut j = 0;
+    while j < LIMBS {
+        (d[j], borrow) = sbb(a.0[j], m[j], borrow);
+        j += 1;
+    }
+    let (hi, borrow) = sbb(a.1, 0, borrow);
+
+    // Keep `a` if the subtraction underflowed.
+    let mut j = 0;
+    while j < LIMBS {
+        d[j] = (a.0[j] & borrow) | (d[j] & !borrow);
+        j += 1;
+    }
+    (d, (a.1 & borrow) | (hi & !borrow))
+}
+
+/// Returns the `LIMBS + 1` limbs of `a * b` starting from limb `from`, where
+/// `a` and `b` have `LIMBS + 1` limbs, given as their low limbs and their top
+/// limb.
+#[inline(always)]
+const fn mul_wide<const LIMBS: usize>(
+    a: &([u64; LIMBS], u64),
+    b: &([u64; LIMBS], u64),
+    from: usize,
+) -> ([u64; LIMBS], u64) {
+    const fn limb<const LIMBS: usize>(a: &([u64; LIMBS], u64), i: usize) -> u64 {
+        if i < LIMBS {
+            a.0[i]
+        } else {
+            a.1
+        }
+    }
+
+    // Product scanning, one column at a time, with a three limb accumulator.
+    let mut r = ([0u64; LIMBS], 0);
+    let mut acc = 0u128;
+    let mut c = 0;
+    while c <= from + LIMBS {
+        let mut top = 0;
+        let mut i = c.saturating_sub(LIMBS);
+        while i <= c && i <= LIMBS {
+            let overflow;
+            (acc, overflow) = acc.overflowing_add(limb(a, i) as u128 * limb(b, c - i) as u128);
+            top += overflow as u64;
+            i += 1;
+        }
+        if c >= from && c - from < LIMBS {
+            r.0[c - from] = acc as u64;
+        } else if c >= from {
+            r.1 = acc as u64;
+        }
+        acc = (acc >> 64) | ((top as u128) << 64);
+        c += 1;
+    }
+    r
+}
+
+/// Returns `a >> shift` for `shift < 64 * LIMBS`.
+const fn shr<const LIMBS: usize>(a: &[u64; LIMBS], shift: u32) -> [u64; LIMBS] {
+    let words = (shift / 64) as usize;
+    let bits = shift % 64;
+    let mut r = [0u64; LIMBS];
+    let mut j = 0;
+    while j + words < LIMBS {
+        r[j] = a[j + words] >> bits;
+        if bits > 0 && j + words + 1 < LIMBS {
+            r[j] |= a[j + words + 1] << (64 - bits);
+        }
+        j += 1;
+    }
+    r
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Fp<LIMBS, P> {
+    /// `-p^-1 mod 2^64`
+    const INV: u64 = neg_inv(P::MODULUS[0]);
+
+    /// The exponent of the factor `2^MONTGOMERY_BITS` of the representation.
+    const MONTGOMERY_BITS: usize = if P::BARRETT { 0 } else { 64 * LIMBS };
+
+    /// One, i.e. `R = 2^(64 * LIMBS) mod p` in Montgomery form.
+    const R: Self = Self(pow2_mod(&P::MODULUS, Self::MONTGOMERY_BITS), PhantomData);
+
+    /// `2^(64 * LIMBS)`, i.e. `R^2 mod p` in Montgomery form.
+    const R2: Self = Self(
+        pow2_mod(&P::MODULUS, 64 * LIMBS + Self::MONTGOMERY_BITS),
+        PhantomData,
+    );
+
+    /// `floor(2^(128 * LIMBS) / p)`, as its low limbs and its top limb, or
+    /// zero in Montgomery form.
+    const MU: ([u64; LIMBS], u64) = if !P::BARRETT {
+        ([0; LIMBS], 0)
+    } else {
+        assert!(
+            P::MODULUS[LIMBS - 1] != 0,
+            "Barrett reduction requires the top limb of the modulus to be non-zero"
+        );
+        // Long division, one bit at a time. The quotient is below
+        // 2^(64 * LIMBS + 64) as the modulus is at least 2^(64 * LIMBS - 64).
+        let mut q = ([0u64; LIMBS], 0);
+        let mut r = [0u64; LIMBS];
+        let mut i = 128 * LIMBS + 1;
+        while i > 0 {
+            i -= 1;
+            let mut carry = (i == 128 * LIMBS) as u64;
+            let mut j = 0;
+            while j < LIMBS {
+                let top = r[j] >> 63;
+                r[j] = (r[j] << 1) | carry;
+                carry = top;
+                j += 1;
+            }
+            let mut d = [0u64; LIMBS];
+            let mut borrow = 0;
+            let mut j = 0;
+            while j < LIMBS {
+                (d[j], borrow) = sbb(r[j], P::MODULUS[j], borrow);
+                j += 1;
+            }
+            if carry == 1 || borrow == 0 {
+                r = d;
+                if i < 64 * LIMBS {
+                    q.0[i / 64] |= 1 << (i % 64);
+                } else {
+                    q.1 |= 1 << (i - 64 * LIMBS);
+                }
+            }
+        }
+        q
+    };
+
+    /// The largest `S` such that `2^S` divides `p - 1`.
+    const TWO_ADICITY: u32 = {
+        // The modulus is odd, so p - 1 only differs from it in the lowest bit.
+        let mut j = 0;
+        let mut limb = P::MODULUS[0] - 1;
+        while limb == 0 {
+            j += 1;
+            limb = P::MODULUS[j];
+        }
+        64 * j as u32 + limb.trailing_zeros()
+    };
+
+    /// `t = (p - 1) / 2^S`
+    const T: [u64; LIMBS] = shr(&P::MODULUS, Self::TWO_ADICITY);
+
+    /// `(t - 1) / 2`, as `t` is odd.
+    const T_MINUS_1_OVER_2: [u64; LIMBS] = shr(&Self::T, 1);
+
+    /// `p - 2`
+    const P_MINUS_2: [u64; LIMBS] = {
+        let mut e = P::MODULUS;
+        // The modulus is odd, so this does not borrow.
+        e[0] -= 2;
+        e
+    };
+
+    /// The multiplicative generator `g`.
+    const GENERATOR: Self = Self::from_raw({
+        let mut g = [0u64; LIMBS];
+        g[0] = P::MULTIPLICATIVE_GENERATOR;
+        g
+    });
+
+    /// `2^-1 = (p + 1) / 2`
+    const HALF: Self = Self::from_raw({
+        // The modulus is odd, so (p + 1) / 2 = p / 2 + 1 without overflow.
+        let mut h = shr(&P::MODULUS, 1);
+        let mut carry = 1;
+        let mut j = 0;
+        while j < LIMBS {
+            (h[j], carry) = adc(h[j], 0, carry);
+            j += 1;
+        }
+        h
+    });
+
+    /// `g^t`, a primitive `2^S`-th root of unity.
+    const ROOT: Self = Self::GENERATOR.pow_const(&Self::T);
+
+    /// `(g^t)^-1 = (g^t)^(2^S - 1)`
+    const ROOT_INV: Self = {
+        let mut acc = Self::R;
+        let mut power = Self::ROOT;
+        let mut i = 0;
+        while i < Self::TWO_ADICITY {
+            acc = Self::mul(&acc, &power);
+            power = power.square();
+            i += 1;
+        }
+        acc
+    };
+
+    /// `g^(2^S)`
+    const GENERATOR_POW_2_S: Self = {
+        let mut acc = Self::GENERATOR;
+        let mut i = 0;
+        while i < Self::TWO_ADICITY {
+            acc = acc.square();
+            i += 1;
+        }
+        acc
+    };
+
+    /// `(p - 1) / 2`, the exponent of Euler's criterion.
+    const P_MINUS_1_OVER_2: [u64; LIMBS] = shr(&P::MODULUS, 1);
+
+    /// `g^((p - 1) / 3)`, a primitive cube root of unity.
+    ///
+    /// Using it fails to compile when `3` does not divide `p - 1`, as the
+    /// field then has no element of order `3`.
+    const CUBE_ROOT: Self = {
+        let mut e = P::MODULUS;
+        e[0] -= 1;
+        match div_rem_small(&e, 3) {
+            (e, 0) => Self::GENERATOR.pow_const(&e),
+            _ => panic!("3 does not divide p - 1, so the field has no cube root of unity"),
+        }
+    };
+
+    /// The number of bits of the modulus.
+    const BITS: u32 = {
+        let mut j = LIMBS - 1;
+        while P::MODULUS[j] == 0 {
+            j -= 1;
+        }
+        64 * j as u32 + 64 - P::MODULUS[j].leading_zeros()
+    };
+
+    /// Returns zero, the additive identity.
+    #[inline]
+    pub const fn zero() -> Self {
+        Self([0; LIMBS], PhantomData)
+    }
+
+    /// Returns one, the multiplicative identity.
+    #[inline]
+    pub const fn one() -> Self {
+        Self::R
+    }
+
+    /// Converts from an integer represented in little endian
+    /// into its (congruent) field representation.
+    pub const fn from_raw(val: [u64; LIMBS]) -> Self {
+        if P::BARRETT {
+            Self::reduce_wide(&val, &[0; LIMBS])
+        } else {
+            // (val * R^2) / R = val * R, as val * R^2 < R * p.
+            Self::mul(&Self(val, PhantomData), &Self::R2)
+        }
+    }
+
+    /// Returns the canonical little-endian limbs of `self`.
+    pub const fn to_raw(&self) -> [u64; LIMBS] {
+        if P::BARRETT {
+            self.0
+        } else {
+            // (a.R) * 1 / R = a
+            let mut one = [0u64; LIMBS];
+            one[0] = 1;
+            Self::mul(self, &Self(one, PhantomData)).0
+        }
+    }
+
+    /// Returns the size in bytes of the canonical representation.
+    pub const fn size() -> usize {
+        8 * LIMBS
+    }
+
+    /// Attempts to convert a little-endian byte representation of an element,
+    /// failing if `bytes` is not `8 * LIMBS` bytes long or not canonical.
+    pub fn from_bytes(bytes: &[u8]) -> CtOption<Self> {
+        let mut repr = P::Repr::default();
+        if bytes.len() != repr.as_ref().len() {
+            return CtOption::new(Self::zero(), Choice::from(0));
+        }
+        repr.as_mut().copy_from_slice(bytes);
+        Self::from_repr(repr)
+    }
+
+    /// Converts an element into its little-endian byte representation.
+    pub fn to_bytes(&self) -> alloc::vec::Vec<u8> {
+        self.to_repr().as_ref().to_vec()
+    }
+
+    /// Attempts to convert a big-endian byte representation of an element,
+    /// failing if `bytes` is not `8 * LIMBS` bytes long or not canonical.
+    pub fn from_bytes_be(bytes: &[u8]) -> CtOption<Self> {
+        let mut le = bytes.to_vec();
+        le.reverse();
+        Self::from_bytes(&le)
+    }
+
+    /// Converts an element into its big-endian byte representation.
+    pub fn to_bytes_be(&self) -> alloc::vec::Vec<u8> {
+        let mut res = self.to_bytes();
+        res.reverse();
+        res
+    }
+
+    /// Attempts to convert a big-endian byte slice into an element, see
+    /// [`from_be_slice`](crate::ff_ext::radix::from_be_slice).
+    pub fn from_be_slice(bytes: &[u8]) -> Result<Self, crate::serde::FieldDecodingError> {
+        crate::ff_ext::radix::from_be_slice(bytes)
+    }
+
+    /// Reduces the little-endian integer `limbs`, of any length, modulo the
+    /// modulus.
+    pub fn from_le_limbs(limbs: &[u64]) -> Self {
+        // Horner's rule in base R = 2^(64 * LIMBS) over chunks of `LIMBS`
+        // limbs, starting from the most significant chunk.
+        let mut acc = Self::zero();
+        for chunk in limbs.chunks(LIMBS).rev() {
+            let mut digit = [0u64; LIMBS];
+            digit[..chunk.len()].copy_from_slice(chunk);
+            acc = acc * Self::R2 + Self::from_raw(digit);
+        }
+        acc
+    }
+
+    /// Reduces a little-endian integer of any length modulo the modulus.
+    pub fn from_bytes_mod_order(bytes: &[u8]) -> Self {
+        crate::ff_ext::radix::reduce_le_bytes(bytes)
+    }
+
+    /// Reduces a big-endian integer of any length modulo the modulus.
+    pub fn from_be_bytes_mod_order(bytes: &[u8]) -> Self {
+        crate::ff_ext::radix::reduce_be_bytes(bytes)
+    }
+
+    /// Parses a decimal, or `0x`-prefixed hexadecimal, integer of any length
+    /// and reduces it modulo the modulus. `FromStr` instead rejects integers
+    /// that are not below the modulus.
+    pub fn from_str_mod_order(s: &str) -> Result<Self, crate::ff_ext::radix::ParseFieldError> {
+        crate::ff_ext::radix::parse_reduced(s)
+    }
+
+    /// Returns the size of the largest radix-2 evaluation domain of the
+    /// field, `2^S`.
+    pub const fn max_fft_size() -> u64 {
+        1 << Self::TWO_ADICITY
+    }
+
+    /// Returns whether the field has a radix-2 evaluation domain of size
+    /// `n`.
+    pub const fn supports_domain(n: u64) -> bool {
+        n.is_power_of_two() && n <= Self::max_fft_size()
+    }
+
+    /// Returns the size of the smallest radix-2 evaluation domain holding
+    /// at least `n` elements, if the field has one.
+    pub const fn smallest_domain_at_least(n: u64) -> Option<u64> {
+        match n.checked_next_power_of_two() {
+            Some(size) if size <= Self::max_fft_size() => Some(size),
+            _ => None,
+        }
+    }
+
+    /// Decomposes `self` into base-`base` digits in `(-base/2, base/2]`,
+    /// least significant first, such that `sum(d_i * base^i) == self`
+    /// over the integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `base` is not in `[2, i64::MAX]`.
+    pub fn to_balanced_digits(&self, base: u64) -> alloc::vec::Vec<i64> {
+        crate::arithmetic::balanced_digits(&self.to_bytes(), base)
+    }
+
+    /// Returns the Jacobi symbol of `self` over the modulus, which is its
+    /// Legendre symbol as the modulus is prime.
+    pub fn jacobi(&self) -> i64 {
+        self.legendre()
+    }
+
+    /// Adds `rhs` to `self`, returning the result.
+    #[inline]
+    pub const fn add(&self, rhs: &Self) -> Self {
+        let mut d = [0u64; LIMBS];
+        let mut carry = 0;
+        let mut j = 0;
+        while j < LIMBS {
+            (d[j], carry) = adc(self.0[j], rhs.0[j], carry);
+            j += 1;
+        }
+        Self(sub_if_geq(d, carry, &P::MODULUS), PhantomData)
+    }
+
+    /// Doubles this field element.
+    #[inline]
+    pub const fn double(&self) -> Self {
+        self.add(self)
+    }
+
+    /// Subtracts `rhs` from `self`, returning the result.
+    #[inline]
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        let mut d = [0u64; LIMBS];
+        let mut borrow = 0;
+        let mut j = 0;
+        while j < LIMBS {
+            (d[j], borrow) = sbb(self.0[j], rhs.0[j], borrow);
+            j += 1;
+        }
+
+        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
+        let mut carry = 0;
+        let mut j = 0;
+        while j < LIMBS {
+            (d[j], carry) = adc(d[j], P::MODULUS[j] & borrow, carry);
+            j += 1;
+        }
+        Self(d, PhantomData)
+    }
+
+    /// Negates `self`.
+    #[inline]
+    pub const fn neg(&self) -> Self {
+        // Subtract `self` from `MODULUS` to negate. Ignore the final
+        // borrow because it cannot underflow; self is guaranteed to
+        // be in the field.
+        let mut d = [0u64; LIMBS];
+        let mut borrow = 0;
+        let mut nonzero = 0;
+        let mut j = 0;
+        while j < LIMBS {
+            (d[j], borrow) = sbb(P::MODULUS[j], self.0[j], borrow);
+            nonzero |= self.0[j];
+            j += 1;
+        }
+
+        // `d` could be `MODULUS` if `self` was zero. Create a mask that is
+        // zero if `self` was zero, and `u64::MAX` if self was nonzero.
+        let mask = ((nonzero == 0) as u64).wrapping_sub(1);
+        let mut j = 0;
+        while j < LIMBS {
+            d[j] &= mask;
+            j += 1;
+        }
+        Self(d, PhantomData)
+    }
+
+    /// Multiplies `rhs` by `self`, returning the result.
+    #[inline]
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        if P::BARRETT {
+            self.mul_barrett(rhs)
+        } else {
+            self.mul_montgomery(rhs)
+        }
+    }
+
+    /// Multiplies in Montgomery form.
+    #[inline(always)]
+    const fn mul_montgomery(&self, rhs: &Self) -> Self {
+        // Coarsely Integrated Operand Scanning (CIOS), keeping the carry of
+        // the top limb so that moduli using all bits of the last limb work.
+        // The accumulator stays below 2 * MODULUS between rows.
+        let mut t = [0u64; LIMBS];
+        let mut t_hi = 0;
+        let mut i = 0;
+        while i < LIMBS {
+            let mut c = 0;
+            let mut j = 0;
+            while j < LIMBS {
+                (t[j], c) = mac(t[j], self.0[j], rhs.0[i], c);
+                j += 1;
+            }
+            let (top, top_hi) = adc(t_hi, c, 0);
+
+            let k = t[0].wrapping_mul(Self::INV);
+            let (_, mut c) = mac(t[0], k, P::MODULUS[0], 0);
+            let mut j = 1;
+            while j < LIMBS {
+                (t[j - 1], c) = mac(t[j], k, P::MODULUS[j], c);
+                j += 1;
+            }
+            let (top, carry) = adc(top, c, 0);
+            t[LIMBS - 1] = top;
+            t_hi = top_hi + carry;
+            i += 1;
+        }
+        Self(sub_if_geq(t, t_hi, &P::MODULUS), PhantomData)
+    }
+
+    /// Multiplies in canonical form, with Barrett reduction.
+    #[inline(always)]
+    const fn mul_barrett(&self, rhs: &Self) -> Self {
+        // The product as its low and its high `LIMBS` limbs.
+        let mut t = [[0u64; LIMBS]; 2];
+        let mut i = 0;
+        while i < LIMBS {
+            let mut c = 0;
+            let mut j = 0;
+            while j < LIMBS {
+                let k = i + j;
+                (t[k / LIMBS][k % LIMBS], c) = mac(t[k / LIMBS][k % LIMBS], self.0[j], rhs.0[i], c);
+                j += 1;
+            }
+            t[1][i] = c;
+            i += 1;
+        }
+        Self::reduce_wide(&t[0], &t[1])
+    }
+
+    /// Returns `lo + hi * 2^(64 * LIMBS) mod p` for any `lo` and `hi`, by
+    /// Barrett reduction as in Algorithm 14.42 of the Handbook of Applied
+    /// Cryptography.
+    #[inline]
+    const fn reduce_wide(lo: &[u64; LIMBS], hi: &[u64; LIMBS]) -> Self {
+        // q = floor(floor(x / 2^(64 * (LIMBS - 1))) * mu / 2^(64 * (LIMBS + 1)))
+        // is at most two below floor(x / p).
+        let mut q = ([0u64; LIMBS], hi[LIMBS - 1]);
+        q.0[0] = lo[LIMBS - 1];
+        let mut j = 1;
+        while j < LIMBS {
+            q.0[j] = hi[j - 1];
+            j += 1;
+        }
+        let q = mul_wide(&q, &Self::MU, LIMBS + 1);
+        let qp = mul_wide(&q, &(P::MODULUS, 0), 0);
+
+        // r = x - q * p < 3p, computed modulo 2^(64 * (LIMBS + 1)).
+        let mut r = ([0u64; LIMBS], 0);
+        let mut borrow = 0;
+        let mut j = 0;
+        while j < LIMBS {
+            (r.0[j], borrow) = sbb(lo[j], qp.0[j], borrow);
+            j += 1;
+        }
+        (r.1, _) = sbb(hi[0], qp.1, borrow);
+
+        let r = sub_if_geq_wide(r, &P::MODULUS);
+        Self(sub_if_geq(r.0, r.1, &P::MODULUS), PhantomData)
+    }
+
+    /// Squares this element.
+    #[inline]
+    pub const fn square(&self) -> Self {
+        self.mul(self)
+    }
+
+    /// Exponentiates `self` by the little-endian `exp`, in variable time with
+    /// respect to the exponent.
+    const fn pow_const(&self, exp: &[u64; LIMBS]) -> Self {
+        let mut acc = Self::R;
+        let mut i = 64 * LIMBS;
+        while i > 0 {
+            i -= 1;
+            acc = acc.square();
+            if (exp[i / 64] >> (i % 64)) & 1 == 1 {
+                acc = Self::mul(&acc, self);
+            }
+        }
+        acc
+    }
+
+    /// Returns the multiplicative inverse of the
+    /// element. If it is zero, the method fails.
+    pub fn invert(&self) -> CtOption<Self> {
+        // The exponent is public, so a variable time exponentiation is fine.
+        let inv = self.pow_vartime(Self::P_MINUS_2);
+        CtOption::new(inv, !self.is_zero())
+    }
+
+    /// Checks that the representation of `self` is fully reduced.
+    pub const fn debug_validate(&self) -> bool {
+        Self::is_less_than(&self.0, &P::MODULUS)
+    }
+
+    /// Lexicographic comparison of representations.
+    #[inline(always)]
+    const fn is_less_than(x: &[u64; LIMBS], y: &[u64; LIMBS]) -> bool {
+        let mut borrow = 0;
+        let mut j = 0;
+        while j < LIMBS {
+            (_, borrow) = sbb(x[j], y[j], borrow);
+            j += 1;
+        }
+        borrow >> 63 == 1
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Clone for Fp<LIMBS, P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Copy for Fp<LIMBS, P> {}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> fmt::Debug for Fp<LIMBS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let tmp = self.to_repr();
+        write!(f, "0x")?;
+        for &b in tmp.as_ref().iter().rev() {
+            write!(f, "{:02x}", b)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> fmt::Display for Fp<LIMBS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::ff_ext::radix::fmt_decimal(self, f)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> fmt::LowerHex for Fp<LIMBS, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        crate::ff_ext::radix::fmt_lower_hex(self, f)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::str::FromStr for Fp<LIMBS, P> {
+    type Err = crate::ff_ext::radix::ParseFieldError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        crate::ff_ext::radix::parse_canonical(s)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> zeroize::DefaultIsZeroes for Fp<LIMBS, P> {}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Default for Fp<LIMBS, P> {
+    #[inline]
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+// Equality compares every limb, so that it runs in constant time like `ct_eq`
+// instead of stopping at the first difference.
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> PartialEq for Fp<LIMBS, P> {
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Eq for Fp<LIMBS, P> {}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Hash for Fp<LIMBS, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::cmp::Ord for Fp<LIMBS, P> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.to_raw().iter().rev().cmp(other.to_raw().iter().rev())
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> core::cmp::PartialOrd for Fp<LIMBS, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> From<u64> for Fp<LIMBS, P> {
+    fn from(val: u64) -> Self {
+        let mut limbs = [0u64; LIMBS];
+        limbs[0] = val;
+        Self::from_raw(limbs)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> From<u128> for Fp<LIMBS, P> {
+    fn from(val: u128) -> Self {
+        Self::from_le_limbs(&[val as u64, (val >> 64) as u64])
+    }
+}
+
+// Keeps `Fp::from(2)` compiling, as integer literals default to `i32` once
+// several integer conversions apply.
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> From<i32> for Fp<LIMBS, P> {
+    fn from(val: i32) -> Self {
+        Self::from(val as i64)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> From<i64> for Fp<LIMBS, P> {
+    fn from(val: i64) -> Self {
+        let abs = Self::from(val.unsigned_abs());
+        Self::conditional_select(&abs, &-abs, Choice::from((val < 0) as u8))
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> From<i128> for Fp<LIMBS, P> {
+    fn from(val: i128) -> Self {
+        let abs = Self::from(val.unsigned_abs());
+        Self::conditional_select(&abs, &-abs, Choice::from((val < 0) as u8))
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> From<[u64; LIMBS]> for Fp<LIMBS, P> {
+    fn from(digits: [u64; LIMBS]) -> Self {
+        Self::from_raw(digits)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> From<bool> for Fp<LIMBS, P> {
+    fn from(bit: bool) -> Self {
+        Self::conditional_select(&Self::zero(), &Self::one(), Choice::from(bit as u8))
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> ConstantTimeEq for Fp<LIMBS, P> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .fold(Choice::from(1), |acc, (a, b)| acc & a.ct_eq(b))
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> ConditionallySelectable for Fp<LIMBS, P> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(
+            core::array::from_fn(|j| u64::conditional_select(&a.0[j], &b.0[j], choice)),
+            PhantomData,
+        )
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Neg for Fp<LIMBS, P> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Neg for &Fp<LIMBS, P> {
+    type Output = Fp<LIMBS, P>;
+
+    #[inline]
+    fn neg(self) -> Fp<LIMBS, P> {
+        crate::debug_validate!(self);
+        self.neg()
+    }
+}
+
+/// Implements a binary operator and its assigning variant for every
+/// combination of owned and borrowed operands.
+macro_rules! impl_binop {
+    ($trait:ident, $method:ident, $op:tt, $assign_trait:ident, $assign_method:ident) => {
+        impl<'a, 'b, const LIMBS: usize, P: FieldParams<LIMBS>> $trait<&'b Fp<LIMBS, P>>
+            for &'a Fp<LIMBS, P>
+        {
+            type Output = Fp<LIMBS, P>;
+
+            #[inline]
+            fn $method(self, rhs: &'b Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+                crate::debug_validate!(self, rhs);
+                Fp::$method(self, rhs)
+            }
+        }
+
+        impl<'b, const LIMBS: usize, P: FieldParams<LIMBS>> $trait<&'b Fp<LIMBS, P>>
+            for Fp<LIMBS, P>
+        {
+            type Output = Fp<LIMBS, P>;
+
+            #[inline]
+            fn $method(self, rhs: &'b Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+                &self $op rhs
+            }
+        }
+
+        impl<'a, const LIMBS: usize, P: FieldParams<LIMBS>> $trait<Fp<LIMBS, P>>
+            for &'a Fp<LIMBS, P>
+        {
+            type Output = Fp<LIMBS, P>;
+
+            #[inline]
+            fn $method(self, rhs: Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+                self $op &rhs
+            }
+        }
+
+        impl<const LIMBS: usize, P: FieldParams<LIMBS>> $trait<Fp<LIMBS, P>> for Fp<LIMBS, P> {
+            type Output = Fp<LIMBS, P>;
+
+            #[inline]
+            fn $method(self, rhs: Fp<LIMBS, P>) -> Fp<LIMBS, P> {
+                &self $op &rhs
+            }
+        }
+
+        impl<'b, const LIMBS: usize, P: FieldParams<LIMBS>> $assign_trait<&'b Fp<LIMBS, P>>
+            for Fp<LIMBS, P>
+        {
+            #[inline]
+            fn $assign_method(&mut self, rhs: &'b Fp<LIMBS, P>) {
+                *self = &*self $op rhs;
+            }
+        }
+
+        impl<const LIMBS: usize, P: FieldParams<LIMBS>> $assign_trait<Fp<LIMBS, P>>
+            for Fp<LIMBS, P>
+        {
+            #[inline]
+            fn $assign_method(&mut self, rhs: Fp<LIMBS, P>) {
+                *self = &*self $op &rhs;
+            }
+        }
+    };
+}
+
+impl_binop!(Add, add, +, AddAssign, add_assign);
+impl_binop!(Sub, sub, -, SubAssign, sub_assign);
+impl_binop!(Mul, mul, *, MulAssign, mul_assign);
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>, T: core::borrow::Borrow<Fp<LIMBS, P>>> Sum<T>
+    for Fp<LIMBS, P>
+{
+    fn sum<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, |acc, item| acc + item.borrow())
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>, T: core::borrow::Borrow<Fp<LIMBS, P>>> Product<T>
+    for Fp<LIMBS, P>
+{
+    fn product<I: Iterator<Item = T>>(iter: I) -> Self {
+        iter.fold(Self::ONE, |acc, item| acc * item.borrow())
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Field for Fp<LIMBS, P> {
+    const ZERO: Self = Self::zero();
+    const ONE: Self = Self::one();
+
+    fn random(mut rng: impl RngCore) -> Self {
+        // Reducing twice as many bits as the modulus makes the bias negligible.
+        let mut limbs = [[0u64; LIMBS]; 2];
+        for limb in limbs.iter_mut().flatten() {
+            *limb = rng.next_u64();
+        }
+        Self::from_raw(limbs[0]) + Self::from_raw(limbs[1]) * Self::R2
+    }
+
+    fn double(&self) -> Self {
+        self.double()
+    }
+
+    #[inline(always)]
+    fn square(&self) -> Self {
+        self.square()
+    }
+
+    fn invert(&self) -> CtOption<Self> {
+        self.invert()
+    }
+
+    fn sqrt(&self) -> CtOption<Self> {
+        ff::helpers::sqrt_tonelli_shanks(self, Self::T_MINUS_1_OVER_2)
+    }
+
+    fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+        ff::helpers::sqrt_ratio_generic(num, div)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> WithSmallOrderMulGroup<3> for Fp<LIMBS, P> {
+    /// A primitive cube root of unity. Using it fails to compile when `3`
+    /// does not divide `p - 1`.
+    const ZETA: Self = Self::CUBE_ROOT;
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> Legendre for Fp<LIMBS, P> {
+    fn legendre(&self) -> i64 {
+        // Euler's criterion.
+        let s = self.pow(Self::P_MINUS_1_OVER_2);
+        let is_one = s.ct_eq(&Self::ONE);
+        let is_zero = s.ct_eq(&Self::ZERO);
+        i64::conditional_select(&i64::conditional_select(&-1, &0, is_zero), &1, is_one)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> BatchMul for Fp<LIMBS, P> {}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> rand::distributions::Distribution<Fp<LIMBS, P>>
+    for rand::distributions::Standard
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Fp<LIMBS, P> {
+        Fp::random(rng)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> PrimeField for Fp<LIMBS, P> {
+    type Repr = P::Repr;
+
+    const MODULUS: &'static str = P::MODULUS_STR;
+    const NUM_BITS: u32 = Self::BITS;
+    const CAPACITY: u32 = Self::BITS - 1;
+    const TWO_INV: Self = Self::HALF;
+    const MULTIPLICATIVE_GENERATOR: Self = Self::GENERATOR;
+    const S: u32 = Self::TWO_ADICITY;
+    const ROOT_OF_UNITY: Self = Self::ROOT;
+    const ROOT_OF_UNITY_INV: Self = Self::ROOT_INV;
+    const DELTA: Self = Self::GENERATOR_POW_2_S;
+
+    fn from_repr(repr: Self::Repr) -> CtOption<Self> {
+        let bytes = repr.as_ref();
+        debug_assert_eq!(bytes.len(), 8 * LIMBS);
+        let limbs: [u64; LIMBS] = core::array::from_fn(|j| {
+            u64::from_le_bytes(bytes[8 * j..8 * j + 8].try_into().unwrap())
+        });
+
+        // Try to subtract the modulus: the final borrow, all ones, is set if
+        // and only if the representation is canonical. It becomes a `Choice`
+        // without going through a `bool` that the compiler may branch on.
+        let mut borrow = 0;
+        for (l, m) in limbs.iter().zip(P::MODULUS.iter()) {
+            (_, borrow) = sbb(*l, *m, borrow);
+        }
+        let is_some = (borrow as u8) & 1;
+        // Canonical limbs are the representation itself in Barrett form.
+        let elt = if P::BARRETT {
+            Self(limbs, PhantomData)
+        } else {
+            Self::from_raw(limbs)
+        };
+        CtOption::new(elt, Choice::from(is_some))
+    }
+
+    fn to_repr(&self) -> Self::Repr {
+        let mut repr = Self::Repr::default();
+        for (bytes, limb) in repr.as_mut().chunks_mut(8).zip(self.to_raw()) {
+            bytes.copy_from_slice(&limb.to_le_bytes());
+        }
+        repr
+    }
+
+    fn is_odd(&self) -> Choice {
+        Choice::from((self.to_raw()[0] & 1) as u8)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> FromUniformBytes<64> for Fp<LIMBS, P> {
+    /// Converts a 512-bit little endian integer into
+    /// an element by reducing by the modulus.
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        let limbs: [u64; 8] = core::array::from_fn(|j| {
+            u64::from_le_bytes(bytes[8 * j..8 * j + 8].try_into().unwrap())
+        });
+        Self::from_le_limbs(&limbs)
+    }
+}
+
+#[cfg(feature = "bits")]
+#[cfg_attr(docsrs, doc(cfg(feature = "bits")))]
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> ff::PrimeFieldBits for Fp<LIMBS, P> {
+    type ReprBits = [u64; LIMBS];
+
+    fn to_le_bits(&self) -> ff::FieldBits<Self::ReprBits> {
+        ff::FieldBits::new(self.to_raw())
+    }
+
+    fn char_le_bits() -> ff::FieldBits<Self::ReprBits> {
+        ff::FieldBits::new(P::MODULUS)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> ArkworksField for Fp<LIMBS, P> {
+    type Prime = Self;
+
+    const DEGREE: usize = 1;
+
+    fn write_coefficients(&self, out: &mut alloc::vec::Vec<Self>) {
+        out.push(*self);
+    }
+
+    fn from_coefficients(coeffs: &[Self]) -> Self {
+        coeffs[0]
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> ExtensionField for Fp<LIMBS, P> {
+    type Prime = Self;
+
+    const DEGREE: usize = 1;
+
+    fn from_coefficients(coeffs: &[Self]) -> Self {
+        coeffs[0]
+    }
+
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> ArkworksSerde for Fp<LIMBS, P> {
+    fn to_arkworks_bytes(&self, _: Compress) -> alloc::vec::Vec<u8> {
+        crate::serde::arkworks::field_to_bytes(self)
+    }
+
+    fn from_arkworks_bytes(bytes: &[u8], _: Compress) -> Option<Self> {
+        crate::serde::arkworks::field_from_bytes(bytes)
+    }
+}
+
+impl<const LIMBS: usize, P: FieldParams<LIMBS>> crate::serde::SerdeObject for Fp<LIMBS, P> {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 8 * LIMBS);
+        Self(
+            core::array::from_fn(|j| {
+                u64::from_le_bytes(bytes[8 * j..8 * j + 8].try_into().unwrap())
+            }),
+            PhantomData,
+        )
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 8 * LIMBS {
+            return None;
+        }
+        let elt = Self::from_raw_bytes_unchecked(bytes);
+        Self::is_less_than(&elt.0, &P::MODULUS).then_some(elt)
+    }
+    fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+        self.0.iter().flat_map(|limb| limb.to_le_bytes()).collect()
+    }
+    #[cfg(feature = "std")]
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        let inner = [(); LIMBS].map(|_| {
+            let mut buf = [0; 8];
+            reader.read_exact(&mut buf).unwrap();
+            u64::from_le_bytes(buf)
+        });
+        Self(inner, PhantomData)
+    }
+    #[cfg(feature = "std")]
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut inner = [0u64; LIMBS];
+        for limb in inner.iter_mut() {
+            let mut buf = [0; 8];
+            reader.read_exact(&mut buf)?;
+            *limb = u64::from_le_bytes(buf);
+        }
+        Self::is_less_than(&inner, &P::MODULUS)
+            .then_some(Self(inner, PhantomData))
+            .ok_or_else(|| {
+                std::io::Error::new(
+                    std::io::ErrorKind::InvalidData,
+                    "input number is not less than field modulus",
+                )
+            })
+    }
+    #[cfg(feature = "std")]
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        for limb in self.0.iter() {
+            writer.write_all(&limb.to_le_bytes())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{FieldParams, Fp};
+    use crate::serde::SerdeObject;
+    use core::marker::PhantomData;
+    use ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+    use rand_core::{RngCore, SeedableRng};
+    use rand_xorshift::XorShiftRng;
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct Bn256FrParams;
+
+    impl FieldParams<4> for Bn256FrParams {
+        type Repr = [u8; 32];
+        const MODULUS: [u64; 4] = [
+            0x43e1f593f0000001,
+            0x2833e84879b97091,
+            0xb85045b68181585d,
+            0x30644e72e131a029,
+        ];
+        const MODULUS_STR: &'static str =
+            "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+        const MULTIPLICATIVE_GENERATOR: u64 = 7;
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct Secp256k1FpParams;
+
+    impl FieldParams<4> for Secp256k1FpParams {
+        type Repr = [u8; 32];
+        const MODULUS: [u64; 4] = [
+            0xfffffffefffffc2f,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ];
+        const MODULUS_STR: &'static str =
+            "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+        const MULTIPLICATIVE_GENERATOR: u64 = 3;
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct Secp256k1FqParams;
+
+    impl FieldParams<4> for Secp256k1FqParams {
+        type Repr = [u8; 32];
+        const MODULUS: [u64; 4] = [
+            0xbfd25e8cd0364141,
+            0xbaaedce6af48a03b,
+            0xfffffffffffffffe,
+            0xffffffffffffffff,
+        ];
+        const MODULUS_STR: &'static str =
+            "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+        const MULTIPLICATIVE_GENERATOR: u64 = 7;
+    }
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct PlutoFpParams;
+
+    impl FieldParams<7> for PlutoFpParams {
+        type Repr = crate::pluto_eris::FpRepr;
+        const MODULUS: [u64; 7] = [
+            0x9ffffcd300000001,
+            0xa2a7e8c30006b945,
+            0xe4a7a5fe8fadffd6,
+            0x443f9a5cda8a6c7b,
+            0xa803ca76f439266f,
+            0x0130e0000d7f70e4,
+            0x2400000000002400,
+        ];
+        const MODULUS_STR: &'static str = "0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000001";
+        const MULTIPLICATIVE_GENERATOR: u64 = 10;
+    }
+
+    /// The parameters `P` with Barrett reduction.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Barrett<P>(PhantomData<P>);
+
+    impl<const LIMBS: usize, P: FieldParams<LIMBS>> FieldParams<LIMBS> for Barrett<P> {
+        type Repr = P::Repr;
+        const MODULUS: [u64; LIMBS] = P::MODULUS;
+        const MODULUS_STR: &'static str = P::MODULUS_STR;
+        const MULTIPLICATIVE_GENERATOR: u64 = P::MULTIPLICATIVE_GENERATOR;
+        const BARRETT: bool = true;
+    }
+
+    /// Checks the derived constants and the arithmetic of `G` against the
+    /// field `F` implemented by the macros.
+    fn check<G, F>()
+    where
+        G: PrimeField + FromUniformBytes<64> + WithSmallOrderMulGroup<3> + SerdeObject,
+        F: PrimeField<Repr = G::Repr> + FromUniformBytes<64>,
+    {
+        fn to<G: PrimeField, F: PrimeField<Repr = G::Repr>>(g: G) -> F {
+            F::from_repr(g.to_repr()).unwrap()
+        }
+
+        assert_eq!(G::MODULUS, F::MODULUS);
+        assert_eq!(G::NUM_BITS, F::NUM_BITS);
+        assert_eq!(G::CAPACITY, F::CAPACITY);
+        assert_eq!(G::S, F::S);
+        assert_eq!(to::<G, F>(G::ONE), F::ONE);
+        assert_eq!(to::<G, F>(G::TWO_INV), F::TWO_INV);
+        assert_eq!(
+            to::<G, F>(G::MULTIPLICATIVE_GENERATOR),
+            F::MULTIPLICATIVE_GENERATOR
+        );
+        assert_eq!(to::<G, F>(G::ROOT_OF_UNITY), F::ROOT_OF_UNITY);
+        assert_eq!(to::<G, F>(G::ROOT_OF_UNITY_INV), F::ROOT_OF_UNITY_INV);
+        assert_eq!(to::<G, F>(G::DELTA), F::DELTA);
+        assert_eq!(to::<G, F>(-G::ONE), -F::ONE);
+        assert_ne!(G::ZETA, G::ONE);
+        assert_eq!(G::ZETA.cube(), G::ONE);
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for _ in 0..10000 {
+            let (a, b) = (G::random(&mut rng), G::random(&mut rng));
+            let (fa, fb) = (to::<G, F>(a), to::<G, F>(b));
+            assert_eq!(to::<G, F>(a + b), fa + fb);
+            assert_eq!(to::<G, F>(a - b), fa - fb);
+            assert_eq!(to::<G, F>(a * b), fa * fb);
+            assert_eq!(to::<G, F>(a.square()), fa.square());
+            assert_eq!(to::<G, F>(a.invert().unwrap()), fa.invert().unwrap());
+            assert_eq!(
+                bool::from(a.sqrt().is_some()),
+                bool::from(fa.sqrt().is_some())
+            );
+            assert_eq!(a.sqrt().map(|s| s.square()).unwrap_or(a), a);
+
+            let mut bytes = [0u8; 64];
+            rng.fill_bytes(&mut bytes);
+            assert_eq!(
+                to::<G, F>(G::from_uniform_bytes(&bytes)),
+                F::from_uniform_bytes(&bytes)
+            );
+
+            assert_eq!(G::from_raw_bytes(&a.to_raw_bytes()), Some(a));
+        }
+        assert!(bool::from(G::ZERO.invert().is_none()));
+    }
+
+    type PlutoFp = Fp<7, PlutoFpParams>;
+
+    mod pluto_fp {
+        use super::*;
+        crate::field_testing_suite!(PlutoFp, "field_arithmetic");
+        crate::field_testing_suite!(PlutoFp, "conversion");
+        crate::field_testing_suite!(PlutoFp, "str");
+        crate::field_testing_suite!(PlutoFp, "constants", PlutoFpParams::MODULUS_STR);
+    }
+
+    #[test]
+    fn test_generic_fp() {
+        check::<Fp<4, Bn256FrParams>, crate::bn256::Fr>();
+        check::<Fp<4, Secp256k1FpParams>, crate::secp256k1::Fp>();
+        check::<Fp<7, PlutoFpParams>, crate::pluto_eris::Fp>();
+    }
+
+    #[test]
+    fn test_barrett_fp() {
+        check::<Fp<4, Barrett<Bn256FrParams>>, crate::bn256::Fr>();
+        check::<Fp<4, Barrett<Secp256k1FpParams>>, crate::secp256k1::Fp>();
+        check::<Fp<7, Barrett<PlutoFpParams>>, crate::pluto_eris::Fp>();
+
+        // The representation is the canonical one in Barrett form.
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for _ in 0..100 {
+            let a = Fp::<4, Barrett<Bn256FrParams>>::random(&mut rng);
+            assert_eq!(a.to_raw_bytes(), a.to_repr().as_ref());
+        }
+    }
+
+    type K1Fp = Fp<4, Secp256k1FpParams>;
+    type K1Fq = Fp<4, Secp256k1FqParams>;
+
+    crate::define_curve!(
+        mod k1 {
+            curve: K1,
+            affine: K1Affine,
+            base: K1Fp,
+            scalar: K1Fq,
+            generator: (
+                K1Fp::from_raw([
+                    0x59F2815B16F81798,
+                    0x029BFCDB2DCE28D9,
+                    0x55A06295CE870B07,
+                    0x79BE667EF9DCBBAC,
+                ]),
+                K1Fp::from_raw([
+                    0x9C47D08FFB10D4B8,
+                    0xFD17B448A6855419,
+                    0x5DA4FBFC0E1108A8,
+                    0x483ADA7726A3C465,
+                ])
+            ),
+            a: K1Fp::zero(),
+            b: K1Fp::from_raw([7, 0, 0, 0]),
+            cofactor: 1,
+            curve_id: "k1",
+            hash_to_curve: |curve_id, domain_prefix| {
+                crate::hash_to_curve::svdw_hash_to_curve(curve_id, domain_prefix, K1Fp::ONE)
+            },
+        }
+    );
+
+    mod k1_curve {
+        use super::k1::K1;
+        use group::{Curve, Group, UncompressedEncoding};
+        crate::curve_testing_suite!(K1);
+    }
+
+    #[test]
+    fn test_define_curve() {
+        use crate::secp256k1::{Fq, Secp256k1};
+        use group::Curve;
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        for _ in 0..100 {
+            let k = K1Fq::random(&mut rng);
+            let p = (k1::K1::generator() * k).to_affine();
+            let q = (Secp256k1::generator() * Fq::from_repr(k.to_repr()).unwrap()).to_affine();
+            assert_eq!(p.x.to_repr(), q.x.to_repr());
+            assert_eq!(p.y.to_repr(), q.y.to_repr());
+            assert_eq!(
+                group::GroupEncoding::to_bytes(&p).as_ref(),
+                group::GroupEncoding::to_bytes(&q).as_ref()
+            );
+        }
+    }
+}
@@ -0,0 +1,65 @@
+//! Constants of the `grumpkin` fields and curves.
+//!
+//! Generated by `script/constants.py` from `script/params/grumpkin.toml`, do not
+//! modify. Building with the `generate-constants` feature regenerates it.
+
+use crate::arithmetic::EndoParameters;
+use crate::grumpkin::Fq;
+
+pub(crate) const G1_GENERATOR_X: Fq = Fq::from_raw([
+    0x0000000000000001,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const G1_GENERATOR_Y: Fq = Fq::from_raw([
+    0x833fc48d823f272c,
+    0x2d270d45f1181294,
+    0xcf135e7506a45d63,
+    0x0000000000000002,
+]);
+
+pub(crate) const G1_A: Fq = Fq::from_raw([
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const G1_B: Fq = Fq::from_raw([
+    0x43e1f593effffff0,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+]);
+
+/// `gamma_i = floor(b_i * 2^256 / r)` for the short basis `b_1`, `b_2` of the
+/// lattice of the decompositions of zero, with
+/// `lambda = 0x30644e72e131a0295e6dd9e7e0acccb0c28f069fbb966e3de4bd44e5607cfd48`.
+pub(crate) const ENDO_PARAMS_GRUMPKIN: EndoParameters = EndoParameters {
+    gamma1: [
+        0xd91d232ec7e0b3d2,
+        0x0000000000000002,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    gamma2: [
+        0x5398fd0300ff655f,
+        0x4ccef014a773d2d2,
+        0x0000000000000002,
+        0x0000000000000000,
+    ],
+    b1: [
+        0x89d3256894d213e2,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+    b2: [
+        0x0be4e1541221250b,
+        0x6f4d8248eeb859fd,
+        0x0000000000000000,
+        0x0000000000000000,
+    ],
+};
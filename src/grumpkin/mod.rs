@@ -1,3 +1,4 @@
+mod constants;
 mod curve;
 
 pub use crate::bn256::{Fq as Fr, Fr as Fq};
@@ -1,15 +1,576 @@
 #![allow(clippy::op_ref)]
 
-use ff::{Field, FromUniformBytes, PrimeField};
+use alloc::boxed::Box;
+use alloc::vec;
+use alloc::vec::Vec;
+use digest::{crypto_common::BlockSizeUser, Digest};
+use ff::{BatchInvert, Field, FromUniformBytes, PrimeField};
+use group::cofactor::CofactorGroup;
 use pasta_curves::arithmetic::CurveExt;
+#[cfg(feature = "sha2")]
+use sha2::Sha256;
 use static_assertions::const_assert;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use crate::{
-    ff_ext::Legendre,
+    ff_ext::{radix::reduce_be_bytes, Legendre},
     secp256k1::{iso_map_secp256k1, IsoSecp256k1, Secp256k1},
 };
 
+/// Domain prefix used by [`derive_generator`].
+pub const GENERATOR_DOMAIN_PREFIX: &str = "halo2curves-generator";
+
+/// Derives the `index`-th independent generator for `label` by hashing
+/// `label || index_le` to the curve under [`GENERATOR_DOMAIN_PREFIX`].
+///
+/// The output only depends on the curve, the label and the index, so it is
+/// stable across versions. Nobody knows the discrete logarithm of a derived
+/// generator with respect to any other point.
+pub fn derive_generator<C: CurveExt>(label: &[u8], index: u64) -> C {
+    let mut message = Vec::with_capacity(label.len() + 8);
+    message.extend_from_slice(label);
+    message.extend_from_slice(&index.to_le_bytes());
+    C::hash_to_curve(GENERATOR_DOMAIN_PREFIX)(&message)
+}
+
+/// Derives the generators of indices `0` to `n - 1` for `label`, see
+/// [`derive_generator`], in parallel, and returns them in affine form.
+pub fn derive_generators<C: CurveExt>(label: &[u8], n: usize) -> Vec<C::AffineExt> {
+    derive_generator_range::<C>(label, 0, n)
+}
+
+// Derives the generators of indices `start` to `end - 1` for `label`.
+fn derive_generator_range<C: CurveExt>(
+    label: &[u8],
+    start: usize,
+    end: usize,
+) -> Vec<C::AffineExt> {
+    use crate::multicore::*;
+    use group::prime::PrimeCurveAffine;
+
+    let indices: Vec<u64> = (start as u64..end as u64).collect();
+    let threads = current_num_threads();
+    let chunk = ((indices.len() + threads - 1) / threads).max(1);
+    let points: Vec<Vec<C>> = indices
+        .par_chunks(chunk)
+        .map(|indices| {
+            let hasher = C::hash_to_curve(GENERATOR_DOMAIN_PREFIX);
+            let mut message = Vec::with_capacity(label.len() + 8);
+            indices
+                .iter()
+                .map(|index| {
+                    message.clear();
+                    message.extend_from_slice(label);
+                    message.extend_from_slice(&index.to_le_bytes());
+                    hasher(&message)
+                })
+                .collect()
+        })
+        .collect();
+    let points = points.concat();
+    let mut affine = vec![C::AffineExt::identity(); points.len()];
+    C::batch_normalize(&points, &mut affine);
+    affine
+}
+
+/// The generators derived from a label with [`derive_generator`], which are
+/// computed on first use and kept for the later ones.
+#[derive(Clone, Debug)]
+pub struct GeneratorCache<C: CurveExt> {
+    label: Vec<u8>,
+    generators: Vec<C::AffineExt>,
+}
+
+impl<C: CurveExt> GeneratorCache<C> {
+    /// Returns an empty cache of the generators of `label`.
+    pub fn new(label: &[u8]) -> Self {
+        Self {
+            label: label.to_vec(),
+            generators: Vec::new(),
+        }
+    }
+
+    /// Returns the label of the generators.
+    pub fn label(&self) -> &[u8] {
+        &self.label
+    }
+
+    /// Returns the generators of indices `0` to `n - 1`, deriving the ones
+    /// that are not cached yet in parallel.
+    pub fn get(&mut self, n: usize) -> &[C::AffineExt] {
+        if n > self.generators.len() {
+            let new = derive_generator_range::<C>(&self.label, self.generators.len(), n);
+            self.generators.extend(new);
+        }
+        &self.generators[..n]
+    }
+
+    /// Returns the generators derived so far.
+    pub fn cached(&self) -> &[C::AffineExt] {
+        &self.generators
+    }
+}
+
+/// Security level, in bits, of [`HashToField`].
+const HASH_TO_FIELD_SECURITY: usize = 128;
+
+/// Prefix of the hashed domain separation tags longer than 255 bytes.
+const OVERSIZE_DST_PREFIX: &[u8] = b"H2C-OVERSIZE-DST-";
+
+/// Expands `msg` into `len_in_bytes` pseudorandom bytes with
+/// `expand_message_xmd` and the hash function `H` under the domain separation
+/// tag `dst`, see
+/// <https://datatracker.ietf.org/doc/html/rfc9380#name-expand_message_xmd>.
+///
+/// `H` is any [`Digest`] with a block size, such as `sha2::Sha256`,
+/// `sha2::Sha512` or `sha3::Keccak256`. Its output must be at least twice as
+/// long as the targeted security level.
+///
+/// A `dst` longer than 255 bytes is replaced with
+/// `H("H2C-OVERSIZE-DST-" || dst)`, see
+/// <https://datatracker.ietf.org/doc/html/rfc9380#name-using-dsts-longer-than-255->.
+///
+/// # Panics
+///
+/// Panics if `len_in_bytes` exceeds 255 outputs of `H` or `65535` bytes.
+pub fn expand_message_xmd<H: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    len_in_bytes: usize,
+) -> Vec<u8> {
+    let b_in_bytes = <H as Digest>::output_size();
+    let s_in_bytes = H::block_size();
+
+    let ell = (len_in_bytes + b_in_bytes - 1) / b_in_bytes;
+    assert!(ell <= 255 && len_in_bytes <= 65535, "output is too long");
+    let oversize_dst;
+    let dst = if dst.len() > 255 {
+        oversize_dst = H::new()
+            .chain_update(OVERSIZE_DST_PREFIX)
+            .chain_update(dst)
+            .finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    let b_0 = H::new()
+        .chain_update(vec![0u8; s_in_bytes])
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    let mut uniform_bytes = Vec::with_capacity(ell * b_in_bytes);
+    let mut b_i = H::new()
+        .chain_update(&b_0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+    uniform_bytes.extend_from_slice(&b_i);
+    for i in 2..=ell {
+        for (b, b_0) in b_i.iter_mut().zip(b_0.iter()) {
+            *b ^= b_0;
+        }
+        b_i = H::new()
+            .chain_update(&b_i)
+            .chain_update([i as u8])
+            .chain_update(dst)
+            .chain_update(dst_len)
+            .finalize();
+        uniform_bytes.extend_from_slice(&b_i);
+    }
+    uniform_bytes.truncate(len_in_bytes);
+    uniform_bytes
+}
+
+/// Hashing of byte strings to elements of a field, as in
+/// <https://datatracker.ietf.org/doc/html/rfc9380#name-hash_to_field-implementatio>,
+/// with [`expand_message_xmd`].
+///
+/// This is implemented for every field of the crate, including the extension
+/// fields, whose coefficients are hashed one after the other from the lowest
+/// degree up, as in the RFC. It also derives scalars, such as Fiat–Shamir
+/// challenges, under a domain separation tag.
+pub trait HashToField: Field {
+    /// Hashes `msg` to `count` field elements under the domain separation
+    /// tag `dst`, with `expand_message_xmd` over the hash function `H`.
+    ///
+    /// Each coefficient over the prime field is reduced from
+    /// `L = ceil((ceil(log2(p)) + 128) / 8)` bytes, so its distribution is
+    /// statistically close to uniform.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the `count * m * L` bytes, `m` being the degree of the
+    /// field, exceed the output length of [`expand_message_xmd`].
+    fn hash_to_field_xmd<H: Digest + BlockSizeUser>(
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+    ) -> Vec<Self>;
+
+    /// Hashes `msg` to `count` field elements under the domain separation
+    /// tag `dst`, with `expand_message_xmd` over SHA-256, see
+    /// [`HashToField::hash_to_field_xmd`].
+    #[cfg(feature = "sha2")]
+    fn hash_to_field(msg: &[u8], dst: &[u8], count: usize) -> Vec<Self> {
+        Self::hash_to_field_xmd::<Sha256>(msg, dst, count)
+    }
+}
+
+/// A field as an extension of its prime field, of degree one for the prime
+/// fields themselves, whose elements [`HashToField`] builds from hashed
+/// coefficients.
+pub trait ExtensionField: Field {
+    /// The prime field of the coefficients.
+    type Prime: PrimeField;
+
+    /// The number of coefficients.
+    const DEGREE: usize;
+
+    /// Builds an element from its `DEGREE` coefficients, lowest degree first.
+    fn from_coefficients(coeffs: &[Self::Prime]) -> Self;
+
+    /// Returns the `sgn0` of RFC 9380, the parity of the first nonzero
+    /// coefficient from the lowest degree up, which decides the sign of the
+    /// points of the maps to the curve, see
+    /// <https://datatracker.ietf.org/doc/html/rfc9380#name-the-sgn0-function>.
+    fn sgn0(&self) -> Choice;
+}
+
+/// Implements [`ExtensionField`] for a prime field, or for an extension field
+/// given the field of its coefficients and their names from the lowest degree
+/// up.
+#[macro_export]
+macro_rules! impl_extension_field {
+    ($field:ident) => {
+        impl $crate::hash_to_curve::ExtensionField for $field {
+            type Prime = $field;
+
+            const DEGREE: usize = 1;
+
+            fn from_coefficients(coeffs: &[$field]) -> Self {
+                coeffs[0]
+            }
+
+            fn sgn0(&self) -> $crate::__private::subtle::Choice {
+                $crate::ff::PrimeField::is_odd(self)
+            }
+        }
+    };
+    ($field:ident, $sub:ident, $($c:ident),+) => {
+        impl $crate::hash_to_curve::ExtensionField for $field {
+            type Prime = <$sub as $crate::hash_to_curve::ExtensionField>::Prime;
+
+            const DEGREE: usize = [$(stringify!($c)),+].len()
+                * <$sub as $crate::hash_to_curve::ExtensionField>::DEGREE;
+
+            fn from_coefficients(coeffs: &[Self::Prime]) -> Self {
+                let mut chunks =
+                    coeffs.chunks(<$sub as $crate::hash_to_curve::ExtensionField>::DEGREE);
+                $field {
+                    $(
+                        $c: <$sub as $crate::hash_to_curve::ExtensionField>::from_coefficients(
+                            chunks.next().unwrap(),
+                        ),
+                    )+
+                }
+            }
+
+            fn sgn0(&self) -> $crate::__private::subtle::Choice {
+                // The sign of the first nonzero coefficient.
+                let mut sign = $crate::__private::subtle::Choice::from(0);
+                let mut zero = $crate::__private::subtle::Choice::from(1);
+                $(
+                    sign |= zero & <$sub as $crate::hash_to_curve::ExtensionField>::sgn0(&self.$c);
+                    zero &= $crate::ff::Field::is_zero(&self.$c);
+                )+
+                sign
+            }
+        }
+    };
+}
+
+impl<F: ExtensionField> HashToField for F {
+    fn hash_to_field_xmd<H: Digest + BlockSizeUser>(
+        msg: &[u8],
+        dst: &[u8],
+        count: usize,
+    ) -> Vec<Self> {
+        let len = (F::Prime::NUM_BITS as usize + HASH_TO_FIELD_SECURITY + 7) / 8;
+        expand_message_xmd::<H>(msg, dst, count * F::DEGREE * len)
+            .chunks(F::DEGREE * len)
+            .map(|bytes| {
+                let coeffs: Vec<F::Prime> = bytes.chunks(len).map(reduce_be_bytes).collect();
+                F::from_coefficients(&coeffs)
+            })
+            .collect()
+    }
+}
+
+/// A curve with a deterministic map from its base field to its points, the
+/// `map_to_curve` of
+/// <https://datatracker.ietf.org/doc/html/rfc9380#name-encoding-byte-strings-to-el>.
+///
+/// The `pasta` curves come from `pasta_curves` and do not implement it.
+pub trait MapToCurve: CurveExt + CofactorGroup<Subgroup = Self> {
+    /// Maps the field element `u` to a point of the curve, which may lie
+    /// outside of the prime order subgroup.
+    fn map_to_curve(u: Self::Base) -> Self;
+
+    /// Maps every element of `us` with [`Self::map_to_curve`].
+    ///
+    /// The curves whose map inverts a field element override it to share a
+    /// single batched inversion among all the elements.
+    fn map_to_curve_many(us: &[Self::Base]) -> Vec<Self> {
+        us.iter().map(|u| Self::map_to_curve(*u)).collect()
+    }
+}
+
+/// Hashes `msg` to a point of the prime order subgroup of `C` under the
+/// domain separation tag `dst`, with `expand_message_xmd` over the hash
+/// function `H`, see
+/// <https://datatracker.ietf.org/doc/html/rfc9380#name-encoding-byte-strings-to-el>.
+///
+/// The output is indistinguishable from a random oracle. Unlike
+/// [`CurveExt::hash_to_curve`], which uses BLAKE2b and a domain separation
+/// tag derived from the domain prefix and the curve, the output is
+/// interoperable with other implementations of the RFC for the same curve,
+/// map, hash function and tag.
+pub fn hash_to_curve_xmd<C, H>(msg: &[u8], dst: &[u8]) -> C
+where
+    C: MapToCurve,
+    C::Base: HashToField,
+    H: Digest + BlockSizeUser,
+{
+    let u = C::Base::hash_to_field_xmd::<H>(msg, dst, 2);
+    let q0 = C::map_to_curve(u[0]);
+    let q1 = C::map_to_curve(u[1]);
+    (q0 + q1).clear_cofactor()
+}
+
+/// Encodes `msg` to a point of the prime order subgroup of `C` under the
+/// domain separation tag `dst`, with `expand_message_xmd` over the hash
+/// function `H`, see
+/// <https://datatracker.ietf.org/doc/html/rfc9380#name-encoding-byte-strings-to-el>.
+///
+/// This is the nonuniform encoding of the RFC: it maps a single field element
+/// and costs about half of [`hash_to_curve_xmd`], but its output is only
+/// distributed over a fraction of the points, and is not indistinguishable
+/// from a random oracle. It suits uses such as deriving generators, which
+/// only need points of unknown discrete logarithm.
+pub fn encode_to_curve_xmd<C, H>(msg: &[u8], dst: &[u8]) -> C
+where
+    C: MapToCurve,
+    C::Base: HashToField,
+    H: Digest + BlockSizeUser,
+{
+    let u = C::Base::hash_to_field_xmd::<H>(msg, dst, 1);
+    C::map_to_curve(u[0]).clear_cofactor()
+}
+
+/// A hash-to-curve suite, which fixes the curve, the hash function of
+/// `expand_message_xmd` and the map of [`hash_to_curve_xmd`], and is named by
+/// its suite ID, see
+/// <https://datatracker.ietf.org/doc/html/rfc9380#name-suite-id-naming-conventions>.
+///
+/// Hashing with the same suite and domain separation tag gives the same point
+/// as the other implementations of the suite.
+pub trait Suite {
+    /// The suite ID, such as `secp256k1_XMD:SHA-256_SSWU_RO_`.
+    const ID: &'static str;
+
+    /// The ID of the nonuniform variant of the suite, such as
+    /// `secp256k1_XMD:SHA-256_SSWU_NU_`.
+    const ENCODE_ID: &'static str;
+
+    /// The curve hashed to.
+    type Curve: MapToCurve;
+
+    /// The hash function of `expand_message_xmd`.
+    type Hash: Digest + BlockSizeUser;
+
+    /// Hashes `msg` to a point of [`Self::Curve`] under the domain separation
+    /// tag `dst`.
+    fn hash_to_curve(msg: &[u8], dst: &[u8]) -> Self::Curve
+    where
+        <Self::Curve as CurveExt>::Base: HashToField,
+    {
+        hash_to_curve_xmd::<Self::Curve, Self::Hash>(msg, dst)
+    }
+
+    /// Encodes `msg` to a point of [`Self::Curve`] under the domain
+    /// separation tag `dst` with the nonuniform variant of the suite, see
+    /// [`encode_to_curve_xmd`].
+    fn encode_to_curve(msg: &[u8], dst: &[u8]) -> Self::Curve
+    where
+        <Self::Curve as CurveExt>::Base: HashToField,
+    {
+        encode_to_curve_xmd::<Self::Curve, Self::Hash>(msg, dst)
+    }
+}
+
+/// The `secp256k1_XMD:SHA-256_SSWU_RO_` suite of RFC 9380, which hashes to
+/// [`Secp256k1`].
+#[cfg(feature = "sha2")]
+#[derive(Clone, Copy, Debug)]
+pub struct Secp256k1XmdSha256SswuRo;
+
+#[cfg(feature = "sha2")]
+impl Suite for Secp256k1XmdSha256SswuRo {
+    const ID: &'static str = "secp256k1_XMD:SHA-256_SSWU_RO_";
+    const ENCODE_ID: &'static str = "secp256k1_XMD:SHA-256_SSWU_NU_";
+    type Curve = Secp256k1;
+    type Hash = Sha256;
+}
+
+/// The `P256_XMD:SHA-256_SSWU_RO_` suite of RFC 9380, which hashes to
+/// [`Secp256r1`](crate::secp256r1::Secp256r1).
+#[cfg(feature = "sha2")]
+#[derive(Clone, Copy, Debug)]
+pub struct P256XmdSha256SswuRo;
+
+#[cfg(feature = "sha2")]
+impl Suite for P256XmdSha256SswuRo {
+    const ID: &'static str = "P256_XMD:SHA-256_SSWU_RO_";
+    const ENCODE_ID: &'static str = "P256_XMD:SHA-256_SSWU_NU_";
+    type Curve = crate::secp256r1::Secp256r1;
+    type Hash = Sha256;
+}
+
+/// The `BN254G1_XMD:SHA-256_SVDW_RO_` suite, which hashes to
+/// [`bn256::G1`](crate::bn256::G1) with the Shallue-van de Woestijne map of
+/// `Z = 1`, as gnark does.
+#[cfg(feature = "sha2")]
+#[derive(Clone, Copy, Debug)]
+pub struct Bn254G1XmdSha256SvdwRo;
+
+#[cfg(feature = "sha2")]
+impl Suite for Bn254G1XmdSha256SvdwRo {
+    const ID: &'static str = "BN254G1_XMD:SHA-256_SVDW_RO_";
+    const ENCODE_ID: &'static str = "BN254G1_XMD:SHA-256_SVDW_NU_";
+    type Curve = crate::bn256::G1;
+    type Hash = Sha256;
+}
+
+/// The `BN254G2_XMD:SHA-256_SVDW_RO_` suite, which hashes to
+/// [`bn256::G2`](crate::bn256::G2) with the Shallue-van de Woestijne map of
+/// `Z = u`, and clears the cofactor with
+/// [`G2::clear_cofactor_fast`](crate::bn256::G2::clear_cofactor_fast).
+#[cfg(feature = "sha2")]
+#[derive(Clone, Copy, Debug)]
+pub struct Bn254G2XmdSha256SvdwRo;
+
+#[cfg(feature = "sha2")]
+impl Suite for Bn254G2XmdSha256SvdwRo {
+    const ID: &'static str = "BN254G2_XMD:SHA-256_SVDW_RO_";
+    const ENCODE_ID: &'static str = "BN254G2_XMD:SHA-256_SVDW_NU_";
+    type Curve = crate::bn256::G2;
+    type Hash = Sha256;
+}
+
+/// Hashes every message of `msgs` to a point of the prime order subgroup of
+/// `C` under the domain separation tag `dst`, as [`hash_to_curve_xmd`] does.
+///
+/// The messages are split among the threads, and the maps of each share of
+/// the messages go through [`MapToCurve::map_to_curve_many`], which batches
+/// their inversions. The square roots of the maps are still computed one by
+/// one, as square roots of unrelated elements cannot share an exponentiation
+/// the way inversions do.
+pub fn hash_to_curve_many<C, H, M>(msgs: &[M], dst: &[u8]) -> Vec<C>
+where
+    C: MapToCurve,
+    C::Base: HashToField,
+    H: Digest + BlockSizeUser,
+    M: AsRef<[u8]> + Sync,
+{
+    use crate::multicore::*;
+
+    let threads = current_num_threads();
+    let chunk = ((msgs.len() + threads - 1) / threads).max(1);
+    let points: Vec<Vec<C>> = msgs
+        .par_chunks(chunk)
+        .map(|msgs| {
+            let u: Vec<C::Base> = msgs
+                .iter()
+                .flat_map(|msg| C::Base::hash_to_field_xmd::<H>(msg.as_ref(), dst, 2))
+                .collect();
+            C::map_to_curve_many(&u)
+                .chunks(2)
+                .map(|q| (q[0] + q[1]).clear_cofactor())
+                .collect()
+        })
+        .collect();
+    points.concat()
+}
+
+// Implements `MapToCurve` with the Shallue-van de Woestijne map of parameter
+// `$curve::SVDW_Z`, whose constants are computed once.
+macro_rules! impl_svdw_map_to_curve {
+    ($curve:ident, $base:ident) => {
+        impl $curve {
+            fn svdw_constants() -> [$base; 4] {
+                lazy_static::lazy_static! {
+                    static ref SVDW_CONSTANTS: [$base; 4] =
+                        $crate::hash_to_curve::svdw_precomputed_constants::<$curve>($curve::SVDW_Z);
+                }
+                *SVDW_CONSTANTS
+            }
+        }
+
+        impl $crate::hash_to_curve::MapToCurve for $curve {
+            fn map_to_curve(u: $base) -> Self {
+                let [c1, c2, c3, c4] = Self::svdw_constants();
+                $crate::hash_to_curve::svdw_map_to_curve(u, c1, c2, c3, c4, $curve::SVDW_Z)
+            }
+
+            fn map_to_curve_many(us: &[$base]) -> alloc::vec::Vec<Self> {
+                let [c1, c2, c3, c4] = Self::svdw_constants();
+                $crate::hash_to_curve::svdw_map_to_curve_many(us, c1, c2, c3, c4, $curve::SVDW_Z)
+            }
+        }
+    };
+}
+pub(crate) use impl_svdw_map_to_curve;
+
+/// BLAKE2b with a 64-byte output, as a [`Digest`] for [`expand_message_xmd`],
+/// which the [`CurveExt::hash_to_curve`] implementations of the crate use.
+#[derive(Clone, Debug)]
+pub struct Blake2b512(blake2b_simd::State);
+
+impl Default for Blake2b512 {
+    fn default() -> Self {
+        Self(blake2b_simd::Params::new().hash_length(64).to_state())
+    }
+}
+
+impl digest::HashMarker for Blake2b512 {}
+
+impl digest::OutputSizeUser for Blake2b512 {
+    type OutputSize = digest::consts::U64;
+}
+
+impl BlockSizeUser for Blake2b512 {
+    type BlockSize = digest::consts::U128;
+}
+
+impl digest::Update for Blake2b512 {
+    fn update(&mut self, data: &[u8]) {
+        self.0.update(data);
+    }
+}
+
+impl digest::FixedOutput for Blake2b512 {
+    fn finalize_into(self, out: &mut digest::Output<Self>) {
+        out.copy_from_slice(self.0.finalize().as_bytes());
+    }
+}
+
 /// Hashes over a message and writes the output to all of `buf`.
 /// Modified from https://github.com/zcash/pasta_curves/blob/7e3fc6a4919f6462a32b79dd226cb2587b7961eb/src/hashtocurve.rs#L11.
 fn hash_to_field<F: FromUniformBytes<64>>(
@@ -19,78 +580,63 @@ fn hash_to_field<F: FromUniformBytes<64>>(
     message: &[u8],
     buf: &mut [F; 2],
 ) {
-    assert!(domain_prefix.len() < 256);
-    assert!((18 + method.len() + curve_id.len() + domain_prefix.len()) < 256);
-
     // Assume that the field size is 32 bytes and k is 256, where k is defined in
     // <https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#name-security-considerations-3>.
     const CHUNKLEN: usize = 64;
     const_assert!(CHUNKLEN * 2 < 256);
 
-    // Input block size of BLAKE2b.
-    const R_IN_BYTES: usize = 128;
-
-    let personal = [0u8; 16];
-    let empty_hasher = blake2b_simd::Params::new()
-        .hash_length(CHUNKLEN)
-        .personal(&personal)
-        .to_state();
-
-    let b_0 = empty_hasher
-        .clone()
-        .update(&[0; R_IN_BYTES])
-        .update(message)
-        .update(&[0, (CHUNKLEN * 2) as u8, 0])
-        .update(domain_prefix.as_bytes())
-        .update(b"-")
-        .update(curve_id.as_bytes())
-        .update(b"_XMD:BLAKE2b_")
-        .update(method.as_bytes())
-        .update(b"_RO_")
-        .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-        .finalize();
-
-    let b_1 = empty_hasher
-        .clone()
-        .update(b_0.as_array())
-        .update(&[1])
-        .update(domain_prefix.as_bytes())
-        .update(b"-")
-        .update(curve_id.as_bytes())
-        .update(b"_XMD:BLAKE2b_")
-        .update(method.as_bytes())
-        .update(b"_RO_")
-        .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-        .finalize();
+    let dst = [
+        domain_prefix.as_bytes(),
+        b"-",
+        curve_id.as_bytes(),
+        b"_XMD:BLAKE2b_",
+        method.as_bytes(),
+        b"_RO_",
+    ]
+    .concat();
+    let uniform_bytes = expand_message_xmd::<Blake2b512>(message, &dst, CHUNKLEN * 2);
 
-    let b_2 = {
-        let mut empty_hasher = empty_hasher;
-        for (l, r) in b_0.as_array().iter().zip(b_1.as_array().iter()) {
-            empty_hasher.update(&[*l ^ *r]);
-        }
-        empty_hasher
-            .update(&[2])
-            .update(domain_prefix.as_bytes())
-            .update(b"-")
-            .update(curve_id.as_bytes())
-            .update(b"_XMD:BLAKE2b_")
-            .update(method.as_bytes())
-            .update(b"_RO_")
-            .update(&[(18 + method.len() + curve_id.len() + domain_prefix.len()) as u8])
-            .finalize()
-    };
-
-    for (big, buf) in [b_1, b_2].iter().zip(buf.iter_mut()) {
+    for (big, buf) in uniform_bytes.chunks(CHUNKLEN).zip(buf.iter_mut()) {
         let mut little = [0u8; CHUNKLEN];
-        little.copy_from_slice(big.as_array());
+        little.copy_from_slice(big);
         little.reverse();
         *buf = F::from_uniform_bytes(&little);
     }
 }
 
 // Implementation of <https://datatracker.ietf.org/doc/html/rfc9380#name-simplified-swu-method>
-#[allow(clippy::too_many_arguments)]
 pub(crate) fn sswu_map_to_curve<C>(u: C::Base, z: C::Base) -> C
+where
+    C: CurveExt,
+{
+    let [tv1, tv3, tv4] = sswu_map_to_curve_denominator::<C>(u, z);
+    sswu_map_to_curve_with_inverse(u, tv1, tv3, tv4, tv4.invert().unwrap(), z)
+}
+
+/// Maps every element of `us` with [`sswu_map_to_curve`], with a single
+/// batched inversion.
+pub(crate) fn sswu_map_to_curve_many<C>(us: &[C::Base], z: C::Base) -> Vec<C>
+where
+    C: CurveExt,
+{
+    let tvs: Vec<_> = us
+        .iter()
+        .map(|u| sswu_map_to_curve_denominator::<C>(*u, z))
+        .collect();
+    let mut tv4_inv: Vec<_> = tvs.iter().map(|[_, _, tv4]| *tv4).collect();
+    tv4_inv.iter_mut().batch_invert();
+    us.iter()
+        .zip(tvs)
+        .zip(tv4_inv)
+        .map(|((u, [tv1, tv3, tv4]), tv4_inv)| {
+            sswu_map_to_curve_with_inverse(*u, tv1, tv3, tv4, tv4_inv, z)
+        })
+        .collect()
+}
+
+// Steps 1 to 8 of the map, which return `tv1`, `tv3` and the denominator
+// `tv4`. `tv4` is never zero, as `A` and `Z` are nonzero.
+fn sswu_map_to_curve_denominator<C>(u: C::Base, z: C::Base) -> [C::Base; 3]
 where
     C: CurveExt,
 {
@@ -116,6 +662,28 @@ where
     let tv4 = C::Base::conditional_select(&z, &-tv2, tv2_is_not_zero);
     //8.  tv4 = A * tv4
     let tv4 = a * tv4;
+    [tv1, tv3, tv4]
+}
+
+// Steps 9 to 26 of the map, given `tv1`, `tv3` and `tv4` of steps 1 to 8 and
+// `tv4_inv = 1 / tv4`. The only inversions of the map, the one of
+// `sqrt_ratio` at step 18 and the one at step 25, are taken from `tv4_inv`,
+// as `tv6 = tv4^3`.
+fn sswu_map_to_curve_with_inverse<C>(
+    u: C::Base,
+    tv1: C::Base,
+    tv3: C::Base,
+    tv4: C::Base,
+    tv4_inv: C::Base,
+    z: C::Base,
+) -> C
+where
+    C: CurveExt,
+{
+    let one = C::Base::ONE;
+    let a = C::a();
+    let b = C::b();
+
     //9.  tv2 = tv3^2
     let tv2 = tv3.square();
     //10. tv6 = tv4^2
@@ -135,7 +703,8 @@ where
     //17.   x = tv1 * tv3
     let x = tv1 * tv3;
     //18. (is_gx1_square, y1) = sqrt_ratio(tv2, tv6)
-    let (is_gx1_square, y1) = sqrt_ratio(&tv2, &tv6, &z);
+    let tv6_inv = tv4_inv.square() * tv4_inv;
+    let (is_gx1_square, y1) = sqrt_ratio(&tv2, &tv6_inv, &z);
     //19.   y = tv1 * u
     let y = tv1 * u;
     //20.   y = y * y1
@@ -149,14 +718,18 @@ where
     //24.   y = CMOV(-y, y, e1) # Select correct sign of y
     let y = C::Base::conditional_select(&-y, &y, e1);
     //25.   x = x / tv4
-    let x = x * tv4.invert().unwrap();
+    let x = x * tv4_inv;
     //26. return (x, y)
     C::new_jacobian(x, y, one).unwrap()
 }
 
-// Implementation of <https://datatracker.ietf.org/doc/html/rfc9380#name-simplified-swu-method>
+/// Returns the `hash_to_curve` function of the curve `C` using the simplified
+/// SWU map with parameter `z`, see
+/// <https://datatracker.ietf.org/doc/html/rfc9380#name-simplified-swu-method>.
+///
+/// The map requires both curve constants `a` and `b` to be nonzero.
 #[allow(clippy::type_complexity)]
-pub(crate) fn sswu_hash_to_curve<'a, C>(
+pub fn sswu_hash_to_curve<'a, C>(
     curve_id: &'static str,
     domain_prefix: &'a str,
     z: C::Base,
@@ -204,11 +777,9 @@ pub(crate) fn svdw_map_to_curve<C>(
 ) -> C
 where
     C: CurveExt,
-    C::Base: Legendre,
+    C::Base: Legendre + ExtensionField,
 {
     let one = C::Base::ONE;
-    let a = C::a();
-    let b = C::b();
 
     // 1. tv1 = u^2
     let tv1 = u.square();
@@ -222,6 +793,65 @@ where
     let tv3 = tv1 * tv2;
     // 6. tv3 = inv0(tv3)
     let tv3 = tv3.invert().unwrap_or(C::Base::ZERO);
+    svdw_map_to_curve_with_inverse(u, tv1, tv2, tv3, c2, c3, c4, z)
+}
+
+/// Maps every element of `us` with [`svdw_map_to_curve`], with a single
+/// batched inversion.
+pub(crate) fn svdw_map_to_curve_many<C>(
+    us: &[C::Base],
+    c1: C::Base,
+    c2: C::Base,
+    c3: C::Base,
+    c4: C::Base,
+    z: C::Base,
+) -> Vec<C>
+where
+    C: CurveExt,
+    C::Base: Legendre + ExtensionField,
+{
+    // Steps 1 to 5 of every map, whose inversions are batched. `batch_invert`
+    // leaves zero unchanged, as `inv0` does.
+    let (tv1, tv2): (Vec<_>, Vec<_>) = us
+        .iter()
+        .map(|u| {
+            let tv1 = u.square() * c1;
+            (C::Base::ONE - tv1, C::Base::ONE + tv1)
+        })
+        .unzip();
+    let mut tv3: Vec<_> = tv1.iter().zip(&tv2).map(|(tv1, tv2)| *tv1 * tv2).collect();
+    tv3.iter_mut().batch_invert();
+    us.iter()
+        .zip(tv1)
+        .zip(tv2)
+        .zip(tv3)
+        .map(|(((u, tv1), tv2), tv3)| {
+            svdw_map_to_curve_with_inverse(*u, tv1, tv2, tv3, c2, c3, c4, z)
+        })
+        .collect()
+}
+
+// Steps 7 to 36 of the map, given `tv1 = 1 - c1 * u^2`, `tv2 = 1 + c1 * u^2`
+// and `tv3 = inv0(tv1 * tv2)`.
+#[allow(clippy::too_many_arguments)]
+fn svdw_map_to_curve_with_inverse<C>(
+    u: C::Base,
+    tv1: C::Base,
+    tv2: C::Base,
+    tv3: C::Base,
+    c2: C::Base,
+    c3: C::Base,
+    c4: C::Base,
+    z: C::Base,
+) -> C
+where
+    C: CurveExt,
+    C::Base: Legendre + ExtensionField,
+{
+    let one = C::Base::ONE;
+    let a = C::a();
+    let b = C::b();
+
     // 7. tv4 = u * tv1
     let tv4 = u * tv1;
     // 8. tv4 = tv4 * tv3
@@ -277,7 +907,7 @@ where
     // 33. y = sqrt(gx)
     let y = gx.sqrt().unwrap();
     // 34. e3 = sgn0(u) == sgn0(y)
-    let e3 = u.is_odd().ct_eq(&y.is_odd());
+    let e3 = u.sgn0().ct_eq(&y.sgn0());
     // 35. y = CMOV(-y, y, e3)    # Select correct sign of y
     let y = C::Base::conditional_select(&-y, &y, e3);
     // 36. return (x, y)
@@ -285,8 +915,9 @@ where
 }
 
 // Implement https://datatracker.ietf.org/doc/html/rfc9380#name-sqrt_ratio-for-any-field
-// Copied from ff sqrt_ratio_generic substituting F::ROOT_OF_UNITY for input Z
-fn sqrt_ratio<F: PrimeField>(num: &F, div: &F, z: &F) -> (Choice, F) {
+// Copied from ff sqrt_ratio_generic substituting F::ROOT_OF_UNITY for input Z,
+// given `div_inv = inv0(div)` rather than `div`.
+fn sqrt_ratio<F: PrimeField>(num: &F, div_inv: &F, z: &F) -> (Choice, F) {
     // General implementation:
     //
     // a = num * inv0(div)
@@ -302,13 +933,13 @@ fn sqrt_ratio<F: PrimeField>(num: &F, div: &F, z: &F) -> (Choice, F) {
     // based on whether a is square, but for the boolean output we need to handle the
     // num != 0 && div == 0 case specifically.
 
-    let a = div.invert().unwrap_or(F::ZERO) * num;
+    let a = *div_inv * num;
     let b = a * z;
     let sqrt_a = a.sqrt();
     let sqrt_b = b.sqrt();
 
     let num_is_zero = num.is_zero();
-    let div_is_zero = div.is_zero();
+    let div_is_zero = div_inv.is_zero();
     let is_square = sqrt_a.is_some();
     let is_nonsquare = sqrt_b.is_some();
     assert!(bool::from(
@@ -321,16 +952,20 @@ fn sqrt_ratio<F: PrimeField>(num: &F, div: &F, z: &F) -> (Choice, F) {
     )
 }
 
-/// Implementation of https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#section-6.6.1
+/// Returns the `hash_to_curve` function of the curve `C` using the
+/// Shallue-van de Woestijne map with parameter `z`, see
+/// <https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#section-6.6.1>.
+///
+/// The map works for any short Weierstrass curve.
 #[allow(clippy::type_complexity)]
-pub(crate) fn svdw_hash_to_curve<'a, C>(
+pub fn svdw_hash_to_curve<'a, C>(
     curve_id: &'static str,
     domain_prefix: &'a str,
     z: C::Base,
 ) -> Box<dyn Fn(&[u8]) -> C + 'a>
 where
     C: CurveExt,
-    C::Base: FromUniformBytes<64> + Legendre,
+    C::Base: FromUniformBytes<64> + Legendre + ExtensionField,
 {
     let [c1, c2, c3, c4] = svdw_precomputed_constants::<C>(z);
 
@@ -346,7 +981,10 @@ where
     })
 }
 
-pub(crate) fn svdw_precomputed_constants<C: CurveExt>(z: C::Base) -> [C::Base; 4] {
+pub(crate) fn svdw_precomputed_constants<C: CurveExt>(z: C::Base) -> [C::Base; 4]
+where
+    C::Base: ExtensionField,
+{
     let a = C::a();
     let b = C::b();
     let one = C::Base::ONE;
@@ -361,10 +999,434 @@ pub(crate) fn svdw_precomputed_constants<C: CurveExt>(z: C::Base) -> [C::Base; 4
     // 3. c3 = sqrt(-g(Z) * (3 * Z^2 + 4 * A))    # sgn0(c3) MUST equal 0
     let c3 = {
         let c3 = (-c1 * tmp).sqrt().unwrap();
-        C::Base::conditional_select(&c3, &-c3, c3.is_odd())
+        C::Base::conditional_select(&c3, &-c3, c3.sgn0())
     };
     // 4. c4 = -4 * g(Z) / (3 * Z^2 + 4 * A)
     let c4 = -four * c1 * tmp.invert().unwrap();
 
     [c1, c2, c3, c4]
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_expand_message_xmd() {
+        // Test vectors of RFC 9380, appendix K.1.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        for (msg, len, expected) in [
+            (
+                &b""[..],
+                0x20,
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                0x20,
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"",
+                0x80,
+                "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe\
+                 e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18\
+                 eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc\
+                 c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+            ),
+        ] {
+            assert_eq!(
+                hex::encode(expand_message_xmd::<Sha256>(msg, dst, len)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_expand_message_xmd_long_dst() {
+        // Test vectors of RFC 9380, appendix K.2.
+        let dst = [
+            &b"QUUX-V01-CS02-with-expander-SHA256-128-long-DST-"[..],
+            &[b'1'; 208],
+        ]
+        .concat();
+        assert_eq!(dst.len(), 256);
+        for (msg, expected) in [
+            (
+                &b""[..],
+                "e8dc0c8b686b7ef2074086fbdd2f30e3f8bfbd3bdf177f73f04b97ce618a3ed3",
+            ),
+            (
+                b"abc",
+                "52dbf4f36cf560fca57dedec2ad924ee9c266341d8f3d6afe5171733b16bbb12",
+            ),
+        ] {
+            assert_eq!(
+                hex::encode(expand_message_xmd::<Sha256>(msg, &dst, 0x20)),
+                expected
+            );
+            // The long tag is equivalent to its hash.
+            let hashed = Sha256::new()
+                .chain_update(OVERSIZE_DST_PREFIX)
+                .chain_update(&dst)
+                .finalize();
+            assert_eq!(
+                expand_message_xmd::<Sha256>(msg, &dst, 0x20),
+                expand_message_xmd::<Sha256>(msg, &hashed, 0x20)
+            );
+        }
+    }
+
+    #[test]
+    fn test_long_domain_prefix() {
+        use crate::bn256::G1;
+
+        // The tag built from a long prefix is hashed rather than rejected.
+        let prefix = "a".repeat(300);
+        let p = G1::hash_to_curve(&prefix)(b"msg");
+        assert!(bool::from(p.is_on_curve()));
+        assert_eq!(p, G1::hash_to_curve(&prefix)(b"msg"));
+        assert_ne!(p, G1::hash_to_curve(&prefix[1..])(b"msg"));
+    }
+
+    #[test]
+    fn test_blake2b512() {
+        for msg in [&b""[..], b"abc", &[0x5a; 300]] {
+            assert_eq!(
+                &Blake2b512::digest(msg)[..],
+                blake2b_simd::blake2b(msg).as_bytes()
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_expand_message_xmd_sha512() {
+        // Test vectors of RFC 9380, appendix K.3.
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        for (msg, expected) in [
+            (
+                &b""[..],
+                "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+            ),
+            (
+                b"abc",
+                "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+            ),
+        ] {
+            assert_eq!(
+                hex::encode(expand_message_xmd::<sha2::Sha512>(msg, dst, 0x20)),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_hash_to_curve_xmd() {
+        use crate::secp256k1::{Fp, Secp256k1};
+        use crate::CurveAffine;
+        use group::Curve;
+
+        // Test vectors of RFC 9380, appendix J.8.1.
+        let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+        for (msg, x, y) in [
+            (
+                &b""[..],
+                "0xc1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                "0x64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+            ),
+            (
+                b"abc",
+                "0x3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                "0x7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+            ),
+        ] {
+            let p = hash_to_curve_xmd::<Secp256k1, Sha256>(msg, dst).to_affine();
+            let coords = p.coordinates().unwrap();
+            assert_eq!(*coords.x(), Fp::from_str_mod_order(x).unwrap());
+            assert_eq!(*coords.y(), Fp::from_str_mod_order(y).unwrap());
+        }
+
+        fn check<C: MapToCurve>()
+        where
+            C::Base: HashToField,
+        {
+            let p = hash_to_curve_xmd::<C, Sha256>(b"msg", b"dst");
+            assert!(bool::from(p.is_on_curve()));
+            assert_eq!(p, hash_to_curve_xmd::<C, Sha256>(b"msg", b"dst"));
+            assert_ne!(p, hash_to_curve_xmd::<C, Sha256>(b"msg", b"other dst"));
+            assert_ne!(p, hash_to_curve_xmd::<C, sha2::Sha512>(b"msg", b"dst"));
+        }
+        check::<crate::bn256::G1>();
+        check::<crate::bn256::G2>();
+        check::<crate::grumpkin::G1>();
+        check::<crate::pluto_eris::G1>();
+        check::<crate::pluto_eris::Eris>();
+        check::<crate::secp256k1::Secp256k1>();
+        check::<crate::secp256r1::Secp256r1>();
+        check::<crate::secq256k1::Secq256k1>();
+    }
+
+    #[cfg(feature = "sha2")]
+    fn check_suite<S: Suite>(encode: bool, vectors: [(&[u8], &str, &str); 2])
+    where
+        <S::Curve as CurveExt>::Base: HashToField + PrimeField,
+    {
+        use crate::CurveAffine;
+        use group::Curve;
+
+        // The domain separation tag of the test vectors.
+        let id = if encode { S::ENCODE_ID } else { S::ID };
+        let dst = [&b"QUUX-V01-CS02-with-"[..], id.as_bytes()].concat();
+        for (msg, x, y) in vectors {
+            let p = if encode {
+                S::encode_to_curve(msg, &dst)
+            } else {
+                S::hash_to_curve(msg, &dst)
+            };
+            let coords = p.to_affine().coordinates().unwrap();
+            let mut repr = coords.x().to_repr();
+            repr.as_mut().reverse();
+            assert_eq!(hex::encode(repr), x);
+            let mut repr = coords.y().to_repr();
+            repr.as_mut().reverse();
+            assert_eq!(hex::encode(repr), y);
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_suites() {
+        // Test vectors of RFC 9380, appendix J.8.1.
+        check_suite::<Secp256k1XmdSha256SswuRo>(
+            false,
+            [
+                (
+                    b"",
+                    "c1cae290e291aee617ebaef1be6d73861479c48b841eaba9b7b5852ddfeb1346",
+                    "64fa678e07ae116126f08b022a94af6de15985c996c3a91b64c406a960e51067",
+                ),
+                (
+                    b"abc",
+                    "3377e01eab42db296b512293120c6cee72b6ecf9f9205760bd9ff11fb3cb2c4b",
+                    "7f95890f33efebd1044d382a01b1bee0900fb6116f94688d487c6c7b9c8371f6",
+                ),
+            ],
+        );
+        // Test vectors of RFC 9380, appendix J.1.1.
+        check_suite::<P256XmdSha256SswuRo>(
+            false,
+            [
+                (
+                    b"",
+                    "2c15230b26dbc6fc9a37051158c95b79656e17a1a920b11394ca91c44247d3e4",
+                    "8a7a74985cc5c776cdfe4b1f19884970453912e9d31528c060be9ab5c43e8415",
+                ),
+                (
+                    b"abc",
+                    "0bb8b87485551aa43ed54f009230450b492fead5f1cc91658775dac4a3388a0f",
+                    "5c41b3d0731a27a7b14bc0bf0ccded2d8751f83493404c84a88e71ffd424212e",
+                ),
+            ],
+        );
+        // Test vectors of gnark-crypto.
+        check_suite::<Bn254G1XmdSha256SvdwRo>(
+            false,
+            [
+                (
+                    b"",
+                    "0a976ab906170db1f9638d376514dbf8c42aef256a54bbd48521f20749e59e86",
+                    "02925ead66b9e68bfc309b014398640ab55f6619ab59bc1fab2210ad4c4d53d5",
+                ),
+                (
+                    b"abc",
+                    "23f717bee89b1003957139f193e6be7da1df5f1374b26a4643b0378b5baf53d1",
+                    "04142f826b71ee574452dbc47e05bc3e1a647478403a7ba38b7b93948f4e151d",
+                ),
+            ],
+        );
+        // Computed with an independent implementation of RFC 9380 and the
+        // cofactor clearing of gnark-crypto, the coordinates as `c1 || c0`.
+        check_suite::<Bn254G2XmdSha256SvdwRo>(
+            false,
+            [
+                (
+                    b"",
+                    "16cdd0997f60c1db037b92d2a1547807cdd197452dcf022939d1319ee0b547340ed0d40000f45c0ccb22f6d4b5556a622aeae7171bc7786384388c72c2d1c457",
+                    "08a263f06c6eef04278720d7b1d775b3d089b11cd333d85b4e7b8b5d8aee040722cbeff2598127f6bd6d978719090096fcb12ecd4963ff6cc319e5cdc81292a9",
+                ),
+                (
+                    b"abc",
+                    "17c113afe7b749ff849e6920b0f72ce986eaaca1e5b7c467be6e88c7aab5c9621a157379821af76197c91be2edaa32896367ec7928c067e6915a03009987517e",
+                    "0e481714afa543e91943b8ae6894bd64d43d1481c8081a43ee7f857397ffe8d3116a0553a3b974ae27aff261e6503477ee021839b0139f58655fad1460b738b7",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_encode_suites() {
+        // Test vectors of RFC 9380, appendix J.8.2.
+        check_suite::<Secp256k1XmdSha256SswuRo>(
+            true,
+            [
+                (
+                    b"",
+                    "a4792346075feae77ac3b30026f99c1441b4ecf666ded19b7522cf65c4c55c5b",
+                    "62c59e2a6aeed1b23be5883e833912b08ba06be7f57c0e9cdc663f31639ff3a7",
+                ),
+                (
+                    b"abc",
+                    "3f3b5842033fff837d504bb4ce2a372bfeadbdbd84a1d2b678b6e1d7ee426b9d",
+                    "902910d1fef15d8ae2006fc84f2a5a7bda0e0407dc913062c3a493c4f5d876a5",
+                ),
+            ],
+        );
+        // Test vectors of RFC 9380, appendix J.1.2.
+        check_suite::<P256XmdSha256SswuRo>(
+            true,
+            [
+                (
+                    b"",
+                    "f871caad25ea3b59c16cf87c1894902f7e7b2c822c3d3f73596c5ace8ddd14d1",
+                    "87b9ae23335bee057b99bac1e68588b18b5691af476234b8971bc4f011ddc99b",
+                ),
+                (
+                    b"abc",
+                    "fc3f5d734e8dce41ddac49f47dd2b8a57257522a865c124ed02b92b5237befa4",
+                    "fe4d197ecf5a62645b9690599e1d80e82c500b22ac705a0b421fac7b47157866",
+                ),
+            ],
+        );
+        check_suite::<Bn254G1XmdSha256SvdwRo>(
+            true,
+            [
+                (
+                    b"",
+                    "1bb8810e2ceaf04786d4efd216fc2820ddd9363712efc736ada11049d8af5925",
+                    "1efbf8d54c60d865cce08437668ea30f5bf90d287dbd9b5af31da852915e8f11",
+                ),
+                (
+                    b"abc",
+                    "0da4a96147df1f35b0f820bd35c6fac3b80e8e320de7c536b1e054667b22c332",
+                    "189bd3fbffe4c8740d6543754d95c790e44cd2d162858e3b733d2b8387983bb7",
+                ),
+            ],
+        );
+        check_suite::<Bn254G2XmdSha256SvdwRo>(
+            true,
+            [
+                (
+                    b"",
+                    "0155a3ef7ca6e82800b449b3cd1a4d8a04758ba37c43e33d9e1575f97a90f1f2103d2ca25313c885ace0b0a3ad64b07937976c9b8737a5033173f9436c08f0a0",
+                    "0c5d30c7ad530f8a076b1519d4cd86bf0cbf9a769187008aea3e64153f0f713414dd7085906cab369121e344725548cf669c1a5ee9cdb68b043f9c5b5cc48437",
+                ),
+                (
+                    b"abc",
+                    "20677f5e2bfc7a59c7507703e86717173edb1c61c0818233c45111d09230dcc407cd9a42badb2d388a6002a4786bf3b6f897956dcfde6d4b2a9b698572bdcf1f",
+                    "1cab09de4113e44365df2ebcd4925fd3404fc2021041e612859e70964cd49aae27f607a21bdd45512347be0ec14990f97c32529f9d9a5aca1d9fd95e1423aabd",
+                ),
+            ],
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_hash_to_curve_many() {
+        fn check<C: MapToCurve>()
+        where
+            C::Base: HashToField,
+        {
+            let msgs: Vec<Vec<u8>> = (0..37u8).map(|i| vec![i; i as usize]).collect();
+            for n in [0, 1, msgs.len()] {
+                let points = hash_to_curve_many::<C, Sha256, _>(&msgs[..n], b"dst");
+                let expected: Vec<C> = msgs[..n]
+                    .iter()
+                    .map(|msg| hash_to_curve_xmd::<C, Sha256>(msg, b"dst"))
+                    .collect();
+                assert_eq!(points, expected);
+            }
+
+            let us: Vec<C::Base> = (0..10u64).map(C::Base::from).collect();
+            let expected: Vec<C> = us.iter().map(|u| C::map_to_curve(*u)).collect();
+            assert_eq!(C::map_to_curve_many(&us), expected);
+        }
+        check::<crate::bn256::G1>();
+        check::<crate::bn256::G2>();
+        check::<crate::grumpkin::G1>();
+        check::<crate::pluto_eris::G1>();
+        check::<crate::secp256k1::Secp256k1>();
+        check::<crate::secp256r1::Secp256r1>();
+    }
+
+    #[test]
+    fn test_derive_generators() {
+        fn check<C: CurveExt>() {
+            let generators = derive_generators::<C>(b"test", 20);
+            assert_eq!(generators.len(), 20);
+            for (i, g) in generators.iter().enumerate() {
+                assert_eq!(*g, derive_generator::<C>(b"test", i as u64).to_affine());
+            }
+            assert!(derive_generators::<C>(b"test", 0).is_empty());
+
+            let mut cache = GeneratorCache::<C>::new(b"test");
+            assert_eq!(cache.label(), b"test");
+            assert!(cache.cached().is_empty());
+            assert_eq!(cache.get(5), &generators[..5]);
+            assert_eq!(cache.get(3), &generators[..3]);
+            assert_eq!(cache.cached().len(), 5);
+            assert_eq!(cache.get(20), &generators[..]);
+            assert_ne!(GeneratorCache::<C>::new(b"other").get(20), &generators[..]);
+        }
+        check::<crate::bn256::G1>();
+        check::<crate::bn256::G2>();
+        check::<crate::secp256k1::Secp256k1>();
+        check::<crate::pasta::Ep>();
+    }
+
+    #[test]
+    #[cfg(feature = "sha2")]
+    fn test_hash_to_field() {
+        use crate::secp256k1::Fp;
+
+        // The field elements of the secp256k1_XMD:SHA-256_SSWU_RO_ test vectors
+        // of RFC 9380, appendix J.8.1.
+        let dst = b"QUUX-V01-CS02-with-secp256k1_XMD:SHA-256_SSWU_RO_";
+        for (msg, expected) in [
+            (
+                &b""[..],
+                [
+                    "0x6b0f9910dd2ba71c78f2ee9f04d73b5f4c5f7fc773a701abea1e573cab002fb3",
+                    "0x1ae6c212e08fe1a5937f6202f929a2cc8ef4ee5b9782db68b0d5799fd8f09e16",
+                ],
+            ),
+            (
+                b"abc",
+                [
+                    "0x128aab5d3679a1f7601e3bdf94ced1f43e491f544767e18a4873f397b08a2b61",
+                    "0x5897b65da3b595a813d0fdcc75c895dc531be76a03518b044daaa0f2e4689e00",
+                ],
+            ),
+        ] {
+            let expected = expected.map(|u| Fp::from_str_mod_order(u).unwrap());
+            assert_eq!(Fp::hash_to_field(msg, dst, 2), expected);
+        }
+
+        // A scalar field, with the same expansion.
+        let scalars = crate::bn256::Fr::hash_to_field(b"abc", b"halo2curves-test", 3);
+        assert_eq!(
+            scalars,
+            [
+                "0x458c6e32e057c56c5cf2b9823ef94d5f5eca0641c651600c31861e879246cd4",
+                "0x1a5e59fbc5776d70aff33e91553537edb863df9c349a4c7dd774b44f17ad725d",
+                "0x20b76f3e9bc9e34eaa10b2e0287532cef2bea8fc619fab5e1500e4a06e88f6a7",
+            ]
+            .map(|u| crate::bn256::Fr::from_str_mod_order(u).unwrap())
+        );
+        assert!(crate::bn256::Fr::hash_to_field(b"abc", b"", 0).is_empty());
+    }
+}
@@ -0,0 +1,569 @@
+//! Jacobian coordinates for short Weierstrass curves.
+//!
+//! The curve types of this crate use homogeneous projective coordinates with
+//! complete, constant time formulas. [`Jacobian`] keeps a point of any curve
+//! in Jacobian coordinates instead, `(X : Y : Z)` standing for
+//! `(X / Z^2, Y / Z^3)`, where doubling is cheaper, especially on `a = 0`
+//! curves such as bn256, secp256k1 and grumpkin. Its arithmetic uses the
+//! incomplete formulas of the Explicit-Formulas Database and branches on
+//! special cases, so it runs in variable time and must only be used with
+//! public data, e.g. for signature or proof verification.
+//!
+//! `Jacobian<C>` implements [`Group`], so generic code such as
+//! [`multi_mul2`](crate::msm::multi_mul2) can run on it directly:
+//!
+//! ```
+//! use halo2curves::bn256::{Fr, G1};
+//! use halo2curves::jacobian::Jacobian;
+//! use halo2curves::msm::multi_mul2;
+//! use halo2curves::group::Group;
+//!
+//! let (a, b) = (Fr::from(3), Fr::from(5));
+//! let p = Jacobian::from(G1::generator());
+//! let q = p.double();
+//! assert_eq!(multi_mul2(&a, &p, &b, &q).to_curve(), G1::generator() * Fr::from(13));
+//! ```
+
+use crate::msm::wnaf_form;
+use crate::{Coordinates, CurveAffine, CurveExt};
+use core::fmt;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use ff::{BatchInvert, Field, PrimeField};
+use group::{prime::PrimeCurveAffine, Group};
+use rand_core::RngCore;
+use subtle::Choice;
+
+const MUL_WINDOW: usize = 5;
+
+/// A point of the curve `C` in Jacobian coordinates, with variable time
+/// arithmetic.
+///
+/// The identity is any point with `Z = 0`.
+#[derive(Clone, Copy)]
+pub struct Jacobian<C: CurveExt> {
+    x: C::Base,
+    y: C::Base,
+    z: C::Base,
+}
+
+impl<C: CurveExt> Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    /// Returns the point at infinity.
+    pub fn identity() -> Self {
+        Self {
+            x: C::Base::ONE,
+            y: C::Base::ONE,
+            z: C::Base::ZERO,
+        }
+    }
+
+    /// Returns the point with affine coordinates `(x, y)`, the identity for
+    /// the identity of `C`.
+    pub fn from_affine(point: &C::AffineExt) -> Self {
+        Self::affine_coordinates(point)
+            .map(|(x, y)| Self {
+                x,
+                y,
+                z: C::Base::ONE,
+            })
+            .unwrap_or_else(Self::identity)
+    }
+
+    // Returns the coordinates of `point`, or `None` for the identity.
+    fn affine_coordinates(point: &C::AffineExt) -> Option<(C::Base, C::Base)> {
+        if bool::from(point.is_identity()) {
+            return None;
+        }
+        Option::<Coordinates<C::AffineExt>>::from(point.coordinates())
+            .map(|coords| (*coords.x(), *coords.y()))
+    }
+
+    /// Returns the Jacobian coordinates `(X, Y, Z)` of the point.
+    pub fn coordinates(&self) -> (C::Base, C::Base, C::Base) {
+        (self.x, self.y, self.z)
+    }
+
+    /// Converts the point back to the projective representation of `C`.
+    pub fn to_curve(&self) -> C {
+        C::new_jacobian(self.x, self.y, self.z).unwrap()
+    }
+
+    /// Converts the point to affine coordinates.
+    pub fn to_affine(&self) -> C::AffineExt {
+        let mut affine = [C::AffineExt::identity()];
+        Self::batch_normalize(core::slice::from_ref(self), &mut affine);
+        affine[0]
+    }
+
+    /// Converts `p` to affine coordinates with a single inversion.
+    ///
+    /// Panics if `p` and `q` have different lengths.
+    pub fn batch_normalize(p: &[Self], q: &mut [C::AffineExt]) {
+        assert_eq!(p.len(), q.len());
+        let mut z_inv: alloc::vec::Vec<_> = p.iter().map(|p| p.z).collect();
+        z_inv.batch_invert();
+        for ((p, z_inv), q) in p.iter().zip(z_inv.iter()).zip(q.iter_mut()) {
+            *q = if p.is_identity_vartime() {
+                C::AffineExt::identity()
+            } else {
+                let z_inv2 = z_inv.square();
+                C::AffineExt::from_xy(p.x * z_inv2, p.y * z_inv2 * z_inv).unwrap()
+            };
+        }
+    }
+
+    fn is_identity_vartime(&self) -> bool {
+        self.z.is_zero_vartime()
+    }
+
+    /// Doubles the point, with `dbl-2009-l` when `a = 0` and `dbl-2007-bl`
+    /// otherwise.
+    pub fn double(&self) -> Self {
+        if self.is_identity_vartime() {
+            return *self;
+        }
+        let a = C::a();
+        if a.is_zero_vartime() {
+            let a = self.x.square();
+            let b = self.y.square();
+            let c = b.square();
+            let d = ((self.x + b).square() - a - c).double();
+            let e = a.double() + a;
+            let f = e.square();
+            let x3 = f - d.double();
+            Self {
+                x: x3,
+                y: e * (d - x3) - c.double().double().double(),
+                z: (self.y * self.z).double(),
+            }
+        } else {
+            let xx = self.x.square();
+            let yy = self.y.square();
+            let yyyy = yy.square();
+            let zz = self.z.square();
+            let s = ((self.x + yy).square() - xx - yyyy).double();
+            let m = xx.double() + xx + a * zz.square();
+            let t = m.square() - s.double();
+            Self {
+                x: t,
+                y: m * (s - t) - yyyy.double().double().double(),
+                z: (self.y + self.z).square() - yy - zz,
+            }
+        }
+    }
+
+    /// Adds the affine point `rhs`, with `madd-2007-bl`.
+    pub fn add_affine(&self, rhs: &C::AffineExt) -> Self {
+        let (x2, y2) = match Self::affine_coordinates(rhs) {
+            Some(coords) => coords,
+            None => return *self,
+        };
+        if self.is_identity_vartime() {
+            return Self {
+                x: x2,
+                y: y2,
+                z: C::Base::ONE,
+            };
+        }
+
+        let z1z1 = self.z.square();
+        let u2 = x2 * z1z1;
+        let s2 = y2 * self.z * z1z1;
+        let h = u2 - self.x;
+        let r = (s2 - self.y).double();
+        if h.is_zero_vartime() {
+            return if r.is_zero_vartime() {
+                self.double()
+            } else {
+                Self::identity()
+            };
+        }
+        let hh = h.square();
+        let i = hh.double().double();
+        let j = h * i;
+        let v = self.x * i;
+        let x3 = r.square() - j - v.double();
+        Self {
+            x: x3,
+            y: r * (v - x3) - (self.y * j).double(),
+            z: (self.z + h).square() - z1z1 - hh,
+        }
+    }
+
+    fn add_jacobian(&self, rhs: &Self) -> Self {
+        if self.is_identity_vartime() {
+            return *rhs;
+        }
+        if rhs.is_identity_vartime() {
+            return *self;
+        }
+
+        // add-2007-bl
+        let z1z1 = self.z.square();
+        let z2z2 = rhs.z.square();
+        let u1 = self.x * z2z2;
+        let u2 = rhs.x * z1z1;
+        let s1 = self.y * rhs.z * z2z2;
+        let s2 = rhs.y * self.z * z1z1;
+        let h = u2 - u1;
+        let r = (s2 - s1).double();
+        if h.is_zero_vartime() {
+            return if r.is_zero_vartime() {
+                self.double()
+            } else {
+                Self::identity()
+            };
+        }
+        let i = h.double().square();
+        let j = h * i;
+        let v = u1 * i;
+        let x3 = r.square() - j - v.double();
+        Self {
+            x: x3,
+            y: r * (v - x3) - (s1 * j).double(),
+            z: ((self.z + rhs.z).square() - z1z1 - z2z2) * h,
+        }
+    }
+
+    fn mul_vartime(&self, scalar: &C::ScalarExt) -> Self {
+        let wnaf = wnaf_form(scalar.to_repr().as_ref(), MUL_WINDOW);
+        let double = self.double();
+        let mut table = alloc::vec::Vec::with_capacity(1 << (MUL_WINDOW - 2));
+        table.push(*self);
+        for i in 1..1 << (MUL_WINDOW - 2) {
+            table.push(table[i - 1] + double);
+        }
+        let mut acc = Self::identity();
+        for digit in wnaf.iter().rev() {
+            acc = acc.double();
+            if *digit > 0 {
+                acc += table[(digit / 2) as usize];
+            } else if *digit < 0 {
+                acc -= table[(-digit / 2) as usize];
+            }
+        }
+        acc
+    }
+}
+
+impl<C: CurveExt> From<C> for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn from(point: C) -> Self {
+        let (x, y, z) = point.jacobian_coordinates();
+        Self { x, y, z }
+    }
+}
+
+impl<C: CurveExt> fmt::Debug for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Jacobian({:?})", self.to_affine())
+    }
+}
+
+impl<C: CurveExt> PartialEq for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn eq(&self, other: &Self) -> bool {
+        match (self.is_identity_vartime(), other.is_identity_vartime()) {
+            (true, true) => true,
+            (false, false) => {
+                let z1z1 = self.z.square();
+                let z2z2 = other.z.square();
+                self.x * z2z2 == other.x * z1z1
+                    && self.y * z2z2 * other.z == other.y * z1z1 * self.z
+            }
+            _ => false,
+        }
+    }
+}
+
+impl<C: CurveExt> Eq for Jacobian<C> where C::AffineExt: CurveAffine<Base = C::Base> {}
+
+impl<C: CurveExt> Neg for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl<C: CurveExt> Neg for &Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    type Output = Jacobian<C>;
+
+    fn neg(self) -> Jacobian<C> {
+        -*self
+    }
+}
+
+impl<'a, C: CurveExt> Add<&'a Jacobian<C>> for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: &'a Self) -> Self {
+        self.add_jacobian(rhs)
+    }
+}
+
+impl<C: CurveExt> Add for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.add_jacobian(&rhs)
+    }
+}
+
+impl<'a, C: CurveExt> Sub<&'a Jacobian<C>> for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: &'a Self) -> Self {
+        self.add_jacobian(&-rhs)
+    }
+}
+
+impl<C: CurveExt> Sub for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.add_jacobian(&-rhs)
+    }
+}
+
+impl<'a, C: CurveExt> AddAssign<&'a Jacobian<C>> for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn add_assign(&mut self, rhs: &'a Self) {
+        *self = self.add_jacobian(rhs);
+    }
+}
+
+impl<C: CurveExt> AddAssign for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = self.add_jacobian(&rhs);
+    }
+}
+
+impl<'a, C: CurveExt> SubAssign<&'a Jacobian<C>> for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn sub_assign(&mut self, rhs: &'a Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<C: CurveExt> SubAssign for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<C: CurveExt> Sum for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), |acc, p| acc + p)
+    }
+}
+
+impl<'a, C: CurveExt> Sum<&'a Jacobian<C>> for Jacobian<C>
+where
+    C::AffineExt: CurveAffine<Base = C::Base>,
+{
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::identity(), |acc, p| acc + p)
+    }
+}
+
+// The scalar multiplication and `Group` impls are written per curve, since
+// generic impls for both `C::ScalarExt` and `&C::ScalarExt` would overlap.
+// The scalar field is given explicitly for the same reason.
+macro_rules! impl_jacobian_group {
+    ($(($curve:ty, $scalar:ty)),*) => {
+        $(
+            impl<'a> Mul<&'a $scalar> for Jacobian<$curve> {
+                type Output = Self;
+
+                fn mul(self, rhs: &'a $scalar) -> Self {
+                    self.mul_vartime(rhs)
+                }
+            }
+
+            impl Mul<$scalar> for Jacobian<$curve> {
+                type Output = Self;
+
+                fn mul(self, rhs: $scalar) -> Self {
+                    self.mul_vartime(&rhs)
+                }
+            }
+
+            impl<'a> MulAssign<&'a $scalar> for Jacobian<$curve> {
+                fn mul_assign(&mut self, rhs: &'a $scalar) {
+                    *self = self.mul_vartime(rhs);
+                }
+            }
+
+            impl MulAssign<$scalar> for Jacobian<$curve> {
+                fn mul_assign(&mut self, rhs: $scalar) {
+                    *self = self.mul_vartime(&rhs);
+                }
+            }
+
+            impl Group for Jacobian<$curve> {
+                type Scalar = $scalar;
+
+                fn random(rng: impl RngCore) -> Self {
+                    <$curve>::random(rng).into()
+                }
+
+                fn identity() -> Self {
+                    Jacobian::identity()
+                }
+
+                fn generator() -> Self {
+                    <$curve>::generator().into()
+                }
+
+                fn is_identity(&self) -> Choice {
+                    self.z.is_zero()
+                }
+
+                fn double(&self) -> Self {
+                    Jacobian::double(self)
+                }
+            }
+        )*
+    };
+}
+
+impl_jacobian_group!(
+    (crate::bn256::G1, crate::bn256::Fr),
+    (crate::bn256::G2, crate::bn256::Fr),
+    (crate::grumpkin::G1, crate::grumpkin::Fr),
+    (crate::pasta::Ep, crate::pasta::Fq),
+    (crate::pasta::Eq, crate::pasta::Fp),
+    (crate::pluto_eris::G1, crate::pluto_eris::Fq),
+    (crate::pluto_eris::G2, crate::pluto_eris::Fq),
+    (crate::pluto_eris::Eris, crate::pluto_eris::Fp),
+    (crate::secp256k1::Secp256k1, crate::secp256k1::Fq),
+    (crate::secp256r1::Secp256r1, crate::secp256r1::Fq),
+    (crate::secq256k1::Secq256k1, crate::secq256k1::Fq)
+);
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::msm::multi_mul2;
+    use rand_core::OsRng;
+
+    fn run_jacobian<C: CurveExt>()
+    where
+        C::AffineExt: CurveAffine<Base = C::Base>,
+        Jacobian<C>: Group<Scalar = C::ScalarExt>,
+    {
+        let identity = Jacobian::<C>::identity();
+        assert!(bool::from(identity.is_identity()));
+        assert_eq!(identity.to_curve(), C::identity());
+        assert_eq!(Jacobian::from(C::identity()), identity);
+        assert_eq!(
+            Jacobian::<C>::from_affine(&C::AffineExt::identity()),
+            identity
+        );
+        assert_eq!(identity.double(), identity);
+
+        for _ in 0..50 {
+            let a = C::random(OsRng);
+            let b = C::random(OsRng);
+            let (ja, jb) = (Jacobian::from(a), Jacobian::from(b));
+            assert_eq!(ja.to_curve(), a);
+            assert_eq!(ja.to_affine(), a.to_affine());
+            assert_eq!(Jacobian::<C>::from_affine(&a.to_affine()), ja);
+
+            assert_eq!((ja + jb).to_curve(), a + b);
+            assert_eq!((ja - jb).to_curve(), a - b);
+            assert_eq!(ja.double().to_curve(), a.double());
+            assert_eq!(ja.add_affine(&b.to_affine()).to_curve(), a + b);
+            assert_eq!(ja + identity, ja);
+            assert_eq!(identity + ja, ja);
+            assert_eq!(ja.add_affine(&C::AffineExt::identity()), ja);
+            assert_eq!(identity.add_affine(&a.to_affine()), ja);
+
+            // Equal and opposite points take the special cases.
+            assert_eq!(ja + ja, ja.double());
+            assert_eq!(
+                ja.double().add_affine(&(a + a).to_affine()),
+                ja.double().double()
+            );
+            assert!(bool::from((ja - Jacobian::from(a)).is_identity()));
+            assert!(bool::from(ja.add_affine(&(-a).to_affine()).is_identity()));
+
+            let s = C::ScalarExt::random(OsRng);
+            let t = C::ScalarExt::random(OsRng);
+            assert_eq!((ja * s).to_curve(), a * s);
+            assert_eq!(multi_mul2(&s, &ja, &t, &jb).to_curve(), a * s + b * t);
+        }
+
+        let points: alloc::vec::Vec<_> = (0..10)
+            .map(|i| {
+                if i == 4 {
+                    identity
+                } else {
+                    Jacobian::<C>::random(OsRng).double()
+                }
+            })
+            .collect();
+        let mut affine = alloc::vec![C::AffineExt::identity(); points.len()];
+        Jacobian::batch_normalize(&points, &mut affine);
+        for (p, q) in points.iter().zip(affine.iter()) {
+            assert_eq!(p.to_curve().to_affine(), *q);
+        }
+    }
+
+    #[test]
+    fn test_jacobian() {
+        run_jacobian::<crate::bn256::G1>();
+        run_jacobian::<crate::bn256::G2>();
+        run_jacobian::<crate::grumpkin::G1>();
+        run_jacobian::<crate::secp256k1::Secp256k1>();
+        run_jacobian::<crate::secp256r1::Secp256r1>();
+        run_jacobian::<crate::pasta::Ep>();
+        run_jacobian::<crate::pluto_eris::G1>();
+    }
+}
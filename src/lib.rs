@@ -1,9 +1,27 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
 mod arithmetic;
+pub mod cpu;
+pub mod ecdh;
+pub mod ecdsa;
 pub mod ff_ext;
 pub mod fft;
+pub mod fixed_base;
+pub mod fp;
 pub mod hash_to_curve;
+pub mod jacobian;
 pub mod msm;
+#[doc(hidden)]
+pub mod multicore;
+pub mod pedersen;
+pub mod secret;
 pub mod serde;
+#[cfg(feature = "shamir")]
+pub mod shamir;
+pub mod soa;
+pub mod subgroup;
 
 pub mod bn256;
 pub mod grumpkin;
@@ -22,5 +40,30 @@ pub use group;
 pub use pairing;
 pub use pasta_curves::arithmetic::{Coordinates, CurveAffine, CurveExt};
 
+pub use arithmetic::CurveEndo;
+
+/// Whether arithmetic validates its operands, see [`debug_validate!`].
+#[doc(hidden)]
+pub const DEBUG_VALIDATE: bool = cfg!(all(feature = "debug-validate", debug_assertions));
+
+/// Items used by the exported macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
+    pub mod alloc {
+        pub use ::alloc::{boxed, vec};
+    }
+    pub use ::lazy_static;
+    pub use ::paste;
+    pub use ::rand;
+    #[cfg(feature = "derive_serde")]
+    pub use ::serde;
+    #[cfg(feature = "derive_serde")]
+    pub use ::serde_arrays;
+    pub use ::subtle;
+    #[cfg(feature = "zeroize")]
+    pub use ::zeroize;
+}
+
 #[cfg(test)]
 pub mod tests;
@@ -1,12 +1,14 @@
-use std::ops::Neg;
-
-use crate::CurveAffine;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::ops::Neg;
+
+use crate::cpu::Codegen;
+use crate::multicore::*;
+use crate::soa::SoAPoints;
+use crate::{CurveAffine, CurveEndo, CurveExt};
 use ff::Field;
 use ff::PrimeField;
-use group::Group;
-use rayon::iter::{
-    IndexedParallelIterator, IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
-};
+use group::{Curve, Group};
 
 const BATCH_SIZE: usize = 64;
 
@@ -287,17 +289,81 @@ impl<C: CurveAffine> Schedule<C> {
 }
 
 pub fn multiexp_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &mut C::Curve) {
+    multiexp_serial_with(crate::cpu::codegen(), coeffs, bases, acc)
+}
+
+/// [`multiexp_serial`] with its loops compiled for `codegen`, or for the
+/// baseline target if the running CPU does not support it.
+///
+/// The parallel routines resolve the codegen once for all their chunks. This
+/// also lets benchmarks compare the variants on the same machine.
+pub fn multiexp_serial_with<C: CurveAffine>(
+    codegen: Codegen,
+    coeffs: &[C::Scalar],
+    bases: &[C],
+    acc: &mut C::Curve,
+) {
+    let codegen = codegen.min(crate::cpu::codegen());
     let coeffs: Vec<_> = coeffs.iter().map(|a| a.to_repr()).collect();
+    multiexp_serial_repr(codegen, &coeffs, C::Scalar::NUM_BITS as usize, bases, acc)
+}
+
+// Performs a multi-exponentiation of little-endian scalars of at most
+// `num_bits` bits.
+fn multiexp_serial_repr<C: CurveAffine, K: AsRef<[u8]>>(
+    codegen: Codegen,
+    coeffs: &[K],
+    num_bits: usize,
+    bases: &[C],
+    acc: &mut C::Curve,
+) {
+    match codegen {
+        #[cfg(target_arch = "x86_64")]
+        // Safety: `Bmi2Adx` is only selected when the CPU supports both features.
+        Codegen::Bmi2Adx => unsafe { multiexp_serial_bmi2_adx(coeffs, num_bits, bases, acc) },
+        _ => multiexp_serial_inner(coeffs, num_bits, bases, acc),
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+#[target_feature(enable = "bmi2,adx")]
+unsafe fn multiexp_serial_bmi2_adx<C: CurveAffine, K: AsRef<[u8]>>(
+    coeffs: &[K],
+    num_bits: usize,
+    bases: &[C],
+    acc: &mut C::Curve,
+) {
+    multiexp_serial_inner(coeffs, num_bits, bases, acc)
+}
 
+// `floor(e^k)` for `k = 1..=22`, the powers of `e` below `2^32`.
+const EXP_FLOORS: [u32; 22] = [
+    2, 7, 20, 54, 148, 403, 1096, 2980, 8103, 22026, 59874, 162754, 442413, 1202604, 3269017,
+    8886110, 24154952, 65659969, 178482300, 485165195, 1318815734, 3584912846,
+];
+
+// Returns `ceil(ln(n))` for `n > 1` without floating point arithmetic, which
+// is unavailable without `std`.
+fn ceil_ln(n: u32) -> usize {
+    1 + EXP_FLOORS.iter().take_while(|e| **e < n).count()
+}
+
+#[inline(always)]
+fn multiexp_serial_inner<C: CurveAffine, K: AsRef<[u8]>>(
+    coeffs: &[K],
+    num_bits: usize,
+    bases: &[C],
+    acc: &mut C::Curve,
+) {
     let c = if bases.len() < 4 {
         1
     } else if bases.len() < 32 {
         3
     } else {
-        (f64::from(bases.len() as u32)).ln().ceil() as usize
+        ceil_ln(bases.len() as u32)
     };
 
-    let number_of_windows = C::Scalar::NUM_BITS as usize / c + 1;
+    let number_of_windows = num_bits / c + 1;
 
     for current_window in (0..number_of_windows).rev() {
         for _ in 0..c {
@@ -367,12 +433,13 @@ pub fn multiexp_serial<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C], acc: &
 pub fn best_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve {
     assert_eq!(coeffs.len(), bases.len());
 
-    let num_threads = rayon::current_num_threads();
+    let codegen = crate::cpu::codegen();
+    let num_threads = crate::multicore::current_num_threads();
     if coeffs.len() > num_threads {
         let chunk = coeffs.len() / num_threads;
         let num_chunks = coeffs.chunks(chunk).len();
         let mut results = vec![C::Curve::identity(); num_chunks];
-        rayon::scope(|scope| {
+        crate::multicore::scope(|scope| {
             let chunk = coeffs.len() / num_threads;
 
             for ((coeffs, bases), acc) in coeffs
@@ -381,17 +448,126 @@ pub fn best_multiexp<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Cu
                 .zip(results.iter_mut())
             {
                 scope.spawn(move |_| {
-                    multiexp_serial(coeffs, bases, acc);
+                    multiexp_serial_with(codegen, coeffs, bases, acc);
+                });
+            }
+        });
+        results.iter().fold(C::Curve::identity(), |a, b| a + b)
+    } else {
+        let mut acc = C::Curve::identity();
+        multiexp_serial_with(codegen, coeffs, bases, &mut acc);
+        acc
+    }
+}
+
+/// Performs a multi-exponentiation, splitting the scalars along the
+/// endomorphism of the curve.
+///
+/// Each scalar `k` is decomposed into two scalars of at most 128 bits with
+/// [`CurveEndo::decompose_scalar`], `k = k1 + k2 * ZETA` up to signs, and each
+/// base `P` is paired with its image `ZETA * P`. The images are computed with
+/// [`CurveExt::endo`] on the projective points, one base field multiplication
+/// each, then normalized back to affine in one batch, which shares a single
+/// inversion across all of them. The bucket method then runs over twice the
+/// bases but half the windows.
+///
+/// This function will panic if coeffs and bases have a different length.
+///
+/// This will use multithreading if beneficial.
+pub fn best_multiexp_glv<C: CurveAffine>(coeffs: &[C::Scalar], bases: &[C]) -> C::Curve
+where
+    C::CurveExt: CurveEndo,
+{
+    assert_eq!(coeffs.len(), bases.len());
+
+    let endo_bases: Vec<_> = bases.par_iter().map(|p| p.to_curve().endo()).collect();
+    let mut endo_bases_affine = vec![C::identity(); bases.len()];
+    C::Curve::batch_normalize(&endo_bases, &mut endo_bases_affine);
+
+    // The signs of the halves are moved to their bases.
+    let (coeffs, bases): (Vec<_>, Vec<_>) = coeffs
+        .par_iter()
+        .zip(bases.par_iter())
+        .zip(endo_bases_affine.par_iter())
+        .map(|((k, p), q)| {
+            let (k1, k1_neg, k2, k2_neg) = C::CurveExt::decompose_scalar(k);
+            let p = if k1_neg { -*p } else { *p };
+            let q = if k2_neg { *q } else { -*q };
+            [(k1.to_le_bytes(), p), (k2.to_le_bytes(), q)]
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .flatten()
+        .unzip();
+
+    let codegen = crate::cpu::codegen();
+    let num_threads = crate::multicore::current_num_threads();
+    if coeffs.len() > num_threads {
+        let chunk = coeffs.len() / num_threads;
+        let num_chunks = coeffs.chunks(chunk).len();
+        let mut results = vec![C::Curve::identity(); num_chunks];
+        crate::multicore::scope(|scope| {
+            for ((coeffs, bases), acc) in coeffs
+                .chunks(chunk)
+                .zip(bases.chunks(chunk))
+                .zip(results.iter_mut())
+            {
+                scope.spawn(move |_| {
+                    multiexp_serial_repr(codegen, coeffs, 128, bases, acc);
                 });
             }
         });
         results.iter().fold(C::Curve::identity(), |a, b| a + b)
     } else {
         let mut acc = C::Curve::identity();
-        multiexp_serial(coeffs, bases, &mut acc);
+        multiexp_serial_repr(codegen, &coeffs, 128, &bases, &mut acc);
         acc
     }
 }
+
+// Points of a `SoAPoints` are rebuilt in chunks of this size, which keeps
+// the extra memory bounded while the per-chunk bucket sums stay negligible.
+const SOA_CHUNK_SIZE: usize = 1 << 14;
+
+/// Performs a multi-exponentiation over points stored as separate coordinate
+/// arrays.
+///
+/// Only one chunk of points per thread is materialized at a time, rather than
+/// the whole array.
+///
+/// This function will panic if coeffs and bases have a different length.
+pub fn best_multiexp_soa<C: CurveAffine>(coeffs: &[C::Scalar], bases: &SoAPoints<C>) -> C::Curve {
+    assert_eq!(coeffs.len(), bases.len());
+
+    let codegen = crate::cpu::codegen();
+    let num_threads = crate::multicore::current_num_threads();
+    let chunk = (coeffs.len() / num_threads).clamp(1, SOA_CHUNK_SIZE);
+    let multiexp_chunk = |points: &mut Vec<C>, (i, coeffs): (usize, &[C::Scalar])| {
+        let start = i * chunk;
+        bases.copy_range_to(start..start + coeffs.len(), points);
+        let mut acc = C::Curve::identity();
+        multiexp_serial_with(codegen, coeffs, points, &mut acc);
+        acc
+    };
+
+    #[cfg(feature = "std")]
+    let acc = coeffs
+        .par_chunks(chunk)
+        .enumerate()
+        .map_init(Vec::new, multiexp_chunk)
+        .reduce(C::Curve::identity, |a, b| a + b);
+    #[cfg(not(feature = "std"))]
+    let acc = {
+        let mut points = Vec::new();
+        coeffs
+            .chunks(chunk)
+            .enumerate()
+            .map(|chunk| multiexp_chunk(&mut points, chunk))
+            .fold(C::Curve::identity(), |a, b| a + b)
+    };
+    acc
+}
+
 ///
 /// This function will panic if coeffs and bases have a different length.
 ///
@@ -408,7 +584,7 @@ pub fn best_multiexp_independent_points<C: CurveAffine>(
     } else if bases.len() < 32 {
         3
     } else {
-        (f64::from(bases.len() as u32)).ln().ceil() as usize
+        ceil_ln(bases.len() as u32)
     };
 
     if c < 10 {
@@ -471,10 +647,261 @@ pub fn best_multiexp_independent_points<C: CurveAffine>(
     acc.into_iter().sum::<_>()
 }
 
+const MULTI_MUL2_WINDOW: usize = 5;
+
+/// Computes the width-`w` non-adjacent form of a little-endian scalar
+/// representation, least significant digit first.
+pub(crate) fn wnaf_form(repr: &[u8], w: usize) -> Vec<i64> {
+    let limbs: Vec<u64> = repr
+        .chunks(8)
+        .map(|chunk| {
+            let mut buf = [0u8; 8];
+            buf[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(buf)
+        })
+        .collect();
+
+    let bit_len = limbs.len() * 64;
+    let width = 1u64 << w;
+    let window_mask = width - 1;
+
+    let mut wnaf = Vec::with_capacity(bit_len + 1);
+    let mut pos = 0;
+    let mut carry = 0;
+    while pos < bit_len {
+        let u64_idx = pos / 64;
+        let bit_idx = pos % 64;
+        let bit_buf = if bit_idx + w < 64 {
+            limbs[u64_idx] >> bit_idx
+        } else {
+            (limbs[u64_idx] >> bit_idx) | (limbs.get(u64_idx + 1).unwrap_or(&0) << (64 - bit_idx))
+        };
+
+        let window_val = carry + (bit_buf & window_mask);
+        if window_val & 1 == 0 {
+            wnaf.push(0);
+            pos += 1;
+        } else {
+            wnaf.push(if window_val < width / 2 {
+                carry = 0;
+                window_val as i64
+            } else {
+                carry = 1;
+                window_val as i64 - width as i64
+            });
+            wnaf.extend(core::iter::repeat(0).take(w - 1));
+            pos += w;
+        }
+    }
+    if carry == 1 {
+        wnaf.push(1);
+    }
+    wnaf
+}
+
+/// Returns the odd multiples `p, 3p, ..., (2^(w-1) - 1)p`.
+fn odd_multiples<G: Group>(p: &G, w: usize) -> Vec<G> {
+    let double = p.double();
+    let mut table = Vec::with_capacity(1 << (w - 2));
+    table.push(*p);
+    for i in 1..1 << (w - 2) {
+        table.push(table[i - 1] + double);
+    }
+    table
+}
+
+/// Computes `a * p + b * q` using Shamir's trick: both scalars are recoded
+/// in wNAF form and processed together, so the doublings are shared.
+///
+/// This function runs in variable time and must only be used with public
+/// scalars, e.g. for signature or proof verification.
+pub fn multi_mul2<G: Group>(a: &G::Scalar, p: &G, b: &G::Scalar, q: &G) -> G {
+    let a = wnaf_form(a.to_repr().as_ref(), MULTI_MUL2_WINDOW);
+    let b = wnaf_form(b.to_repr().as_ref(), MULTI_MUL2_WINDOW);
+    let table_p = odd_multiples(p, MULTI_MUL2_WINDOW);
+    let table_q = odd_multiples(q, MULTI_MUL2_WINDOW);
+
+    let mut acc = G::identity();
+    for i in (0..a.len().max(b.len())).rev() {
+        acc = acc.double();
+        for (wnaf, table) in [(&a, &table_p), (&b, &table_q)] {
+            let digit = wnaf.get(i).copied().unwrap_or(0);
+            if digit > 0 {
+                acc += table[(digit / 2) as usize];
+            } else if digit < 0 {
+                acc -= table[(-digit / 2) as usize];
+            }
+        }
+    }
+    acc
+}
+
+const BATCH_MUL_WINDOW: usize = 5;
+const BATCH_MUL_CT_WINDOW: usize = 4;
+const BATCH_MUL_CHUNK_SIZE: usize = 1 << 10;
+
+/// Computes the products `scalars[i] * points[i]` independently, as opposed
+/// to their sum, in constant time with respect to the scalars.
+///
+/// This is the function to rerandomize commitments with secret blinding
+/// factors. Each product is a fixed-window multiplication whose table entries
+/// are selected with a full scan, and the products are normalized with the
+/// constant-time [`invert_ct`](crate::ff_ext::invert_ct). The tables only
+/// depend on the points, so the tables of all the points of a chunk are
+/// normalized together with a single inversion and the main loops use mixed
+/// additions. Chunks are processed in parallel.
+///
+/// This function will panic if points and scalars have a different length.
+pub fn batch_mul<C: CurveAffine>(points: &[C], scalars: &[C::Scalar]) -> Vec<C> {
+    batch_mul_chunks(points, scalars, batch_mul_serial)
+}
+
+/// Computes the products `scalars[i] * points[i]` independently, like
+/// [`batch_mul`], with width-5 wNAF tables instead of fixed windows.
+///
+/// This function runs in variable time: the digits of the scalars decide
+/// which table entries are read and which additions happen, so it leaks the
+/// scalars and is unusable for rerandomization with secret blinding factors.
+/// It must only be used with public scalars. It will panic if points and
+/// scalars have a different length.
+pub fn batch_mul_vartime<C: CurveAffine>(points: &[C], scalars: &[C::Scalar]) -> Vec<C> {
+    batch_mul_chunks(points, scalars, batch_mul_vartime_serial)
+}
+
+fn batch_mul_chunks<C: CurveAffine>(
+    points: &[C],
+    scalars: &[C::Scalar],
+    serial: fn(&[C], &[C::Scalar]) -> Vec<C>,
+) -> Vec<C> {
+    assert_eq!(points.len(), scalars.len());
+
+    let num_threads = current_num_threads();
+    let chunk_size =
+        ((points.len() + num_threads - 1) / num_threads).clamp(1, BATCH_MUL_CHUNK_SIZE);
+    points
+        .par_chunks(chunk_size)
+        .enumerate()
+        .map(|(i, points)| {
+            let scalars = &scalars[i * chunk_size..i * chunk_size + points.len()];
+            serial(points, scalars)
+        })
+        .collect::<Vec<_>>()
+        .concat()
+}
+
+fn batch_mul_serial<C: CurveAffine>(points: &[C], scalars: &[C::Scalar]) -> Vec<C> {
+    use subtle::ConstantTimeEq;
+
+    // The multiples `0, P, 2P, ..., 15P` of every point.
+    let table_size = 1 << BATCH_MUL_CT_WINDOW;
+    let tables: Vec<_> = points
+        .iter()
+        .flat_map(|p| {
+            let p = p.to_curve();
+            core::iter::successors(Some(C::Curve::identity()), move |acc| Some(*acc + p))
+                .take(table_size)
+        })
+        .collect();
+    let mut tables_affine = vec![C::identity(); tables.len()];
+    C::Curve::batch_normalize(&tables, &mut tables_affine);
+
+    let products: Vec<_> = scalars
+        .iter()
+        .zip(tables_affine.chunks(table_size))
+        .map(|(scalar, table)| {
+            let repr = scalar.to_repr();
+            let mut acc = C::Curve::identity();
+            for byte in repr.as_ref().iter().rev() {
+                for digit in [byte >> 4, byte & 0xf] {
+                    for _ in 0..BATCH_MUL_CT_WINDOW {
+                        acc = acc.double();
+                    }
+                    let mut entry = C::identity();
+                    for (j, point) in table.iter().enumerate() {
+                        entry.conditional_assign(point, digit.ct_eq(&(j as u8)));
+                    }
+                    acc += entry;
+                }
+            }
+            acc
+        })
+        .collect();
+    normalize_ct(&products)
+}
+
+// Normalizes `points` with Montgomery's trick and a single constant-time
+// inversion. The coordinates are divided in the Jacobian form returned by
+// `jacobian_coordinates`, so that the final `batch_normalize` only inverts
+// `Z`s equal to one.
+fn normalize_ct<C: CurveAffine>(points: &[C::Curve]) -> Vec<C> {
+    use subtle::ConditionallySelectable;
+
+    let coordinates: Vec<_> = points.iter().map(|p| p.jacobian_coordinates()).collect();
+    let mut prefix = Vec::with_capacity(points.len());
+    let mut acc = <C::Curve as CurveExt>::Base::ONE;
+    for (_, _, z) in coordinates.iter() {
+        prefix.push(acc);
+        acc = ConditionallySelectable::conditional_select(&(acc * z), &acc, z.is_zero());
+    }
+    let mut acc_inv = crate::ff_ext::invert_ct(&acc).unwrap();
+    let mut normalized = vec![C::Curve::identity(); points.len()];
+    for (((x, y, z), prefix), p) in coordinates
+        .iter()
+        .zip(prefix.iter())
+        .zip(normalized.iter_mut())
+        .rev()
+    {
+        let z_inv = acc_inv * prefix;
+        acc_inv =
+            ConditionallySelectable::conditional_select(&(acc_inv * z), &acc_inv, z.is_zero());
+        let z_inv2 = z_inv.square();
+        let one = ConditionallySelectable::conditional_select(
+            &<C::Curve as CurveExt>::Base::ONE,
+            &<C::Curve as CurveExt>::Base::ZERO,
+            z.is_zero(),
+        );
+        *p = C::Curve::new_jacobian(*x * z_inv2, *y * z_inv2 * z_inv, one).unwrap();
+    }
+    let mut out = vec![C::identity(); points.len()];
+    C::Curve::batch_normalize(&normalized, &mut out);
+    out
+}
+
+fn batch_mul_vartime_serial<C: CurveAffine>(points: &[C], scalars: &[C::Scalar]) -> Vec<C> {
+    let table_size = 1 << (BATCH_MUL_WINDOW - 2);
+    let tables: Vec<_> = points
+        .iter()
+        .flat_map(|p| odd_multiples(&p.to_curve(), BATCH_MUL_WINDOW))
+        .collect();
+    let mut tables_affine = vec![C::identity(); tables.len()];
+    C::Curve::batch_normalize(&tables, &mut tables_affine);
+
+    let products: Vec<_> = scalars
+        .iter()
+        .zip(tables_affine.chunks(table_size))
+        .map(|(scalar, table)| {
+            let wnaf = wnaf_form(scalar.to_repr().as_ref(), BATCH_MUL_WINDOW);
+            let mut acc = C::Curve::identity();
+            for digit in wnaf.iter().rev() {
+                acc = acc.double();
+                if *digit > 0 {
+                    acc += table[(digit / 2) as usize];
+                } else if *digit < 0 {
+                    acc -= table[(-digit / 2) as usize];
+                }
+            }
+            acc
+        })
+        .collect();
+    let mut out = vec![C::identity(); products.len()];
+    C::Curve::batch_normalize(&products, &mut out);
+    out
+}
+
 #[cfg(test)]
 mod test {
 
-    use std::ops::Neg;
+    use core::ops::Neg;
 
     use crate::bn256::{Fr, G1Affine, G1};
     use ark_std::{end_timer, start_timer};
@@ -483,6 +910,43 @@ mod test {
     use pasta_curves::arithmetic::CurveAffine;
     use rand_core::OsRng;
 
+    #[test]
+    fn test_batch_mul() {
+        fn run<C: CurveAffine>(n: usize) {
+            let mut points: Vec<C> = (0..n)
+                .map(|_| C::Curve::random(OsRng).to_affine())
+                .collect();
+            let mut scalars: Vec<_> = (0..n).map(|_| C::Scalar::random(OsRng)).collect();
+            if n > 2 {
+                points[0] = C::identity();
+                scalars[1] = C::Scalar::ZERO;
+                scalars[2] = -C::Scalar::ONE;
+            }
+            let expected: Vec<_> = points
+                .iter()
+                .zip(scalars.iter())
+                .map(|(p, s)| (*p * s).to_affine())
+                .collect();
+            assert_eq!(super::batch_mul(&points, &scalars), expected);
+            assert_eq!(super::batch_mul_vartime(&points, &scalars), expected);
+        }
+
+        for n in [0, 1, 7, 3000] {
+            run::<G1Affine>(n);
+        }
+        run::<crate::secp256k1::Secp256k1Affine>(20);
+        run::<crate::pasta::EpAffine>(20);
+    }
+
+    #[test]
+    fn test_ceil_ln() {
+        let boundaries = super::EXP_FLOORS.iter().flat_map(|e| [*e, *e + 1]);
+        for n in (2..10_000).chain(boundaries) {
+            assert_eq!(super::ceil_ln(n), f64::from(n).ln().ceil() as usize);
+        }
+        assert_eq!(super::ceil_ln(u32::MAX), 23);
+    }
+
     #[test]
     fn test_booth_encoding() {
         fn mul(scalar: &Fr, point: &G1Affine, window: usize) -> G1Affine {
@@ -556,6 +1020,59 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_multiexp_backend() {
+        let (scalars, points): (Vec<_>, Vec<_>) = (0..1 << 8)
+            .map(|_| (Fr::random(OsRng), G1Affine::random(OsRng)))
+            .unzip();
+
+        let mut e0 = G1::identity();
+        super::multiexp_serial(&scalars, &points, &mut e0);
+        let mut e1 = G1::identity();
+        let reprs: Vec<_> = scalars.iter().map(|a| a.to_repr()).collect();
+        super::multiexp_serial_inner(&reprs, Fr::NUM_BITS as usize, &points, &mut e1);
+        assert_eq!(e0, e1);
+    }
+
+    fn run_msm_glv<C: CurveAffine>()
+    where
+        C::CurveExt: crate::CurveEndo,
+    {
+        for k in [0, 1, 2, 3, 5, 8, 12] {
+            let (mut scalars, points): (Vec<_>, Vec<_>) = (0..1 << k)
+                .map(|_| {
+                    (
+                        C::Scalar::random(OsRng),
+                        C::Curve::random(OsRng).to_affine(),
+                    )
+                })
+                .unzip();
+            for (scalar, edge) in
+                scalars
+                    .iter_mut()
+                    .zip([C::Scalar::ZERO, C::Scalar::ONE, -C::Scalar::ONE])
+            {
+                *scalar = edge;
+            }
+            assert_eq!(
+                super::best_multiexp_glv(&scalars, &points),
+                super::best_multiexp(&scalars, &points)
+            );
+        }
+        assert_eq!(
+            super::best_multiexp_glv::<C>(&[], &[]),
+            C::Curve::identity()
+        );
+    }
+
+    #[test]
+    fn test_msm_glv() {
+        run_msm_glv::<G1Affine>();
+        run_msm_glv::<crate::grumpkin::G1Affine>();
+        run_msm_glv::<crate::pasta::EpAffine>();
+        run_msm_glv::<crate::pasta::EqAffine>();
+    }
+
     #[test]
     fn test_msm_cross() {
         run_msm_cross::<G1Affine>(14, 22);
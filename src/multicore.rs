@@ -0,0 +1,71 @@
+//! Parallel iteration that falls back to serial iteration without `std`.
+//!
+//! With the `std` feature this re-exports the parts of rayon used by the
+//! crate. Without it, the `par_*` methods return the serial iterators of the
+//! slice, the thread count is one, and `join` and `scope` run their closures on
+//! the calling thread.
+
+#[cfg(feature = "std")]
+pub use rayon::{current_num_threads, join, prelude::*, scope};
+
+#[cfg(not(feature = "std"))]
+pub use serial::*;
+
+#[cfg(not(feature = "std"))]
+mod serial {
+    use core::slice::{Chunks, ChunksMut, Iter, IterMut};
+
+    pub fn current_num_threads() -> usize {
+        1
+    }
+
+    pub fn join<A, B, RA, RB>(a: A, b: B) -> (RA, RB)
+    where
+        A: FnOnce() -> RA,
+        B: FnOnce() -> RB,
+    {
+        (a(), b())
+    }
+
+    pub struct Scope(());
+
+    impl Scope {
+        pub fn spawn<F: FnOnce(&Scope)>(&self, f: F) {
+            f(self)
+        }
+    }
+
+    pub fn scope<R, F: FnOnce(&Scope) -> R>(f: F) -> R {
+        f(&Scope(()))
+    }
+
+    pub trait ParallelSlice<T> {
+        fn par_iter(&self) -> Iter<'_, T>;
+        fn par_chunks(&self, chunk_size: usize) -> Chunks<'_, T>;
+    }
+
+    impl<T> ParallelSlice<T> for [T] {
+        fn par_iter(&self) -> Iter<'_, T> {
+            self.iter()
+        }
+
+        fn par_chunks(&self, chunk_size: usize) -> Chunks<'_, T> {
+            self.chunks(chunk_size)
+        }
+    }
+
+    pub trait ParallelSliceMut<T> {
+        fn par_iter_mut(&mut self) -> IterMut<'_, T>;
+        fn par_chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_, T>;
+    }
+
+    impl<T> ParallelSliceMut<T> for [T] {
+        fn par_iter_mut(&mut self) -> IterMut<'_, T> {
+            self.iter_mut()
+        }
+
+        fn par_chunks_mut(&mut self, chunk_size: usize) -> ChunksMut<'_, T> {
+            self.chunks_mut(chunk_size)
+        }
+    }
+}
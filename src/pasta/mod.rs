@@ -1,13 +1,19 @@
+//! The Pallas and Vesta curves.
+//!
+//! The curve and field types are re-exported from `pasta_curves` rather than
+//! redefined, so values are shared with code using that crate directly and
+//! need no conversion. This module adds the GLV endomorphism parameters.
+
 use crate::arithmetic::mul_512;
 use crate::arithmetic::sbb;
 use crate::{
     arithmetic::{CurveEndo, EndoParameters},
     endo,
 };
+use core::convert::TryInto;
 use ff::PrimeField;
 use ff::WithSmallOrderMulGroup;
 pub use pasta_curves::{pallas, vesta, Ep, EpAffine, Eq, EqAffine, Fp, Fq};
-use std::convert::TryInto;
 
 // Generated using https://github.com/ConsenSys/gnark-crypto/blob/master/ecc/utils.go
 // with `pasta_curves::Fp::ZETA`
@@ -38,6 +44,9 @@ const ENDO_PARAMS_EP: EndoParameters = EndoParameters {
 endo!(Eq, Fp, ENDO_PARAMS_EQ);
 endo!(Ep, Fq, ENDO_PARAMS_EP);
 
+crate::impl_extension_field!(Fp);
+crate::impl_extension_field!(Fq);
+
 #[test]
 fn test_endo() {
     use ff::Field;
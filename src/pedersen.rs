@@ -0,0 +1,232 @@
+//! Pedersen vector commitments.
+//!
+//! A commitment to the values `v_1, ..., v_n` with blinding factor `r` is
+//! `r H + v_1 G_1 + ... + v_n G_n`, where `H` is the blinding generator and
+//! `G_1, ..., G_n` the value generators. It is hiding when `r` is uniformly
+//! random, and binding as long as nobody knows a discrete logarithm relation
+//! between the generators, which holds for the generators of
+//! [`PedersenCommitter::derive`].
+
+use alloc::vec;
+use alloc::vec::Vec;
+use ff::Field;
+use group::{Curve, Group};
+use rand_core::RngCore;
+
+use crate::hash_to_curve::derive_generators;
+use crate::msm::best_multiexp;
+use crate::CurveAffine;
+
+/// The opening of a Pedersen commitment: the committed values and the
+/// blinding factor.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Opening<F> {
+    /// The committed values.
+    pub values: Vec<F>,
+    /// The blinding factor.
+    pub blind: F,
+}
+
+/// Computes and verifies Pedersen commitments to vectors of up to
+/// [`len`](Self::len) scalars.
+#[derive(Clone, Debug)]
+pub struct PedersenCommitter<C: CurveAffine> {
+    generators: Vec<C>,
+    blinding_generator: C,
+}
+
+impl<C: CurveAffine> PedersenCommitter<C> {
+    /// Returns the committer with the given value generators and blinding
+    /// generator.
+    ///
+    /// The commitments are only binding if no discrete logarithm relation
+    /// between these points is known.
+    pub fn new(generators: Vec<C>, blinding_generator: C) -> Self {
+        Self {
+            generators,
+            blinding_generator,
+        }
+    }
+
+    /// Derives a committer to vectors of `len` scalars from `label` with
+    /// [`derive_generators`]: the blinding generator is the generator of index
+    /// `0`, and the value generators those of index `1` to `len`.
+    ///
+    /// Committers derived from the same label with different lengths share
+    /// their generators, so a commitment to a short vector is also one to the
+    /// vector padded with zeros.
+    pub fn derive(label: &[u8], len: usize) -> Self {
+        let mut generators = derive_generators::<C::CurveExt>(label, len + 1);
+        let blinding_generator = generators.remove(0);
+        Self::new(generators, blinding_generator)
+    }
+
+    /// Returns the value generators.
+    pub fn generators(&self) -> &[C] {
+        &self.generators
+    }
+
+    /// Returns the blinding generator.
+    pub fn blinding_generator(&self) -> &C {
+        &self.blinding_generator
+    }
+
+    /// Returns the maximum number of values in a commitment.
+    pub fn len(&self) -> usize {
+        self.generators.len()
+    }
+
+    /// Returns whether the committer has no value generator, in which case
+    /// it only commits to the blinding factor.
+    pub fn is_empty(&self) -> bool {
+        self.generators.is_empty()
+    }
+
+    /// Commits to `values` with the blinding factor `blind`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more values than generators.
+    pub fn commit(&self, values: &[C::Scalar], blind: &C::Scalar) -> C::CurveExt {
+        assert!(values.len() <= self.len(), "too many values to commit to");
+
+        let mut coeffs = Vec::with_capacity(values.len() + 1);
+        coeffs.push(*blind);
+        coeffs.extend_from_slice(values);
+        let mut bases = Vec::with_capacity(values.len() + 1);
+        bases.push(self.blinding_generator);
+        bases.extend_from_slice(&self.generators[..values.len()]);
+        best_multiexp(&coeffs, &bases)
+    }
+
+    /// Returns whether `opening` opens `commitment`.
+    pub fn verify(&self, commitment: &C, opening: &Opening<C::Scalar>) -> bool {
+        opening.values.len() <= self.len()
+            && self.commit(&opening.values, &opening.blind).to_affine() == *commitment
+    }
+
+    /// Returns whether every one of `openings` opens the commitment of the
+    /// same index in `commitments`, with a single multi-exponentiation over
+    /// a random linear combination of the openings.
+    ///
+    /// A `true` result is wrong with probability at most `2^-128`, and a
+    /// `false` result is always correct.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `commitments` and `openings` have a different length.
+    pub fn batch_verify(
+        &self,
+        commitments: &[C],
+        openings: &[Opening<C::Scalar>],
+        mut rng: impl RngCore,
+    ) -> bool {
+        assert_eq!(commitments.len(), openings.len());
+        if openings
+            .iter()
+            .any(|opening| opening.values.len() > self.len())
+        {
+            return false;
+        }
+
+        // sum_i c_i (r_i H + sum_j v_ij G_j - C_i) = 0, where the 128-bit
+        // coefficients c_i make the combination unpredictable.
+        let shift = C::Scalar::from(1 << 32).square();
+        let mut blind = C::Scalar::ZERO;
+        let mut values = vec![C::Scalar::ZERO; self.len()];
+        let mut coeffs = Vec::with_capacity(self.len() + 1 + commitments.len());
+        for opening in openings {
+            let hi = C::Scalar::from(rng.next_u64());
+            let lo = C::Scalar::from(rng.next_u64());
+            let c = hi * shift + lo;
+            blind += c * opening.blind;
+            for (acc, value) in values.iter_mut().zip(&opening.values) {
+                *acc += c * value;
+            }
+            coeffs.push(-c);
+        }
+        coeffs.push(blind);
+        coeffs.extend(values);
+
+        let mut bases = Vec::with_capacity(coeffs.len());
+        bases.extend_from_slice(commitments);
+        bases.push(self.blinding_generator);
+        bases.extend_from_slice(&self.generators);
+        bool::from(best_multiexp(&coeffs, &bases).is_identity())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::hash_to_curve::derive_generator;
+    use rand_core::OsRng;
+
+    fn check<C: CurveAffine>() {
+        let committer = PedersenCommitter::<C>::derive(b"test", 8);
+        assert_eq!(committer.len(), 8);
+        assert_eq!(
+            committer.blinding_generator().to_curve(),
+            derive_generator::<C::CurveExt>(b"test", 0)
+        );
+        for (i, g) in committer.generators().iter().enumerate() {
+            assert_eq!(
+                g.to_curve(),
+                derive_generator::<C::CurveExt>(b"test", i as u64 + 1)
+            );
+        }
+        // Shorter committers share their generators.
+        let short = PedersenCommitter::<C>::derive(b"test", 3);
+        assert_eq!(short.generators(), &committer.generators()[..3]);
+        assert_eq!(short.blinding_generator(), committer.blinding_generator());
+
+        let random_opening = |len: usize| Opening {
+            values: (0..len).map(|_| C::Scalar::random(OsRng)).collect(),
+            blind: C::Scalar::random(OsRng),
+        };
+        let openings: Vec<_> = (0..=8).map(random_opening).collect();
+        let commitments: Vec<C> = openings
+            .iter()
+            .map(|o| committer.commit(&o.values, &o.blind).to_affine())
+            .collect();
+        for (commitment, opening) in commitments.iter().zip(&openings) {
+            assert!(committer.verify(commitment, opening));
+        }
+        assert!(committer.batch_verify(&commitments, &openings, OsRng));
+        assert!(committer.batch_verify(&[], &[], OsRng));
+
+        // Commitments are additively homomorphic.
+        let (a, b) = (&openings[8], random_opening(8));
+        let sum = Opening {
+            values: a
+                .values
+                .iter()
+                .zip(&b.values)
+                .map(|(x, y)| *x + y)
+                .collect(),
+            blind: a.blind + b.blind,
+        };
+        let sum_commitment =
+            (commitments[8].to_curve() + committer.commit(&b.values, &b.blind)).to_affine();
+        assert!(committer.verify(&sum_commitment, &sum));
+
+        // Wrong openings.
+        let mut wrong = openings.clone();
+        wrong[5].values[2] += C::Scalar::ONE;
+        assert!(!committer.verify(&commitments[5], &wrong[5]));
+        assert!(!committer.batch_verify(&commitments, &wrong, OsRng));
+        let mut wrong = openings.clone();
+        wrong[0].blind += C::Scalar::ONE;
+        assert!(!committer.batch_verify(&commitments, &wrong, OsRng));
+        let too_long = random_opening(9);
+        assert!(!committer.verify(&commitments[8], &too_long));
+        assert!(!committer.batch_verify(&commitments[..1], &[too_long], OsRng));
+    }
+
+    #[test]
+    fn test_pedersen() {
+        check::<crate::bn256::G1Affine>();
+        check::<crate::grumpkin::G1Affine>();
+        check::<crate::secp256k1::Secp256k1Affine>();
+    }
+}
@@ -0,0 +1,427 @@
+//! Constants of the `pluto_eris` fields and curves.
+//!
+//! Generated by `script/constants.py` from `script/params/pluto_eris.toml`, do not
+//! modify. Building with the `generate-constants` feature regenerates it.
+
+use crate::pluto_eris::Fp;
+use crate::pluto_eris::Fq;
+
+pub(crate) mod fp {
+    use crate::pluto_eris::Fp;
+
+    /// Constant representing the modulus
+    /// `0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000001`
+    pub(crate) const MODULUS: Fp = Fp([
+        0x9ffffcd300000001,
+        0xa2a7e8c30006b945,
+        0xe4a7a5fe8fadffd6,
+        0x443f9a5cda8a6c7b,
+        0xa803ca76f439266f,
+        0x0130e0000d7f70e4,
+        0x2400000000002400,
+    ]);
+
+    /// The modulus as u32 limbs.
+    #[cfg(not(target_pointer_width = "64"))]
+    pub(crate) const MODULUS_LIMBS_32: [u32; 14] = [
+        0x0000_0001,
+        0x9fff_fcd3,
+        0x0006_b945,
+        0xa2a7_e8c3,
+        0x8fad_ffd6,
+        0xe4a7_a5fe,
+        0xda8a_6c7b,
+        0x443f_9a5c,
+        0xf439_266f,
+        0xa803_ca76,
+        0x0d7f_70e4,
+        0x0130_e000,
+        0x0000_2400,
+        0x2400_0000,
+    ];
+
+    /// Constant representing the modulus as static str
+    pub(crate) const MODULUS_STR: &str =
+        "0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000001";
+
+    /// INV = -(p^{-1} mod 2^64) mod 2^64
+    pub(crate) const INV: u64 = 0x9ffffcd2ffffffff;
+
+    /// R = 2^448 mod p
+    pub(crate) const R: Fp = Fp([
+        0xa000163afffffff9,
+        0x8d68a2aaffd0ef18,
+        0xbf6a760a123e0121,
+        0x2242c7760637089c,
+        0x67e576bf526ff2f5,
+        0xf7a9dfffa183e9bf,
+        0x03ffffffffff03ff,
+    ]);
+
+    /// R2 = 2^896 mod p
+    pub(crate) const R2: Fp = Fp([
+        0xd9702c6d54dc0598,
+        0x4b20c07277ae01f1,
+        0x7a42067a8ccd154b,
+        0x734fd363b575c23e,
+        0x20b6db3d7481a84c,
+        0x8bcb0f20758aec85,
+        0x1a4b16581f66e3cc,
+    ]);
+
+    /// R3 = 2^1344 mod p
+    pub(crate) const R3: Fp = Fp([
+        0xc67afa3fe916dd69,
+        0x49937d6b40e58f05,
+        0xe5446680e4c4ea04,
+        0xb25aa8b46a40b225,
+        0xf3bc57efac4b3280,
+        0x89010189f4df0ae1,
+        0x1f51e40a048ddc17,
+    ]);
+
+    /// A generator of the multiplicative group of the field.
+    /// `0xa`
+    pub(crate) const MULTIPLICATIVE_GENERATOR: Fp = Fp::from_raw([
+        0x000000000000000a,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// The largest `S` such that `2^S` divides `p - 1`.
+    pub(crate) const S: u32 = 32;
+
+    /// MULTIPLICATIVE_GENERATOR^t where t * 2^S + 1 = p with t odd, a 2^S root of unity.
+    /// `0x2d39f8c5f9adb3f35fe3f4222db17451ddd9602a013af5276bdbe3903ec85fc889232f5c8bc6857060c75e6f399661d6c7b82d31d563091`
+    pub(crate) const ROOT_OF_UNITY: Fp = Fp::from_raw([
+        0x6c7b82d31d563091,
+        0x060c75e6f399661d,
+        0x889232f5c8bc6857,
+        0x76bdbe3903ec85fc,
+        0x1ddd9602a013af52,
+        0x35fe3f4222db1745,
+        0x02d39f8c5f9adb3f,
+    ]);
+
+    /// 1 / ROOT_OF_UNITY mod p
+    /// `0x17725d635b00cda4153eb10c7105919d012822bd86c08691803272fbc5c9f8378055eb56ae2d55f9272bf208aad57f666deaead2c693ff66`
+    pub(crate) const ROOT_OF_UNITY_INV: Fp = Fp::from_raw([
+        0x6deaead2c693ff66,
+        0x272bf208aad57f66,
+        0x8055eb56ae2d55f9,
+        0x803272fbc5c9f837,
+        0x012822bd86c08691,
+        0x153eb10c7105919d,
+        0x17725d635b00cda4,
+    ]);
+
+    /// 1 / 2 mod p
+    /// `0x12000000000012000098700006bfb8725401e53b7a1c9337a21fcd2e6d45363df253d2ff47d6ffeb5153f46180035ca2cffffe6980000001`
+    pub(crate) const TWO_INV: Fp = Fp::from_raw([
+        0xcffffe6980000001,
+        0x5153f46180035ca2,
+        0xf253d2ff47d6ffeb,
+        0xa21fcd2e6d45363d,
+        0x5401e53b7a1c9337,
+        0x0098700006bfb872,
+        0x1200000000001200,
+    ]);
+
+    /// MULTIPLICATIVE_GENERATOR^{2^S}, a t root of unity.
+    /// `0xeacefc6504d028d42ed23fc8766d5a5f195b456887e1e0021fb760c53233e9170c23749b459b95cc6cbb5faf3754a1e1916b2007775db04`
+    pub(crate) const DELTA: Fp = Fp::from_raw([
+        0x1916b2007775db04,
+        0xc6cbb5faf3754a1e,
+        0x70c23749b459b95c,
+        0x21fb760c53233e91,
+        0xf195b456887e1e00,
+        0x42ed23fc8766d5a5,
+        0x0eacefc6504d028d,
+    ]);
+
+    /// `ZETA^3 = 1 mod p` where `ZETA != 1 mod p`
+    /// `0x480000000000360001c950000d7ee0e4a803c956d01c903d720dc8ad8b38dffaf50c100004c37ffffffe`
+    pub(crate) const ZETA: Fp = Fp::from_raw([
+        0x100004c37ffffffe,
+        0xc8ad8b38dffaf50c,
+        0xc956d01c903d720d,
+        0x50000d7ee0e4a803,
+        0x00000000360001c9,
+        0x0000000000004800,
+        0x0000000000000000,
+    ]);
+}
+
+pub(crate) mod fq {
+    use crate::pluto_eris::Fq;
+
+    /// Constant representing the modulus
+    /// `0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5c7a8a6c7be4a775fe8e177fd69ca7e85d60050af41ffffcd300000001`
+    pub(crate) const MODULUS: Fq = Fq([
+        0x1ffffcd300000001,
+        0x9ca7e85d60050af4,
+        0xe4a775fe8e177fd6,
+        0x443f9a5c7a8a6c7b,
+        0xa803ca76f439266f,
+        0x0130e0000d7f70e4,
+        0x2400000000002400,
+    ]);
+
+    /// The modulus as u32 limbs.
+    #[cfg(not(target_pointer_width = "64"))]
+    pub(crate) const MODULUS_LIMBS_32: [u32; 14] = [
+        0x0000_0001,
+        0x1fff_fcd3,
+        0x6005_0af4,
+        0x9ca7_e85d,
+        0x8e17_7fd6,
+        0xe4a7_75fe,
+        0x7a8a_6c7b,
+        0x443f_9a5c,
+        0xf439_266f,
+        0xa803_ca76,
+        0x0d7f_70e4,
+        0x0130_e000,
+        0x0000_2400,
+        0x2400_0000,
+    ];
+
+    /// Constant representing the modulus as static str
+    pub(crate) const MODULUS_STR: &str =
+        "0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5c7a8a6c7be4a775fe8e177fd69ca7e85d60050af41ffffcd300000001";
+
+    /// INV = -(p^{-1} mod 2^64) mod 2^64
+    pub(crate) const INV: u64 = 0x1ffffcd2ffffffff;
+
+    /// R = 2^448 mod p
+    pub(crate) const R: Fq = Fq([
+        0x2000163afffffff9,
+        0xb768a5725fdcb353,
+        0xbf6bc60a1d5b8121,
+        0x2242c778a637089c,
+        0x67e576bf526ff2f5,
+        0xf7a9dfffa183e9bf,
+        0x03ffffffffff03ff,
+    ]);
+
+    /// R2 = 2^896 mod p
+    pub(crate) const R2: Fq = Fq([
+        0x740808c831022522,
+        0xbc64e865fe4552ad,
+        0x19bd905e6e4ff6c2,
+        0x51da4da1c97f7164,
+        0x44d51e923f646956,
+        0xe436895a5a630ff5,
+        0x050d7c998f46144e,
+    ]);
+
+    /// R3 = 2^1344 mod p
+    pub(crate) const R3: Fq = Fq([
+        0x7252704d7495b38a,
+        0xc89b51b218477a6f,
+        0x295c3c8bd6945406,
+        0xe65191fab1f6ce25,
+        0x3b2c1031e6d01279,
+        0xaa10b8225e69f7de,
+        0x02f2c41fb476072b,
+    ]);
+
+    /// A generator of the multiplicative group of the field.
+    /// `0x7`
+    pub(crate) const MULTIPLICATIVE_GENERATOR: Fq = Fq::from_raw([
+        0x0000000000000007,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// The largest `S` such that `2^S` divides `p - 1`.
+    pub(crate) const S: u32 = 32;
+
+    /// MULTIPLICATIVE_GENERATOR^t where t * 2^S + 1 = p with t odd, a 2^S root of unity.
+    /// `0xa5e6f78289fd24b1c64c90821c44cdce9ba1b3e90f2e88957f869667f6dfdbdbce6bb9ed38a8c2382fa11e3d3810fcc3c7bb406ec7bce04`
+    pub(crate) const ROOT_OF_UNITY: Fq = Fq::from_raw([
+        0x3c7bb406ec7bce04,
+        0x82fa11e3d3810fcc,
+        0xbce6bb9ed38a8c23,
+        0x57f869667f6dfdbd,
+        0xe9ba1b3e90f2e889,
+        0x1c64c90821c44cdc,
+        0x0a5e6f78289fd24b,
+    ]);
+
+    /// 1 / ROOT_OF_UNITY mod p
+    /// `0x1a8c636e293fe9928f85aa6ec68f950ebb57e3f0502dd05667c990c1c2f57128c77768be1824fd3f60869f410287a1879ec16a35ca69b6fb`
+    pub(crate) const ROOT_OF_UNITY_INV: Fq = Fq::from_raw([
+        0x9ec16a35ca69b6fb,
+        0x60869f410287a187,
+        0xc77768be1824fd3f,
+        0x67c990c1c2f57128,
+        0xbb57e3f0502dd056,
+        0x8f85aa6ec68f950e,
+        0x1a8c636e293fe992,
+    ]);
+
+    /// 1 / 2 mod p
+    /// `0x12000000000012000098700006bfb8725401e53b7a1c9337a21fcd2e3d45363df253baff470bbfeb4e53f42eb002857a0ffffe6980000001`
+    pub(crate) const TWO_INV: Fq = Fq::from_raw([
+        0x0ffffe6980000001,
+        0x4e53f42eb002857a,
+        0xf253baff470bbfeb,
+        0xa21fcd2e3d45363d,
+        0x5401e53b7a1c9337,
+        0x0098700006bfb872,
+        0x1200000000001200,
+    ]);
+
+    /// MULTIPLICATIVE_GENERATOR^{2^S}, a t root of unity.
+    /// `0x657946fe07116ceca983fe28713a2b257ab7a7866c95121e727f3776c3e84cb0a14f6a7f83f8cdaeadb479c657bdf2de4589640faf72e67`
+    pub(crate) const DELTA: Fq = Fq::from_raw([
+        0xe4589640faf72e67,
+        0xeadb479c657bdf2d,
+        0x0a14f6a7f83f8cda,
+        0xe727f3776c3e84cb,
+        0x57ab7a7866c95121,
+        0xca983fe28713a2b2,
+        0x0657946fe07116ce,
+    ]);
+
+    /// `ZETA^3 = 1 mod p` where `ZETA != 1 mod p`
+    /// `0x9000000000006c000392a0001afee1c9500792ae3039253e641ba35817a29ffaf50be000032cfffffffe`
+    pub(crate) const ZETA: Fq = Fq::from_raw([
+        0xe000032cfffffffe,
+        0xa35817a29ffaf50b,
+        0x92ae3039253e641b,
+        0xa0001afee1c95007,
+        0x000000006c000392,
+        0x0000000000009000,
+        0x0000000000000000,
+    ]);
+}
+
+pub(crate) const FP_NEG_ONE: Fp = Fp::from_raw([
+    0x9ffffcd300000000,
+    0xa2a7e8c30006b945,
+    0xe4a7a5fe8fadffd6,
+    0x443f9a5cda8a6c7b,
+    0xa803ca76f439266f,
+    0x0130e0000d7f70e4,
+    0x2400000000002400,
+]);
+
+pub(crate) const U_SQUARE: Fp = Fp::from_raw([
+    0x9ffffcd2fffffffc,
+    0xa2a7e8c30006b945,
+    0xe4a7a5fe8fadffd6,
+    0x443f9a5cda8a6c7b,
+    0xa803ca76f439266f,
+    0x0130e0000d7f70e4,
+    0x2400000000002400,
+]);
+
+pub(crate) const G1_GENERATOR_X: Fp = Fp::from_raw([
+    0x9ffffcd2ffffffff,
+    0xa2a7e8c30006b945,
+    0xe4a7a5fe8fadffd6,
+    0x443f9a5cda8a6c7b,
+    0xa803ca76f439266f,
+    0x0130e0000d7f70e4,
+    0x2400000000002400,
+]);
+
+pub(crate) const G1_GENERATOR_Y: Fp = Fp::from_raw([
+    0x0000000000000007,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const PLUTO_A: Fp = Fp::from_raw([
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const PLUTO_B: Fp = Fp::from_raw([
+    0x0000000000000039,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const PLUTO_SVDW_Z: Fp = Fp::from_raw([
+    0x0000000000000001,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const ERIS_GENERATOR_X: Fq = Fq::from_raw([
+    0x1ffffcd2ffffffff,
+    0x9ca7e85d60050af4,
+    0xe4a775fe8e177fd6,
+    0x443f9a5c7a8a6c7b,
+    0xa803ca76f439266f,
+    0x0130e0000d7f70e4,
+    0x2400000000002400,
+]);
+
+pub(crate) const ERIS_GENERATOR_Y: Fq = Fq::from_raw([
+    0x0000000000000007,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const ERIS_A: Fq = Fq::from_raw([
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const ERIS_B: Fq = Fq::from_raw([
+    0x0000000000000039,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const ERIS_SVDW_Z: Fq = Fq::from_raw([
+    0x0000000000000001,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
@@ -1,4 +1,6 @@
-use super::fields::{fp::Fp, fp2::Fp2, fq::Fq};
+use super::constants::*;
+use super::engine::{NEG_PLUTO_U, XI_TO_P_MINUS_1_OVER_2};
+use super::fields::{fp::Fp, fp2::Fp2, fp6::FROBENIUS_COEFF_FP6_C1, fq::Fq};
 use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
@@ -13,43 +15,12 @@ use group::cofactor::CofactorGroup;
 use rand::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-#[cfg(feature = "derive_serde")]
-use serde::{Deserialize, Serialize};
-
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
-    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
-    new_curve_impl,
+    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_derive_generator,
+    impl_sub_binop_specify_output, new_curve_impl,
 };
 
-const G1_GENERATOR_X: Fp = Fp::from_raw([
-    0x9ffffcd2ffffffff,
-    0xa2a7e8c30006b945,
-    0xe4a7a5fe8fadffd6,
-    0x443f9a5cda8a6c7b,
-    0xa803ca76f439266f,
-    0x0130e0000d7f70e4,
-    0x2400000000002400,
-]);
-const G1_GENERATOR_Y: Fp = Fp::from_raw([0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
-
-const PLUTO_A: Fp = Fp::ZERO;
-const PLUTO_B: Fp = Fp::from_raw([0x39, 0, 0, 0, 0, 0, 0]);
-
-const ERIS_GENERATOR_X: Fq = Fq::from_raw([
-    0x1ffffcd2ffffffff,
-    0x9ca7e85d60050af4,
-    0xe4a775fe8e177fd6,
-    0x443f9a5c7a8a6c7b,
-    0xa803ca76f439266f,
-    0x0130e0000d7f70e4,
-    0x2400000000002400,
-]);
-const ERIS_GENERATOR_Y: Fq = Fq::from_raw([0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
-
-const ERIS_A: Fq = Fq::ZERO;
-const ERIS_B: Fq = Fq::from_raw([0x39, 0, 0, 0, 0, 0, 0]);
-
 const G2_GENERATOR_X: Fp2 = Fp2 {
     // 0x13576c81faf3a13fd815d0e9bd54b845ee935948b84498b27ca972bfb93722e223c9e276a4ebe7559cfc86dd865f07d64f2b5fe6556f9066
     c0: Fp::from_raw([
@@ -134,6 +105,8 @@ new_curve_impl!(
     |curve_id, domain_prefix| svdw_hash_to_curve(curve_id, domain_prefix, G1::SVDW_Z),
 );
 
+impl_derive_generator!(G1);
+
 impl group::cofactor::CofactorGroup for Eris {
     type Subgroup = Eris;
 
@@ -153,9 +126,11 @@ impl group::cofactor::CofactorGroup for Eris {
 impl G1 {
     /// Constant Z for the Shallue-van de Woestijne map.
     /// Computed using https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#svdw-z-code
-    const SVDW_Z: Fp = Fp::ONE;
+    const SVDW_Z: Fp = PLUTO_SVDW_Z;
 }
 
+crate::hash_to_curve::impl_svdw_map_to_curve!(G1, Fp);
+
 new_curve_impl!(
     (pub),
     Eris,
@@ -169,6 +144,8 @@ new_curve_impl!(
     |curve_id, domain_prefix| svdw_hash_to_curve(curve_id, domain_prefix, Eris::SVDW_Z),
 );
 
+impl_derive_generator!(Eris);
+
 impl CofactorGroup for G2 {
     type Subgroup = G2;
 
@@ -196,16 +173,39 @@ impl CofactorGroup for G2 {
     }
 
     fn into_subgroup(self) -> CtOption<Self::Subgroup> {
-        CtOption::new(self.clear_cofactor(), 1.into())
+        CtOption::new(self, self.is_torsion_free())
     }
 
     fn is_torsion_free(&self) -> Choice {
-        // group order = p
+        self.is_in_correct_subgroup_fast()
+    }
+}
+
+impl G2 {
+    /// Checks that the point lies in the prime order subgroup using the
+    /// untwist-Frobenius-twist endomorphism `psi`, which costs about one
+    /// 111-bit scalar multiplication instead of a full one.
+    ///
+    /// A point `P` on the twist is in the subgroup iff
+    /// `[u+1]P + psi([u]P) + psi^2([u]P) = psi^3([2u]P)`, where `u` is the
+    /// BN parameter of Pluto (<https://eprint.iacr.org/2022/352>, Section 5).
+    pub fn is_in_correct_subgroup_fast(&self) -> Choice {
+        let up = self.mul_by_u();
+        let psi_up = up.psi();
+        let lhs = up + self + psi_up + psi_up.psi();
+        let rhs = up.double().psi().psi().psi();
+        lhs.ct_eq(&rhs)
+    }
+
+    /// Checks that the point lies in the prime order subgroup by multiplying
+    /// it by the group order.
+    pub fn is_in_correct_subgroup_slow(&self) -> Choice {
+        // group order = q
         let e: [u8; 56] = [
             0x24, 0x00, 0x00, 0x00, 0x00, 0x00, 0x24, 0x00, 0x01, 0x30, 0xe0, 0x00, 0x0d, 0x7f,
             0x70, 0xe4, 0xa8, 0x03, 0xca, 0x76, 0xf4, 0x39, 0x26, 0x6f, 0x44, 0x3f, 0x9a, 0x5c,
-            0xda, 0x8a, 0x6c, 0x7b, 0xe4, 0xa7, 0xa5, 0xfe, 0x8f, 0xad, 0xff, 0xd6, 0xa2, 0xa7,
-            0xe8, 0xc3, 0x00, 0x06, 0xb9, 0x45, 0x9f, 0xff, 0xfc, 0xd3, 0x00, 0x00, 0x00, 0x01,
+            0x7a, 0x8a, 0x6c, 0x7b, 0xe4, 0xa7, 0x75, 0xfe, 0x8e, 0x17, 0x7f, 0xd6, 0x9c, 0xa7,
+            0xe8, 0x5d, 0x60, 0x05, 0x0a, 0xf4, 0x1f, 0xff, 0xfc, 0xd3, 0x00, 0x00, 0x00, 0x01,
         ];
         // self * GROUP_ORDER;
         let mut acc = G2::identity();
@@ -219,14 +219,41 @@ impl CofactorGroup for G2 {
         }
         acc.is_identity()
     }
+
+    // Applies the endomorphism `psi = twist^-1 . frobenius . twist`.
+    fn psi(&self) -> Self {
+        let conjugate = |mut e: Fp2| {
+            e.conjugate();
+            e
+        };
+        G2 {
+            x: conjugate(self.x) * FROBENIUS_COEFF_FP6_C1[1],
+            y: conjugate(self.y) * XI_TO_P_MINUS_1_OVER_2,
+            z: conjugate(self.z),
+        }
+    }
+
+    // Multiplies by the public BN parameter `u`, which is negative.
+    fn mul_by_u(&self) -> Self {
+        let mut acc = G2::identity();
+        for i in (0..128 - NEG_PLUTO_U.leading_zeros()).rev() {
+            acc = acc.double();
+            if (NEG_PLUTO_U >> i) & 1 == 1 {
+                acc += self;
+            }
+        }
+        -acc
+    }
 }
 
 impl Eris {
     /// Constant Z for the Shallue-van de Woestijne map.
     /// Computed using https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#svdw-z-code
-    const SVDW_Z: Fq = Fq::ONE;
+    const SVDW_Z: Fq = ERIS_SVDW_Z;
 }
 
+crate::hash_to_curve::impl_svdw_map_to_curve!(Eris, Fq);
+
 new_curve_impl!(
     (pub),
     G2,
@@ -277,4 +304,29 @@ mod test {
         G2_GENERATOR_Y,
         Fq::MODULUS
     );
+
+    #[test]
+    fn test_g2_subgroup_check() {
+        use rand_core::OsRng;
+
+        assert!(bool::from(G2::identity().is_in_correct_subgroup_fast()));
+        for _ in 0..20 {
+            let p = G2::random(OsRng);
+            assert!(bool::from(p.is_in_correct_subgroup_fast()));
+            assert!(bool::from(p.is_in_correct_subgroup_slow()));
+
+            // A point on the twist that is not cleared of the cofactor.
+            let p = loop {
+                let x = Fp2::random(OsRng);
+                if let Some(y) = Option::<Fp2>::from((x.square() * x + TRITON_B).sqrt()) {
+                    break G2::from(G2Affine { x, y });
+                }
+            };
+            assert_eq!(
+                bool::from(p.is_in_correct_subgroup_fast()),
+                bool::from(p.is_in_correct_subgroup_slow())
+            );
+            assert!(!bool::from(p.is_torsion_free()));
+        }
+    }
 }
@@ -2,22 +2,25 @@
 use crate::ff::{Field, PrimeField};
 use crate::group::cofactor::CofactorCurveAffine;
 use crate::group::Group;
-use crate::pairing::{Engine, MillerLoopResult, MultiMillerLoop, PairingCurveAffine};
+use crate::impl_pairing_engine;
+use crate::pairing::MillerLoopResult;
 use crate::pluto_eris::curve::*;
 use crate::pluto_eris::fields::fp::*;
 use crate::pluto_eris::fields::fp12::*;
 use crate::pluto_eris::fields::fp2::*;
 use crate::pluto_eris::fields::fp6::FROBENIUS_COEFF_FP6_C1;
 use crate::pluto_eris::fields::fq::*;
+use alloc::vec;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::iter::Sum;
-use core::ops::{Add, Mul, MulAssign, Neg, Sub};
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub};
 use rand_core::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 /// Adaptation of Algorithm 1, https://eprint.iacr.org/2013/722.pdf
 /// the parameter for the curve Pluto: u = -0x4000000000001000008780000000
-const NEG_PLUTO_U: u128 = 0x4000000000001000008780000000;
+pub(crate) const NEG_PLUTO_U: u128 = 0x4000000000001000008780000000;
 
 const NEG_SIX_U_PLUS_2_NAF: [i8; 114] = [
     0, -1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -27,7 +30,7 @@ const NEG_SIX_U_PLUS_2_NAF: [i8; 114] = [
 ];
 
 /// Value of (57/(u + 3))^((p - 1)/2) where u^2 + 5 = 0 in Fp2.
-const XI_TO_P_MINUS_1_OVER_2: Fp2 = Fp2 {
+pub(crate) const XI_TO_P_MINUS_1_OVER_2: Fp2 = Fp2 {
     c0: Fp::from_raw([
         0x54cf5ad1c0926216,
         0x186c1f3ce4a46d4e,
@@ -49,29 +52,20 @@ const XI_TO_P_MINUS_1_OVER_2: Fp2 = Fp2 {
     ]),
 };
 
-impl PairingCurveAffine for G1Affine {
-    type Pair = G2Affine;
-    type PairingResult = Gt;
-
-    fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
-        Pluto::pairing(self, other)
-    }
-}
-
-impl PairingCurveAffine for G2Affine {
-    type Pair = G1Affine;
-    type PairingResult = Gt;
+#[derive(Copy, Clone, Debug)]
+pub struct Gt(pub(crate) Fp12);
 
-    fn pairing_with(&self, other: &Self::Pair) -> Self::PairingResult {
-        Pluto::pairing(other, self)
+/// The default element is the identity, not zero.
+impl Default for Gt {
+    fn default() -> Self {
+        Self::identity()
     }
 }
 
-#[derive(Copy, Clone, Debug, Default)]
-pub struct Gt(pub(crate) Fp12);
+crate::__impl_zeroize!(Gt);
 
-impl std::fmt::Display for Gt {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl core::fmt::Display for Gt {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self)
     }
 }
@@ -102,12 +96,36 @@ impl Gt {
         Gt(Fp12::ONE)
     }
 
+    /// Returns the fixed generator of the group, `e(G1, G2)` for the
+    /// generators of G1 and G2.
+    pub fn generator() -> Gt {
+        *GT_GENERATOR
+    }
+
+    /// Returns whether `self` is the identity.
+    pub fn is_identity(&self) -> Choice {
+        self.ct_eq(&Self::identity())
+    }
+
     /// Doubles this group element.
+    ///
+    /// Uses cyclotomic squaring, which is valid since `Gt` lies in the
+    /// cyclotomic subgroup of Fp12. Miller loop outputs, which do not, are
+    /// kept apart as [`MillerLoopOutput`].
     pub fn double(&self) -> Gt {
-        Gt(self.0.square())
+        let mut res = self.0;
+        res.cyclotomic_square();
+        Gt(res)
     }
 }
 
+lazy_static::lazy_static! {
+    static ref GT_GENERATOR: Gt = <Pluto as crate::pairing::Engine>::pairing(
+        &G1Affine::generator(),
+        &G2Affine::generator(),
+    );
+}
+
 impl<'a> Neg for &'a Gt {
     type Output = Gt;
 
@@ -120,14 +138,7 @@ impl<'a> Neg for &'a Gt {
     }
 }
 
-impl Neg for Gt {
-    type Output = Gt;
-
-    #[inline]
-    fn neg(self) -> Gt {
-        -&self
-    }
-}
+crate::impl_neg!(Gt);
 
 impl<'a, 'b> Add<&'b Gt> for &'a Gt {
     type Output = Gt;
@@ -187,11 +198,13 @@ where
     }
 }
 
+crate::impl_standard_distribution!(Gt, crate::group::Group);
+
 impl Group for Gt {
     type Scalar = Fq;
 
-    fn random(_: impl RngCore) -> Self {
-        unimplemented!();
+    fn random(rng: impl RngCore) -> Self {
+        Self::generator() * Fq::random(rng)
     }
 
     fn identity() -> Self {
@@ -199,11 +212,11 @@ impl Group for Gt {
     }
 
     fn generator() -> Self {
-        unimplemented!();
+        Self::generator()
     }
 
     fn is_identity(&self) -> Choice {
-        self.ct_eq(&Self::identity())
+        self.is_identity()
     }
 
     #[must_use]
@@ -484,8 +497,69 @@ impl From<G2Affine> for G2Prepared {
     }
 }
 
-impl MillerLoopResult for Gt {
-    type Gt = Self;
+/// The output of a Miller loop, an element of Fp12 that only lands in [`Gt`]
+/// after the final exponentiation.
+///
+/// Outputs are outside of the cyclotomic subgroup, so they only support
+/// multiplication, which accumulates several Miller loops for a single
+/// final exponentiation. The `pairing` crate writes that product additively,
+/// so `+` multiplies as well.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MillerLoopOutput(pub(crate) Fp12);
+
+/// The default output is one, the output of an empty Miller loop.
+impl Default for MillerLoopOutput {
+    fn default() -> Self {
+        MillerLoopOutput(Fp12::ONE)
+    }
+}
+
+impl<'b> Mul<&'b MillerLoopOutput> for &MillerLoopOutput {
+    type Output = MillerLoopOutput;
+
+    #[inline]
+    fn mul(self, rhs: &'b MillerLoopOutput) -> MillerLoopOutput {
+        MillerLoopOutput(self.0 * rhs.0)
+    }
+}
+
+impl_binops_multiplicative!(MillerLoopOutput, MillerLoopOutput);
+
+impl Add for MillerLoopOutput {
+    type Output = MillerLoopOutput;
+
+    #[inline]
+    fn add(self, rhs: MillerLoopOutput) -> MillerLoopOutput {
+        self * rhs
+    }
+}
+
+impl<'b> Add<&'b MillerLoopOutput> for MillerLoopOutput {
+    type Output = MillerLoopOutput;
+
+    #[inline]
+    fn add(self, rhs: &'b MillerLoopOutput) -> MillerLoopOutput {
+        self * rhs
+    }
+}
+
+impl AddAssign for MillerLoopOutput {
+    #[inline]
+    fn add_assign(&mut self, rhs: MillerLoopOutput) {
+        self.mul_assign(rhs);
+    }
+}
+
+impl<'b> AddAssign<&'b MillerLoopOutput> for MillerLoopOutput {
+    #[inline]
+    fn add_assign(&mut self, rhs: &'b MillerLoopOutput) {
+        self.mul_assign(rhs);
+    }
+}
+
+impl MillerLoopResult for MillerLoopOutput {
+    type Gt = Gt;
+
     fn final_exponentiation(&self) -> Gt {
         fn exp_by_x(f: &mut Fp12) {
             let x = NEG_PLUTO_U;
@@ -588,106 +662,124 @@ impl MillerLoopResult for Gt {
             .unwrap())
     }
 }
-impl MultiMillerLoop for Pluto {
-    /// The prepared form of `Self::G2Affine`.
-    type G2Prepared = G2Prepared;
 
-    /// The type returned by `Engine::miller_loop`.
-    type Result = Gt;
-    fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> Self::Result {
-        let mut pairs = vec![];
-        for &(p, q) in terms {
-            if !bool::from(p.is_identity()) && !q.is_zero() {
-                pairs.push((p, q.coeffs.iter()));
-            }
+/// Computes the product of the Miller loops of `terms`.
+pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> MillerLoopOutput {
+    let mut pairs = vec![];
+    for &(p, q) in terms {
+        if !bool::from(p.is_identity()) && !q.is_zero() {
+            pairs.push((p, q.coeffs.iter()));
         }
+    }
 
-        // Final steps of the line function on prepared coefficients
-        fn ell(f: &mut Fp12, coeffs: &(Fp2, Fp2, Fp2), p: &G1Affine) {
-            let mut c0 = coeffs.0;
-            let mut c1 = coeffs.1;
+    // Final steps of the line function on prepared coefficients
+    fn ell(f: &mut Fp12, coeffs: &(Fp2, Fp2, Fp2), p: &G1Affine) {
+        let mut c0 = coeffs.0;
+        let mut c1 = coeffs.1;
 
-            c0.c0.mul_assign(&p.y);
-            c0.c1.mul_assign(&p.y);
+        c0.c0.mul_assign(&p.y);
+        c0.c1.mul_assign(&p.y);
 
-            c1.c0.mul_assign(&p.x);
-            c1.c1.mul_assign(&p.x);
+        c1.c0.mul_assign(&p.x);
+        c1.c1.mul_assign(&p.x);
 
-            // Sparse multiplication in Fq12
-            f.mul_by_034(&c0, &c1, &coeffs.2);
-        }
+        // Sparse multiplication in Fq12
+        f.mul_by_034(&c0, &c1, &coeffs.2);
+    }
 
-        let mut f = Fp12::ONE;
+    let mut f = Fp12::ONE;
 
+    for &mut (p, ref mut coeffs) in &mut pairs {
+        ell(&mut f, coeffs.next().unwrap(), p);
+    }
+
+    // length - 2
+    let len_min2 = NEG_SIX_U_PLUS_2_NAF.len() - 2;
+
+    if NEG_SIX_U_PLUS_2_NAF[len_min2] != 0 {
         for &mut (p, ref mut coeffs) in &mut pairs {
             ell(&mut f, coeffs.next().unwrap(), p);
         }
+    }
 
-        // length - 2
-        let len_min2 = NEG_SIX_U_PLUS_2_NAF.len() - 2;
+    for x in NEG_SIX_U_PLUS_2_NAF[..len_min2].iter().rev() {
+        f.square_assign();
 
-        if NEG_SIX_U_PLUS_2_NAF[len_min2] != 0 {
-            for &mut (p, ref mut coeffs) in &mut pairs {
-                ell(&mut f, coeffs.next().unwrap(), p);
-            }
+        for &mut (p, ref mut coeffs) in &mut pairs {
+            ell(&mut f, coeffs.next().unwrap(), p);
         }
-
-        for x in NEG_SIX_U_PLUS_2_NAF[..len_min2].iter().rev() {
-            f.square_assign();
-
+        if *x != 0 {
             for &mut (p, ref mut coeffs) in &mut pairs {
                 ell(&mut f, coeffs.next().unwrap(), p);
             }
-            if *x != 0 {
-                for &mut (p, ref mut coeffs) in &mut pairs {
-                    ell(&mut f, coeffs.next().unwrap(), p);
-                }
-            }
         }
+    }
 
-        f.conjugate();
-
-        for &mut (p, ref mut coeffs) in &mut pairs {
-            ell(&mut f, coeffs.next().unwrap(), p);
-        }
+    f.conjugate();
 
-        for &mut (p, ref mut coeffs) in &mut pairs {
-            ell(&mut f, coeffs.next().unwrap(), p);
-        }
+    for &mut (p, ref mut coeffs) in &mut pairs {
+        ell(&mut f, coeffs.next().unwrap(), p);
+    }
 
-        for &mut (_p, ref mut coeffs) in &mut pairs {
-            assert_eq!(coeffs.next(), None);
-        }
+    for &mut (p, ref mut coeffs) in &mut pairs {
+        ell(&mut f, coeffs.next().unwrap(), p);
+    }
 
-        Gt(f)
+    for &mut (_p, ref mut coeffs) in &mut pairs {
+        assert_eq!(coeffs.next(), None);
     }
+
+    MillerLoopOutput(f)
+}
+
+/// Checks whether the product of the pairings `e(p_i, q_i)` equals one.
+///
+/// All terms share a single Miller loop and a single final exponentiation.
+pub fn pairing_check(terms: &[(&G1Affine, &G2Affine)]) -> bool {
+    let prepared: Vec<G2Prepared> = terms.iter().map(|(_, q)| G2Prepared::from(**q)).collect();
+    let terms: Vec<(&G1Affine, &G2Prepared)> = terms
+        .iter()
+        .zip(prepared.iter())
+        .map(|((p, _), q)| (*p, q))
+        .collect();
+    bool::from(
+        multi_miller_loop(&terms)
+            .final_exponentiation()
+            .is_identity(),
+    )
 }
 
 /// Pluto pairing-friendly curve. See: https://github.com/daira/pluto-eris
 #[derive(Clone, Debug)]
 pub struct Pluto;
 
-impl Engine for Pluto {
-    type Fr = Fq;
-    type G1 = G1;
-    type G1Affine = G1Affine;
-    type G2 = G2;
-    type G2Affine = G2Affine;
-    type Gt = Gt;
-
-    fn pairing(p: &Self::G1Affine, q: &Self::G2Affine) -> Self::Gt {
-        let q = G2Prepared::from_affine(*q);
-        let terms: &[(&G1Affine, &G2Prepared)] = &[(p, &q)];
-        let u = Self::multi_miller_loop(terms);
-        u.final_exponentiation()
-    }
-}
+impl_pairing_engine!(
+    Pluto,
+    Fq,
+    G1,
+    G1Affine,
+    G2,
+    G2Affine,
+    G2Prepared,
+    Gt,
+    MillerLoopOutput,
+    multi_miller_loop
+);
 
+#[cfg(test)]
+use crate::pairing::{Engine, MultiMillerLoop, PairingCurveAffine};
 #[cfg(test)]
 use rand::SeedableRng;
 #[cfg(test)]
 use rand_xorshift::XorShiftRng;
 
+#[test]
+fn test_gt_identity() {
+    const IDENTITY: Gt = Gt::identity();
+    assert_eq!(Gt::default(), IDENTITY);
+    assert_eq!(<Gt as Group>::identity(), IDENTITY);
+}
+
 #[test]
 fn test_pairing() {
     let g1 = G1::generator();
@@ -943,3 +1035,52 @@ pub fn multi_miller_final_exp_tests() {
         assert_eq!(lhs, rhs, "failed trivial check");
     }
 }
+
+#[test]
+fn test_pairing_check() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let p = G1Affine::from(G1::random(&mut rng));
+    let q = G2Affine::from(G2::random(&mut rng));
+    let s = Fq::random(&mut rng);
+    let sp = G1Affine::from(p * s);
+    let sq = G2Affine::from(q * s);
+
+    assert!(pairing_check(&[]));
+    assert!(pairing_check(&[(&sp, &q), (&-p, &sq)]));
+    assert!(pairing_check(&[(&G1Affine::identity(), &q)]));
+    assert!(!pairing_check(&[(&p, &q)]));
+    assert!(!pairing_check(&[(&sp, &q), (&p, &sq)]));
+}
+
+#[test]
+fn test_gt_generator() {
+    let mut rng = XorShiftRng::from_seed([
+        0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+        0xe5,
+    ]);
+
+    let generator = Gt::generator();
+    assert_eq!(
+        generator,
+        Pluto::pairing(&G1Affine::generator(), &G2Affine::generator())
+    );
+    assert!(!bool::from(generator.is_identity()));
+    assert!(bool::from(Gt::identity().is_identity()));
+
+    let s = Fq::random(&mut rng);
+    assert_eq!(
+        generator * s,
+        Pluto::pairing(&G1Affine::from(G1::generator() * s), &G2Affine::generator())
+    );
+
+    // Random elements have order dividing q, also when sampled through rand's
+    // `Standard` distribution.
+    let a = Gt::random(&mut rng);
+    assert_eq!(a * -Fq::ONE, -a);
+    let a: Gt = rand::Rng::gen(&mut rng);
+    assert_eq!(a * -Fq::ONE, -a);
+}
@@ -1,8 +1,9 @@
 use crate::arithmetic::{adc, mac, sbb};
 use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
+use crate::pluto_eris::constants::fp::*;
 use crate::{
     extend_field_legendre, field_arithmetic_7_limbs, field_bits_7_limbs, field_common_7_limbs,
-    impl_from_u64_7_limbs,
+    impl_from_signed, impl_from_u128_7_limbs, impl_from_u64_7_limbs,
 };
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
@@ -12,13 +13,10 @@ use crate::{
 use core::convert::TryInto;
 use core::fmt;
 use core::ops::{Add, Mul, Neg, Sub};
+use core::slice::Iter;
 use rand::RngCore;
-use std::slice::Iter;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-#[cfg(feature = "derive_serde")]
-use serde::{Deserialize, Serialize};
-
 /// This represents an element of $\mathbb{F}_p$ where
 ///
 /// `p = 0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000001`
@@ -27,156 +25,25 @@ use serde::{Deserialize, Serialize};
 /// The internal representation of this type is seven 64-bit unsigned
 /// integers in little-endian order which account for the 446 bits required to be represented.
 /// `Fp` values are always in Montgomery form; i.e., Fp(a) = aR mod p, with R = 2^448.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Fp(pub(crate) [u64; 7]);
 
+#[cfg(feature = "derive_serde")]
+crate::serialize_deserialize_7_limbs_primefield!(Fp, MODULUS);
+
+#[cfg(target_endian = "little")]
+// SAFETY: `Fp` is `#[repr(transparent)]` over its Montgomery form in
+// little-endian limbs.
+unsafe impl crate::serde::montgomery::MontgomeryLimbs for Fp {
+    fn is_reduced(&self) -> bool {
+        self.debug_validate()
+    }
+}
+
 /// Size of `Fp` element in bytes
 const SIZE: usize = 56;
 
-/// Constant representing the modulus
-/// p = 0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000001
-const MODULUS: Fp = Fp([
-    0x9ffffcd300000001,
-    0xa2a7e8c30006b945,
-    0xe4a7a5fe8fadffd6,
-    0x443f9a5cda8a6c7b,
-    0xa803ca76f439266f,
-    0x0130e0000d7f70e4,
-    0x2400000000002400,
-]);
-
-/// The modulus as u32 limbs.
-#[cfg(not(target_pointer_width = "64"))]
-const MODULUS_LIMBS_32: [u32; 14] = [
-    0x00000001, 0x9ffffcd3, 0x0006b945, 0xa2a7e8c3, 0x8fadffd6, 0xe4a7a5fe, 0xda8a6c7b, 0x443f9a5c,
-    0xf439266f, 0xa803ca76, 0x0d7f70e4, 0x0130e000, 0x00002400, 0x24000000,
-];
-
-// pub const NEGATIVE_ONE: Fp = Fp([]);
-
-pub(crate) const MODULUS_STR: &str = "0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd300000001";
-
-/// INV = -r^{-1} mod 2^64
-/// `0x9ffffcd2ffffffff`
-const INV: u64 = 0x9ffffcd2ffffffff;
-
-/// Let M be the power of `2^64` nearest to `Self::MODULUS_BITS`. Then `R = M % Self::MODULUS`.
-/// `R = 2^448 mod p`
-/// `0x3ffffffffff03fff7a9dfffa183e9bf67e576bf526ff2f52242c7760637089cbf6a760a123e01218d68a2aaffd0ef18a000163afffffff9`
-const R: Fp = Fp([
-    0xa000163afffffff9,
-    0x8d68a2aaffd0ef18,
-    0xbf6a760a123e0121,
-    0x2242c7760637089c,
-    0x67e576bf526ff2f5,
-    0xf7a9dfffa183e9bf,
-    0x03ffffffffff03ff,
-]);
-
-/// `R^2 = 2^896 mod p`
-/// `0x1a4b16581f66e3cc8bcb0f20758aec8520b6db3d7481a84c734fd363b575c23e7a42067a8ccd154b4b20c07277ae01f1d9702c6d54dc0598`
-const R2: Fp = Fp([
-    0xd9702c6d54dc0598,
-    0x4b20c07277ae01f1,
-    0x7a42067a8ccd154b,
-    0x734fd363b575c23e,
-    0x20b6db3d7481a84c,
-    0x8bcb0f20758aec85,
-    0x1a4b16581f66e3cc,
-]);
-
-/// `R^3 = 2^1792 mod p`
-/// `0x1f51e40a048ddc1789010189f4df0ae1f3bc57efac4b3280b25aa8b46a40b225e5446680e4c4ea0449937d6b40e58f05c67afa3fe916dd69`
-const R3: Fp = Fp([
-    0xc67afa3fe916dd69,
-    0x49937d6b40e58f05,
-    0xe5446680e4c4ea04,
-    0xb25aa8b46a40b225,
-    0xf3bc57efac4b3280,
-    0x89010189f4df0ae1,
-    0x1f51e40a048ddc17,
-]);
-
-/// `GENERATOR = 10 mod p` is a generator of the `p - 1` order multiplicative
-/// subgroup, or in other words a primitive root of the field.
-const GENERATOR: Fp = Fp::from_raw([0x0a, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00]);
-
-/// Size of the 2-adic sub-group of the field.
-const S: u32 = 32;
-
-/// GENERATOR^t where t * 2^s + 1 = p
-/// with t odd. In other words, this
-/// is a 2^s root of unity.
-/// `0x2d39f8c5f9adb3f35fe3f4222db17451ddd9602a013af5276bdbe3903ec85fc889232f5c8bc6857060c75e6f399661d6c7b82d31d563091`
-const ROOT_OF_UNITY: Fp = Fp::from_raw([
-    0x6c7b82d31d563091,
-    0x060c75e6f399661d,
-    0x889232f5c8bc6857,
-    0x76bdbe3903ec85fc,
-    0x1ddd9602a013af52,
-    0x35fe3f4222db1745,
-    0x02d39f8c5f9adb3f,
-]);
-
-/// 1 / ROOT_OF_UNITY mod p
-/// `0x17725d635b00cda4153eb10c7105919d012822bd86c08691803272fbc5c9f8378055eb56ae2d55f9272bf208aad57f666deaead2c693ff66`
-const ROOT_OF_UNITY_INV: Fp = Fp::from_raw([
-    0x6deaead2c693ff66,
-    0x272bf208aad57f66,
-    0x8055eb56ae2d55f9,
-    0x803272fbc5c9f837,
-    0x012822bd86c08691,
-    0x153eb10c7105919d,
-    0x17725d635b00cda4,
-]);
-
-/// 1 / 2 mod p
-/// `0x12000000000012000098700006bfb8725401e53b7a1c9337a21fcd2e6d45363df253d2ff47d6ffeb5153f46180035ca2cffffe6980000001`
-pub(crate) const TWO_INV: Fp = Fp::from_raw([
-    0xcffffe6980000001,
-    0x5153f46180035ca2,
-    0xf253d2ff47d6ffeb,
-    0xa21fcd2e6d45363d,
-    0x5401e53b7a1c9337,
-    0x0098700006bfb872,
-    0x1200000000001200,
-]);
-/// GENERATOR^{2^s} where t * 2^s + 1 = r with t odd. In other words, this is a t root of unity.
-/// `0xeacefc6504d028d42ed23fc8766d5a5f195b456887e1e0021fb760c53233e9170c23749b459b95cc6cbb5faf3754a1e1916b2007775db04`
-const DELTA: Fp = Fp::from_raw([
-    0x1916b2007775db04,
-    0xc6cbb5faf3754a1e,
-    0x70c23749b459b95c,
-    0x21fb760c53233e91,
-    0xf195b456887e1e00,
-    0x42ed23fc8766d5a5,
-    0x0eacefc6504d028d,
-]);
-
-/// `ZETA^3 = 1 mod p` where `ZETA^2 != 1 mod p`
-/// `0x480000000000360001c950000d7ee0e4a803c956d01c903d720dc8ad8b38dffaf50c100004c37ffffffe`
-const ZETA: Fp = Fp::from_raw([
-    0x100004c37ffffffe,
-    0xc8ad8b38dffaf50c,
-    0xc956d01c903d720d,
-    0x50000d7ee0e4a803,
-    0x00000000360001c9,
-    0x0000000000004800,
-    0x0000000000000000,
-]);
-
-/// NEG_ONE ; -1 mod p
-pub(crate) const NEG_ONE: Fp = Fp::from_raw([
-    0x9ffffcd300000000,
-    0xa2a7e8c30006b945,
-    0xe4a7a5fe8fadffd6,
-    0x443f9a5cda8a6c7b,
-    0xa803ca76f439266f,
-    0x0130e0000d7f70e4,
-    0x2400000000002400,
-]);
-
 impl_binops_additive!(Fp, Fp);
 impl_binops_multiplicative!(Fp, Fp);
 field_common_7_limbs!(
@@ -195,6 +62,10 @@ field_common_7_limbs!(
 );
 impl_sum_prod!(Fp);
 impl_from_u64_7_limbs!(Fp, R2);
+impl_from_u128_7_limbs!(Fp, R2);
+impl_from_signed!(Fp);
+#[cfg(feature = "crypto-bigint")]
+crate::derive::bigint::impl_crypto_bigint_conversions!(Fp, U448);
 field_arithmetic_7_limbs!(Fp, MODULUS, INV, sparse);
 
 #[cfg(target_pointer_width = "64")]
@@ -314,7 +185,7 @@ impl ff::PrimeField for Fp {
     const NUM_BITS: u32 = 446;
     const CAPACITY: u32 = 445;
     const MODULUS: &'static str = MODULUS_STR;
-    const MULTIPLICATIVE_GENERATOR: Self = GENERATOR;
+    const MULTIPLICATIVE_GENERATOR: Self = MULTIPLICATIVE_GENERATOR;
     const ROOT_OF_UNITY: Self = ROOT_OF_UNITY;
     const ROOT_OF_UNITY_INV: Self = ROOT_OF_UNITY_INV;
     const TWO_INV: Self = TWO_INV;
@@ -349,7 +220,7 @@ impl ff::PrimeField for Fp {
 
         // Convert to Montgomery form by computing
         // (a.R^0 * R^2) / R = a.R
-        tmp *= &R2;
+        let tmp = Self::mul(&tmp, &R2);
 
         CtOption::new(tmp, Choice::from(is_some))
     }
@@ -403,11 +274,20 @@ impl WithSmallOrderMulGroup<3> for Fp {
 mod test {
     use super::*;
     crate::field_testing_suite!(Fp, "field_arithmetic");
+    #[cfg(feature = "zeroize")]
+    crate::field_testing_suite!(Fp, "zeroize");
     crate::field_testing_suite!(Fp, "conversion");
+    crate::field_testing_suite!(Fp, "str");
+    #[cfg(feature = "crypto-bigint")]
+    crate::field_testing_suite!(Fp, "crypto_bigint", U448);
     crate::field_testing_suite!(Fp, "serialization");
     crate::field_testing_suite!(Fp, "quadratic_residue");
     crate::field_testing_suite!(Fp, "bits");
     crate::field_testing_suite!(Fp, "serialization_check");
+    crate::field_testing_suite!(Fp, "from_be_slice");
+    crate::field_testing_suite!(Fp, "domain");
+    crate::field_testing_suite!(Fp, "debug_validate");
+    crate::field_testing_suite!(Fp, "balanced_digits");
     crate::field_testing_suite!(Fp, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fp, "sqrt");
     crate::field_testing_suite!(Fp, "zeta");
@@ -507,4 +387,24 @@ mod test {
             ]),
         ]
     );
+
+    #[test]
+    #[cfg(feature = "derive_serde")]
+    fn test_serde_hex() {
+        let one = format!("\"0x{}01\"", "00".repeat(55));
+        assert_eq!(serde_json::to_string(&Fp::one()).unwrap(), one);
+        assert_eq!(serde_json::from_str::<Fp>(&one).unwrap(), Fp::one());
+
+        // Earlier versions wrote the Montgomery limbs, which are still read.
+        let legacy = serde_json::to_string(&Fp::one().0).unwrap();
+        assert_eq!(serde_json::from_str::<Fp>(&legacy).unwrap(), Fp::one());
+        let unreduced = serde_json::to_string(&MODULUS.0).unwrap();
+        assert!(serde_json::from_str::<Fp>(&unreduced).is_err());
+
+        // Binary formats keep the Montgomery limbs.
+        assert_eq!(
+            bincode::serialize(&Fp::one()).unwrap(),
+            bincode::serialize(&Fp::one().0).unwrap()
+        );
+    }
 }
@@ -2,19 +2,25 @@ use super::fp::Fp;
 use super::fp2::Fp2;
 use super::fp6::Fp6;
 use crate::ff::Field;
+use alloc::vec::Vec;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// -GAMMA is a quadratic non-residue in Fp6. Fp12 = Fp6[X]/(X^2 + GAMMA)
 /// We introduce the variable w such that w^2 = -GAMMA
 /// GAMMA = - v
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
 pub struct Fp12 {
     c0: Fp6,
     c1: Fp6,
 }
 
+crate::__impl_zeroize!(Fp12);
+
 impl ConditionallySelectable for Fp12 {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Fp12 {
@@ -30,15 +36,18 @@ impl ConstantTimeEq for Fp12 {
     }
 }
 
-impl Neg for Fp12 {
-    type Output = Fp12;
-
+// Compares all the coefficients, in constant time like `ct_eq`.
+impl PartialEq for Fp12 {
     #[inline]
-    fn neg(self) -> Fp12 {
-        -&self
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
     }
 }
 
+impl Eq for Fp12 {}
+
+crate::impl_neg!(Fp12);
+
 impl<'a> Neg for &'a Fp12 {
     type Output = Fp12;
 
@@ -85,6 +94,54 @@ impl_binops_multiplicative!(Fp12, Fp12);
 impl_sum_prod!(Fp12);
 
 impl Fp12 {
+    /// Checks that all coefficients are fully reduced.
+    pub const fn debug_validate(&self) -> bool {
+        self.c0.debug_validate() && self.c1.debug_validate()
+    }
+
+    pub const fn size() -> usize {
+        672
+    }
+
+    /// Attempts to convert a little-endian byte representation of
+    /// an element into a `Fp12`, failing if a coefficient is not canonical.
+    pub fn from_bytes(bytes: &[u8; 672]) -> CtOption<Fp12> {
+        let [c0, c1] = [0, 336].map(|i| Fp6::from_bytes(bytes[i..i + 336].try_into().unwrap()));
+        CtOption::new(
+            Fp12 {
+                c0: c0.unwrap_or(Fp6::ZERO),
+                c1: c1.unwrap_or(Fp6::ZERO),
+            },
+            c0.is_some() & c1.is_some(),
+        )
+    }
+
+    /// Converts an element of `Fp12` into a byte representation in
+    /// little-endian byte order, with the coefficients in order.
+    pub fn to_bytes(self) -> [u8; 672] {
+        let mut res = [0u8; 672];
+        res[0..336].copy_from_slice(&self.c0.to_bytes());
+        res[336..672].copy_from_slice(&self.c1.to_bytes());
+        res
+    }
+
+    /// Attempts to convert a big-endian byte representation of an element
+    /// into a `Fp12`, the reverse of [`to_bytes`](Self::to_bytes), failing if
+    /// a coefficient is not canonical.
+    pub fn from_bytes_be(bytes: &[u8; 672]) -> CtOption<Fp12> {
+        let mut repr = *bytes;
+        repr.reverse();
+        Self::from_bytes(&repr)
+    }
+
+    /// Converts an element of `Fp12` into a byte representation in
+    /// big-endian byte order, the reverse of [`to_bytes`](Self::to_bytes).
+    pub fn to_bytes_be(self) -> [u8; 672] {
+        let mut res = self.to_bytes();
+        res.reverse();
+        res
+    }
+
     #[inline]
     pub const fn zero() -> Self {
         Fp12 {
@@ -192,6 +249,7 @@ impl Fp12 {
         self.c1.c2.mul_assign(&FROBENIUS_COEFF_FP12_C1[power % 12]);
     }
 
+    /// Multiplies `self` by the sparse element `c0 + c1 * v + c4 * v * w`.
     pub fn mul_by_014(&mut self, c0: &Fp2, c1: &Fp2, c4: &Fp2) {
         let mut aa = self.c0;
         aa.mul_by_01(c0, c1);
@@ -207,6 +265,8 @@ impl Fp12 {
         self.c0 += &aa;
     }
 
+    /// Multiplies `self` by the sparse element `c0 + (c3 + c4 * v) * w`, the
+    /// shape of the line functions evaluated in the Miller loop.
     pub fn mul_by_034(&mut self, c0: &Fp2, c3: &Fp2, c4: &Fp2) {
         let t0 = Fp6 {
             c0: self.c0.c0 * c0,
@@ -289,6 +349,48 @@ impl Fp12 {
     }
 }
 
+crate::impl_standard_distribution!(Fp12, crate::ff::Field);
+crate::impl_arkworks_field!(Fp12, Fp6, c0, c1);
+crate::impl_extension_field!(Fp12, Fp6, c0, c1);
+
+impl crate::serde::SerdeObject for Fp12 {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 672);
+        let [c0, c1] = [0, 336].map(|i| Fp6::from_raw_bytes_unchecked(&bytes[i..i + 336]));
+        Self { c0, c1 }
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 672 {
+            return None;
+        }
+        let [c0, c1] = [0, 336].map(|i| Fp6::from_raw_bytes(&bytes[i..i + 336]));
+        c0.zip(c1).map(|(c0, c1)| Self { c0, c1 })
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(672);
+        for c in [&self.c0, &self.c1] {
+            res.extend_from_slice(&c.to_raw_bytes());
+        }
+        res
+    }
+    #[cfg(feature = "std")]
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        let [c0, c1] = [(); 2].map(|_| Fp6::read_raw_unchecked(reader));
+        Self { c0, c1 }
+    }
+    #[cfg(feature = "std")]
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let c0 = Fp6::read_raw(reader)?;
+        let c1 = Fp6::read_raw(reader)?;
+        Ok(Self { c0, c1 })
+    }
+    #[cfg(feature = "std")]
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.c0.write_raw(writer)?;
+        self.c1.write_raw(writer)
+    }
+}
+
 impl Field for Fp12 {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
@@ -549,6 +651,7 @@ pub const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fp12, "field_arithmetic");
+    crate::field_testing_suite!(Fp12, "serialization");
     // extension field-specific
     crate::field_testing_suite!(Fp12, "f12_tests", Fp6, Fp2);
     crate::field_testing_suite!(
@@ -1,38 +1,29 @@
-use super::fp::{Fp, MODULUS_STR};
+use super::fp::Fp;
 use crate::ff::{Field, FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
-use crate::ff_ext::Legendre;
+use crate::ff_ext::{BatchMul, Legendre};
+use crate::pluto_eris::constants::fp::MODULUS_STR;
+use crate::pluto_eris::constants::{FP_NEG_ONE, U_SQUARE};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::convert::TryInto;
+use core::ops::MulAssign;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
-use std::cmp::Ordering;
-use std::ops::MulAssign;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 #[cfg(feature = "derive_serde")]
 use serde::{Deserialize, Serialize};
 
-/// -ALPHA is a quadratic non-residue in Fp. Fp2 = Fp[X]/(X^2 + ALPHA)
-/// We introduce the variable u such that u^2 = -ALPHA
-
-/// U_SQUARE = -5
-/// 0x24000000000024000130e0000d7f70e4a803ca76f439266f443f9a5cda8a6c7be4a7a5fe8fadffd6a2a7e8c30006b9459ffffcd2fffffffc
-const U_SQUARE: Fp = Fp::from_raw([
-    0x9ffffcd2fffffffc,
-    0xa2a7e8c30006b945,
-    0xe4a7a5fe8fadffd6,
-    0x443f9a5cda8a6c7b,
-    0xa803ca76f439266f,
-    0x0130e0000d7f70e4,
-    0x2400000000002400,
-]);
+// -ALPHA is a quadratic non-residue in Fp. Fp2 = Fp[X]/(X^2 + ALPHA)
+// We introduce the variable u such that u^2 = -ALPHA = U_SQUARE = -5
 
 const NEG_ONE: Fp2 = Fp2 {
-    c0: super::fp::NEG_ONE,
+    c0: FP_NEG_ONE,
     c1: Fp::ZERO,
 };
 
 /// An element of Fp2, represented by c0 + c1 * u.
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[derive(Copy, Clone, Debug)]
 #[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
 pub struct Fp2 {
     pub c0: Fp,
@@ -73,6 +64,16 @@ impl ConstantTimeEq for Fp2 {
     }
 }
 
+// Compares all the coefficients, in constant time like `ct_eq`.
+impl PartialEq for Fp2 {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
+    }
+}
+
+impl Eq for Fp2 {}
+
 impl Default for Fp2 {
     #[inline]
     fn default() -> Self {
@@ -80,6 +81,8 @@ impl Default for Fp2 {
     }
 }
 
+crate::__impl_zeroize!(Fp2);
+
 impl From<Fp2> for [u8; 112] {
     fn from(value: Fp2) -> [u8; 112] {
         value.to_bytes()
@@ -92,14 +95,7 @@ impl<'a> From<&'a Fp2> for [u8; 112] {
     }
 }
 
-impl Neg for Fp2 {
-    type Output = Fp2;
-
-    #[inline]
-    fn neg(self) -> Fp2 {
-        -&self
-    }
-}
+crate::impl_neg!(Fp2);
 
 impl<'a> Neg for &'a Fp2 {
     type Output = Fp2;
@@ -152,6 +148,11 @@ const SIZE: usize = 112;
 const COEF_SIZE: usize = 56;
 
 impl Fp2 {
+    /// Checks that all coefficients are fully reduced.
+    pub const fn debug_validate(&self) -> bool {
+        self.c0.debug_validate() && self.c1.debug_validate()
+    }
+
     /// Returns the zero element.
     #[inline]
     pub const fn zero() -> Fp2 {
@@ -187,8 +188,8 @@ impl Fp2 {
         let c1 = Fp::from_bytes(bytes[COEF_SIZE..SIZE].try_into().unwrap());
         CtOption::new(
             Fp2 {
-                c0: c0.unwrap(),
-                c1: c1.unwrap(),
+                c0: c0.unwrap_or(Fp::zero()),
+                c1: c1.unwrap_or(Fp::zero()),
             },
             c0.is_some() & c1.is_some(),
         )
@@ -205,6 +206,23 @@ impl Fp2 {
         res
     }
 
+    /// Attempts to convert a big-endian byte representation of an element
+    /// into a `Fp2`, the reverse of [`to_bytes`](Self::to_bytes), failing if
+    /// a coefficient is not canonical.
+    pub fn from_bytes_be(bytes: &[u8; SIZE]) -> CtOption<Fp2> {
+        let mut repr = *bytes;
+        repr.reverse();
+        Self::from_bytes(&repr)
+    }
+
+    /// Converts an element of `Fp2` into a byte representation in
+    /// big-endian byte order, the reverse of [`to_bytes`](Self::to_bytes).
+    pub fn to_bytes_be(self) -> [u8; SIZE] {
+        let mut res = self.to_bytes();
+        res.reverse();
+        res
+    }
+
     // TODO: This is a naive method using 4 multiplications
     pub fn mul_assign(&mut self, other: &Self) {
         // r0 = s0 * s0 + U_SQUARE * s1 * o1
@@ -331,6 +349,12 @@ impl Legendre for Fp2 {
     }
 }
 
+impl BatchMul for Fp2 {}
+
+crate::impl_standard_distribution!(Fp2, crate::ff::Field);
+crate::impl_arkworks_field!(Fp2, Fp, c0, c1);
+crate::impl_extension_field!(Fp2, Fp, c0, c1);
+
 impl Field for Fp2 {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
@@ -380,7 +404,7 @@ impl Field for Fp2 {
             c1: Fp::ZERO,
         };
 
-        // Algorithm (not constant time)
+        // The exponent is public, so `pow_vartime` only branches on public data.
         let b = self.pow_vartime([
             // (p-1)/4 =
             // 0x900000000000900004c3800035fdc392a00f29dbd0e499bd10fe69736a29b1ef929e97fa3eb7ff5a8a9fa30c001ae5167ffff34c0000000
@@ -398,22 +422,26 @@ impl Field for Fp2 {
         b_2_q.frobenius_map(1);
 
         let a0 = b_2_q * b_2;
-        if a0 == NEG_ONE {
-            CtOption::new(a0, Choice::from(0))
-        } else {
-            let mut x = b;
-            x.frobenius_map(1);
-            if x * b == Fp2::ONE {
-                let x0 = (b_2 * self).c0.sqrt().unwrap();
-                x.c0.mul_assign(x0);
-                x.c1.mul_assign(x0);
-                CtOption::new(x, Choice::from(1))
-            } else {
-                let x0 = (self * b_2 * F).sqrt().unwrap();
-                x *= x0 * E;
-                CtOption::new(x, Choice::from(1))
-            }
-        }
+        let is_square = !a0.ct_eq(&NEG_ONE);
+
+        // Both cases are computed and selected between, so that the running
+        // time does not depend on the input. In both, the radicand is in Fp.
+        let mut x = b;
+        x.frobenius_map(1);
+        let mut x_real = x;
+        let x0 = (b_2 * self).c0.sqrt().unwrap_or(Fp::ZERO);
+        x_real.c0.mul_assign(x0);
+        x_real.c1.mul_assign(x0);
+        let x0 = Fp2 {
+            c0: (self * b_2 * F).c0.sqrt().unwrap_or(Fp::ZERO),
+            c1: Fp::ZERO,
+        };
+        let x_other = x * x0 * E;
+
+        CtOption::new(
+            Fp2::conditional_select(&x_other, &x_real, (x * b).ct_eq(&Fp2::ONE)),
+            is_square,
+        )
     }
 
     fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
@@ -427,11 +455,7 @@ impl Field for Fp2 {
 
 impl From<bool> for Fp2 {
     fn from(bit: bool) -> Fp2 {
-        if bit {
-            Fp2::ONE
-        } else {
-            Fp2::ZERO
-        }
+        Fp2::conditional_select(&Fp2::ZERO, &Fp2::ONE, Choice::from(bit as u8))
     }
 }
 
@@ -444,6 +468,42 @@ impl From<u64> for Fp2 {
     }
 }
 
+impl From<u128> for Fp2 {
+    fn from(val: u128) -> Self {
+        Fp2 {
+            c0: Fp::from(val),
+            c1: Fp::zero(),
+        }
+    }
+}
+
+impl From<i32> for Fp2 {
+    fn from(val: i32) -> Self {
+        Fp2 {
+            c0: Fp::from(val),
+            c1: Fp::zero(),
+        }
+    }
+}
+
+impl From<i64> for Fp2 {
+    fn from(val: i64) -> Self {
+        Fp2 {
+            c0: Fp::from(val),
+            c1: Fp::zero(),
+        }
+    }
+}
+
+impl From<i128> for Fp2 {
+    fn from(val: i128) -> Self {
+        Fp2 {
+            c0: Fp::from(val),
+            c1: Fp::zero(),
+        }
+    }
+}
+
 // This trait is only implemented to satisfy the requirement of CurveExt
 impl PrimeField for Fp2 {
     type Repr = Fp2Bytes;
@@ -473,10 +533,8 @@ impl PrimeField for Fp2 {
     };
 
     fn from_repr(repr: Self::Repr) -> CtOption<Self> {
-        let c0 = Fp::from_bytes(&repr.0[..COEF_SIZE].try_into().unwrap());
-        let c1 = Fp::from_bytes(&repr.0[COEF_SIZE..].try_into().unwrap());
-        // Disallow overflow representation
-        CtOption::new(Fp2::new(c0.unwrap(), c1.unwrap()), Choice::from(1))
+        // Fails, without panicking, if either coefficient is not canonical.
+        Self::from_bytes(&repr.0)
     }
 
     fn to_repr(&self) -> Self::Repr {
@@ -536,15 +594,18 @@ impl crate::serde::SerdeObject for Fp2 {
         }
         res
     }
+    #[cfg(feature = "std")]
     fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
         let [c0, c1] = [(); 2].map(|_| Fp::read_raw_unchecked(reader));
         Self { c0, c1 }
     }
+    #[cfg(feature = "std")]
     fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
         let c0 = Fp::read_raw(reader)?;
         let c1 = Fp::read_raw(reader)?;
         Ok(Self { c0, c1 })
     }
+    #[cfg(feature = "std")]
     fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
         self.c0.write_raw(writer)?;
         self.c1.write_raw(writer)
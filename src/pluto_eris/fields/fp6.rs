@@ -1,8 +1,11 @@
 use super::fp::Fp;
 use super::fp2::Fp2;
 use crate::ff::Field;
+use alloc::vec::Vec;
 use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
+#[cfg(feature = "derive_serde")]
+use serde::{Deserialize, Serialize};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 /// -BETA is a cubic non-residue in Fp2. Fp6 = Fp2[X]/(X^3 + BETA)
@@ -33,7 +36,8 @@ pub(crate) const V_CUBE: Fp2 = Fp2 {
     ]),
 };
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, Default)]
+#[cfg_attr(feature = "derive_serde", derive(Serialize, Deserialize))]
 /// The `Fp6` element c0 + c1 * v + c2 * v3
 pub struct Fp6 {
     pub c0: Fp2,
@@ -41,6 +45,8 @@ pub struct Fp6 {
     pub c2: Fp2,
 }
 
+crate::__impl_zeroize!(Fp6);
+
 impl ConditionallySelectable for Fp6 {
     fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         Fp6 {
@@ -57,15 +63,18 @@ impl ConstantTimeEq for Fp6 {
     }
 }
 
-impl Neg for Fp6 {
-    type Output = Fp6;
-
+// Compares all the coefficients, in constant time like `ct_eq`.
+impl PartialEq for Fp6 {
     #[inline]
-    fn neg(self) -> Fp6 {
-        -&self
+    fn eq(&self, other: &Self) -> bool {
+        bool::from(self.ct_eq(other))
     }
 }
 
+impl Eq for Fp6 {}
+
+crate::impl_neg!(Fp6);
+
 impl<'a> Neg for &'a Fp6 {
     type Output = Fp6;
 
@@ -112,6 +121,57 @@ impl_binops_multiplicative!(Fp6, Fp6);
 impl_sum_prod!(Fp6);
 
 impl Fp6 {
+    /// Checks that all coefficients are fully reduced.
+    pub const fn debug_validate(&self) -> bool {
+        self.c0.debug_validate() && self.c1.debug_validate() && self.c2.debug_validate()
+    }
+
+    pub const fn size() -> usize {
+        336
+    }
+
+    /// Attempts to convert a little-endian byte representation of
+    /// an element into a `Fp6`, failing if a coefficient is not canonical.
+    pub fn from_bytes(bytes: &[u8; 336]) -> CtOption<Fp6> {
+        let [c0, c1, c2] =
+            [0, 112, 224].map(|i| Fp2::from_bytes(bytes[i..i + 112].try_into().unwrap()));
+        CtOption::new(
+            Fp6 {
+                c0: c0.unwrap_or(Fp2::ZERO),
+                c1: c1.unwrap_or(Fp2::ZERO),
+                c2: c2.unwrap_or(Fp2::ZERO),
+            },
+            c0.is_some() & c1.is_some() & c2.is_some(),
+        )
+    }
+
+    /// Converts an element of `Fp6` into a byte representation in
+    /// little-endian byte order, with the coefficients in order.
+    pub fn to_bytes(self) -> [u8; 336] {
+        let mut res = [0u8; 336];
+        res[0..112].copy_from_slice(&self.c0.to_bytes());
+        res[112..224].copy_from_slice(&self.c1.to_bytes());
+        res[224..336].copy_from_slice(&self.c2.to_bytes());
+        res
+    }
+
+    /// Attempts to convert a big-endian byte representation of an element
+    /// into a `Fp6`, the reverse of [`to_bytes`](Self::to_bytes), failing if
+    /// a coefficient is not canonical.
+    pub fn from_bytes_be(bytes: &[u8; 336]) -> CtOption<Fp6> {
+        let mut repr = *bytes;
+        repr.reverse();
+        Self::from_bytes(&repr)
+    }
+
+    /// Converts an element of `Fp6` into a byte representation in
+    /// big-endian byte order, the reverse of [`to_bytes`](Self::to_bytes).
+    pub fn to_bytes_be(self) -> [u8; 336] {
+        let mut res = self.to_bytes();
+        res.reverse();
+        res
+    }
+
     #[inline]
     pub const fn zero() -> Self {
         Fp6 {
@@ -286,13 +346,14 @@ impl Fp6 {
 
     /// Multiply by cubic nonresidue v.
     pub fn mul_by_nonresidue(&mut self) {
-        use std::mem::swap;
+        use core::mem::swap;
         swap(&mut self.c0, &mut self.c1);
         swap(&mut self.c0, &mut self.c2);
         // c0, c1, c2 -> c2, c0, c1
         self.c0.mul_by_nonresidue();
     }
 
+    /// Multiplies `self` by the sparse element `c1 * v`.
     pub fn mul_by_1(&mut self, c1: &Fp2) {
         let mut b_b = self.c1;
         b_b *= c1;
@@ -321,6 +382,7 @@ impl Fp6 {
         self.c2 = b_b;
     }
 
+    /// Multiplies `self` by the sparse element `c0 + c1 * v`.
     pub fn mul_by_01(&mut self, c0: &Fp2, c1: &Fp2) {
         let mut a_a = self.c0;
         let mut b_b = self.c1;
@@ -415,6 +477,50 @@ impl Fp6 {
     }
 }
 
+crate::impl_standard_distribution!(Fp6, crate::ff::Field);
+crate::impl_arkworks_field!(Fp6, Fp2, c0, c1, c2);
+crate::impl_extension_field!(Fp6, Fp2, c0, c1, c2);
+
+impl crate::serde::SerdeObject for Fp6 {
+    fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
+        debug_assert_eq!(bytes.len(), 336);
+        let [c0, c1, c2] = [0, 112, 224].map(|i| Fp2::from_raw_bytes_unchecked(&bytes[i..i + 112]));
+        Self { c0, c1, c2 }
+    }
+    fn from_raw_bytes(bytes: &[u8]) -> Option<Self> {
+        if bytes.len() != 336 {
+            return None;
+        }
+        let [c0, c1, c2] = [0, 112, 224].map(|i| Fp2::from_raw_bytes(&bytes[i..i + 112]));
+        c0.zip(c1).zip(c2).map(|((c0, c1), c2)| Self { c0, c1, c2 })
+    }
+    fn to_raw_bytes(&self) -> Vec<u8> {
+        let mut res = Vec::with_capacity(336);
+        for c in [&self.c0, &self.c1, &self.c2] {
+            res.extend_from_slice(&c.to_raw_bytes());
+        }
+        res
+    }
+    #[cfg(feature = "std")]
+    fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
+        let [c0, c1, c2] = [(); 3].map(|_| Fp2::read_raw_unchecked(reader));
+        Self { c0, c1, c2 }
+    }
+    #[cfg(feature = "std")]
+    fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let c0 = Fp2::read_raw(reader)?;
+        let c1 = Fp2::read_raw(reader)?;
+        let c2 = Fp2::read_raw(reader)?;
+        Ok(Self { c0, c1, c2 })
+    }
+    #[cfg(feature = "std")]
+    fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.c0.write_raw(writer)?;
+        self.c1.write_raw(writer)?;
+        self.c2.write_raw(writer)
+    }
+}
+
 impl Field for Fp6 {
     const ZERO: Self = Self::zero();
     const ONE: Self = Self::one();
@@ -662,6 +768,7 @@ pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
 mod test {
     use super::*;
     crate::field_testing_suite!(Fp6, "field_arithmetic");
+    crate::field_testing_suite!(Fp6, "serialization");
     // extension field-specific
     crate::field_testing_suite!(Fp6, "f6_tests", Fp2);
     crate::field_testing_suite!(
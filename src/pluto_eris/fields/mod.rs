@@ -15,6 +15,17 @@ macro_rules! impl_from_u64_7_limbs {
     };
 }
 
+#[macro_export]
+macro_rules! impl_from_u128_7_limbs {
+    ($field:ident, $r2:ident) => {
+        impl From<u128> for $field {
+            fn from(val: u128) -> $field {
+                $field([val as u64, (val >> 64) as u64, 0, 0, 0, 0, 0]) * $r2
+            }
+        }
+    };
+}
+
 #[macro_export]
 macro_rules! field_common_7_limbs {
     (
@@ -31,6 +42,10 @@ macro_rules! field_common_7_limbs {
         $r2:ident,
         $r3:ident
     ) => {
+        $crate::impl_standard_distribution!($field, $crate::ff::Field);
+        $crate::impl_arkworks_field!($field);
+        $crate::impl_extension_field!($field);
+
         impl $field {
             /// Returns zero, the additive identity.
             #[inline]
@@ -73,7 +88,7 @@ macro_rules! field_common_7_limbs {
                 ]);
                 let d1 = $field([limbs[7], 0u64, 0u64, 0u64, 0u64, 0u64, 0u64]);
                 // Convert to Montgomery form
-                d0 * $r2 + d1 * $r3
+                $field::add(&$field::mul(&d0, &$r2), &$field::mul(&d1, &$r3))
             }
 
             /// Converts from an integer represented in little endian
@@ -245,6 +260,87 @@ macro_rules! field_common_7_limbs {
                 <Self as ff::PrimeField>::to_repr(self).repr
             }
 
+            /// Attempts to convert a big-endian byte representation of
+            /// a scalar into a `$field`, failing if the input is not canonical.
+            pub fn from_bytes_be(bytes: &[u8; 56]) -> CtOption<$field> {
+                let mut repr = *bytes;
+                repr.reverse();
+                Self::from_bytes(&repr)
+            }
+
+            /// Converts an element of `$field` into a byte representation in
+            /// big-endian byte order.
+            pub fn to_bytes_be(&self) -> [u8; 56] {
+                let mut res = self.to_bytes();
+                res.reverse();
+                res
+            }
+
+            /// Attempts to convert a big-endian byte slice into a `$field`, see
+            /// [`from_be_slice`]($crate::ff_ext::radix::from_be_slice).
+            pub fn from_be_slice(
+                bytes: &[u8],
+            ) -> Result<$field, $crate::serde::FieldDecodingError> {
+                $crate::ff_ext::radix::from_be_slice(bytes)
+            }
+
+            /// Reduces a little-endian integer of any length modulo the
+            /// modulus.
+            pub fn from_bytes_mod_order(bytes: &[u8]) -> $field {
+                $crate::ff_ext::radix::reduce_le_bytes(bytes)
+            }
+
+            /// Reduces a big-endian integer of any length modulo the modulus.
+            pub fn from_be_bytes_mod_order(bytes: &[u8]) -> $field {
+                $crate::ff_ext::radix::reduce_be_bytes(bytes)
+            }
+
+            /// Parses a decimal, or `0x`-prefixed hexadecimal, integer of any
+            /// length and reduces it modulo the modulus. `FromStr` instead
+            /// rejects integers that are not below the modulus.
+            pub fn from_str_mod_order(
+                s: &str,
+            ) -> Result<$field, $crate::ff_ext::radix::ParseFieldError> {
+                $crate::ff_ext::radix::parse_reduced(s)
+            }
+
+            /// Returns the size of the largest radix-2 evaluation domain of the
+            /// field, `2^S`.
+            pub const fn max_fft_size() -> u64 {
+                1 << <Self as ff::PrimeField>::S
+            }
+
+            /// Returns whether the field has a radix-2 evaluation domain of size
+            /// `n`.
+            pub const fn supports_domain(n: u64) -> bool {
+                n.is_power_of_two() && n <= Self::max_fft_size()
+            }
+
+            /// Returns the size of the smallest radix-2 evaluation domain holding
+            /// at least `n` elements, if the field has one.
+            pub const fn smallest_domain_at_least(n: u64) -> Option<u64> {
+                match n.checked_next_power_of_two() {
+                    Some(size) if size <= Self::max_fft_size() => Some(size),
+                    _ => None,
+                }
+            }
+
+            /// Decomposes `self` into base-`base` digits in `(-base/2, base/2]`,
+            /// least significant first, such that `sum(d_i * base^i) == self`
+            /// over the integers.
+            ///
+            /// # Panics
+            ///
+            /// Panics if `base` is not in `[2, i64::MAX]`.
+            pub fn to_balanced_digits(&self, base: u64) -> alloc::vec::Vec<i64> {
+                $crate::arithmetic::balanced_digits(&self.to_bytes(), base)
+            }
+
+            /// Checks that the Montgomery form of `self` is fully reduced.
+            pub const fn debug_validate(&self) -> bool {
+                Self::is_less_than(&self.0, &$modulus.0)
+            }
+
             /// Lexicographic comparison of Montgomery forms.
             #[inline(always)]
             const fn is_less_than(x: &[u64; 7], y: &[u64; 7]) -> bool {
@@ -270,6 +366,9 @@ macro_rules! field_common_7_limbs {
             }
         }
 
+        $crate::impl_field_str!($field);
+        $crate::__impl_zeroize!($field);
+
         impl Default for $field {
             #[inline]
             fn default() -> Self {
@@ -279,11 +378,7 @@ macro_rules! field_common_7_limbs {
 
         impl From<bool> for $field {
             fn from(bit: bool) -> $field {
-                if bit {
-                    $field::one()
-                } else {
-                    $field::zero()
-                }
+                $field::conditional_select(&$field::zero(), &$field::one(), Choice::from(bit as u8))
             }
         }
 
@@ -299,6 +394,23 @@ macro_rules! field_common_7_limbs {
             }
         }
 
+        // Equality compares every limb, so that it runs in constant time like
+        // `ct_eq` instead of stopping at the first difference.
+        impl PartialEq for $field {
+            #[inline]
+            fn eq(&self, other: &Self) -> bool {
+                bool::from(self.ct_eq(other))
+            }
+        }
+
+        impl Eq for $field {}
+
+        impl core::hash::Hash for $field {
+            fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
+
         impl core::cmp::Ord for $field {
             fn cmp(&self, other: &Self) -> core::cmp::Ordering {
                 let left = self.to_repr();
@@ -339,24 +451,19 @@ macro_rules! field_common_7_limbs {
 
             #[inline]
             fn neg(self) -> $field {
+                $crate::debug_validate!(self);
                 self.neg()
             }
         }
 
-        impl Neg for $field {
-            type Output = $field;
-
-            #[inline]
-            fn neg(self) -> $field {
-                -&self
-            }
-        }
+        $crate::impl_neg!($field);
 
         impl<'a, 'b> Sub<&'b $field> for &'a $field {
             type Output = $field;
 
             #[inline]
             fn sub(self, rhs: &'b $field) -> $field {
+                $crate::debug_validate!(self, rhs);
                 self.sub(rhs)
             }
         }
@@ -366,6 +473,7 @@ macro_rules! field_common_7_limbs {
 
             #[inline]
             fn add(self, rhs: &'b $field) -> $field {
+                $crate::debug_validate!(self, rhs);
                 self.add(rhs)
             }
         }
@@ -375,6 +483,7 @@ macro_rules! field_common_7_limbs {
 
             #[inline]
             fn mul(self, rhs: &'b $field) -> $field {
+                $crate::debug_validate!(self, rhs);
                 self.mul(rhs)
             }
         }
@@ -391,6 +500,8 @@ macro_rules! field_common_7_limbs {
             }
         }
 
+        impl $crate::ff_ext::BatchMul for $field {}
+
         impl $crate::serde::SerdeObject for $field {
             fn from_raw_bytes_unchecked(bytes: &[u8]) -> Self {
                 debug_assert_eq!(bytes.len(), 56);
@@ -405,13 +516,14 @@ macro_rules! field_common_7_limbs {
                 let elt = Self::from_raw_bytes_unchecked(bytes);
                 Self::is_less_than(&elt.0, &$modulus.0).then(|| elt)
             }
-            fn to_raw_bytes(&self) -> Vec<u8> {
-                let mut res = Vec::with_capacity(56);
+            fn to_raw_bytes(&self) -> alloc::vec::Vec<u8> {
+                let mut res = alloc::vec::Vec::with_capacity(56);
                 for limb in self.0.iter() {
                     res.extend_from_slice(&limb.to_le_bytes());
                 }
                 res
             }
+            #[cfg(feature = "std")]
             fn read_raw_unchecked<R: std::io::Read>(reader: &mut R) -> Self {
                 let inner = [(); 7].map(|_| {
                     let mut buf = [0; 8];
@@ -420,6 +532,7 @@ macro_rules! field_common_7_limbs {
                 });
                 Self(inner)
             }
+            #[cfg(feature = "std")]
             fn read_raw<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
                 let mut inner = [0u64; 7];
                 for limb in inner.iter_mut() {
@@ -437,6 +550,7 @@ macro_rules! field_common_7_limbs {
                         )
                     })
             }
+            #[cfg(feature = "std")]
             fn write_raw<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
                 for limb in self.0.iter() {
                     writer.write_all(&limb.to_le_bytes())?;
@@ -854,3 +968,82 @@ macro_rules! field_bits_7_limbs {
         }
     };
 }
+
+/// Serialization of the 7-limb prime fields. Human-readable formats use
+/// `0x`-prefixed big-endian hex strings, as the other prime fields do, and
+/// still accept the arrays of Montgomery limbs written by earlier versions.
+/// Binary formats keep the Montgomery limbs.
+#[macro_export]
+macro_rules! serialize_deserialize_7_limbs_primefield {
+    ($field:ident, $modulus:ident) => {
+        impl ::serde::Serialize for $field {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                if serializer.is_human_readable() {
+                    $crate::serde::hex_be::serialize(&self.to_repr().repr, serializer)
+                } else {
+                    serializer.serialize_newtype_struct(stringify!($field), &self.0)
+                }
+            }
+        }
+
+        impl<'de> ::serde::Deserialize<'de> for $field {
+            fn deserialize<D: ::serde::Deserializer<'de>>(
+                deserializer: D,
+            ) -> Result<Self, D::Error> {
+                struct Visitor;
+
+                impl Visitor {
+                    fn from_limbs<E: ::serde::de::Error>(limbs: [u64; 7]) -> Result<$field, E> {
+                        if $field::is_less_than(&limbs, &$modulus.0) {
+                            Ok($field(limbs))
+                        } else {
+                            Err(E::custom(
+                                "deserialized limbs don't encode a valid field element",
+                            ))
+                        }
+                    }
+                }
+
+                impl<'de> ::serde::de::Visitor<'de> for Visitor {
+                    type Value = $field;
+
+                    fn expecting(&self, f: &mut core::fmt::Formatter) -> core::fmt::Result {
+                        f.write_str("a hex string or the Montgomery limbs of a field element")
+                    }
+
+                    fn visit_str<E: ::serde::de::Error>(self, s: &str) -> Result<$field, E> {
+                        let bytes: [u8; 56] =
+                            $crate::serde::hex_be::decode(s).map_err(E::custom)?;
+                        Option::from($field::from_repr(bytes.into())).ok_or_else(|| {
+                            E::custom("deserialized bytes don't encode a valid field element")
+                        })
+                    }
+
+                    fn visit_seq<A: ::serde::de::SeqAccess<'de>>(
+                        self,
+                        seq: A,
+                    ) -> Result<$field, A::Error> {
+                        let limbs = <[u64; 7] as ::serde::Deserialize>::deserialize(
+                            ::serde::de::value::SeqAccessDeserializer::new(seq),
+                        )?;
+                        Self::from_limbs(limbs)
+                    }
+
+                    fn visit_newtype_struct<E: ::serde::Deserializer<'de>>(
+                        self,
+                        deserializer: E,
+                    ) -> Result<$field, E::Error> {
+                        let limbs = <[u64; 7] as ::serde::Deserialize>::deserialize(deserializer)?;
+                        Self::from_limbs(limbs)
+                    }
+                }
+
+                if deserializer.is_human_readable() {
+                    deserializer.deserialize_any(Visitor)
+                } else {
+                    deserializer.deserialize_newtype_struct(stringify!($field), Visitor)
+                }
+            }
+        }
+    };
+}
@@ -0,0 +1,436 @@
+//! BIP-340 x-only public keys and Schnorr signature verification.
+//!
+//! BIP-340 identifies a point by its `x` coordinate alone, the 32-byte
+//! big-endian x-only encoding, and picks the point with an even `y` among the
+//! two with that `x`, see [`lift_x`]. Challenges are tagged hashes, SHA-256
+//! hashes prefixed with the hash of a tag, see [`tagged_hash`].
+
+use sha2::{Digest, Sha256};
+use subtle::{Choice, ConditionallySelectable, CtOption};
+
+use super::{Fp, Fq, Secp256k1, Secp256k1Affine};
+use crate::ecdsa::{decode_compressed, encode_compressed};
+use crate::ff::{Field, PrimeField};
+use crate::ff_ext::radix::reduce_be_bytes;
+use crate::group::{prime::PrimeCurveAffine, Curve};
+use crate::msm::multi_mul2;
+use crate::CurveExt;
+
+/// Tag of the challenge hash of BIP-340 signatures.
+pub const CHALLENGE_TAG: &[u8] = b"BIP0340/challenge";
+
+/// Returns the point with `x` coordinate `x` and an even `y`, if there is one.
+///
+/// This runs in constant time.
+pub fn lift_x(x: &Fp) -> CtOption<Secp256k1Affine> {
+    let y2 = x.square() * x + Secp256k1::b();
+    y2.sqrt().map(|y| Secp256k1Affine {
+        x: *x,
+        y: Fp::conditional_select(&y, &-y, y.is_odd()),
+    })
+}
+
+/// Returns whether the `y` coordinate of `point` is even. The identity has an
+/// even `y`.
+pub fn has_even_y(point: &Secp256k1Affine) -> Choice {
+    !point.y.is_odd()
+}
+
+/// Returns the x-only encoding of `point`, its big-endian `x` coordinate.
+///
+/// The point may have an odd `y`, in which case the encoding is that of its
+/// negation.
+pub fn to_x_only(point: &Secp256k1Affine) -> [u8; 32] {
+    to_be_bytes(&point.x)
+}
+
+/// Decodes an x-only encoding, failing if the integer is not below the field
+/// modulus or is not the `x` coordinate of a point.
+pub fn from_x_only(bytes: &[u8; 32]) -> CtOption<Secp256k1Affine> {
+    from_be_bytes::<Fp>(bytes).and_then(|x| lift_x(&x))
+}
+
+impl Secp256k1Affine {
+    /// Returns the 33-byte SEC1 compressed encoding of the point, a `0x02`
+    /// or `0x03` prefix for an even or odd `y` followed by the big-endian `x`.
+    /// The identity has no encoding, as for
+    /// [`ecdsa::encode_public_key`](crate::ecdsa::encode_public_key).
+    pub fn to_sec1_compressed(&self) -> CtOption<[u8; 33]> {
+        let mut bytes = [0u8; 33];
+        let is_some = encode_compressed(self, &mut bytes);
+        CtOption::new(bytes, is_some)
+    }
+
+    /// Decodes a SEC1 compressed encoding as returned by
+    /// [`Self::to_sec1_compressed`], failing if the prefix is not `0x02` or
+    /// `0x03`, or `x` is not canonical or not the `x` coordinate of a point.
+    pub fn from_sec1_compressed(bytes: &[u8; 33]) -> CtOption<Self> {
+        decode_compressed(bytes)
+    }
+
+    /// Returns the BIP-340 x-only encoding of the point, see [`to_x_only`].
+    pub fn to_x_only(&self) -> [u8; 32] {
+        to_x_only(self)
+    }
+
+    /// Decodes a BIP-340 x-only encoding into the point with an even `y`, see
+    /// [`from_x_only`].
+    pub fn from_x_only(bytes: &[u8; 32]) -> CtOption<Self> {
+        from_x_only(bytes)
+    }
+}
+
+/// Returns the BIP-340 tagged hash of the concatenation of `parts`,
+/// `SHA256(SHA256(tag) || SHA256(tag) || parts)`.
+pub fn tagged_hash(tag: &[u8], parts: &[&[u8]]) -> [u8; 32] {
+    let tag_hash = Sha256::digest(tag);
+    let mut hasher = Sha256::new();
+    hasher.update(tag_hash);
+    hasher.update(tag_hash);
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// Returns the challenge `e` of a signature with nonce `r_x` under the public
+/// key `public_key` for message `msg`, the tagged hash of `r_x || public_key
+/// || msg` under [`CHALLENGE_TAG`] reduced modulo the group order.
+pub fn challenge(r_x: &[u8; 32], public_key: &[u8; 32], msg: &[u8]) -> Fq {
+    reduce_be_bytes(&tagged_hash(CHALLENGE_TAG, &[r_x, public_key, msg]))
+}
+
+/// Verifies the 64-byte BIP-340 signature `signature` of `msg` under the
+/// x-only public key `public_key`.
+///
+/// This runs in variable time, as all inputs are public.
+pub fn verify(public_key: &[u8; 32], msg: &[u8], signature: &[u8; 64]) -> bool {
+    let Some(p) = Option::<Secp256k1Affine>::from(from_x_only(public_key)) else {
+        return false;
+    };
+    let r_x: [u8; 32] = signature[..32].try_into().unwrap();
+    let (Some(r), Some(s)) = (
+        Option::<Fp>::from(from_be_bytes(&r_x)),
+        Option::<Fq>::from(from_be_bytes(signature[32..].try_into().unwrap())),
+    ) else {
+        return false;
+    };
+
+    // R = s * G - e * P
+    let e = challenge(&r_x, public_key, msg);
+    let point = multi_mul2(&s, &Secp256k1::generator(), &-e, &p.to_curve()).to_affine();
+    !bool::from(point.is_identity()) && bool::from(has_even_y(&point)) && point.x == r
+}
+
+fn from_be_bytes<F: PrimeField<Repr = [u8; 32]>>(bytes: &[u8; 32]) -> CtOption<F> {
+    let mut repr = *bytes;
+    repr.reverse();
+    F::from_repr(repr)
+}
+
+fn to_be_bytes<F: PrimeField<Repr = [u8; 32]>>(x: &F) -> [u8; 32] {
+    let mut bytes = x.to_repr();
+    bytes.reverse();
+    bytes
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::ecdsa::{encode_public_key, parse_public_key};
+    use rand_core::OsRng;
+
+    fn from_hex<const N: usize>(hex: &str) -> [u8; N] {
+        hex::decode(hex).unwrap().try_into().unwrap()
+    }
+
+    #[test]
+    fn test_lift_x() {
+        for _ in 0..100 {
+            let point = (Secp256k1::generator() * Fq::random(OsRng)).to_affine();
+            let lifted = from_x_only(&to_x_only(&point)).unwrap();
+            assert!(bool::from(has_even_y(&lifted)));
+            assert_eq!(lifted.x, point.x);
+            assert!(lifted == point || lifted == -point);
+        }
+
+        // x = 5 is not the x coordinate of a point, as 5^3 + 7 is not a square.
+        assert!(bool::from(lift_x(&Fp::from(5)).is_none()));
+        // The modulus is not a canonical encoding.
+        let modulus = from_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f");
+        assert!(bool::from(from_x_only(&modulus).is_none()));
+    }
+
+    #[test]
+    fn test_encodings() {
+        let generator = Secp256k1Affine::generator();
+        let expected: [u8; 33] =
+            from_hex("0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798");
+        assert_eq!(generator.to_sec1_compressed().unwrap(), expected);
+        assert_eq!(generator.to_x_only()[..], expected[1..]);
+        assert_eq!(
+            Secp256k1Affine::from_sec1_compressed(&expected).unwrap(),
+            generator
+        );
+
+        // The identity has no encoding.
+        let identity = Secp256k1Affine::identity();
+        assert!(bool::from(identity.to_sec1_compressed().is_none()));
+        assert!(bool::from(
+            Secp256k1Affine::from_sec1_compressed(&[0u8; 33]).is_none()
+        ));
+
+        for _ in 0..100 {
+            let point = (Secp256k1::generator() * Fq::random(OsRng)).to_affine();
+            let sec1 = point.to_sec1_compressed().unwrap();
+            assert_eq!(sec1[0], 2 + u8::from(bool::from(point.y.is_odd())));
+            assert_eq!(Secp256k1Affine::from_sec1_compressed(&sec1).unwrap(), point);
+            // The same codec as ECDSA public keys.
+            assert_eq!(encode_public_key(&point, true), sec1);
+            assert_eq!(parse_public_key::<Secp256k1Affine>(&sec1), Ok(point));
+
+            // Both encodings share `x`, the x-only one implies an even `y`.
+            let x_only = point.to_x_only();
+            assert_eq!(x_only[..], sec1[1..]);
+            let mut even = sec1;
+            even[0] = 0x02;
+            assert_eq!(
+                Secp256k1Affine::from_x_only(&x_only).unwrap(),
+                Secp256k1Affine::from_sec1_compressed(&even).unwrap()
+            );
+
+            let mut invalid = sec1;
+            invalid[0] = 0x04;
+            assert!(bool::from(
+                Secp256k1Affine::from_sec1_compressed(&invalid).is_none()
+            ));
+        }
+
+        // x = 5 is not the x coordinate of a point.
+        let mut invalid = [0u8; 33];
+        invalid[0] = 0x02;
+        invalid[32] = 5;
+        assert!(bool::from(
+            Secp256k1Affine::from_sec1_compressed(&invalid).is_none()
+        ));
+    }
+
+    #[test]
+    fn test_tagged_hash() {
+        // The tagged hash is a SHA-256 hash over the tag hashes and the message.
+        let tag_hash = Sha256::digest(CHALLENGE_TAG);
+        let expected: [u8; 32] = Sha256::new()
+            .chain_update(tag_hash)
+            .chain_update(tag_hash)
+            .chain_update(b"message")
+            .finalize()
+            .into();
+        assert_eq!(tagged_hash(CHALLENGE_TAG, &[b"mess", b"age"]), expected);
+    }
+
+    #[test]
+    fn test_verify() {
+        // Test vector 0 of BIP-340: secret key 3, zero auxiliary randomness,
+        // zero message.
+        let public_key =
+            from_hex("f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9");
+        let msg = [0u8; 32];
+        let signature = from_hex(
+            "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca8215\
+             25f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0",
+        );
+        assert_eq!(
+            to_x_only(&(Secp256k1::generator() * Fq::from(3)).to_affine()),
+            public_key
+        );
+        assert!(verify(&public_key, &msg, &signature));
+
+        let mut other = signature;
+        other[63] ^= 1;
+        assert!(!verify(&public_key, &msg, &other));
+        assert!(!verify(&public_key, &[1u8; 32], &signature));
+
+        // Signatures produced by the textbook signing algorithm.
+        for _ in 0..20 {
+            let sk = Fq::random(OsRng);
+            let p = (Secp256k1::generator() * sk).to_affine();
+            let sk = if bool::from(has_even_y(&p)) { sk } else { -sk };
+            let k = Fq::random(OsRng);
+            let r = (Secp256k1::generator() * k).to_affine();
+            let k = if bool::from(has_even_y(&r)) { k } else { -k };
+            let (r_x, pk) = (to_x_only(&r), to_x_only(&p));
+            let s = k + challenge(&r_x, &pk, b"message") * sk;
+
+            let mut signature = [0u8; 64];
+            signature[..32].copy_from_slice(&r_x);
+            signature[32..].copy_from_slice(&to_be_bytes(&s));
+            assert!(verify(&pk, b"message", &signature));
+            assert!(!verify(&pk, b"other message", &signature));
+        }
+    }
+
+    #[test]
+    fn test_verify_vectors() {
+        // The test vectors of BIP-340, `test-vectors.csv`: the public key, the
+        // message, the signature and the verification result.
+        let long_msg = "99".repeat(100);
+        for (index, (public_key, msg, signature, expected)) in [
+            (
+                "f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9",
+                "0000000000000000000000000000000000000000000000000000000000000000",
+                "e907831f80848d1069a5371b402410364bdf1c5f8307b0084c55f1ce2dca8215\
+                 25f66a4a85ea8b71e482a74f382d2ce5ebeee8fdb2172f477df4900d310536c0",
+                true,
+            ),
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "6896bd60eeae296db48a229ff71dfe071bde413e6d43f917dc8dcf8c78de3341\
+                 8906d11ac976abccb20b091292bff4ea897efcb639ea871cfa95f6de339e4b0a",
+                true,
+            ),
+            (
+                "dd308afec5777e13121fa72b9cc1b7cc0139715309b086c960e18fd969774eb8",
+                "7e2d58d8b3bcdf1abadec7829054f90dda9805aab56c77333024b9d0a508b75c",
+                "5831aaeed7b44bb74e5eab94ba9d4294c49bcf2a60728d8b4c200f50dd313c1b\
+                 ab745879a5ad954a72c45a91c3a51d3c7adea98d82f8481e0e1e03674a6f3fb7",
+                true,
+            ),
+            // 3: test fails if msg is reduced modulo p or n.
+            (
+                "25d1dff95105f5253c4022f628a996ad3a0d95fbf21d468a1b33f8c160d8f517",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+                "7eb0509757e246f19449885651611cb965ecc1a187dd51b64fda1edc9637d5ec\
+                 97582b9cb13db3933705b32ba982af5af25fd78881ebb32771fc5922efc66ea3",
+                true,
+            ),
+            (
+                "d69c3509bb99e412e68b0fe8544e72837dfa30746d8be2aa65975f29d22dc7b9",
+                "4df3c3f68fcc83b27e9d42c90431a72499f17875c81a599b566c9889b9696703",
+                "00000000000000000000003b78ce563f89a0ed9414f5aa28ad0d96d6795f9c63\
+                 76afb1548af603b3eb45c9f8207dee1060cb71c04e80f593060b07d28308d7f4",
+                true,
+            ),
+            // 5: public key not on the curve.
+            (
+                "eefdea4cdb677750a420fee807eacf21eb9898ae79b9768766e4faa04a2d4a34",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769\
+                 69e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b",
+                false,
+            ),
+            // 6: has_even_y(R) is false.
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "fff97bd5755eeea420453a14355235d382f6472f8568a18b2f057a1460297556\
+                 3cc27944640ac607cd107ae10923d9ef7a73c643e166be5ebeafa34b1ac553e2",
+                false,
+            ),
+            // 7: negated message.
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "1fa62e331edbc21c394792d2ab1100a7b432b013df3f6ff4f99fcb33e0e1515f\
+                 28890b3edb6e7189b630448b515ce4f8622a954cfe545735aaea5134fccdb2bd",
+                false,
+            ),
+            // 8: negated s value.
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769\
+                 961764b3aa9b2ffcb6ef947b6887a226e8d7c93e00c5ed0c1834ff0d0c2e6da6",
+                false,
+            ),
+            // 9: sG - eP is infinite, with r = 0.
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "0000000000000000000000000000000000000000000000000000000000000000\
+                 123dda8328af9c23a94c1feecfd123ba4fb73476f0d594dcb65c6425bd186051",
+                false,
+            ),
+            // 10: sG - eP is infinite, with r = 1.
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "0000000000000000000000000000000000000000000000000000000000000001\
+                 7615fbaf5ae28864013c099742deadb4dba87f11ac6754f93780d5a1837cf197",
+                false,
+            ),
+            // 11: sig[0:32] is not an X coordinate on the curve.
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "4a298dacae57395a15d0795ddbfd1dcb564da82b0f269bc70a74f8220429ba1d\
+                 69e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b",
+                false,
+            ),
+            // 12: sig[0:32] is equal to field size.
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f\
+                 69e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b",
+                false,
+            ),
+            // 13: sig[32:64] is equal to curve order.
+            (
+                "dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769\
+                 fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+                false,
+            ),
+            // 14: public key exceeds the field size.
+            (
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc30",
+                "243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89",
+                "6cff5c3ba86c69ea4b7376f31a9bcb4f74c1976089b2d9963da2e5543e177769\
+                 69e89b4c5564d00349106b8497785dd7d1d713a8ae82b32fa79d5f7fc407d39b",
+                false,
+            ),
+            // 15: message of size 0.
+            (
+                "778caa53b4393ac467774d09497a87224bf9fab6f6e68b23086497324d6fd117",
+                "",
+                "71535db165ecd9fbbc046e5ffaea61186bb6ad436732fccc25291a55895464cf\
+                 6069ce26bf03466228f19a3a62db8a649f2d560fac652827d1af0574e427ab63",
+                true,
+            ),
+            // 16: message of size 1.
+            (
+                "778caa53b4393ac467774d09497a87224bf9fab6f6e68b23086497324d6fd117",
+                "11",
+                "08a20a0afef64124649232e0693c583ab1b9934ae63b4c3511f3ae1134c6a303\
+                 ea3173bfea6683bd101fa5aa5dbc1996fe7cacfc5a577d33ec14564cec2bacbf",
+                true,
+            ),
+            // 17: message of size 17.
+            (
+                "778caa53b4393ac467774d09497a87224bf9fab6f6e68b23086497324d6fd117",
+                "0102030405060708090a0b0c0d0e0f1011",
+                "5130f39a4059b43bc7cac09a19ece52b5d8699d1a71e3c52da9afdb6b50ac370\
+                 c4a482b77bf960f8681540e25b6771ece1e5a37fd80e5a51897c5566a97ea5a5",
+                true,
+            ),
+            // 18: message of size 100.
+            (
+                "778caa53b4393ac467774d09497a87224bf9fab6f6e68b23086497324d6fd117",
+                long_msg.as_str(),
+                "403b12b0d8555a344175ea7ec746566303321e5dbfa8be6f091635163eca79a8\
+                 585ed3e3170807e7c03b720fc54c7b23897fcba0e9d0b4a06894cfd249f22367",
+                true,
+            ),
+        ]
+        .into_iter()
+        .enumerate()
+        {
+            let public_key = from_hex(public_key);
+            let msg = hex::decode(msg).unwrap();
+            let signature = from_hex(signature);
+            assert_eq!(verify(&public_key, &msg, &signature), expected, "{index}");
+        }
+    }
+}
@@ -0,0 +1,437 @@
+//! Constants of the `secp256k1` fields and curves.
+//!
+//! Generated by `script/constants.py` from `script/params/secp256k1.toml`, do not
+//! modify. Building with the `generate-constants` feature regenerates it.
+
+use crate::secp256k1::Fp;
+
+pub(crate) mod fp {
+    use crate::secp256k1::Fp;
+
+    /// Constant representing the modulus
+    /// `0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f`
+    pub(crate) const MODULUS: Fp = Fp([
+        0xfffffffefffffc2f,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+
+    /// The modulus as u32 limbs.
+    #[cfg(not(target_pointer_width = "64"))]
+    pub(crate) const MODULUS_LIMBS_32: [u32; 8] = [
+        0xffff_fc2f,
+        0xffff_fffe,
+        0xffff_ffff,
+        0xffff_ffff,
+        0xffff_ffff,
+        0xffff_ffff,
+        0xffff_ffff,
+        0xffff_ffff,
+    ];
+
+    /// Constant representing the modulus as static str
+    pub(crate) const MODULUS_STR: &str =
+        "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
+
+    /// INV = -(p^{-1} mod 2^64) mod 2^64
+    pub(crate) const INV: u64 = 0xd838091dd2253531;
+
+    /// R = 2^256 mod p
+    pub(crate) const R: Fp = Fp([
+        0x00000001000003d1,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// R2 = 2^512 mod p
+    pub(crate) const R2: Fp = Fp([
+        0x000007a2000e90a1,
+        0x0000000000000001,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// R3 = 2^768 mod p
+    pub(crate) const R3: Fp = Fp([
+        0x002bb1e33795f671,
+        0x0000000100000b73,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// A generator of the multiplicative group of the field.
+    /// `0x3`
+    pub(crate) const MULTIPLICATIVE_GENERATOR: Fp = Fp::from_raw([
+        0x0000000000000003,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// The largest `S` such that `2^S` divides `p - 1`.
+    pub(crate) const S: u32 = 1;
+
+    /// MULTIPLICATIVE_GENERATOR^t where t * 2^S + 1 = p with t odd, a 2^S root of unity.
+    /// `0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e`
+    pub(crate) const ROOT_OF_UNITY: Fp = Fp::from_raw([
+        0xfffffffefffffc2e,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+
+    /// 1 / ROOT_OF_UNITY mod p
+    /// `0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2e`
+    pub(crate) const ROOT_OF_UNITY_INV: Fp = Fp::from_raw([
+        0xfffffffefffffc2e,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+    ]);
+
+    /// 1 / 2 mod p
+    /// `0x7fffffffffffffffffffffffffffffffffffffffffffffffffffffff7ffffe18`
+    pub(crate) const TWO_INV: Fp = Fp::from_raw([
+        0xffffffff7ffffe18,
+        0xffffffffffffffff,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    /// MULTIPLICATIVE_GENERATOR^{2^S}, a t root of unity.
+    /// `0x9`
+    pub(crate) const DELTA: Fp = Fp::from_raw([
+        0x0000000000000009,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// `ZETA^3 = 1 mod p` where `ZETA != 1 mod p`
+    /// `0x7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee`
+    pub(crate) const ZETA: Fp = Fp::from_raw([
+        0xc1396c28719501ee,
+        0x9cf0497512f58995,
+        0x6e64479eac3434e9,
+        0x7ae96a2b657c0710,
+    ]);
+}
+
+pub(crate) mod fq {
+    use crate::secp256k1::Fq;
+
+    /// Constant representing the modulus
+    /// `0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141`
+    pub(crate) const MODULUS: Fq = Fq([
+        0xbfd25e8cd0364141,
+        0xbaaedce6af48a03b,
+        0xfffffffffffffffe,
+        0xffffffffffffffff,
+    ]);
+
+    /// The modulus as u32 limbs.
+    #[cfg(not(target_pointer_width = "64"))]
+    pub(crate) const MODULUS_LIMBS_32: [u32; 8] = [
+        0xd036_4141,
+        0xbfd2_5e8c,
+        0xaf48_a03b,
+        0xbaae_dce6,
+        0xffff_fffe,
+        0xffff_ffff,
+        0xffff_ffff,
+        0xffff_ffff,
+    ];
+
+    /// Constant representing the modulus as static str
+    pub(crate) const MODULUS_STR: &str =
+        "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
+
+    /// INV = -(p^{-1} mod 2^64) mod 2^64
+    pub(crate) const INV: u64 = 0x4b0dff665588b13f;
+
+    /// R = 2^256 mod p
+    pub(crate) const R: Fq = Fq([
+        0x402da1732fc9bebf,
+        0x4551231950b75fc4,
+        0x0000000000000001,
+        0x0000000000000000,
+    ]);
+
+    /// R2 = 2^512 mod p
+    pub(crate) const R2: Fq = Fq([
+        0x896cf21467d7d140,
+        0x741496c20e7cf878,
+        0xe697f5e45bcd07c6,
+        0x9d671cd581c69bc5,
+    ]);
+
+    /// R3 = 2^768 mod p
+    pub(crate) const R3: Fq = Fq([
+        0x7bc0cfe0e9ff41ed,
+        0x0017648444d4322c,
+        0xb1b31347f1d0b2da,
+        0x555d800c18ef116d,
+    ]);
+
+    /// A generator of the multiplicative group of the field.
+    /// `0x7`
+    pub(crate) const MULTIPLICATIVE_GENERATOR: Fq = Fq::from_raw([
+        0x0000000000000007,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// The largest `S` such that `2^S` divides `p - 1`.
+    pub(crate) const S: u32 = 6;
+
+    /// MULTIPLICATIVE_GENERATOR^t where t * 2^S + 1 = p with t odd, a 2^S root of unity.
+    /// `0xc1dc060e7a91986df9879a3fbc483a898bdeab680756045992f4b5402b052f2`
+    pub(crate) const ROOT_OF_UNITY: Fq = Fq::from_raw([
+        0x992f4b5402b052f2,
+        0x98bdeab680756045,
+        0xdf9879a3fbc483a8,
+        0x0c1dc060e7a91986,
+    ]);
+
+    /// 1 / ROOT_OF_UNITY mod p
+    /// `0xfd3ae181f12d7096efc7b0c75b8cbb7277a275910aa413c3b6fb30a0884f0d1c`
+    pub(crate) const ROOT_OF_UNITY_INV: Fq = Fq::from_raw([
+        0xb6fb30a0884f0d1c,
+        0x77a275910aa413c3,
+        0xefc7b0c75b8cbb72,
+        0xfd3ae181f12d7096,
+    ]);
+
+    /// 1 / 2 mod p
+    /// `0x7fffffffffffffffffffffffffffffff5d576e7357a4501ddfe92f46681b20a1`
+    pub(crate) const TWO_INV: Fq = Fq::from_raw([
+        0xdfe92f46681b20a1,
+        0x5d576e7357a4501d,
+        0xffffffffffffffff,
+        0x7fffffffffffffff,
+    ]);
+
+    /// MULTIPLICATIVE_GENERATOR^{2^S}, a t root of unity.
+    /// `0xcbc21fe4561c8d63b78e780e1341e199417c8c0bb7601`
+    pub(crate) const DELTA: Fq = Fq::from_raw([
+        0x199417c8c0bb7601,
+        0xd63b78e780e1341e,
+        0x000cbc21fe4561c8,
+        0x0000000000000000,
+    ]);
+
+    /// `ZETA^3 = 1 mod p` where `ZETA != 1 mod p`
+    /// `0x5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72`
+    pub(crate) const ZETA: Fq = Fq::from_raw([
+        0xdf02967c1b23bd72,
+        0x122e22ea20816678,
+        0xa5261c028812645a,
+        0x5363ad4cc05c30e0,
+    ]);
+}
+
+pub(crate) const SECP_GENERATOR_X: Fp = Fp::from_raw([
+    0x59f2815b16f81798,
+    0x029bfcdb2dce28d9,
+    0x55a06295ce870b07,
+    0x79be667ef9dcbbac,
+]);
+
+pub(crate) const SECP_GENERATOR_Y: Fp = Fp::from_raw([
+    0x9c47d08ffb10d4b8,
+    0xfd17b448a6855419,
+    0x5da4fbfc0e1108a8,
+    0x483ada7726a3c465,
+]);
+
+pub(crate) const SECP_A: Fp = Fp::from_raw([
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const SECP_B: Fp = Fp::from_raw([
+    0x0000000000000007,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const SSWU_Z: Fp = Fp::from_raw([
+    0xfffffffefffffc24,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+    0xffffffffffffffff,
+]);
+
+/// The `A'` of the curve 3-isogenous to secp256k1, used by hash to curve.
+pub const ISO_SECP_A: Fp = Fp::from_raw([
+    0x405447c01a444533,
+    0xe953d363cb6f0e5d,
+    0xa08a5558f0f5d272,
+    0x3f8731abdd661adc,
+]);
+
+/// The `B'` of the curve 3-isogenous to secp256k1, used by hash to curve.
+pub const ISO_SECP_B: Fp = Fp::from_raw([
+    0x00000000000006eb,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const ISO_SECP_GENERATOR_X: Fp = Fp::from_raw([
+    0xd11d739d05a9f7a8,
+    0x00e448e38af94593,
+    0x2287b72788f0933a,
+    0xc49b6c192e36ab1a,
+]);
+
+pub(crate) const ISO_SECP_GENERATOR_Y: Fp = Fp::from_raw([
+    0x10836bbad9e12f4f,
+    0xc054381c214e65d4,
+    0x6df11cc434b9fac0,
+    0x9a9322d799106965,
+]);
+
+/// The coefficients `k_(i,j) = ISO_MAP_K[i][j]` of the 3-isogeny map from
+/// [`IsoSecp256k1`](super::IsoSecp256k1) to secp256k1, see
+/// [`iso_map_secp256k1`](super::iso_map_secp256k1). Row `0` and the
+/// coefficients missing from the RFC are zero, and the leading coefficients
+/// of `x_den` and `y_den`, which are one, are omitted.
+pub const ISO_MAP_K: [[Fp; 4]; 5] = [
+    [
+        Fp::from_raw([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        Fp::from_raw([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        Fp::from_raw([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        Fp::from_raw([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x8e38e38daaaaa8c7,
+            0x38e38e38e38e38e3,
+            0xe38e38e38e38e38e,
+            0x8e38e38e38e38e38,
+        ]),
+        Fp::from_raw([
+            0xdfff1044f17c6581,
+            0xd595d2fc0bf63b92,
+            0xb9f315cea7fd44c5,
+            0x07d3d4c80bc321d5,
+        ]),
+        Fp::from_raw([
+            0x4ecbd0b53d9dd262,
+            0xe4506144037c4031,
+            0xe2a413deca25caec,
+            0x534c328d23f234e6,
+        ]),
+        Fp::from_raw([
+            0x8e38e38daaaaa88c,
+            0x38e38e38e38e38e3,
+            0xe38e38e38e38e38e,
+            0x8e38e38e38e38e38,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0x9fe6b745781eb49b,
+            0x86cd409542f8487d,
+            0x9ca34ccbb7b640dd,
+            0xd35771193d94918a,
+        ]),
+        Fp::from_raw([
+            0xc52a56612a8c6d14,
+            0x06d36b641f5e41bb,
+            0xf7c4b2d51b542254,
+            0xedadc6f64383dc1d,
+        ]),
+        Fp::from_raw([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+        Fp::from_raw([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xa12f684b8e38e23c,
+            0x2f684bda12f684bd,
+            0x684bda12f684bda1,
+            0x4bda12f684bda12f,
+        ]),
+        Fp::from_raw([
+            0xdffc90fc201d71a3,
+            0x647ab046d686da6f,
+            0xa9d0a54b12a0a6d5,
+            0xc75e0c32d5cb7c0f,
+        ]),
+        Fp::from_raw([
+            0xa765e85a9ecee931,
+            0x722830a201be2018,
+            0x715209ef6512e576,
+            0x29a6194691f91a73,
+        ]),
+        Fp::from_raw([
+            0x84bda12f38e38d84,
+            0xbda12f684bda12f6,
+            0xa12f684bda12f684,
+            0x2f684bda12f684bd,
+        ]),
+    ],
+    [
+        Fp::from_raw([
+            0xfffffffefffff93b,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+            0xffffffffffffffff,
+        ]),
+        Fp::from_raw([
+            0xdfb425d2685c2573,
+            0x9467c1bfc8e8d978,
+            0xd5e9e6632722c298,
+            0x7a06534bb8bdb49f,
+        ]),
+        Fp::from_raw([
+            0xa7bf8192bfd2a76f,
+            0x0a3d21162f0d6299,
+            0xf3a70c3fa8fe337e,
+            0x6484aa716545ca2c,
+        ]),
+        Fp::from_raw([
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+            0x0000000000000000,
+        ]),
+    ],
+];
@@ -1,3 +1,4 @@
+use super::constants::*;
 use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
@@ -15,13 +16,10 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
-    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
-    new_curve_impl,
+    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_derive_generator,
+    impl_sub_binop_specify_output, new_curve_impl,
 };
 
-#[cfg(feature = "derive_serde")]
-use serde::{Deserialize, Serialize};
-
 impl group::cofactor::CofactorGroup for Secp256k1 {
     type Subgroup = Secp256k1;
 
@@ -38,23 +36,6 @@ impl group::cofactor::CofactorGroup for Secp256k1 {
     }
 }
 
-// Reference: https://neuromancer.sk/std/secg/secp256k1
-const SECP_GENERATOR_X: Fp = Fp::from_raw([
-    0x59F2815B16F81798,
-    0x029BFCDB2DCE28D9,
-    0x55A06295CE870B07,
-    0x79BE667EF9DCBBAC,
-]);
-const SECP_GENERATOR_Y: Fp = Fp::from_raw([
-    0x9C47D08FFB10D4B8,
-    0xFD17B448A6855419,
-    0x5DA4FBFC0E1108A8,
-    0x483ADA7726A3C465,
-]);
-
-const SECP_A: Fp = Fp::from_raw([0, 0, 0, 0]);
-const SECP_B: Fp = Fp::from_raw([7, 0, 0, 0]);
-
 new_curve_impl!(
     (pub),
     Secp256k1,
@@ -68,16 +49,22 @@ new_curve_impl!(
     |curve_id, domain_prefix| sswu_hash_to_curve_secp256k1(curve_id, domain_prefix),
 );
 
+impl_derive_generator!(Secp256k1);
+
+#[cfg(feature = "k256")]
+crate::derive::rustcrypto::impl_rustcrypto_conversions!(
+    k256,
+    Secp256k1,
+    Fp,
+    Fq,
+    Secp256k1,
+    Secp256k1Affine
+);
+
 impl Secp256k1 {
     // Z = -11 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>)
-    // 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc24
     #[allow(dead_code)]
-    const SSWU_Z: Fp = Fp::from_raw([
-        0xfffffffefffffc24,
-        0xffffffffffffffff,
-        0xffffffffffffffff,
-        0xffffffffffffffff,
-    ]);
+    const SSWU_Z: Fp = SSWU_Z;
 }
 
 // Simplified SWU for AB == 0 <https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-swu-for-ab-0>
@@ -86,27 +73,6 @@ impl Secp256k1 {
 //   A': 0x3f8731abdd661adca08a5558f0f5d272e953d363cb6f0e5d405447c01a444533
 //   B': 1771
 // (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>)
-pub const ISO_SECP_A: Fp = Fp::from_raw([
-    0x405447c01a444533,
-    0xe953d363cb6f0e5d,
-    0xa08a5558f0f5d272,
-    0x3f8731abdd661adc,
-]);
-pub const ISO_SECP_B: Fp = Fp::from_raw([1771, 0, 0, 0]);
-
-const ISO_SECP_GENERATOR_X: Fp = Fp::from_raw([
-    0xD11D739D05A9F7A8,
-    0x00E448E38AF94593,
-    0x2287B72788F0933A,
-    0xC49B6C192E36AB1A,
-]);
-const ISO_SECP_GENERATOR_Y: Fp = Fp::from_raw([
-    0x10836BBAD9E12F4F,
-    0xC054381C214E65D4,
-    0x6DF11CC434B9FAC0,
-    0x9A9322D799106965,
-]);
-
 impl group::cofactor::CofactorGroup for IsoSecp256k1 {
     type Subgroup = IsoSecp256k1;
 
@@ -124,7 +90,7 @@ impl group::cofactor::CofactorGroup for IsoSecp256k1 {
 }
 
 new_curve_impl!(
-    (pub(crate)),
+    (pub),
     IsoSecp256k1,
     IsoSecp256k1Affine,
     Fp,
@@ -137,113 +103,43 @@ new_curve_impl!(
 );
 
 impl IsoSecp256k1 {
-    // Z = -11 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-suites-for-secp256k1>)
-    // 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc24
     // NOTE: This `Z` is the `SSWU_Z` of `Secp256k1` curve.
-    const SSWU_Z: Fp = Fp::from_raw([
-        0xfffffffefffffc24,
-        0xffffffffffffffff,
-        0xffffffffffffffff,
-        0xffffffffffffffff,
-    ]);
+    const SSWU_Z: Fp = SSWU_Z;
+}
+
+// The simplified SWU map to the isogenous curve, followed by the isogeny.
+impl crate::hash_to_curve::MapToCurve for Secp256k1 {
+    fn map_to_curve(u: Fp) -> Self {
+        iso_map_secp256k1(crate::hash_to_curve::sswu_map_to_curve::<IsoSecp256k1>(
+            u,
+            IsoSecp256k1::SSWU_Z,
+        ))
+    }
+
+    fn map_to_curve_many(us: &[Fp]) -> alloc::vec::Vec<Self> {
+        crate::hash_to_curve::sswu_map_to_curve_many::<IsoSecp256k1>(us, IsoSecp256k1::SSWU_Z)
+            .into_iter()
+            .map(iso_map_secp256k1)
+            .collect()
+    }
 }
 
-/// 3-Isogeny Map for Secp256k1
-/// Reference: <https://www.rfc-editor.org/rfc/rfc9380.html#name-3-isogeny-map-for-secp256k1>
-pub(crate) fn iso_map_secp256k1(rp: IsoSecp256k1) -> Secp256k1 {
-    // constants for secp256k1 iso_map computation
-    const K: [[Fp; 4]; 5] = [
-        [Fp::ZERO; 4],
-        [
-            Fp::from_raw([
-                0x8e38e38daaaaa8c7,
-                0x38e38e38e38e38e3,
-                0xe38e38e38e38e38e,
-                0x8e38e38e38e38e38,
-            ]),
-            Fp::from_raw([
-                0xdfff1044f17c6581,
-                0xd595d2fc0bf63b92,
-                0xb9f315cea7fd44c5,
-                0x7d3d4c80bc321d5,
-            ]),
-            Fp::from_raw([
-                0x4ecbd0b53d9dd262,
-                0xe4506144037c4031,
-                0xe2a413deca25caec,
-                0x534c328d23f234e6,
-            ]),
-            Fp::from_raw([
-                0x8e38e38daaaaa88c,
-                0x38e38e38e38e38e3,
-                0xe38e38e38e38e38e,
-                0x8e38e38e38e38e38,
-            ]),
-        ],
-        [
-            Fp::from_raw([
-                0x9fe6b745781eb49b,
-                0x86cd409542f8487d,
-                0x9ca34ccbb7b640dd,
-                0xd35771193d94918a,
-            ]),
-            Fp::from_raw([
-                0xc52a56612a8c6d14,
-                0x06d36b641f5e41bb,
-                0xf7c4b2d51b542254,
-                0xedadc6f64383dc1d,
-            ]),
-            Fp::ZERO,
-            Fp::ZERO,
-        ],
-        [
-            Fp::from_raw([
-                0xa12f684b8e38e23c,
-                0x2f684bda12f684bd,
-                0x684bda12f684bda1,
-                0x4bda12f684bda12f,
-            ]),
-            Fp::from_raw([
-                0xdffc90fc201d71a3,
-                0x647ab046d686da6f,
-                0xa9d0a54b12a0a6d5,
-                0xc75e0c32d5cb7c0f,
-            ]),
-            Fp::from_raw([
-                0xa765e85a9ecee931,
-                0x722830a201be2018,
-                0x715209ef6512e576,
-                0x29a6194691f91a73,
-            ]),
-            Fp::from_raw([
-                0x84bda12f38e38d84,
-                0xbda12f684bda12f6,
-                0xa12f684bda12f684,
-                0x2f684bda12f684bd,
-            ]),
-        ],
-        [
-            Fp::from_raw([
-                0xfffffffefffff93b,
-                0xffffffffffffffff,
-                0xffffffffffffffff,
-                0xffffffffffffffff,
-            ]),
-            Fp::from_raw([
-                0xdfb425d2685c2573,
-                0x9467c1bfc8e8d978,
-                0xd5e9e6632722c298,
-                0x7a06534bb8bdb49f,
-            ]),
-            Fp::from_raw([
-                0xa7bf8192bfd2a76f,
-                0x0a3d21162f0d6299,
-                0xf3a70c3fa8fe337e,
-                0x6484aa716545ca2c,
-            ]),
-            Fp::ZERO,
-        ],
-    ];
+/// The 3-isogeny map from [`IsoSecp256k1`], the curve `E'` of the simplified
+/// SWU map, to secp256k1, which `hash_to_curve` applies to the output of the
+/// map, see <https://www.rfc-editor.org/rfc/rfc9380.html#name-3-isogeny-map-for-secp256k1>.
+///
+/// With `k_(i,j) = ISO_MAP_K[i][j]`, it maps the affine point `(x', y')` to
+/// `(x_num / x_den, y' * y_num / y_den)`, where
+/// - `x_num = k_(1,3) * x'^3 + k_(1,2) * x'^2 + k_(1,1) * x' + k_(1,0)`,
+/// - `x_den = x'^2 + k_(2,1) * x' + k_(2,0)`,
+/// - `y_num = k_(3,3) * x'^3 + k_(3,2) * x'^2 + k_(3,1) * x' + k_(3,0)`,
+/// - `y_den = x'^3 + k_(4,2) * x'^2 + k_(4,1) * x' + k_(4,0)`.
+///
+/// The points where `x_den` or `y_den` is zero, which form the kernel of the
+/// isogeny, and the identity map to the identity. The map is evaluated on
+/// Jacobian coordinates, without inversion.
+pub fn iso_map_secp256k1(rp: IsoSecp256k1) -> Secp256k1 {
+    const K: [[Fp; 4]; 5] = ISO_MAP_K;
 
     let (x, y, z) = rp.jacobian_coordinates();
 
@@ -272,6 +168,8 @@ mod test {
     use super::*;
     use group::UncompressedEncoding;
     crate::curve_testing_suite!(Secp256k1);
+    #[cfg(feature = "zeroize")]
+    crate::curve_testing_suite!(Secp256k1, "zeroize");
     crate::curve_testing_suite!(Secp256k1, "endo_consistency");
     crate::curve_testing_suite!(Secp256k1, "ecdsa_example");
     crate::curve_testing_suite!(
@@ -284,4 +182,86 @@ mod test {
         SECP_GENERATOR_Y,
         Fq::MODULUS
     );
+
+    #[test]
+    fn test_iso_map() {
+        use rand_core::OsRng;
+
+        // The rational maps of the documentation, in affine coordinates.
+        let iso_map_affine = |p: IsoSecp256k1Affine| {
+            let k = ISO_MAP_K;
+            let x = p.x;
+            let x_num = ((k[1][3] * x + k[1][2]) * x + k[1][1]) * x + k[1][0];
+            let x_den = (x + k[2][1]) * x + k[2][0];
+            let y_num = ((k[3][3] * x + k[3][2]) * x + k[3][1]) * x + k[3][0];
+            let y_den = ((x + k[4][2]) * x + k[4][1]) * x + k[4][0];
+            Secp256k1Affine {
+                x: x_num * x_den.invert().unwrap(),
+                y: p.y * y_num * y_den.invert().unwrap(),
+            }
+        };
+
+        assert!(bool::from(
+            iso_map_secp256k1(IsoSecp256k1::identity()).is_identity()
+        ));
+        for _ in 0..100 {
+            let p = IsoSecp256k1::random(OsRng);
+            let q = IsoSecp256k1::random(OsRng);
+            let image = iso_map_secp256k1(p);
+            assert!(bool::from(image.is_on_curve()));
+            assert_eq!(image.to_affine(), iso_map_affine(p.to_affine()));
+            // The isogeny is a group homomorphism.
+            assert_eq!(iso_map_secp256k1(p + q), image + iso_map_secp256k1(q));
+        }
+    }
+
+    #[cfg(feature = "k256")]
+    #[test]
+    fn test_k256_conversions() {
+        use k256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+        use rand_core::OsRng;
+
+        assert_eq!(
+            k256::AffinePoint::from(Secp256k1Affine::generator()),
+            k256::AffinePoint::GENERATOR
+        );
+        assert_eq!(
+            Secp256k1Affine::from(k256::AffinePoint::IDENTITY),
+            Secp256k1Affine::identity()
+        );
+
+        for _ in 0..100 {
+            let k = Fq::random(OsRng);
+            let rc_k = k256::Scalar::from(k);
+            assert_eq!(Fq::from(rc_k), k);
+            assert_eq!(Fq::from(rc_k.square()), k.square());
+
+            let p = Secp256k1::generator() * k;
+            let rc_p = k256::ProjectivePoint::GENERATOR * rc_k;
+            assert_eq!(k256::ProjectivePoint::from(p), rc_p);
+            assert_eq!(Secp256k1::from(rc_p), p);
+
+            let p = p.to_affine();
+            for compress in [false, true] {
+                let encoded = p.to_encoded_point(compress);
+                assert_eq!(
+                    encoded,
+                    k256::AffinePoint::from(p).to_encoded_point(compress)
+                );
+                assert_eq!(Secp256k1Affine::from_encoded_point(&encoded).unwrap(), p);
+            }
+            assert_eq!(
+                p.to_encoded_point(true).as_bytes(),
+                &p.to_sec1_compressed().unwrap()[..]
+            );
+        }
+
+        let identity = Secp256k1Affine::identity().to_encoded_point(true);
+        assert_eq!(identity, k256::EncodedPoint::identity());
+        assert!(bool::from(
+            Secp256k1Affine::from_encoded_point(&identity)
+                .unwrap()
+                .is_identity()
+        ));
+    }
 }
@@ -1,10 +1,22 @@
-use crate::arithmetic::{adc, bigint_geq, mac, macx, sbb};
+#[cfg(all(feature = "asm", target_arch = "x86_64", not(feature = "fiat-backend")))]
+use crate::derive::asm::field_arithmetic_asm;
+#[cfg(feature = "fiat-backend")]
+use crate::derive::fiat::field_arithmetic_fiat;
+#[cfg(not(any(feature = "fiat-backend", all(feature = "asm", target_arch = "x86_64"))))]
+use crate::{
+    arithmetic::{bigint_geq, macx},
+    field_arithmetic, field_specific,
+};
+
+use super::constants::fp::*;
+use crate::arithmetic::{adc, mac, sbb};
 use crate::extend_field_legendre;
 use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
 use crate::{
-    field_arithmetic, field_bits, field_common, field_specific, impl_add_binop_specify_output,
-    impl_binops_additive, impl_binops_additive_specify_output, impl_binops_multiplicative,
-    impl_binops_multiplicative_mixed, impl_from_u64, impl_sub_binop_specify_output, impl_sum_prod,
+    field_bits, field_common, impl_add_binop_specify_output, impl_binops_additive,
+    impl_binops_additive_specify_output, impl_binops_multiplicative,
+    impl_binops_multiplicative_mixed, impl_from_signed, impl_from_u128, impl_from_u64,
+    impl_sub_binop_specify_output, impl_sum_prod,
 };
 use core::convert::TryInto;
 use core::fmt;
@@ -20,97 +32,22 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 // The internal representation of this type is four 64-bit unsigned
 // integers in little-endian order. `Fp` values are always in
 // Montgomery form; i.e., Fp(a) = aR mod p, with R = 2^256.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Fp(pub(crate) [u64; 4]);
 
+#[cfg(target_endian = "little")]
+// SAFETY: `Fp` is `#[repr(transparent)]` over its Montgomery form in
+// little-endian limbs.
+unsafe impl crate::serde::montgomery::MontgomeryLimbs for Fp {
+    fn is_reduced(&self) -> bool {
+        self.debug_validate()
+    }
+}
+
 #[cfg(feature = "derive_serde")]
 crate::serialize_deserialize_32_byte_primefield!(Fp);
 
-/// Constant representing the modulus
-/// p = 0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f
-const MODULUS: Fp = Fp([
-    0xfffffffefffffc2f,
-    0xffffffffffffffff,
-    0xffffffffffffffff,
-    0xffffffffffffffff,
-]);
-
-/// Constant representing the multiplicative generator of the modulus.
-/// It's derived with SageMath with: `GF(MODULUS).primitive_element()`.
-const MULTIPLICATIVE_GENERATOR: Fp = Fp::from_raw([0x03, 0x00, 0x00, 0x00]);
-
-/// The modulus as u32 limbs.
-#[cfg(not(target_pointer_width = "64"))]
-const MODULUS_LIMBS_32: [u32; 8] = [
-    0xffff_fc2f,
-    0xffff_fffe,
-    0xffff_ffff,
-    0xffff_ffff,
-    0xffff_ffff,
-    0xffff_ffff,
-    0xffff_ffff,
-    0xffff_ffff,
-];
-
-/// Constant representing the modulus as static str
-const MODULUS_STR: &str = "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f";
-
-/// INV = -(p^{-1} mod 2^64) mod 2^64
-const INV: u64 = 0xd838091dd2253531;
-
-/// R = 2^256 mod p
-/// 0x1000003d1
-const R: Fp = Fp([0x1000003d1, 0, 0, 0]);
-
-/// R^2 = 2^512 mod p
-/// 0x1000007a2000e90a1
-const R2: Fp = Fp([0x000007a2000e90a1, 0x1, 0, 0]);
-
-/// R^3 = 2^768 mod p
-/// 0x100000b73002bb1e33795f671
-const R3: Fp = Fp([0x002bb1e33795f671, 0x100000b73, 0, 0]);
-
-/// 1 / 2 mod p
-const TWO_INV: Fp = Fp::from_raw([
-    0xffffffff7ffffe18,
-    0xffffffffffffffff,
-    0xffffffffffffffff,
-    0x7fffffffffffffff,
-]);
-
-const ZETA: Fp = Fp::from_raw([
-    0xc1396c28719501ee,
-    0x9cf0497512f58995,
-    0x6e64479eac3434e9,
-    0x7ae96a2b657c0710,
-]);
-
-/// Generator of the t-order multiplicative subgroup.
-/// Computed by exponentiating Self::MULTIPLICATIVE_GENERATOR by 2^s, where s is Self::S.
-/// `0x0000000000000000000000000000000000000000000000000000000000000009`.
-const DELTA: Fp = Fp([0x900002259u64, 0, 0, 0]);
-
-/// Implementations of this trait MUST ensure that this is the generator used to derive Self::ROOT_OF_UNITY.
-/// Derived from:
-/// ```ignore
-/// Zp(Zp(mul_generator)^t) where t = (modulus - 1 )/ 2
-/// 115792089237316195423570985008687907853269984665640564039457584007908834671662
-/// ```
-const ROOT_OF_UNITY: Fp = Fp([
-    0xfffffffdfffff85eu64,
-    0xffffffffffffffffu64,
-    0xffffffffffffffffu64,
-    0xffffffffffffffffu64,
-]);
-
-/// Inverse of [`ROOT_OF_UNITY`].
-const ROOT_OF_UNITY_INV: Fp = Fp([
-    0xfffffffdfffff85eu64,
-    0xffffffffffffffffu64,
-    0xffffffffffffffffu64,
-    0xffffffffffffffffu64,
-]);
-
 impl_binops_additive!(Fp, Fp);
 impl_binops_multiplicative!(Fp, Fp);
 field_common!(
@@ -127,7 +64,16 @@ field_common!(
     R3
 );
 impl_from_u64!(Fp, R2);
+impl_from_u128!(Fp, R2);
+impl_from_signed!(Fp);
+#[cfg(feature = "crypto-bigint")]
+crate::derive::bigint::impl_crypto_bigint_conversions!(Fp, U256);
+#[cfg(not(any(feature = "fiat-backend", all(feature = "asm", target_arch = "x86_64"))))]
 field_arithmetic!(Fp, MODULUS, INV, dense);
+#[cfg(all(feature = "asm", target_arch = "x86_64", not(feature = "fiat-backend")))]
+field_arithmetic_asm!(Fp, MODULUS, INV, dense);
+#[cfg(feature = "fiat-backend")]
+field_arithmetic_fiat!(Fp, secp256k1_montgomery);
 impl_sum_prod!(Fp);
 
 #[cfg(target_pointer_width = "64")]
@@ -219,7 +165,7 @@ impl ff::PrimeField for Fp {
     const DELTA: Self = DELTA;
     const NUM_BITS: u32 = 256;
     const CAPACITY: u32 = 255;
-    const S: u32 = 1;
+    const S: u32 = S;
 
     fn from_repr(repr: Self::Repr) -> CtOption<Self> {
         let mut tmp = Fp([0, 0, 0, 0]);
@@ -242,7 +188,7 @@ impl ff::PrimeField for Fp {
 
         // Convert to Montgomery form by computing
         // (a.R^0 * R^2) / R = a.R
-        tmp *= &R2;
+        let tmp = Self::mul(&tmp, &R2);
 
         CtOption::new(tmp, Choice::from(is_some))
     }
@@ -295,10 +241,17 @@ mod test {
     use super::*;
     crate::field_testing_suite!(Fp, "field_arithmetic");
     crate::field_testing_suite!(Fp, "conversion");
+    crate::field_testing_suite!(Fp, "str");
+    #[cfg(feature = "crypto-bigint")]
+    crate::field_testing_suite!(Fp, "crypto_bigint", U256);
     crate::field_testing_suite!(Fp, "serialization");
     crate::field_testing_suite!(Fp, "quadratic_residue");
     crate::field_testing_suite!(Fp, "bits");
     crate::field_testing_suite!(Fp, "serialization_check");
+    crate::field_testing_suite!(Fp, "from_be_slice");
+    crate::field_testing_suite!(Fp, "domain");
+    crate::field_testing_suite!(Fp, "debug_validate");
+    crate::field_testing_suite!(Fp, "balanced_digits");
     crate::field_testing_suite!(Fp, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fp, "sqrt");
     crate::field_testing_suite!(Fp, "zeta");
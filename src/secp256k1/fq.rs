@@ -1,10 +1,22 @@
-use crate::arithmetic::{adc, bigint_geq, mac, macx, sbb};
+#[cfg(all(feature = "asm", target_arch = "x86_64", not(feature = "fiat-backend")))]
+use crate::derive::asm::field_arithmetic_asm;
+#[cfg(feature = "fiat-backend")]
+use crate::derive::fiat::field_arithmetic_fiat;
+#[cfg(not(any(feature = "fiat-backend", all(feature = "asm", target_arch = "x86_64"))))]
+use crate::{
+    arithmetic::{bigint_geq, macx},
+    field_arithmetic, field_specific,
+};
+
+use super::constants::fq::*;
+use crate::arithmetic::{adc, mac, sbb};
 use crate::extend_field_legendre;
 use crate::ff::{FromUniformBytes, PrimeField, WithSmallOrderMulGroup};
 use crate::{
-    field_arithmetic, field_bits, field_common, field_specific, impl_add_binop_specify_output,
-    impl_binops_additive, impl_binops_additive_specify_output, impl_binops_multiplicative,
-    impl_binops_multiplicative_mixed, impl_from_u64, impl_sub_binop_specify_output, impl_sum_prod,
+    field_bits, field_common, impl_add_binop_specify_output, impl_binops_additive,
+    impl_binops_additive_specify_output, impl_binops_multiplicative,
+    impl_binops_multiplicative_mixed, impl_from_signed, impl_from_u128, impl_from_u64,
+    impl_sub_binop_specify_output, impl_sum_prod,
 };
 use core::convert::TryInto;
 use core::fmt;
@@ -20,109 +32,22 @@ use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 // The internal representation of this type is four 64-bit unsigned
 // integers in little-endian order. `Fq` values are always in
 // Montgomery form; i.e., Fq(a) = aR mod q, with R = 2^256.
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy)]
+#[repr(transparent)]
 pub struct Fq(pub(crate) [u64; 4]);
 
+#[cfg(target_endian = "little")]
+// SAFETY: `Fq` is `#[repr(transparent)]` over its Montgomery form in
+// little-endian limbs.
+unsafe impl crate::serde::montgomery::MontgomeryLimbs for Fq {
+    fn is_reduced(&self) -> bool {
+        self.debug_validate()
+    }
+}
+
 #[cfg(feature = "derive_serde")]
 crate::serialize_deserialize_32_byte_primefield!(Fq);
 
-/// Constant representing the modulus
-/// q = 0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141
-const MODULUS: Fq = Fq([
-    0xbfd25e8cd0364141,
-    0xbaaedce6af48a03b,
-    0xfffffffffffffffe,
-    0xffffffffffffffff,
-]);
-
-/// The modulus as u32 limbs.
-#[cfg(not(target_pointer_width = "64"))]
-const MODULUS_LIMBS_32: [u32; 8] = [
-    0xd036_4141,
-    0xbfd2_5e8c,
-    0xaf48_a03b,
-    0xbaae_dce6,
-    0xffff_fffe,
-    0xffff_ffff,
-    0xffff_ffff,
-    0xffff_ffff,
-];
-
-///Constant representing the modulus as static str
-const MODULUS_STR: &str = "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141";
-
-/// INV = -(q^{-1} mod 2^64) mod 2^64
-const INV: u64 = 0x4b0dff665588b13f;
-
-/// R = 2^256 mod q
-/// 0x14551231950b75fc4402da1732fc9bebf
-const R: Fq = Fq([0x402da1732fc9bebf, 0x4551231950b75fc4, 0x1, 0]);
-
-/// R^2 = 2^512 mod q
-/// 0x9d671cd581c69bc5e697f5e45bcd07c6741496c20e7cf878896cf21467d7d140
-const R2: Fq = Fq([
-    0x896cf21467d7d140,
-    0x741496c20e7cf878,
-    0xe697f5e45bcd07c6,
-    0x9d671cd581c69bc5,
-]);
-
-/// R^3 = 2^768 mod q
-/// 0x555d800c18ef116db1b31347f1d0b2da0017648444d4322c7bc0cfe0e9ff41ed
-const R3: Fq = Fq([
-    0x7bc0cfe0e9ff41ed,
-    0x0017648444d4322c,
-    0xb1b31347f1d0b2da,
-    0x555d800c18ef116d,
-]);
-
-/// `GENERATOR = 7 mod r` is a generator of the `q - 1` order multiplicative
-/// subgroup, or in other words a primitive root of the field.
-/// It's derived with SageMath with: `GF(MODULUS).primitive_element()`.
-const GENERATOR: Fq = Fq::from_raw([0x07, 0x00, 0x00, 0x00]);
-
-/// GENERATOR^t where t * 2^s + 1 = r with t odd. In other words, this is a 2^s root of unity.
-/// `0xc1dc060e7a91986df9879a3fbc483a898bdeab680756045992f4b5402b052f2`
-const ROOT_OF_UNITY: Fq = Fq::from_raw([
-    0x992f4b5402b052f2,
-    0x98bdeab680756045,
-    0xdf9879a3fbc483a8,
-    0xc1dc060e7a91986,
-]);
-
-/// 1 / ROOT_OF_UNITY mod q
-const ROOT_OF_UNITY_INV: Fq = Fq::from_raw([
-    0xb6fb30a0884f0d1c,
-    0x77a275910aa413c3,
-    0xefc7b0c75b8cbb72,
-    0xfd3ae181f12d7096,
-]);
-
-/// 1 / 2 mod q
-const TWO_INV: Fq = Fq::from_raw([
-    0xdfe92f46681b20a1,
-    0x5d576e7357a4501d,
-    0xffffffffffffffff,
-    0x7fffffffffffffff,
-]);
-
-const ZETA: Fq = Fq::from_raw([
-    0xdf02967c1b23bd72,
-    0x122e22ea20816678,
-    0xa5261c028812645a,
-    0x5363ad4cc05c30e0,
-]);
-
-/// Generator of the t-order multiplicative subgroup.
-/// Computed by exponentiating Self::MULTIPLICATIVE_GENERATOR by 2^s, where s is Self::S.
-/// `0x0000000000000000000cbc21fe4561c8d63b78e780e1341e199417c8c0bb7601`
-const DELTA: Fq = Fq([
-    0xd91b33d24319d9e8,
-    0xb81c6596ff5d6740,
-    0xa463969ca14c51c1,
-    0x1900960de4b7929c,
-]);
-
 impl_binops_additive!(Fq, Fq);
 impl_binops_multiplicative!(Fq, Fq);
 field_common!(
@@ -139,7 +64,16 @@ field_common!(
     R3
 );
 impl_from_u64!(Fq, R2);
+impl_from_u128!(Fq, R2);
+impl_from_signed!(Fq);
+#[cfg(feature = "crypto-bigint")]
+crate::derive::bigint::impl_crypto_bigint_conversions!(Fq, U256);
+#[cfg(not(any(feature = "fiat-backend", all(feature = "asm", target_arch = "x86_64"))))]
 field_arithmetic!(Fq, MODULUS, INV, dense);
+#[cfg(all(feature = "asm", target_arch = "x86_64", not(feature = "fiat-backend")))]
+field_arithmetic_asm!(Fq, MODULUS, INV, dense);
+#[cfg(feature = "fiat-backend")]
+field_arithmetic_fiat!(Fq, secp256k1_montgomery_scalar);
 impl_sum_prod!(Fq);
 
 #[cfg(target_pointer_width = "64")]
@@ -225,12 +159,12 @@ impl ff::PrimeField for Fq {
     const NUM_BITS: u32 = 256;
     const CAPACITY: u32 = 255;
     const MODULUS: &'static str = MODULUS_STR;
-    const MULTIPLICATIVE_GENERATOR: Self = GENERATOR;
+    const MULTIPLICATIVE_GENERATOR: Self = MULTIPLICATIVE_GENERATOR;
     const ROOT_OF_UNITY: Self = ROOT_OF_UNITY;
     const ROOT_OF_UNITY_INV: Self = ROOT_OF_UNITY_INV;
     const TWO_INV: Self = TWO_INV;
     const DELTA: Self = DELTA;
-    const S: u32 = 6;
+    const S: u32 = S;
 
     fn from_repr(repr: Self::Repr) -> CtOption<Self> {
         let mut tmp = Fq([0, 0, 0, 0]);
@@ -253,7 +187,7 @@ impl ff::PrimeField for Fq {
 
         // Convert to Montgomery form by computing
         // (a.R^0 * R^2) / R = a.R
-        tmp *= &R2;
+        let tmp = Self::mul(&tmp, &R2);
 
         CtOption::new(tmp, Choice::from(is_some))
     }
@@ -301,11 +235,20 @@ extend_field_legendre!(Fq);
 mod test {
     use super::*;
     crate::field_testing_suite!(Fq, "field_arithmetic");
+    #[cfg(feature = "zeroize")]
+    crate::field_testing_suite!(Fq, "zeroize");
     crate::field_testing_suite!(Fq, "conversion");
+    crate::field_testing_suite!(Fq, "str");
+    #[cfg(feature = "crypto-bigint")]
+    crate::field_testing_suite!(Fq, "crypto_bigint", U256);
     crate::field_testing_suite!(Fq, "serialization");
     crate::field_testing_suite!(Fq, "quadratic_residue");
     crate::field_testing_suite!(Fq, "bits");
     crate::field_testing_suite!(Fq, "serialization_check");
+    crate::field_testing_suite!(Fq, "from_be_slice");
+    crate::field_testing_suite!(Fq, "domain");
+    crate::field_testing_suite!(Fq, "debug_validate");
+    crate::field_testing_suite!(Fq, "balanced_digits");
     crate::field_testing_suite!(Fq, "constants", MODULUS_STR);
     crate::field_testing_suite!(Fq, "sqrt");
     crate::field_testing_suite!(Fq, "zeta");
@@ -1,7 +1,11 @@
+#[cfg(feature = "sha2")]
+pub mod bip340;
+mod constants;
 mod curve;
 mod fp;
 mod fq;
 
+pub use constants::{ISO_MAP_K, ISO_SECP_A, ISO_SECP_B};
 pub use curve::*;
 pub use fp::*;
 pub use fq::*;
@@ -0,0 +1,155 @@
+//! Constants of the `secp256r1` fields and curves.
+//!
+//! Generated by `script/constants.py` from `script/params/secp256r1.toml`, do not
+//! modify. Building with the `generate-constants` feature regenerates it.
+
+use crate::secp256r1::Fp;
+
+pub(crate) mod fp {
+    use crate::secp256r1::Fp;
+
+    /// Constant representing the modulus
+    /// `0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff`
+    pub(crate) const MODULUS: Fp = Fp([
+        0xffffffffffffffff,
+        0x00000000ffffffff,
+        0x0000000000000000,
+        0xffffffff00000001,
+    ]);
+
+    /// The modulus as u32 limbs.
+    #[cfg(not(target_pointer_width = "64"))]
+    pub(crate) const MODULUS_LIMBS_32: [u32; 8] = [
+        0xffff_ffff,
+        0xffff_ffff,
+        0xffff_ffff,
+        0x0000_0000,
+        0x0000_0000,
+        0x0000_0000,
+        0x0000_0001,
+        0xffff_ffff,
+    ];
+
+    /// Constant representing the modulus as static str
+    pub(crate) const MODULUS_STR: &str =
+        "0xffffffff00000001000000000000000000000000ffffffffffffffffffffffff";
+
+    /// INV = -(p^{-1} mod 2^64) mod 2^64
+    pub(crate) const INV: u64 = 0x0000000000000001;
+
+    /// R = 2^256 mod p
+    pub(crate) const R: Fp = Fp([
+        0x0000000000000001,
+        0xffffffff00000000,
+        0xffffffffffffffff,
+        0x00000000fffffffe,
+    ]);
+
+    /// R2 = 2^512 mod p
+    pub(crate) const R2: Fp = Fp([
+        0x0000000000000003,
+        0xfffffffbffffffff,
+        0xfffffffffffffffe,
+        0x00000004fffffffd,
+    ]);
+
+    /// R3 = 2^768 mod p
+    pub(crate) const R3: Fp = Fp([
+        0xfffffffd0000000a,
+        0xffffffedfffffff7,
+        0x00000005fffffffc,
+        0x0000001800000001,
+    ]);
+
+    /// A generator of the multiplicative group of the field.
+    /// `0x6`
+    pub(crate) const MULTIPLICATIVE_GENERATOR: Fp = Fp::from_raw([
+        0x0000000000000006,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// The largest `S` such that `2^S` divides `p - 1`.
+    pub(crate) const S: u32 = 1;
+
+    /// MULTIPLICATIVE_GENERATOR^t where t * 2^S + 1 = p with t odd, a 2^S root of unity.
+    /// `0xffffffff00000001000000000000000000000000fffffffffffffffffffffffe`
+    pub(crate) const ROOT_OF_UNITY: Fp = Fp::from_raw([
+        0xfffffffffffffffe,
+        0x00000000ffffffff,
+        0x0000000000000000,
+        0xffffffff00000001,
+    ]);
+
+    /// 1 / ROOT_OF_UNITY mod p
+    /// `0xffffffff00000001000000000000000000000000fffffffffffffffffffffffe`
+    pub(crate) const ROOT_OF_UNITY_INV: Fp = Fp::from_raw([
+        0xfffffffffffffffe,
+        0x00000000ffffffff,
+        0x0000000000000000,
+        0xffffffff00000001,
+    ]);
+
+    /// 1 / 2 mod p
+    /// `0x7fffffff80000000800000000000000000000000800000000000000000000000`
+    pub(crate) const TWO_INV: Fp = Fp::from_raw([
+        0x0000000000000000,
+        0x0000000080000000,
+        0x8000000000000000,
+        0x7fffffff80000000,
+    ]);
+
+    /// MULTIPLICATIVE_GENERATOR^{2^S}, a t root of unity.
+    /// `0x24`
+    pub(crate) const DELTA: Fp = Fp::from_raw([
+        0x0000000000000024,
+        0x0000000000000000,
+        0x0000000000000000,
+        0x0000000000000000,
+    ]);
+
+    /// `ZETA^3 = 1 mod p` where `ZETA != 1 mod p`
+    /// `0x4d6ea8928adb86cf62388a8e0ef623312e68c59bdef3e53fd964598eb819acce`
+    pub(crate) const ZETA: Fp = Fp::from_raw([
+        0xd964598eb819acce,
+        0x2e68c59bdef3e53f,
+        0x62388a8e0ef62331,
+        0x4d6ea8928adb86cf,
+    ]);
+}
+
+pub(crate) const SECP_GENERATOR_X: Fp = Fp::from_raw([
+    0xf4a13945d898c296,
+    0x77037d812deb33a0,
+    0xf8bce6e563a440f2,
+    0x6b17d1f2e12c4247,
+]);
+
+pub(crate) const SECP_GENERATOR_Y: Fp = Fp::from_raw([
+    0xcbb6406837bf51f5,
+    0x2bce33576b315ece,
+    0x8ee7eb4a7c0f9e16,
+    0x4fe342e2fe1a7f9b,
+]);
+
+pub(crate) const SECP_A: Fp = Fp::from_raw([
+    0xfffffffffffffffc,
+    0x00000000ffffffff,
+    0x0000000000000000,
+    0xffffffff00000001,
+]);
+
+pub(crate) const SECP_B: Fp = Fp::from_raw([
+    0x3bce3c3e27d2604b,
+    0x651d06b0cc53b0f6,
+    0xb3ebbd55769886bc,
+    0x5ac635d8aa3a93e7,
+]);
+
+pub(crate) const SSWU_Z: Fp = Fp::from_raw([
+    0xfffffffffffffff5,
+    0x00000000ffffffff,
+    0x0000000000000000,
+    0xffffffff00000001,
+]);
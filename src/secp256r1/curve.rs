@@ -1,3 +1,4 @@
+use super::constants::*;
 use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
@@ -13,9 +14,6 @@ use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-#[cfg(feature = "derive_serde")]
-use serde::{Deserialize, Serialize};
-
 impl group::cofactor::CofactorGroup for Secp256r1 {
     type Subgroup = Secp256r1;
 
@@ -32,38 +30,10 @@ impl group::cofactor::CofactorGroup for Secp256r1 {
     }
 }
 
-// Reference: https://neuromancer.sk/std/secg/secp256r1
-const SECP_GENERATOR_X: Fp = Fp::from_raw([
-    0xF4A13945D898C296,
-    0x77037D812DEB33A0,
-    0xF8BCE6E563A440F2,
-    0x6B17D1F2E12C4247,
-]);
-
-const SECP_GENERATOR_Y: Fp = Fp::from_raw([
-    0xCBB6406837BF51F5,
-    0x2BCE33576B315ECE,
-    0x8EE7EB4A7C0F9E16,
-    0x4FE342E2FE1A7F9B,
-]);
-
-const SECP_A: Fp = Fp::from_raw([
-    0xFFFFFFFFFFFFFFFC,
-    0x00000000FFFFFFFF,
-    0x0000000000000000,
-    0xFFFFFFFF00000001,
-]);
-const SECP_B: Fp = Fp::from_raw([
-    0x3BCE3C3E27D2604B,
-    0x651D06B0CC53B0F6,
-    0xB3EBBD55769886BC,
-    0x5AC635D8AA3A93E7,
-]);
-
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
-    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
-    new_curve_impl,
+    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_derive_generator,
+    impl_sub_binop_specify_output, new_curve_impl,
 };
 
 new_curve_impl!(
@@ -79,16 +49,32 @@ new_curve_impl!(
     |curve_id, domain_prefix| sswu_hash_to_curve(curve_id, domain_prefix, Secp256r1::SSVDW_Z),
 );
 
+impl_derive_generator!(Secp256r1);
+
+#[cfg(feature = "p256")]
+crate::derive::rustcrypto::impl_rustcrypto_conversions!(
+    p256,
+    NistP256,
+    Fp,
+    Fq,
+    Secp256r1,
+    Secp256r1Affine
+);
+
 impl Secp256r1 {
     // Optimal Z with: <https://datatracker.ietf.org/doc/html/rfc9380#sswu-z-code>
-    // 0xffffffff00000001000000000000000000000000fffffffffffffffffffffff5
     // Z = -10 (reference: <https://www.rfc-editor.org/rfc/rfc9380.html#section-8.2>)
-    const SSVDW_Z: Fp = Fp::from_raw([
-        0xfffffffffffffff5,
-        0x00000000ffffffff,
-        0x0000000000000000,
-        0xffffffff00000001,
-    ]);
+    const SSVDW_Z: Fp = SSWU_Z;
+}
+
+impl crate::hash_to_curve::MapToCurve for Secp256r1 {
+    fn map_to_curve(u: Fp) -> Self {
+        crate::hash_to_curve::sswu_map_to_curve(u, Secp256r1::SSVDW_Z)
+    }
+
+    fn map_to_curve_many(us: &[Fp]) -> alloc::vec::Vec<Self> {
+        crate::hash_to_curve::sswu_map_to_curve_many(us, Secp256r1::SSVDW_Z)
+    }
 }
 
 #[cfg(test)]
@@ -107,4 +93,50 @@ mod test {
         SECP_GENERATOR_Y,
         Fq::MODULUS
     );
+
+    #[cfg(feature = "p256")]
+    #[test]
+    fn test_p256_conversions() {
+        use p256::elliptic_curve::sec1::{FromEncodedPoint, ToEncodedPoint};
+        use rand_core::OsRng;
+
+        assert_eq!(
+            p256::AffinePoint::from(Secp256r1Affine::generator()),
+            p256::AffinePoint::GENERATOR
+        );
+        assert_eq!(
+            Secp256r1Affine::from(p256::AffinePoint::IDENTITY),
+            Secp256r1Affine::identity()
+        );
+
+        for _ in 0..100 {
+            let k = Fq::random(OsRng);
+            let rc_k = p256::Scalar::from(k);
+            assert_eq!(Fq::from(rc_k), k);
+            assert_eq!(Fq::from(rc_k.square()), k.square());
+
+            let p = Secp256r1::generator() * k;
+            let rc_p = p256::ProjectivePoint::GENERATOR * rc_k;
+            assert_eq!(p256::ProjectivePoint::from(p), rc_p);
+            assert_eq!(Secp256r1::from(rc_p), p);
+
+            let p = p.to_affine();
+            for compress in [false, true] {
+                let encoded = p.to_encoded_point(compress);
+                assert_eq!(
+                    encoded,
+                    p256::AffinePoint::from(p).to_encoded_point(compress)
+                );
+                assert_eq!(Secp256r1Affine::from_encoded_point(&encoded).unwrap(), p);
+            }
+        }
+
+        let identity = Secp256r1Affine::identity().to_encoded_point(true);
+        assert_eq!(identity, p256::EncodedPoint::identity());
+        assert!(bool::from(
+            Secp256r1Affine::from_encoded_point(&identity)
+                .unwrap()
+                .is_identity()
+        ));
+    }
 }
@@ -1,3 +1,4 @@
+mod constants;
 mod curve;
 mod fp;
 mod fq;
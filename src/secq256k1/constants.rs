@@ -0,0 +1,41 @@
+//! Constants of the `secq256k1` fields and curves.
+//!
+//! Generated by `script/constants.py` from `script/params/secq256k1.toml`, do not
+//! modify. Building with the `generate-constants` feature regenerates it.
+
+use crate::secp256k1::Fq;
+
+pub(crate) const SECQ_GENERATOR_X: Fq = Fq::from_raw([
+    0xa24288e37702eda6,
+    0x3134e45a097781a6,
+    0xb6b06c87a2ce32e2,
+    0x76c39f5585cb160e,
+]);
+
+pub(crate) const SECQ_GENERATOR_Y: Fq = Fq::from_raw([
+    0xa4120ddad952677f,
+    0xd18983d26e8dc055,
+    0xdc2d265a8e82a7f7,
+    0x3ffc646c7b2918b5,
+]);
+
+pub(crate) const SECQ_A: Fq = Fq::from_raw([
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const SECQ_B: Fq = Fq::from_raw([
+    0x0000000000000007,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
+
+pub(crate) const SVDW_Z: Fq = Fq::from_raw([
+    0x0000000000000001,
+    0x0000000000000000,
+    0x0000000000000000,
+    0x0000000000000000,
+]);
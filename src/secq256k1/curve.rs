@@ -1,3 +1,4 @@
+use super::constants::*;
 use crate::derive::curve::{IDENTITY_MASK, IDENTITY_SHIFT, SIGN_MASK, SIGN_SHIFT};
 use crate::ff::WithSmallOrderMulGroup;
 use crate::ff::{Field, PrimeField};
@@ -7,8 +8,8 @@ use crate::hash_to_curve::svdw_hash_to_curve;
 use crate::secp256k1::{Fp, Fq};
 use crate::{
     impl_add_binop_specify_output, impl_binops_additive, impl_binops_additive_specify_output,
-    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_sub_binop_specify_output,
-    new_curve_impl,
+    impl_binops_multiplicative, impl_binops_multiplicative_mixed, impl_derive_generator,
+    impl_sub_binop_specify_output, new_curve_impl,
 };
 use crate::{Coordinates, CurveAffine, CurveExt};
 use core::cmp;
@@ -18,26 +19,6 @@ use core::ops::{Add, Mul, Neg, Sub};
 use rand::RngCore;
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
-#[cfg(feature = "derive_serde")]
-use serde::{Deserialize, Serialize};
-
-const SECQ_GENERATOR_X: Fq = Fq::from_raw([
-    0xA24288E37702EDA6,
-    0x3134E45A097781A6,
-    0xB6B06C87A2CE32E2,
-    0x76C39F5585CB160E,
-]);
-
-const SECQ_GENERATOR_Y: Fq = Fq::from_raw([
-    0xA4120DDAD952677F,
-    0xD18983D26E8DC055,
-    0xDC2D265A8E82A7F7,
-    0x3FFC646C7B2918B5,
-]);
-
-const SECQ_A: Fq = Fq::from_raw([0, 0, 0, 0]);
-const SECQ_B: Fq = Fq::from_raw([7, 0, 0, 0]);
-
 new_curve_impl!(
     (pub),
     Secq256k1,
@@ -51,6 +32,8 @@ new_curve_impl!(
     |curve_id, domain_prefix| svdw_hash_to_curve(curve_id, domain_prefix, Secq256k1::SVDW_Z),
 );
 
+impl_derive_generator!(Secq256k1);
+
 impl group::cofactor::CofactorGroup for Secq256k1 {
     type Subgroup = Secq256k1;
 
@@ -68,9 +51,11 @@ impl group::cofactor::CofactorGroup for Secq256k1 {
 }
 
 impl Secq256k1 {
-    const SVDW_Z: Fq = Fq::ONE;
+    const SVDW_Z: Fq = SVDW_Z;
 }
 
+crate::hash_to_curve::impl_svdw_map_to_curve!(Secq256k1, Fq);
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1,3 +1,4 @@
+mod constants;
 mod curve;
 
 pub use crate::secp256k1::{Fp as Fq, Fq as Fp};
@@ -0,0 +1,202 @@
+//! A wrapper for secret scalars, such as private keys.
+
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+use core::sync::atomic::{compiler_fence, Ordering};
+
+use ff::{Field, PrimeField};
+use rand_core::RngCore;
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+use crate::ff_ext::invert_ct;
+
+/// A scalar that only exposes constant-time operations.
+///
+/// Unlike `F`, a `SecretScalar` is not `Copy`, its `Debug` output omits the
+/// value, it implements `ConstantTimeEq` but not `PartialEq`, and it has no
+/// variable-time operation such as `pow_vartime` or `sqrt`. It is overwritten
+/// with zero when dropped.
+///
+/// The points of the bundled curves, and those of `define_curve!`, can be
+/// multiplied by a `SecretScalar` with their constant-time double-and-add, and
+/// [`FixedBaseTable::mul_secret`](crate::fixed_base::FixedBaseTable::mul_secret)
+/// uses the constant-time table lookup. [`SecretScalar::expose_secret`] gives
+/// access to the value for the other operations.
+#[derive(Clone)]
+pub struct SecretScalar<F: Field>(F);
+
+impl<F: Field> SecretScalar<F> {
+    /// Wraps `value`.
+    pub fn new(value: F) -> Self {
+        Self(value)
+    }
+
+    /// Samples a uniformly random scalar.
+    pub fn random(rng: impl RngCore) -> Self {
+        Self(F::random(rng))
+    }
+
+    /// Returns the scalar. Whatever is done with it is not protected by this
+    /// type anymore.
+    pub fn expose_secret(&self) -> &F {
+        &self.0
+    }
+
+    /// Returns whether the scalar is zero.
+    pub fn is_zero(&self) -> Choice {
+        self.0.is_zero()
+    }
+
+    /// Returns the square of the scalar.
+    pub fn square(&self) -> Self {
+        Self(self.0.square())
+    }
+
+    /// Returns the double of the scalar.
+    pub fn double(&self) -> Self {
+        Self(self.0.double())
+    }
+
+    /// Exponentiates the scalar by `exp`, a little-endian integer, in constant
+    /// time with respect to the scalar for exponents of the same length.
+    pub fn pow<S: AsRef<[u64]>>(&self, exp: S) -> Self {
+        Self(self.0.pow(exp))
+    }
+}
+
+impl<F: PrimeField> SecretScalar<F> {
+    /// Returns the inverse of the scalar, if it is not zero.
+    ///
+    /// This uses [`invert_ct`] rather than `F::invert`, which runs in variable
+    /// time for the 4-limb fields.
+    pub fn invert(&self) -> CtOption<Self> {
+        invert_ct(&self.0).map(Self)
+    }
+}
+
+impl<F: Field> From<F> for SecretScalar<F> {
+    fn from(value: F) -> Self {
+        Self(value)
+    }
+}
+
+impl<F: Field> fmt::Debug for SecretScalar<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretScalar(..)")
+    }
+}
+
+impl<F: Field> ConstantTimeEq for SecretScalar<F> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<F: Field> Drop for SecretScalar<F> {
+    fn drop(&mut self) {
+        // A volatile write is not elided even though the value is not read
+        // again, and the fence keeps it ordered before the memory is reused.
+        // Safety: `self.0` is a valid and aligned `F`, and `F: Copy` has no
+        // destructor to run on the overwritten value.
+        unsafe { core::ptr::write_volatile(&mut self.0, F::ZERO) };
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: Field> zeroize::Zeroize for SecretScalar<F> {
+    fn zeroize(&mut self) {
+        // Safety: see `Drop`.
+        unsafe { core::ptr::write_volatile(&mut self.0, F::ZERO) };
+        compiler_fence(Ordering::SeqCst);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<F: Field> zeroize::ZeroizeOnDrop for SecretScalar<F> {}
+
+impl<F: Field> Neg for &SecretScalar<F> {
+    type Output = SecretScalar<F>;
+
+    fn neg(self) -> SecretScalar<F> {
+        SecretScalar(-self.0)
+    }
+}
+
+impl<'a, F: Field> Add<&'a SecretScalar<F>> for &SecretScalar<F> {
+    type Output = SecretScalar<F>;
+
+    fn add(self, rhs: &'a SecretScalar<F>) -> SecretScalar<F> {
+        SecretScalar(self.0 + rhs.0)
+    }
+}
+
+impl<'a, F: Field> Sub<&'a SecretScalar<F>> for &SecretScalar<F> {
+    type Output = SecretScalar<F>;
+
+    fn sub(self, rhs: &'a SecretScalar<F>) -> SecretScalar<F> {
+        SecretScalar(self.0 - rhs.0)
+    }
+}
+
+impl<'a, F: Field> Mul<&'a SecretScalar<F>> for &SecretScalar<F> {
+    type Output = SecretScalar<F>;
+
+    fn mul(self, rhs: &'a SecretScalar<F>) -> SecretScalar<F> {
+        SecretScalar(self.0 * rhs.0)
+    }
+}
+
+impl<'a, F: Field> Add<&'a F> for &SecretScalar<F> {
+    type Output = SecretScalar<F>;
+
+    fn add(self, rhs: &'a F) -> SecretScalar<F> {
+        SecretScalar(self.0 + rhs)
+    }
+}
+
+impl<'a, F: Field> Mul<&'a F> for &SecretScalar<F> {
+    type Output = SecretScalar<F>;
+
+    fn mul(self, rhs: &'a F) -> SecretScalar<F> {
+        SecretScalar(self.0 * rhs)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SecretScalar;
+    use crate::bn256::{Fr, G1Affine, G1};
+    use crate::fixed_base::FixedBaseTable;
+    use ff::Field;
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+    use subtle::ConstantTimeEq;
+
+    #[test]
+    fn test_secret_scalar() {
+        let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+        let (sa, sb) = (SecretScalar::new(a), SecretScalar::from(b));
+
+        assert_eq!(*(&sa + &sb).expose_secret(), a + b);
+        assert_eq!(*(&sa - &sb).expose_secret(), a - b);
+        assert_eq!(*(&sa * &sb).expose_secret(), a * b);
+        assert_eq!(*(&sa + &b).expose_secret(), a + b);
+        assert_eq!(*(&sa * &b).expose_secret(), a * b);
+        assert_eq!(*(-&sa).expose_secret(), -a);
+        assert_eq!(*sa.square().expose_secret(), a.square());
+        assert_eq!(*sa.double().expose_secret(), a.double());
+        assert_eq!(*sa.invert().unwrap().expose_secret(), a.invert().unwrap());
+        assert_eq!(*sa.pow([5, 1]).expose_secret(), a.pow([5, 1]));
+        assert!(bool::from(sa.ct_eq(&sa.clone())));
+        assert!(!bool::from(sa.ct_eq(&sb)));
+        assert!(bool::from(SecretScalar::<Fr>::new(Fr::ZERO).is_zero()));
+        assert_eq!(format!("{sa:?}"), "SecretScalar(..)");
+
+        let g = G1::random(OsRng);
+        assert_eq!(g * &sa, g * a);
+        assert_eq!(g.to_affine() * &sa, g * a);
+        let table = FixedBaseTable::<G1Affine>::new(g.to_affine());
+        assert_eq!(table.mul_secret(&sa), g * a);
+    }
+}
@@ -1,7 +1,112 @@
+pub mod arkworks;
+#[cfg(target_endian = "little")]
+pub mod montgomery;
+
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
 use std::io::{self, Read, Write};
 
+/// Error returned when decoding a field element from a variable-length byte slice.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FieldDecodingError {
+    /// The input is longer than the canonical encoding of the field.
+    InvalidLength,
+    /// The input encodes an integer that is not smaller than the field modulus.
+    NotCanonical,
+}
+
+impl core::fmt::Display for FieldDecodingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "input is longer than the field encoding"),
+            Self::NotCanonical => write!(f, "input number is not less than field modulus"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FieldDecodingError {}
+
+/// Error returned by the strict point decoders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointDecodingError {
+    /// The flag bits are invalid or do not match the encoded point.
+    InvalidFlags,
+    /// A coordinate is not smaller than the field modulus.
+    NotCanonical,
+    /// The encoded coordinates do not define a point on the curve.
+    NotOnCurve,
+    /// The point is not in the prime order subgroup.
+    NotInSubgroup,
+}
+
+impl core::fmt::Display for PointDecodingError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidFlags => write!(f, "invalid flag bits"),
+            Self::NotCanonical => write!(f, "coordinate is not less than field modulus"),
+            Self::NotOnCurve => write!(f, "point is not on the curve"),
+            Self::NotInSubgroup => write!(f, "point is not in the prime order subgroup"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PointDecodingError {}
+
+/// Serde helpers encoding byte arrays as `0x`-prefixed big-endian hex strings.
+///
+/// The byte arrays are little-endian encodings, so they are reversed before
+/// being hex encoded. Used for human-readable formats such as JSON.
+///
+/// Earlier versions wrote the little-endian bytes as hex without a prefix.
+/// Strings without the `0x` prefix are still read in that format.
+#[cfg(feature = "derive_serde")]
+#[doc(hidden)]
+pub mod hex_be {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec::Vec;
+    use serde::de::Error as _;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        let be: Vec<u8> = bytes.iter().rev().copied().collect();
+        serializer.serialize_str(&format!("0x{}", hex::encode(be)))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        let s = String::deserialize(deserializer)?;
+        decode(&s).map_err(D::Error::custom)
+    }
+
+    /// Decodes a `0x`-prefixed big-endian hex string, or an unprefixed
+    /// little-endian one, into little-endian bytes.
+    pub fn decode<const N: usize>(s: &str) -> Result<[u8; N], hex::FromHexError> {
+        let mut bytes = [0u8; N];
+        match s.strip_prefix("0x") {
+            Some(be) => {
+                hex::decode_to_slice(be, &mut bytes)?;
+                bytes.reverse();
+            }
+            None => hex::decode_to_slice(s, &mut bytes)?,
+        }
+        Ok(bytes)
+    }
+}
+
 /// Trait for converting raw bytes to/from the internal representation of a type.
 /// For example, field elements are represented in Montgomery form and serialized/deserialized without Montgomery reduction.
+///
+/// The checked functions, `from_raw_bytes` and `read_raw`, accept exactly the
+/// values the type can hold: field elements must be reduced, points must be on
+/// the curve and in the prime order subgroup, and pairing target elements must
+/// be in `Gt`. The unchecked functions perform none of these checks.
+///
+/// The functions reading from and writing to `std::io` streams require the
+/// `std` feature.
 pub trait SerdeObject: Sized {
     /// The purpose of unchecked functions is to read the internal memory representation
     /// of a type from bytes as quickly as possible. No sanitization checks are performed
@@ -18,8 +123,59 @@ pub trait SerdeObject: Sized {
     /// to ensure the bytes represent a valid object. This function should only be used
     /// internally when some machine state cannot be kept in memory (e.g., between runs)
     /// and needs to be reloaded as quickly as possible.
+    #[cfg(feature = "std")]
     fn read_raw_unchecked<R: Read>(reader: &mut R) -> Self;
+    #[cfg(feature = "std")]
     fn read_raw<R: Read>(reader: &mut R) -> io::Result<Self>;
 
+    #[cfg(feature = "std")]
     fn write_raw<W: Write>(&self, writer: &mut W) -> io::Result<()>;
 }
+
+/// Encoding of a point, as produced by [`group::GroupEncoding`] or
+/// [`group::UncompressedEncoding`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PointFormat {
+    /// The x-coordinate and the sign of the y-coordinate.
+    Compressed,
+    /// Both coordinates.
+    Uncompressed,
+}
+
+/// Encoding and decoding of points in either of their formats.
+///
+/// This is implemented for every affine point type that implements both
+/// [`group::GroupEncoding`] and [`group::UncompressedEncoding`].
+pub trait PointEncoding: Sized {
+    /// Encodes the point in the given format.
+    fn encode(&self, format: PointFormat) -> Vec<u8>;
+
+    /// Decodes a point in either format, detected from the length of the
+    /// input. Returns the point and the format it was encoded in, or `None`
+    /// if the length matches neither format or the encoding is invalid.
+    fn decode(bytes: &[u8]) -> Option<(Self, PointFormat)>;
+}
+
+impl<T: group::GroupEncoding + group::UncompressedEncoding> PointEncoding for T {
+    fn encode(&self, format: PointFormat) -> Vec<u8> {
+        match format {
+            PointFormat::Compressed => self.to_bytes().as_ref().to_vec(),
+            PointFormat::Uncompressed => self.to_uncompressed().as_ref().to_vec(),
+        }
+    }
+
+    fn decode(bytes: &[u8]) -> Option<(Self, PointFormat)> {
+        let mut compressed = T::Repr::default();
+        if bytes.len() == compressed.as_ref().len() {
+            compressed.as_mut().copy_from_slice(bytes);
+            return Option::from(T::from_bytes(&compressed)).map(|p| (p, PointFormat::Compressed));
+        }
+        let mut uncompressed = T::Uncompressed::default();
+        if bytes.len() == uncompressed.as_ref().len() {
+            uncompressed.as_mut().copy_from_slice(bytes);
+            return Option::from(T::from_uncompressed(&uncompressed))
+                .map(|p| (p, PointFormat::Uncompressed));
+        }
+        None
+    }
+}
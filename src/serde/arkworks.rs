@@ -0,0 +1,417 @@
+//! Encodings compatible with arkworks' `CanonicalSerialize` and
+//! `CanonicalDeserialize`.
+//!
+//! A prime field element is written as its little-endian integer value in
+//! `ceil(NUM_BITS / 8)` bytes, and an extension field element as the
+//! concatenation of its coefficients, lowest degree first. A point is
+//! written in affine form, as `x` when compressed and as `x || y` otherwise.
+//! The last coordinate carries the two flags of arkworks' `SWFlags` in the
+//! most significant bits of its last byte, which takes an extra byte when the
+//! field has fewer than two spare bits:
+//!
+//! - bit 7 is set when `y > -y`, comparing coefficients from the highest
+//!   degree down,
+//! - bit 6 is set for the identity, whose coordinates are encoded as zero.
+//!
+//! Decoding performs the checks of arkworks' `Validate::Yes`: coordinates
+//! must be canonical and the point must lie in the prime order subgroup. It
+//! additionally rejects the encodings that arkworks never produces, namely a
+//! nonzero identity and an uncompressed point whose sign flag does not match
+//! its y-coordinate.
+
+use crate::subgroup::TorsionFreeAffine;
+use crate::CurveAffine;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use ff::{Field, PrimeField};
+
+const FLAG_NEGATIVE: u8 = 1 << 7;
+const FLAG_INFINITY: u8 = 1 << 6;
+const FLAG_BITS: u32 = 2;
+
+/// Whether points are encoded with or without their y-coordinate.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Compress {
+    Yes,
+    No,
+}
+
+/// Conversion from and to the byte encoding of arkworks.
+pub trait ArkworksSerde: Sized {
+    /// Encodes `self` as arkworks' `serialize_with_mode` would. Field
+    /// elements ignore `compress`.
+    fn to_arkworks_bytes(&self, compress: Compress) -> Vec<u8>;
+
+    /// Decodes an encoding produced by arkworks' `serialize_with_mode`,
+    /// failing if `bytes` does not have the exact length of an encoding or
+    /// does not represent a valid element. Field elements ignore `compress`.
+    fn from_arkworks_bytes(bytes: &[u8], compress: Compress) -> Option<Self>;
+}
+
+/// A field viewed as a vector space over its prime field, which is how
+/// arkworks serializes it.
+pub trait ArkworksField: Field {
+    /// The prime field of the coefficients.
+    type Prime: PrimeField;
+
+    /// The number of coefficients.
+    const DEGREE: usize;
+
+    /// Appends the coefficients of `self` to `out`, lowest degree first.
+    fn write_coefficients(&self, out: &mut Vec<Self::Prime>);
+
+    /// Builds an element from its `DEGREE` coefficients.
+    fn from_coefficients(coeffs: &[Self::Prime]) -> Self;
+}
+
+fn prime_size<F: PrimeField>(flag_bits: u32) -> usize {
+    ((F::NUM_BITS + flag_bits + 7) / 8) as usize
+}
+
+fn field_size<F: ArkworksField>(flag_bits: u32) -> usize {
+    (F::DEGREE - 1) * prime_size::<F::Prime>(0) + prime_size::<F::Prime>(flag_bits)
+}
+
+fn write_prime<F: PrimeField>(e: &F, flags: Option<u8>, out: &mut Vec<u8>) {
+    let size = prime_size::<F>(if flags.is_some() { FLAG_BITS } else { 0 });
+    let start = out.len();
+    // The fields of this crate represent elements in little-endian order.
+    out.extend(e.to_repr().as_ref().iter().take(size));
+    out.resize(start + size, 0);
+    if let Some(flags) = flags {
+        out[start + size - 1] |= flags;
+    }
+}
+
+fn read_prime<F: PrimeField>(bytes: &[u8]) -> Option<F> {
+    let mut repr = F::Repr::default();
+    let len = repr.as_ref().len();
+    if bytes.len() > len && bytes[len..].iter().any(|b| *b != 0) {
+        return None;
+    }
+    let n = len.min(bytes.len());
+    repr.as_mut()[..n].copy_from_slice(&bytes[..n]);
+    Option::from(F::from_repr(repr))
+}
+
+// Compares the integer values of two prime field elements.
+fn cmp_prime<F: PrimeField>(a: &F, b: &F) -> Ordering {
+    let (a, b) = (a.to_repr(), b.to_repr());
+    a.as_ref().iter().rev().cmp(b.as_ref().iter().rev())
+}
+
+fn coefficients<F: ArkworksField>(e: &F) -> Vec<F::Prime> {
+    let mut coeffs = Vec::with_capacity(F::DEGREE);
+    e.write_coefficients(&mut coeffs);
+    coeffs
+}
+
+// Returns whether `y > -y` in arkworks' lexicographic order.
+fn is_negative<F: ArkworksField>(y: &F) -> bool {
+    let (y, neg_y) = (coefficients(y), coefficients(&-*y));
+    y.iter()
+        .rev()
+        .zip(neg_y.iter().rev())
+        .map(|(a, b)| cmp_prime(a, b))
+        .find(|ord| *ord != Ordering::Equal)
+        == Some(Ordering::Greater)
+}
+
+fn write_field<F: ArkworksField>(e: &F, flags: Option<u8>, out: &mut Vec<u8>) {
+    let coeffs = coefficients(e);
+    let (last, rest) = coeffs.split_last().unwrap();
+    for c in rest {
+        write_prime(c, None, out);
+    }
+    write_prime(last, flags, out);
+}
+
+// Reads a field element and the flags stored in its last byte. `bytes` must
+// have the exact length of the encoding.
+fn read_field<F: ArkworksField>(bytes: &[u8], with_flags: bool) -> Option<(F, u8)> {
+    let mut bytes = bytes.to_vec();
+    let flags = if with_flags {
+        let last = bytes.last_mut().unwrap();
+        let flags = *last & (FLAG_NEGATIVE | FLAG_INFINITY);
+        *last &= !flags;
+        flags
+    } else {
+        0
+    };
+
+    let size = prime_size::<F::Prime>(0);
+    let coeffs = (0..F::DEGREE)
+        .map(|i| {
+            let end = if i + 1 == F::DEGREE {
+                bytes.len()
+            } else {
+                (i + 1) * size
+            };
+            read_prime(&bytes[i * size..end])
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((F::from_coefficients(&coeffs), flags))
+}
+
+/// Encodes a field element.
+pub(crate) fn field_to_bytes<F: ArkworksField>(e: &F) -> Vec<u8> {
+    let mut out = Vec::with_capacity(field_size::<F>(0));
+    write_field(e, None, &mut out);
+    out
+}
+
+/// Decodes a field element.
+pub(crate) fn field_from_bytes<F: ArkworksField>(bytes: &[u8]) -> Option<F> {
+    if bytes.len() != field_size::<F>(0) {
+        return None;
+    }
+    read_field(bytes, false).map(|(e, _)| e)
+}
+
+/// Encodes an affine point.
+#[doc(hidden)]
+pub fn point_to_bytes<C>(p: &C, compress: Compress) -> Vec<u8>
+where
+    C: CurveAffine,
+    C::Base: ArkworksField,
+{
+    let (x, y, flags) = if bool::from(p.is_identity()) {
+        (C::Base::ZERO, C::Base::ZERO, FLAG_INFINITY)
+    } else {
+        let coords = p.coordinates().unwrap();
+        let y = *coords.y();
+        let flags = if is_negative(&y) { FLAG_NEGATIVE } else { 0 };
+        (*coords.x(), y, flags)
+    };
+
+    let mut out = Vec::new();
+    match compress {
+        Compress::Yes => write_field(&x, Some(flags), &mut out),
+        Compress::No => {
+            write_field(&x, None, &mut out);
+            write_field(&y, Some(flags), &mut out);
+        }
+    }
+    out
+}
+
+/// Decodes an affine point, checking that it lies in the prime order
+/// subgroup.
+#[doc(hidden)]
+pub fn point_from_bytes<C>(bytes: &[u8], compress: Compress) -> Option<C>
+where
+    C: CurveAffine + TorsionFreeAffine,
+    C::Base: ArkworksField,
+{
+    let x_size = field_size::<C::Base>(0);
+    let (x, y, flags) = match compress {
+        Compress::Yes => {
+            if bytes.len() != field_size::<C::Base>(FLAG_BITS) {
+                return None;
+            }
+            let (x, flags) = read_field::<C::Base>(bytes, true)?;
+            (x, None, flags)
+        }
+        Compress::No => {
+            if bytes.len() != x_size + field_size::<C::Base>(FLAG_BITS) {
+                return None;
+            }
+            let (x, _) = read_field::<C::Base>(&bytes[..x_size], false)?;
+            let (y, flags) = read_field::<C::Base>(&bytes[x_size..], true)?;
+            (x, Some(y), flags)
+        }
+    };
+
+    match flags {
+        FLAG_INFINITY => {
+            let is_zero = x.is_zero_vartime() && y.map_or(true, |y| y.is_zero_vartime());
+            is_zero.then(C::identity)
+        }
+        0 | FLAG_NEGATIVE => {
+            let negative = flags == FLAG_NEGATIVE;
+            let y = match y {
+                Some(y) => (is_negative(&y) == negative).then_some(y)?,
+                None => {
+                    let y2 = (x.square() + C::a()) * x + C::b();
+                    let y = Option::<C::Base>::from(y2.sqrt())?;
+                    if is_negative(&y) == negative {
+                        y
+                    } else {
+                        -y
+                    }
+                }
+            };
+            Option::<C>::from(C::from_xy(x, y)).filter(|p| bool::from(p.is_torsion_free()))
+        }
+        _ => None,
+    }
+}
+
+/// Implements [`ArkworksField`] and [`ArkworksSerde`] for a prime field, or
+/// for an extension field given the field of its coefficients and their names
+/// from the lowest degree up.
+#[macro_export]
+macro_rules! impl_arkworks_field {
+    ($field:ident) => {
+        impl $crate::serde::arkworks::ArkworksField for $field {
+            type Prime = $field;
+
+            const DEGREE: usize = 1;
+
+            fn write_coefficients(&self, out: &mut alloc::vec::Vec<$field>) {
+                out.push(*self);
+            }
+
+            fn from_coefficients(coeffs: &[$field]) -> Self {
+                coeffs[0]
+            }
+        }
+
+        $crate::impl_arkworks_field!(@serde $field);
+    };
+    ($field:ident, $sub:ident, $($c:ident),+) => {
+        impl $crate::serde::arkworks::ArkworksField for $field {
+            type Prime = <$sub as $crate::serde::arkworks::ArkworksField>::Prime;
+
+            const DEGREE: usize = [$(stringify!($c)),+].len()
+                * <$sub as $crate::serde::arkworks::ArkworksField>::DEGREE;
+
+            fn write_coefficients(&self, out: &mut alloc::vec::Vec<Self::Prime>) {
+                $(
+                    <$sub as $crate::serde::arkworks::ArkworksField>::write_coefficients(
+                        &self.$c, out,
+                    );
+                )+
+            }
+
+            fn from_coefficients(coeffs: &[Self::Prime]) -> Self {
+                let mut chunks =
+                    coeffs.chunks(<$sub as $crate::serde::arkworks::ArkworksField>::DEGREE);
+                $field {
+                    $(
+                        $c: <$sub as $crate::serde::arkworks::ArkworksField>::from_coefficients(
+                            chunks.next().unwrap(),
+                        ),
+                    )+
+                }
+            }
+        }
+
+        $crate::impl_arkworks_field!(@serde $field);
+    };
+    (@serde $field:ident) => {
+        impl $crate::serde::arkworks::ArkworksSerde for $field {
+            fn to_arkworks_bytes(&self, _: $crate::serde::arkworks::Compress) -> alloc::vec::Vec<u8> {
+                $crate::serde::arkworks::field_to_bytes(self)
+            }
+
+            fn from_arkworks_bytes(
+                bytes: &[u8],
+                _: $crate::serde::arkworks::Compress,
+            ) -> Option<Self> {
+                $crate::serde::arkworks::field_from_bytes(bytes)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{Fq, Fq12, Fq2, G1Affine, G2Affine, G1, G2};
+    use crate::group::{prime::PrimeCurveAffine, Group};
+    use crate::pluto_eris::{Eris, Fp, G1 as Pluto, G2 as Triton};
+    use crate::secp256k1::{Fp as Secp256k1Base, Secp256k1};
+    use rand_core::OsRng;
+
+    fn roundtrip<T: ArkworksSerde + PartialEq + core::fmt::Debug>(
+        t: &T,
+        compress: Compress,
+        size: usize,
+    ) {
+        let bytes = t.to_arkworks_bytes(compress);
+        assert_eq!(bytes.len(), size);
+        assert_eq!(T::from_arkworks_bytes(&bytes, compress).unwrap(), *t);
+        assert!(T::from_arkworks_bytes(&bytes[1..], compress).is_none());
+    }
+
+    #[test]
+    fn test_arkworks_fields() {
+        for _ in 0..10 {
+            roundtrip(&Fq::random(OsRng), Compress::Yes, 32);
+            roundtrip(&Fq2::random(OsRng), Compress::No, 64);
+            roundtrip(&Fq12::random(OsRng), Compress::Yes, 384);
+            roundtrip(&Secp256k1Base::random(OsRng), Compress::Yes, 32);
+            roundtrip(&Fp::random(OsRng), Compress::Yes, 56);
+        }
+
+        // Non-canonical encodings are rejected.
+        assert!(Fq::from_arkworks_bytes(&[0xff; 32], Compress::Yes).is_none());
+    }
+
+    #[test]
+    fn test_arkworks_points() {
+        // The generator (1, 2) has a positive y-coordinate.
+        let mut expected = [0u8; 64];
+        expected[0] = 1;
+        expected[32] = 2;
+        let generator = G1Affine::generator();
+        assert_eq!(generator.to_arkworks_bytes(Compress::No), expected);
+        assert_eq!(generator.to_arkworks_bytes(Compress::Yes), expected[..32]);
+        let mut negated = expected[..32].to_vec();
+        negated[31] |= FLAG_NEGATIVE;
+        assert_eq!((-generator).to_arkworks_bytes(Compress::Yes), negated);
+        assert_eq!(
+            G1Affine::from_arkworks_bytes(&negated, Compress::Yes).unwrap(),
+            -generator
+        );
+
+        // A sign flag that does not match the y-coordinate.
+        expected[63] |= FLAG_NEGATIVE;
+        assert!(G1Affine::from_arkworks_bytes(&expected, Compress::No).is_none());
+
+        let mut identity = [0u8; 32];
+        identity[31] = FLAG_INFINITY;
+        assert_eq!(
+            G1Affine::identity().to_arkworks_bytes(Compress::Yes),
+            identity
+        );
+        assert!(bool::from(
+            G1Affine::from_arkworks_bytes(&identity, Compress::Yes)
+                .unwrap()
+                .is_identity()
+        ));
+        identity[0] = 1;
+        assert!(G1Affine::from_arkworks_bytes(&identity, Compress::Yes).is_none());
+
+        for compress in [Compress::Yes, Compress::No] {
+            let factor = if compress == Compress::Yes { 1 } else { 2 };
+            for _ in 0..10 {
+                roundtrip(&G1::random(OsRng), compress, 32 * factor);
+                roundtrip(&G2::random(OsRng), compress, 64 * factor);
+                // The flags take an extra byte as the base field has no spare
+                // bits.
+                roundtrip(&Secp256k1::random(OsRng), compress, 32 * factor + 1);
+                roundtrip(&Pluto::random(OsRng), compress, 56 * factor);
+                roundtrip(&Eris::random(OsRng), compress, 56 * factor);
+                roundtrip(&Triton::random(OsRng), compress, 112 * factor);
+            }
+            roundtrip(&G2::identity(), compress, 64 * factor);
+        }
+
+        // A point on the twist outside the prime order subgroup.
+        let p = loop {
+            let x = Fq2::random(OsRng);
+            if let Some(y) = Option::<Fq2>::from((x.square() * x + G2Affine::b()).sqrt()) {
+                let p = G2Affine { x, y };
+                if !bool::from(p.is_torsion_free()) {
+                    break p;
+                }
+            }
+        };
+        for compress in [Compress::Yes, Compress::No] {
+            let bytes = p.to_arkworks_bytes(compress);
+            assert!(G2Affine::from_arkworks_bytes(&bytes, compress).is_none());
+        }
+    }
+}
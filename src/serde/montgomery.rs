@@ -0,0 +1,153 @@
+//! Zero-copy views of slices of field elements as bytes.
+//!
+//! A prime field element is stored as the little-endian `u64` limbs of its
+//! Montgomery form, so on little-endian targets its memory is exactly its
+//! [`SerdeObject`](super::SerdeObject) raw encoding. A slice of elements can
+//! thus be viewed as bytes, and bytes as a slice of elements, without copying,
+//! which makes snapshots of large vectors of elements cheap.
+//!
+//! These bytes are not a canonical encoding of the elements: they depend on
+//! the Montgomery constant of the implementation. They should only be read
+//! back by this crate, as with the other raw encodings.
+
+use core::marker::PhantomData;
+use core::mem::{align_of, size_of, size_of_val};
+
+/// Field elements whose memory is the raw encoding of their Montgomery form.
+///
+/// # Safety
+///
+/// Implementors must be `#[repr(transparent)]` over an array of `u64` limbs
+/// holding the Montgomery form in little-endian limb order.
+pub unsafe trait MontgomeryLimbs: Copy {
+    /// Returns whether the limbs are a fully reduced Montgomery form.
+    fn is_reduced(&self) -> bool;
+}
+
+/// Error returned when bytes cannot be viewed as a slice of field elements.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RawCastError {
+    /// The length is not a multiple of the size of an element.
+    InvalidLength,
+    /// The bytes are not aligned as the elements must be.
+    Misaligned,
+}
+
+impl core::fmt::Display for RawCastError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidLength => write!(f, "length is not a multiple of the element size"),
+            Self::Misaligned => write!(f, "bytes are not aligned to the element alignment"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RawCastError {}
+
+/// Bytes holding a slice of field elements in raw Montgomery form.
+///
+/// This marks bytes that are not a canonical encoding of the elements, see the
+/// [module documentation](self).
+#[derive(Clone, Copy, Debug)]
+pub struct RawMontgomery<'a, F> {
+    bytes: &'a [u8],
+    _marker: PhantomData<&'a [F]>,
+}
+
+impl<'a, F: MontgomeryLimbs> RawMontgomery<'a, F> {
+    /// Views a slice of elements as bytes.
+    pub fn from_elements(elements: &'a [F]) -> Self {
+        // SAFETY: `F` is an array of `u64`, which has no padding bytes.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(elements.as_ptr() as *const u8, size_of_val(elements))
+        };
+        Self {
+            bytes,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Wraps bytes holding elements in raw Montgomery form, failing if their
+    /// length or alignment does not fit a slice of `F`.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, RawCastError> {
+        if bytes.len() % size_of::<F>() != 0 {
+            return Err(RawCastError::InvalidLength);
+        }
+        if bytes.as_ptr().align_offset(align_of::<F>()) != 0 {
+            return Err(RawCastError::Misaligned);
+        }
+        Ok(Self {
+            bytes,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the bytes, e.g. to write them to a snapshot.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Views the bytes as elements, failing if any of them is not fully
+    /// reduced.
+    pub fn elements(&self) -> Option<&'a [F]> {
+        let elements = self.elements_unchecked();
+        elements.iter().all(F::is_reduced).then_some(elements)
+    }
+
+    /// Views the bytes as elements without checking that they are fully
+    /// reduced. As with the other unchecked functions, this should only be
+    /// used on bytes produced by this crate.
+    pub fn elements_unchecked(&self) -> &'a [F] {
+        // SAFETY: the length and alignment were checked on construction, and
+        // any limbs are a valid value of `F`.
+        unsafe {
+            core::slice::from_raw_parts(
+                self.bytes.as_ptr() as *const F,
+                self.bytes.len() / size_of::<F>(),
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::Fr;
+    use crate::pluto_eris::Fp;
+    use crate::serde::SerdeObject;
+    use ff::Field;
+    use rand_core::OsRng;
+
+    fn roundtrip<F: MontgomeryLimbs + SerdeObject + Field>() {
+        let elements: Vec<F> = (0..100).map(|_| F::random(OsRng)).collect();
+        let raw = RawMontgomery::from_elements(&elements);
+        let expected: Vec<u8> = elements.iter().flat_map(|e| e.to_raw_bytes()).collect();
+        assert_eq!(raw.as_bytes(), &expected[..]);
+
+        let raw = RawMontgomery::<F>::from_bytes(raw.as_bytes()).unwrap();
+        assert_eq!(raw.elements(), Some(&elements[..]));
+
+        let size = size_of::<F>();
+        assert_eq!(
+            RawMontgomery::<F>::from_bytes(&raw.as_bytes()[..size - 1]).unwrap_err(),
+            RawCastError::InvalidLength
+        );
+        assert_eq!(
+            RawMontgomery::<F>::from_bytes(&raw.as_bytes()[1..size + 1]).unwrap_err(),
+            RawCastError::Misaligned
+        );
+    }
+
+    #[test]
+    fn test_raw_montgomery() {
+        roundtrip::<Fr>();
+        roundtrip::<Fp>();
+
+        // Limbs that are not reduced are only accepted unchecked.
+        let elements = [Fr::one(), Fr([u64::MAX; 4])];
+        let raw = RawMontgomery::from_elements(&elements);
+        assert!(raw.elements().is_none());
+        assert_eq!(raw.elements_unchecked(), &elements);
+    }
+}
@@ -0,0 +1,140 @@
+//! Shamir secret sharing over prime fields.
+//!
+//! A secret is the constant term of a random polynomial of degree
+//! `threshold - 1`, and share `i` is the evaluation of that polynomial at `i`.
+//! Any `threshold` shares recover the secret by Lagrange interpolation at zero.
+
+use alloc::vec::Vec;
+use ff::{BatchInvert, PrimeField};
+use rand_core::RngCore;
+
+/// A share of a secret: the evaluation of the sharing polynomial at `index`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Share<F> {
+    /// Non-zero evaluation point of the share.
+    pub index: u64,
+    /// Value of the sharing polynomial at `index`.
+    pub value: F,
+}
+
+/// Splits `secret` into `num_shares` shares with indices `1..=num_shares`,
+/// any `threshold` of which recover it.
+///
+/// Panics if `threshold` is zero or greater than `num_shares`.
+pub fn share<F: PrimeField>(
+    secret: F,
+    threshold: usize,
+    num_shares: u64,
+    mut rng: impl RngCore,
+) -> Vec<Share<F>> {
+    assert!(threshold > 0, "threshold must be positive");
+    assert!(
+        threshold as u64 <= num_shares,
+        "threshold must not exceed the number of shares"
+    );
+
+    let coeffs: Vec<F> = core::iter::once(secret)
+        .chain((1..threshold).map(|_| F::random(&mut rng)))
+        .collect();
+
+    (1..=num_shares)
+        .map(|index| {
+            let x = F::from(index);
+            let value = coeffs
+                .iter()
+                .rev()
+                .fold(F::ZERO, |acc, coeff| acc * x + coeff);
+            Share { index, value }
+        })
+        .collect()
+}
+
+/// Recovers the secret from `shares` by interpolating at zero.
+///
+/// Returns `None` if `shares` is empty or contains a zero or repeated index.
+/// The result is only the original secret if at least `threshold` shares of
+/// the same sharing are provided.
+pub fn reconstruct<F: PrimeField>(shares: &[Share<F>]) -> Option<F> {
+    if shares.is_empty() {
+        return None;
+    }
+
+    let xs: Vec<F> = shares.iter().map(|share| F::from(share.index)).collect();
+
+    // The Lagrange coefficient of share `i` at zero is
+    // `prod_{j != i} x_j / (x_j - x_i)`.
+    let mut numerators = Vec::with_capacity(xs.len());
+    let mut denominators = Vec::with_capacity(xs.len());
+    for (i, x_i) in xs.iter().enumerate() {
+        let (num, den) = xs
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold((F::ONE, F::ONE), |(num, den), (_, x_j)| {
+                (num * x_j, den * (*x_j - x_i))
+            });
+        numerators.push(num);
+        denominators.push(den);
+    }
+
+    // Indices are public, so bailing out early on invalid ones leaks nothing.
+    if xs.iter().any(|x| bool::from(x.is_zero()))
+        || denominators.iter().any(|den| bool::from(den.is_zero()))
+    {
+        return None;
+    }
+    denominators.iter_mut().batch_invert();
+
+    Some(
+        shares
+            .iter()
+            .zip(numerators.iter().zip(denominators.iter()))
+            .fold(F::ZERO, |acc, (share, (num, den_inv))| {
+                acc + share.value * num * den_inv
+            }),
+    )
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::Fr;
+    use ff::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_share_reconstruct() {
+        let secret = Fr::random(OsRng);
+        let shares = share(secret, 3, 5, OsRng);
+        assert_eq!(shares.len(), 5);
+
+        for subset in [[0, 1, 2], [4, 2, 0], [1, 3, 4]] {
+            let subset: Vec<_> = subset.iter().map(|&i| shares[i]).collect();
+            assert_eq!(reconstruct(&subset), Some(secret));
+        }
+        assert_eq!(reconstruct(&shares), Some(secret));
+        assert_ne!(reconstruct(&shares[..2]), Some(secret));
+
+        let single = share(secret, 1, 1, OsRng);
+        assert_eq!(single[0].value, secret);
+        assert_eq!(reconstruct(&single), Some(secret));
+    }
+
+    #[test]
+    fn test_reconstruct_invalid() {
+        let shares = share(Fr::ONE, 2, 3, OsRng);
+        assert_eq!(reconstruct::<Fr>(&[]), None);
+        assert_eq!(reconstruct(&[shares[0], shares[0]]), None);
+        let zero = Share {
+            index: 0,
+            value: Fr::ONE,
+        };
+        assert_eq!(reconstruct(&[zero, shares[1]]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_share_threshold_too_large() {
+        share(Fr::ONE, 4, 3, OsRng);
+    }
+}
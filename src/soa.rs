@@ -0,0 +1,206 @@
+//! Structure-of-arrays storage for affine points.
+//!
+//! GPU transfers and SIMD batch operations want the coordinates of a point
+//! vector in separate contiguous arrays rather than interleaved. [`SoAPoints`]
+//! keeps them that way, and the entry points that accept it (such as
+//! [`best_multiexp_soa`](crate::msm::best_multiexp_soa)) avoid materializing a
+//! full array of points.
+
+use crate::multicore::*;
+use crate::{Coordinates, CurveAffine, CurveExt};
+use alloc::vec::Vec;
+use ff::{BatchInvert, Field};
+
+/// Affine points stored as separate arrays of `x` and `y` coordinates.
+///
+/// The identity is stored as `(0, 0)`, like in the affine types of this
+/// crate.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SoAPoints<C: CurveAffine> {
+    x: Vec<C::Base>,
+    y: Vec<C::Base>,
+}
+
+impl<C: CurveAffine> SoAPoints<C> {
+    /// Creates an empty vector of points.
+    pub fn new() -> Self {
+        Self {
+            x: Vec::new(),
+            y: Vec::new(),
+        }
+    }
+
+    /// Creates an empty vector of points with room for `capacity` points.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            x: Vec::with_capacity(capacity),
+            y: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Splits `points` into coordinate arrays.
+    pub fn from_affine(points: &[C]) -> Self {
+        let (x, y) = points.par_iter().map(Self::coordinates).unzip();
+        Self { x, y }
+    }
+
+    /// Appends `point`.
+    pub fn push(&mut self, point: &C) {
+        let (x, y) = Self::coordinates(point);
+        self.x.push(x);
+        self.y.push(y);
+    }
+
+    /// Returns the number of points.
+    pub fn len(&self) -> usize {
+        self.x.len()
+    }
+
+    /// Returns whether there are no points.
+    pub fn is_empty(&self) -> bool {
+        self.x.is_empty()
+    }
+
+    /// Returns the `x` coordinates.
+    pub fn x(&self) -> &[C::Base] {
+        &self.x
+    }
+
+    /// Returns the `y` coordinates.
+    pub fn y(&self) -> &[C::Base] {
+        &self.y
+    }
+
+    /// Returns the point at `index`.
+    ///
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> C {
+        Self::point(&self.x[index], &self.y[index])
+    }
+
+    /// Converts the points back to an array of affine points.
+    pub fn to_affine(&self) -> Vec<C> {
+        self.x
+            .par_iter()
+            .zip(self.y.par_iter())
+            .map(|(x, y)| Self::point(x, y))
+            .collect()
+    }
+
+    /// Writes the points of `range` into `out`, reusing its allocation.
+    pub(crate) fn copy_range_to(&self, range: core::ops::Range<usize>, out: &mut Vec<C>) {
+        out.clear();
+        out.extend(
+            self.x[range.clone()]
+                .iter()
+                .zip(self.y[range].iter())
+                .map(|(x, y)| Self::point(x, y)),
+        );
+    }
+
+    fn coordinates(point: &C) -> (C::Base, C::Base) {
+        Option::from(point.coordinates())
+            .map(|coords: Coordinates<C>| (*coords.x(), *coords.y()))
+            .unwrap_or((C::Base::ZERO, C::Base::ZERO))
+    }
+
+    fn point(x: &C::Base, y: &C::Base) -> C {
+        if bool::from(x.is_zero() & y.is_zero()) {
+            C::identity()
+        } else {
+            C::from_xy(*x, *y).unwrap()
+        }
+    }
+}
+
+impl<C> SoAPoints<C>
+where
+    C: CurveAffine,
+    C::CurveExt: CurveExt<Base = C::Base>,
+{
+    /// Normalizes `points` directly into coordinate arrays, sharing a single
+    /// field inversion between all of them.
+    pub fn from_projective(points: &[C::CurveExt]) -> Self {
+        let (mut x, mut y, mut z): (Vec<_>, Vec<_>, Vec<_>) = (
+            Vec::with_capacity(points.len()),
+            Vec::with_capacity(points.len()),
+            Vec::with_capacity(points.len()),
+        );
+        for point in points {
+            let (px, py, pz) = point.jacobian_coordinates();
+            x.push(px);
+            y.push(py);
+            z.push(pz);
+        }
+
+        // The inverse of zero stays zero, which maps the identity to `(0, 0)`.
+        z.iter_mut().batch_invert();
+        x.par_iter_mut()
+            .zip(y.par_iter_mut())
+            .zip(z.par_iter())
+            .for_each(|((x, y), z_inv)| {
+                let z_inv2 = z_inv.square();
+                *x *= z_inv2;
+                *y *= z_inv2 * z_inv;
+            });
+
+        Self { x, y }
+    }
+}
+
+impl<'a, C: CurveAffine> From<&'a [C]> for SoAPoints<C> {
+    fn from(points: &'a [C]) -> Self {
+        Self::from_affine(points)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{Fr, G1Affine, G1};
+    use crate::msm::{best_multiexp, best_multiexp_soa};
+    use group::{Curve, Group};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_soa_points() {
+        let mut projective: Vec<_> = (0..100).map(|_| G1::random(OsRng)).collect();
+        projective[17] = G1::identity();
+        let mut affine = vec![G1Affine::identity(); projective.len()];
+        G1::batch_normalize(&projective, &mut affine);
+
+        let soa = SoAPoints::from_affine(&affine);
+        assert_eq!(soa.len(), affine.len());
+        assert_eq!(soa.to_affine(), affine);
+        assert_eq!(SoAPoints::from_projective(&projective), soa);
+        assert_eq!(soa.get(17), G1Affine::identity());
+        assert_eq!(soa.x()[3], affine[3].x);
+        assert_eq!(soa.y()[3], affine[3].y);
+
+        let mut pushed = SoAPoints::with_capacity(affine.len());
+        for point in affine.iter() {
+            pushed.push(point);
+        }
+        assert_eq!(pushed, soa);
+        assert!(SoAPoints::<G1Affine>::new().is_empty());
+    }
+
+    #[test]
+    fn test_multiexp_soa() {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(4)
+            .build()
+            .unwrap();
+        for n in [0, 1, 7, 100, 1 << 15] {
+            let coeffs: Vec<_> = (0..n).map(|_| Fr::random(OsRng)).collect();
+            let bases: Vec<_> = (0..n).map(|_| G1::random(OsRng).to_affine()).collect();
+            let soa = SoAPoints::from(&bases[..]);
+            pool.install(|| {
+                assert_eq!(
+                    best_multiexp_soa(&coeffs, &soa),
+                    best_multiexp(&coeffs, &bases)
+                );
+            });
+        }
+    }
+}
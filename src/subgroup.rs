@@ -0,0 +1,403 @@
+//! Prime-order subgroup checks for curves with a cofactor.
+
+use crate::{msm::best_multiexp, CurveExt};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use ff::Field;
+use group::{
+    cofactor::CofactorGroup,
+    prime::{PrimeCurveAffine, PrimeGroup},
+    Group, GroupEncoding,
+};
+use rand_core::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+/// Probabilistically checks that all of `points` are torsion free, using a
+/// single subgroup check on a random linear combination of them.
+///
+/// A `true` result is wrong with probability at most `1/q`, where `q` is the
+/// smallest prime factor of the cofactor, and a `false` result is always
+/// correct. Use [`batch_is_torsion_free_strict`] when `q` is too small for
+/// the application or when the invalid points have to be identified.
+pub fn batch_is_torsion_free<G>(points: &[G], mut rng: impl RngCore) -> bool
+where
+    G: CurveExt + CofactorGroup,
+{
+    // 128-bit coefficients are enough to make the combination unpredictable
+    // while keeping the multiexponentiation cheap.
+    let shift = G::ScalarExt::from(1 << 32).square();
+    let coeffs: Vec<_> = (0..points.len())
+        .map(|_| {
+            let hi = G::ScalarExt::from(rng.next_u64());
+            let lo = G::ScalarExt::from(rng.next_u64());
+            hi * shift + lo
+        })
+        .collect();
+
+    let mut bases = vec![G::AffineExt::identity(); points.len()];
+    G::batch_normalize(points, &mut bases);
+
+    best_multiexp(&coeffs, &bases).is_torsion_free().into()
+}
+
+/// Checks each of `points` individually, returning whether it is torsion
+/// free.
+pub fn batch_is_torsion_free_strict<G: CofactorGroup + Send + Sync>(points: &[G]) -> Vec<Choice> {
+    use crate::multicore::*;
+
+    points.par_iter().map(|p| p.is_torsion_free()).collect()
+}
+
+/// Subgroup check on affine points.
+///
+/// The affine types of this crate also have an inherent `is_torsion_free`;
+/// the trait makes the check available to code that is generic over a
+/// [`pairing::Engine`], which only bounds its affine types by
+/// [`PrimeCurveAffine`].
+pub trait TorsionFreeAffine {
+    /// Returns whether the point lies in the prime-order subgroup.
+    fn is_torsion_free(&self) -> Choice;
+}
+
+impl<C> TorsionFreeAffine for C
+where
+    C: PrimeCurveAffine,
+    C::Curve: CofactorGroup,
+{
+    fn is_torsion_free(&self) -> Choice {
+        self.to_curve().is_torsion_free()
+    }
+}
+
+/// A point that is known to lie in the prime-order subgroup.
+///
+/// The subgroup check is done once, when the wrapper is constructed, and the
+/// group operations keep the invariant, so code holding a `Prime<G>` never
+/// has to repeat it. `Prime<G>` implements [`PrimeGroup`] and can be used
+/// wherever a prime-order group is expected.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[repr(transparent)]
+pub struct Prime<G>(G);
+
+impl<G: CofactorGroup<Subgroup = G>> Prime<G> {
+    /// Wraps `point` if it lies in the prime-order subgroup.
+    pub fn new(point: G) -> CtOption<Self> {
+        CtOption::new(Prime(point), point.is_torsion_free())
+    }
+
+    /// Maps `point` into the prime-order subgroup by clearing the cofactor.
+    pub fn clear_cofactor(point: &G) -> Self {
+        Prime(point.clear_cofactor())
+    }
+
+    /// Wraps `point` without checking it.
+    ///
+    /// The caller must guarantee that `point` lies in the prime-order
+    /// subgroup.
+    pub fn new_unchecked(point: G) -> Self {
+        Prime(point)
+    }
+}
+
+impl<G> Prime<G> {
+    /// Returns the wrapped point.
+    pub fn inner(&self) -> &G {
+        &self.0
+    }
+
+    /// Unwraps the point.
+    pub fn into_inner(self) -> G {
+        self.0
+    }
+}
+
+impl<G> AsRef<G> for Prime<G> {
+    fn as_ref(&self) -> &G {
+        &self.0
+    }
+}
+
+impl<G: ConstantTimeEq> ConstantTimeEq for Prime<G> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<G: ConditionallySelectable> ConditionallySelectable for Prime<G> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Prime(G::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+macro_rules! impl_prime_binop {
+    ($trait:ident, $fn:ident, $assign_trait:ident, $assign_fn:ident) => {
+        impl<G: CofactorGroup<Subgroup = G>> $trait<Prime<G>> for Prime<G> {
+            type Output = Prime<G>;
+
+            fn $fn(self, rhs: Prime<G>) -> Prime<G> {
+                Prime(self.0.$fn(rhs.0))
+            }
+        }
+
+        impl<'a, G: CofactorGroup<Subgroup = G>> $trait<&'a Prime<G>> for Prime<G> {
+            type Output = Prime<G>;
+
+            fn $fn(self, rhs: &'a Prime<G>) -> Prime<G> {
+                Prime(self.0.$fn(&rhs.0))
+            }
+        }
+
+        impl<'a, G: CofactorGroup<Subgroup = G>> $trait<Prime<G>> for &'a Prime<G> {
+            type Output = Prime<G>;
+
+            fn $fn(self, rhs: Prime<G>) -> Prime<G> {
+                Prime(self.0.$fn(rhs.0))
+            }
+        }
+
+        impl<'a, 'b, G: CofactorGroup<Subgroup = G>> $trait<&'b Prime<G>> for &'a Prime<G> {
+            type Output = Prime<G>;
+
+            fn $fn(self, rhs: &'b Prime<G>) -> Prime<G> {
+                Prime(self.0.$fn(&rhs.0))
+            }
+        }
+
+        impl<G: CofactorGroup<Subgroup = G>> $assign_trait<Prime<G>> for Prime<G> {
+            fn $assign_fn(&mut self, rhs: Prime<G>) {
+                self.0.$assign_fn(rhs.0);
+            }
+        }
+
+        impl<'a, G: CofactorGroup<Subgroup = G>> $assign_trait<&'a Prime<G>> for Prime<G> {
+            fn $assign_fn(&mut self, rhs: &'a Prime<G>) {
+                self.0.$assign_fn(&rhs.0);
+            }
+        }
+    };
+}
+
+impl_prime_binop!(Add, add, AddAssign, add_assign);
+impl_prime_binop!(Sub, sub, SubAssign, sub_assign);
+
+// Scalars are taken by value or by reference, whichever `G` supports.
+impl<G: Mul<S, Output = G>, S> Mul<S> for Prime<G> {
+    type Output = Prime<G>;
+
+    fn mul(self, rhs: S) -> Prime<G> {
+        Prime(self.0 * rhs)
+    }
+}
+
+impl<G: MulAssign<S>, S> MulAssign<S> for Prime<G> {
+    fn mul_assign(&mut self, rhs: S) {
+        self.0 *= rhs;
+    }
+}
+
+impl<G: CofactorGroup<Subgroup = G>> Neg for Prime<G> {
+    type Output = Prime<G>;
+
+    fn neg(self) -> Prime<G> {
+        Prime(-self.0)
+    }
+}
+
+impl<G: CofactorGroup<Subgroup = G>> Neg for &Prime<G> {
+    type Output = Prime<G>;
+
+    fn neg(self) -> Prime<G> {
+        Prime(-self.0)
+    }
+}
+
+impl<G: CofactorGroup<Subgroup = G>> Sum for Prime<G> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Prime(iter.map(|p| p.0).sum())
+    }
+}
+
+impl<'a, G: CofactorGroup<Subgroup = G>> Sum<&'a Prime<G>> for Prime<G> {
+    fn sum<I: Iterator<Item = &'a Prime<G>>>(iter: I) -> Self {
+        Prime(iter.map(|p| p.0).sum())
+    }
+}
+
+impl<G: CofactorGroup<Subgroup = G>> Group for Prime<G> {
+    type Scalar = G::Scalar;
+
+    fn random(rng: impl RngCore) -> Self {
+        Self::clear_cofactor(&G::random(rng))
+    }
+
+    fn identity() -> Self {
+        Prime(G::identity())
+    }
+
+    fn generator() -> Self {
+        // The generators of the curves in this crate lie in the prime-order
+        // subgroup.
+        Prime(G::generator())
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.0.is_identity()
+    }
+
+    fn double(&self) -> Self {
+        Prime(self.0.double())
+    }
+}
+
+impl<G> GroupEncoding for Prime<G>
+where
+    G: CofactorGroup<Subgroup = G> + Default + ConditionallySelectable,
+{
+    type Repr = G::Repr;
+
+    fn from_bytes(bytes: &Self::Repr) -> CtOption<Self> {
+        G::from_bytes(bytes).and_then(Self::new)
+    }
+
+    /// Like [`GroupEncoding::from_bytes`] for `G`, the encoding is trusted and
+    /// the subgroup check is skipped.
+    fn from_bytes_unchecked(bytes: &Self::Repr) -> CtOption<Self> {
+        G::from_bytes_unchecked(bytes).map(Prime)
+    }
+
+    fn to_bytes(&self) -> Self::Repr {
+        self.0.to_bytes()
+    }
+}
+
+impl<G> PrimeGroup for Prime<G> where
+    G: CofactorGroup<Subgroup = G> + Default + ConditionallySelectable
+{
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bn256::{Fr, G2};
+    use group::Curve;
+    use pairing::Engine;
+    use rand::SeedableRng;
+    use rand_xorshift::XorShiftRng;
+
+    // Returns a point on the curve that is not cleared of the cofactor.
+    fn random_point_on_curve<G: CurveExt>(mut rng: impl RngCore) -> G {
+        loop {
+            let x = G::Base::random(&mut rng);
+            let y2 = x.square() * x + G::a() * x + G::b();
+            if let Some(y) = Option::<G::Base>::from(y2.sqrt()) {
+                return G::new_jacobian(x, y, G::Base::ONE).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn test_prime_wrapper() {
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let invalid = random_point_on_curve::<G2>(&mut rng);
+        assert!(bool::from(Prime::new(invalid).is_none()));
+        assert!(bool::from(invalid.into_subgroup().is_none()));
+        assert!(bool::from(G2::from_bytes(&invalid.to_bytes()).is_some()));
+        assert!(bool::from(
+            Prime::<G2>::from_bytes(&invalid.to_bytes()).is_none()
+        ));
+
+        let cleared = Prime::clear_cofactor(&invalid);
+        assert!(bool::from(cleared.inner().is_torsion_free()));
+
+        let a = Prime::new(G2::random(&mut rng)).unwrap();
+        let b = Prime::<G2>::random(&mut rng);
+        let s = Fr::random(&mut rng);
+        let c = (a + b) * s - a.double();
+        assert_eq!(
+            c.into_inner(),
+            (a.into_inner() + b.into_inner()) * s - a.into_inner().double()
+        );
+        assert!(bool::from(c.inner().is_torsion_free()));
+        assert_eq!(Prime::<G2>::from_bytes(&c.to_bytes()).unwrap(), c);
+        assert_eq!([a, b, -b].iter().sum::<Prime<G2>>(), a);
+
+        // `Prime<G>` plugs into code that is generic over prime-order groups.
+        fn double_generator<P: PrimeGroup>() -> P {
+            P::generator().double()
+        }
+        assert_eq!(
+            double_generator::<Prime<G2>>().into_inner(),
+            G2::generator().double()
+        );
+    }
+
+    // Checks both arguments of a pairing the way generic code over an engine
+    // would.
+    fn is_valid_pairing_input<E: Engine>(p: &E::G1Affine, q: &E::G2Affine) -> bool
+    where
+        E::G1Affine: TorsionFreeAffine,
+        E::G2Affine: TorsionFreeAffine,
+    {
+        bool::from(TorsionFreeAffine::is_torsion_free(p) & TorsionFreeAffine::is_torsion_free(q))
+    }
+
+    #[test]
+    fn test_is_torsion_free_affine() {
+        use crate::{bn256, pluto_eris};
+
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+
+        let p = bn256::G1::random(&mut rng).to_affine();
+        let q = bn256::G2::random(&mut rng).to_affine();
+        assert!(bool::from(p.is_torsion_free() & q.is_torsion_free()));
+        assert!(is_valid_pairing_input::<bn256::Bn256>(&p, &q));
+        let invalid = random_point_on_curve::<bn256::G2>(&mut rng).to_affine();
+        assert!(!bool::from(invalid.is_torsion_free()));
+        assert!(!is_valid_pairing_input::<bn256::Bn256>(&p, &invalid));
+
+        let p = pluto_eris::G1::random(&mut rng).to_affine();
+        let q = pluto_eris::G2::random(&mut rng).to_affine();
+        assert!(bool::from(p.is_torsion_free() & q.is_torsion_free()));
+        assert!(is_valid_pairing_input::<pluto_eris::Pluto>(&p, &q));
+        let invalid = random_point_on_curve::<pluto_eris::G2>(&mut rng).to_affine();
+        assert!(!bool::from(invalid.is_torsion_free()));
+        assert!(!is_valid_pairing_input::<pluto_eris::Pluto>(&p, &invalid));
+    }
+
+    #[test]
+    fn test_batch_is_torsion_free() {
+        // The probabilistic check may accept an invalid batch with small
+        // probability, so the test is seeded to keep it deterministic.
+        let mut rng = XorShiftRng::from_seed([
+            0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+            0xbc, 0xe5,
+        ]);
+        let mut points: Vec<_> = (0..20).map(|_| G2::random(&mut rng)).collect();
+        points.push(G2::identity());
+        assert!(batch_is_torsion_free(&points, &mut rng));
+        assert!(batch_is_torsion_free_strict(&points)
+            .into_iter()
+            .all(bool::from));
+        assert!(batch_is_torsion_free::<G2>(&[], &mut rng));
+
+        let invalid = random_point_on_curve::<G2>(&mut rng);
+        assert!(!bool::from(invalid.is_torsion_free()));
+        points[7] = invalid;
+        assert!(!batch_is_torsion_free(&points, &mut rng));
+        for (i, valid) in batch_is_torsion_free_strict(&points)
+            .into_iter()
+            .enumerate()
+        {
+            assert_eq!(bool::from(valid), i != 7);
+        }
+    }
+}
@@ -12,6 +12,18 @@ macro_rules! curve_testing_suite {
                     let affine_point: <$c as CurveExt>::AffineExt = point.into();
                     assert!(bool::from(affine_point.is_on_curve()));
                 }
+
+                // Affine points are sampled directly, in the prime order
+                // subgroup and with either sign.
+                let mut odd = 0;
+                for _ in 0..100 {
+                    let point = <$c as CurveExt>::AffineExt::random(OsRng);
+                    assert!(bool::from(point.is_on_curve()));
+                    assert!(bool::from(point.is_torsion_free()));
+                    assert!(!bool::from(point.is_identity()));
+                    odd += bool::from(ff::PrimeField::is_odd(point.coordinates().unwrap().y())) as usize;
+                }
+                assert!(odd > 0 && odd < 100);
             }
         }
 
@@ -45,6 +57,53 @@ macro_rules! curve_testing_suite {
             }
         }
 
+        macro_rules! constant_time {
+            ($c: ident) => {{
+                use subtle::{ConditionallyNegatable, ConditionallySelectable, ConstantTimeEq};
+                type Affine = <$c as CurveExt>::AffineExt;
+
+                for _ in 0..10 {
+                    let a = $c::random(OsRng);
+                    let b = $c::random(OsRng);
+
+                    // Equal points with different coordinates.
+                    let a_other = a + b - b;
+                    assert!(bool::from(a.ct_eq(&a_other)));
+                    assert!(!bool::from(a.ct_eq(&b)));
+                    assert!(bool::from((a - a).ct_eq(&$c::identity())));
+                    assert!(!bool::from(a.ct_eq(&$c::identity())));
+
+                    let choice = subtle::Choice::from(0);
+                    assert_eq!($c::conditional_select(&a, &b, choice), a);
+                    assert_eq!($c::conditional_select(&a, &b, !choice), b);
+                    let mut c = a;
+                    c.conditional_negate(choice);
+                    assert_eq!(c, a);
+                    c.conditional_negate(!choice);
+                    assert_eq!(c, -a);
+
+                    let (a, b) = (a.to_affine(), b.to_affine());
+                    assert!(bool::from(a.ct_eq(&a_other.to_affine())));
+                    assert!(!bool::from(a.ct_eq(&b)));
+                    assert!(bool::from(Affine::identity().ct_eq(&Affine::identity())));
+                    assert!(!bool::from(a.ct_eq(&Affine::identity())));
+                    assert_eq!(Affine::conditional_select(&a, &b, choice), a);
+                    assert_eq!(Affine::conditional_select(&a, &b, !choice), b);
+
+                    let (ea, eb) = (a.to_bytes(), b.to_bytes());
+                    assert!(bool::from(ea.ct_eq(&ea)) && !bool::from(ea.ct_eq(&eb)));
+                    let e = ConditionallySelectable::conditional_select(&ea, &eb, !choice);
+                    assert_eq!(e.as_ref(), eb.as_ref());
+                    let (ea, eb) = (
+                        group::UncompressedEncoding::to_uncompressed(&a),
+                        group::UncompressedEncoding::to_uncompressed(&b),
+                    );
+                    let e = ConditionallySelectable::conditional_select(&ea, &eb, !choice);
+                    assert_eq!(e.as_ref(), eb.as_ref());
+                }
+            }}
+        }
+
         macro_rules! projective_affine_roundtrip {
             ($c: ident) => {
                 let a = $c::generator();
@@ -155,9 +214,59 @@ macro_rules! curve_testing_suite {
                 let c0 = a + b;
                 let c1 = a + $c::from(b);
                 assert_eq!(c0, c1);
+                assert_eq!(a.add_mixed(&b), c0);
+            }
+        }
+
+        macro_rules! unchecked_addition {
+            ($c: ident) => {
+                for _ in 0..100 {
+                    let a = $c::random(OsRng);
+                    let b = $c::random(OsRng);
+                    let c = a.add_unchecked(&b);
+                    assert!(bool::from(c.is_on_curve()));
+                    assert_eq!(c, a + b);
+
+                    let b: <$c as CurveExt>::AffineExt = b.into();
+                    let c = a.add_mixed_unchecked(&b);
+                    assert!(bool::from(c.is_on_curve()));
+                    assert_eq!(c, a + b);
+                }
+
+                // Points with `Z != 1` on both sides.
+                let a = $c::generator().double().double();
+                let b = $c::generator().double();
+                assert_eq!(a.add_unchecked(&b), a + b);
+            }
+        }
+
+        macro_rules! mul_by_3b {
+            ($c: ident) => {
+                let b3 = $c::b() + $c::b() + $c::b();
+                assert_eq!($c::curve_constant_3b(), b3);
+                for _ in 0..100 {
+                    let x = <$c as CurveExt>::Base::random(OsRng);
+                    assert_eq!($c::mul_by_3b(&x), x * b3);
+                }
             }
         }
 
+        macro_rules! identity {
+            ($c: ident) => {{
+                const IDENTITY: $c = $c::identity();
+                const AFFINE_IDENTITY: <$c as CurveExt>::AffineExt = <$c as CurveExt>::AffineExt::identity();
+
+                assert!(bool::from(IDENTITY.is_identity()));
+                assert_eq!(IDENTITY, <$c as $crate::group::Group>::identity());
+                assert_eq!($c::default(), IDENTITY);
+
+                assert!(bool::from(AFFINE_IDENTITY.is_identity()));
+                assert_eq!(AFFINE_IDENTITY, <<$c as CurveExt>::AffineExt as PrimeCurveAffine>::identity());
+                assert_eq!(<$c as CurveExt>::AffineExt::default(), AFFINE_IDENTITY);
+                assert_eq!(AFFINE_IDENTITY.to_curve(), IDENTITY);
+            }};
+        }
+
         macro_rules! multiplication {
             ($c: ident) => {
                 for _ in 1..1000 {
@@ -191,6 +300,56 @@ macro_rules! curve_testing_suite {
             }
         }
 
+        macro_rules! double_scalar_multiplication {
+            ($c: ident) => {
+                let scalars = [<$c as CurveExt>::ScalarExt::ZERO, <$c as CurveExt>::ScalarExt::ONE, -<$c as CurveExt>::ScalarExt::ONE];
+                for (a, b) in scalars.iter().flat_map(|a| scalars.iter().map(move |b| (a, b))) {
+                    let p = $c::random(OsRng);
+                    let q = $c::random(OsRng);
+                    assert_eq!($crate::msm::multi_mul2(a, &p, b, &q), p * a + q * b);
+                    assert_eq!($crate::msm::multi_mul2(a, &$c::identity(), b, &q), q * b);
+                }
+
+                for _ in 0..100 {
+                    let a = <$c as CurveExt>::ScalarExt::random(OsRng);
+                    let b = <$c as CurveExt>::ScalarExt::random(OsRng);
+                    let p = $c::random(OsRng);
+                    let q = $c::random(OsRng);
+                    assert_eq!($crate::msm::multi_mul2(&a, &p, &b, &q), p * a + q * b);
+                }
+            }
+        }
+
+        macro_rules! fixed_base_multiplication {
+            ($c: ident) => {
+                for s in [<$c as CurveExt>::ScalarExt::ZERO, <$c as CurveExt>::ScalarExt::ONE, -<$c as CurveExt>::ScalarExt::ONE] {
+                    assert_eq!($c::mul_by_generator(&s), $c::generator() * s);
+                }
+
+                for _ in 0..100 {
+                    let s = <$c as CurveExt>::ScalarExt::random(OsRng);
+                    assert_eq!($c::mul_by_generator(&s), $c::generator() * s);
+                }
+            }
+        }
+
+        macro_rules! batch_on_curve {
+            ($c: ident) => {
+                let mut points: Vec<_> = (0..100).map(|_| <$c as CurveExt>::AffineExt::random(OsRng)).collect();
+                points.push(<$c as CurveExt>::AffineExt::identity());
+                assert!(<$c as CurveExt>::AffineExt::batch_is_on_curve(&points).into_iter().all(bool::from));
+                assert!(bool::from(<$c as CurveExt>::AffineExt::batch_all_on_curve(&points)));
+                assert!(bool::from(<$c as CurveExt>::AffineExt::batch_all_on_curve(&[])));
+
+                points[42].y = points[42].y.double();
+                let valid = <$c as CurveExt>::AffineExt::batch_is_on_curve(&points);
+                for (i, valid) in valid.into_iter().enumerate() {
+                    assert_eq!(bool::from(valid), i != 42);
+                }
+                assert!(!bool::from(<$c as CurveExt>::AffineExt::batch_all_on_curve(&points)));
+            }
+        }
+
         macro_rules! batch_normalize {
             ($c: ident) => {
                 let a = $c::generator().double();
@@ -228,6 +387,24 @@ macro_rules! curve_testing_suite {
                         }
                     }
                 }
+
+                // Large enough to be split across threads.
+                let g = $c::random(OsRng);
+                let mut v: Vec<_> = core::iter::successors(Some(g), |p| Some(p + g)).take(5000).collect();
+                v[0] = $c::identity();
+                v[4321] = $c::identity();
+                let expected: Vec<_> = v.iter().map(|p| p.to_affine()).collect();
+
+                let pool = rayon::ThreadPoolBuilder::new().num_threads(4).build().unwrap();
+                let mut t = vec![<$c as CurveExt>::AffineExt::identity(); v.len()];
+                pool.install(|| $c::batch_normalize(&v, &mut t));
+                assert_eq!(t, expected);
+
+                pool.install(|| $c::batch_normalize_in_place(&mut v));
+                for (p, q) in v.iter().zip(expected.iter()) {
+                    assert!(bool::from(p.is_identity()) || p.z == <$c as CurveExt>::Base::ONE);
+                    assert_eq!(p.to_affine(), *q);
+                }
             }
         }
 
@@ -279,6 +456,48 @@ macro_rules! curve_testing_suite {
                     assert_eq!(affine_point, affine_point_rec);
                     assert_eq!(affine_point, affine_point_rec_unchecked);
                 }
+
+                let points: Vec<_> = (0..100).map(|_| <$c as CurveExt>::AffineExt::random(OsRng)).chain(Some(<$c as CurveExt>::AffineExt::identity())).collect();
+                let mut reprs: Vec<_> = points.iter().map(|p| p.to_bytes()).collect();
+                // An x-coordinate that is not canonical.
+                reprs[42].as_mut()[..<$c as CurveExt>::Base::size()].fill(0xff);
+                let decoded = <$c as CurveExt>::AffineExt::batch_from_bytes(&reprs);
+                assert_eq!(decoded.len(), points.len());
+                for (i, (decoded, point)) in decoded.into_iter().zip(points.iter()).enumerate() {
+                    if i == 42 {
+                        assert!(bool::from(decoded.is_none()));
+                    } else {
+                        assert_eq!(decoded.unwrap(), *point);
+                    }
+                }
+
+                // The unified decoder detects the format from the length.
+                {
+                    use $crate::serde::{PointEncoding, PointFormat};
+                    for point in points.iter() {
+                        for format in [PointFormat::Compressed, PointFormat::Uncompressed] {
+                            let bytes = point.encode(format);
+                            assert_eq!(<$c as CurveExt>::AffineExt::decode(&bytes), Some((*point, format)));
+                            assert!(<$c as CurveExt>::AffineExt::decode(&bytes[1..]).is_none());
+                        }
+                    }
+                }
+
+                // Strict decoding accepts exactly the canonical encodings.
+                for (i, (point, repr)) in points.iter().zip(reprs.iter()).enumerate() {
+                    if i != 42 {
+                        assert_eq!(<$c as CurveExt>::AffineExt::from_bytes_strict(repr), Ok(*point));
+                    }
+                }
+                assert_eq!(
+                    <$c as CurveExt>::AffineExt::from_bytes_strict(&reprs[42]),
+                    Err($crate::serde::PointDecodingError::NotCanonical)
+                );
+                let mut identity = <$c as CurveExt>::AffineExt::identity().to_bytes();
+                // The lenient decoder ignores the sign flag of the identity.
+                *identity.as_mut().last_mut().unwrap() |= $crate::derive::curve::SIGN_MASK;
+                assert!(bool::from($c::from_bytes(&identity).unwrap().is_identity()));
+                assert_eq!($c::from_bytes_strict(&identity), Err($crate::serde::PointDecodingError::InvalidFlags));
             }
         }
 
@@ -305,9 +524,118 @@ macro_rules! curve_testing_suite {
                     let affine_point_rec = <$c as CurveExt>::AffineExt::read_raw(&mut &buf[..]).unwrap();
                     assert_eq!(affine_point, affine_point_rec);
                 }
+
+                let points: Vec<_> = (0..100).map(|_| $c::random(OsRng)).chain(Some($c::identity())).collect();
+                let mut buf = Vec::new();
+                $c::write_raw_batch(&points, &mut buf).unwrap();
+                assert_eq!($c::read_raw_batch(&mut &buf[..]).unwrap(), points);
+                assert!($c::read_raw_batch(&mut &buf[..buf.len() - 1]).is_err());
+
+                // A point off the curve, and a degenerate point at infinity.
+                let mut off_curve = $c::generator();
+                off_curve.y = off_curve.y.double();
+                let mut degenerate = $c::identity();
+                degenerate.x = degenerate.y;
+                for point in [off_curve, degenerate] {
+                    let bytes = point.to_raw_bytes();
+                    assert!($c::from_raw_bytes(&bytes).is_none());
+                    assert!($c::read_raw(&mut &bytes[..]).is_err());
+                    let mut buf = Vec::new();
+                    $c::write_raw_batch(&[$c::generator(), point], &mut buf).unwrap();
+                    assert!($c::read_raw_batch(&mut &buf[..]).is_err());
+                }
+
+                // The unchecked functions read back invalid points as they are.
+                assert_eq!($c::from_raw_bytes_unchecked(&off_curve.to_raw_bytes()), off_curve);
+                assert_eq!($c::read_raw_unchecked(&mut &off_curve.to_raw_bytes()[..]), off_curve);
+                let off_curve = <$c as CurveExt>::AffineExt::from_raw_bytes_unchecked(&off_curve.to_raw_bytes()[..2 * <$c as CurveExt>::Base::size()]);
+                assert!(!bool::from(off_curve.is_on_curve()));
+                let bytes = off_curve.to_raw_bytes();
+                assert!(<$c as CurveExt>::AffineExt::from_raw_bytes(&bytes).is_none());
+                assert!(<$c as CurveExt>::AffineExt::read_raw(&mut &bytes[..]).is_err());
+                assert_eq!(<$c as CurveExt>::AffineExt::read_raw_unchecked(&mut &bytes[..]), off_curve);
             }
         }
 
+        macro_rules! validate_invariants {
+            ($c: ident) => {{
+                assert!($c::identity().debug_validate());
+                assert!($c::generator().debug_validate());
+                assert!(<$c as CurveExt>::AffineExt::identity().debug_validate());
+
+                let point = $c::random(OsRng);
+                assert!(point.debug_validate());
+                assert!(point.to_affine().debug_validate());
+
+                // A point at infinity with a non-zero X coordinate.
+                let mut invalid = $c::identity();
+                invalid.x = <$c as CurveExt>::Base::ONE;
+                assert!(!invalid.debug_validate());
+
+                // Points off the curve.
+                let mut invalid = point;
+                invalid.y += invalid.z;
+                assert!(!invalid.debug_validate());
+                let mut invalid = point.to_affine();
+                invalid.x += <$c as CurveExt>::Base::ONE;
+                assert!(!invalid.debug_validate());
+            }};
+        }
+
+        macro_rules! standard_distribution {
+            ($c: ident) => {{
+                use rand::{Rng, SeedableRng};
+
+                let seed = [
+                    0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                    0x06, 0xbc, 0xe5,
+                ];
+                let mut rng = rand_xorshift::XorShiftRng::from_seed(seed);
+                let mut expected = rand_xorshift::XorShiftRng::from_seed(seed);
+                for _ in 0..10 {
+                    let point: $c = rng.gen();
+                    assert_eq!(point, $c::random(&mut expected));
+
+                    let affine: <$c as CurveExt>::AffineExt = rng.gen();
+                    assert_eq!(affine, <$c as CurveExt>::AffineExt::random(&mut expected));
+                    assert!(bool::from(affine.is_on_curve()));
+                }
+            }};
+        }
+
+        macro_rules! reference_operators {
+            ($c: ident) => {{
+                for _ in 0..10 {
+                    let a = $c::random(OsRng);
+                    let b = $c::random(OsRng);
+                    let b_affine: <$c as CurveExt>::AffineExt = b.into();
+                    let s = <$c as CurveExt>::ScalarExt::random(OsRng);
+
+                    let sum = &a + &b;
+                    assert_eq!(sum, a + &b);
+                    assert_eq!(sum, &a + b);
+                    assert_eq!(sum, a + b);
+                    assert_eq!(sum, &a + &b_affine);
+                    assert_eq!(sum, a + b_affine);
+
+                    let difference = &a - &b;
+                    assert_eq!(difference, a - &b);
+                    assert_eq!(difference, &a - b);
+                    assert_eq!(difference, a - b);
+                    assert_eq!(difference, &a - &b_affine);
+
+                    let product = &a * &s;
+                    assert_eq!(product, a * &s);
+                    assert_eq!(product, &a * s);
+                    assert_eq!(product, a * s);
+
+                    assert_eq!(-&a, -a);
+                    assert_eq!(-&b_affine, -b_affine);
+                    assert!(bool::from((&a + &(-&a)).is_identity()));
+                }
+            }};
+        }
+
         #[cfg(feature = "derive_serde")]
         macro_rules! random_serde_test {
             ($c: ident) => {
@@ -323,6 +651,9 @@ macro_rules! curve_testing_suite {
                     }
                     {
                         let affine_json = serde_json::to_string(&affine_point).unwrap();
+                        let mut be_bytes = affine_point.to_bytes().as_ref().to_vec();
+                        be_bytes.reverse();
+                        assert_eq!(affine_json, format!("\"0x{}\"", hex::encode(be_bytes)));
                         let reader = std::io::Cursor::new(affine_json);
                         let affine_point_rec: <$c as CurveExt>::AffineExt = serde_json::from_reader(reader).unwrap();
                         assert_eq!(affine_point, affine_point_rec);
@@ -355,12 +686,22 @@ macro_rules! curve_testing_suite {
             $(
                 is_on_curve!($curve);
                 equality!($curve);
+                constant_time!($curve);
                 projective_affine_roundtrip!($curve);
                 projective_addition!($curve);
                 mixed_addition!($curve);
+                unchecked_addition!($curve);
+                mul_by_3b!($curve);
+                identity!($curve);
                 multiplication!($curve);
+                fixed_base_multiplication!($curve);
+                double_scalar_multiplication!($curve);
                 batch_normalize!($curve);
+                batch_on_curve!($curve);
                 serdes!($curve);
+                standard_distribution!($curve);
+                reference_operators!($curve);
+                validate_invariants!($curve);
             )*
         }
 
@@ -386,13 +727,22 @@ macro_rules! curve_testing_suite {
                         .collect::<Vec<_>>();
                     assert!(bool::from(hasher(&message).is_on_curve()));
                 }
+
+                let generators: Vec<_> = (0..10).map(|i| $c::derive_generator(b"test", i)).collect();
+                for (i, g) in generators.iter().enumerate() {
+                    assert!(bool::from(g.is_on_curve()));
+                    assert!(!bool::from(g.is_identity()));
+                    assert_eq!(*g, $c::derive_generator(b"test", i as u64));
+                    assert_ne!(*g, $c::derive_generator(b"other", i as u64));
+                    assert!(generators[..i].iter().all(|h| h != g));
+                }
             }
         }
 
         #[test]
         fn test_hash_to_curve() {
             use rand_core::{OsRng, RngCore};
-            use std::iter;
+            use core::iter;
             $(
                 hash_to_curve_test!($curve);
             )*
@@ -443,6 +793,26 @@ macro_rules! curve_testing_suite {
         }
     };
 
+    ($($curve: ident),*, "zeroize") => {
+        #[test]
+        fn test_zeroize() {
+            use rand_core::OsRng;
+            use zeroize::Zeroize;
+            $(
+                let mut p = $curve::random(OsRng);
+                let mut a = p.to_affine();
+                p.zeroize();
+                a.zeroize();
+                assert!(bool::from(p.is_identity()));
+                assert!(bool::from(a.is_identity()));
+
+                let mut points = vec![$curve::random(OsRng); 4];
+                points.as_mut_slice().zeroize();
+                assert!(points.iter().all(|p| bool::from(p.is_identity())));
+            )*
+        }
+    };
+
     ($curve: ident, "ecdsa_example") => {
         #[test]
         fn ecdsa_example() {
@@ -528,7 +898,7 @@ macro_rules! curve_testing_suite {
                 precomputed_constants: [&'static str; 4],
                 test_vector: impl IntoIterator<Item = (&'static str, (&'static str, &'static str))>,
             ) where
-                <G as CurveExt>::Base: Legendre,
+                <G as CurveExt>::Base: Legendre + hash_to_curve::ExtensionField,
             {
                 let [c1, c2, c3, c4] = hash_to_curve::svdw_precomputed_constants::<G>(z);
                 assert_eq!([c1, c2, c3, c4], precomputed_constants.map(fe_from_str));
@@ -144,6 +144,7 @@ macro_rules! field_testing_suite {
 
         fn zero_tests<F: Field, R: RngCore>(mut rng: R) {
             assert_eq!(F::ZERO.is_zero().unwrap_u8(), 1);
+            assert_eq!(F::default(), F::ZERO);
             {
                 let mut z = F::ZERO;
                 z = z.neg();
@@ -216,6 +217,51 @@ macro_rules! field_testing_suite {
             zero_tests::<$field, _>(&mut rng);
             one_tests::<$field, _>(&mut rng);
         }
+
+        #[test]
+        fn test_standard_distribution() {
+            use rand::Rng;
+
+            let seed = [
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ];
+            let mut rng = XorShiftRng::from_seed(seed);
+            let mut expected = XorShiftRng::from_seed(seed);
+            for _ in 0..100 {
+                assert_eq!(rng.gen::<$field>(), $field::random(&mut expected));
+            }
+        }
+
+        #[test]
+        fn test_reference_operators() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            for _ in 0..100 {
+                let a = $field::random(&mut rng);
+                let b = $field::random(&mut rng);
+
+                let sum = &a + &b;
+                assert_eq!(sum, a + &b);
+                assert_eq!(sum, &a + b);
+                assert_eq!(sum, a + b);
+
+                let difference = &a - &b;
+                assert_eq!(difference, a - &b);
+                assert_eq!(difference, &a - b);
+                assert_eq!(difference, a - b);
+
+                let product = &a * &b;
+                assert_eq!(product, a * &b);
+                assert_eq!(product, &a * b);
+                assert_eq!(product, a * b);
+
+                assert_eq!(-&a, -a);
+                assert_eq!(&a + &(-&a), $field::ZERO);
+            }
+        }
     };
 
     ($field: ident, "conversion") => {
@@ -232,6 +278,156 @@ macro_rules! field_testing_suite {
                 assert_eq!(a, b);
             }
         }
+
+        #[test]
+        fn test_from_integers() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            let two_64 = $field::from(u64::MAX) + $field::ONE;
+            let edges = [0, 1, u64::MAX as u128, 1 << 64, u128::MAX];
+            for x in edges.into_iter().chain((0..1000).map(|_| {
+                (rng.next_u64() as u128) << 64 | rng.next_u64() as u128
+            })) {
+                let lo = $field::from(x as u64);
+                let hi = $field::from((x >> 64) as u64);
+                assert_eq!($field::from(x), hi * two_64 + lo);
+
+                let y = x as i128;
+                let abs = $field::from(y.unsigned_abs());
+                assert_eq!($field::from(y), if y < 0 { -abs } else { abs });
+                let z = x as i64;
+                let abs = $field::from(z.unsigned_abs());
+                assert_eq!($field::from(z), if z < 0 { -abs } else { abs });
+            }
+            assert_eq!($field::from(-1), -$field::ONE);
+            assert_eq!($field::from(i64::MIN), -$field::from(1u64 << 63));
+            assert_eq!($field::from(i128::MIN), -$field::from(1u128 << 127));
+        }
+    };
+
+    ($field: ident, "crypto_bigint", $uint: ident) => {
+        #[test]
+        fn test_crypto_bigint() {
+            use crypto_bigint::{Encoding, $uint, U512};
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let n = $uint::from(a);
+                assert_eq!(n.to_le_bytes().as_ref(), a.to_repr().as_ref());
+                assert_eq!($field::from_uint(&n).unwrap(), a);
+
+                let mut bytes = [0u8; 64];
+                rng.fill_bytes(&mut bytes);
+                assert_eq!(
+                    $field::reduce(&U512::from_le_bytes(bytes)),
+                    $field::from_uniform_bytes(&bytes)
+                );
+            }
+
+            // The modulus is one more than the canonical form of -1.
+            let modulus = $uint::from(-$field::ONE).wrapping_add(&$uint::ONE);
+            assert!(bool::from($field::from_uint(&modulus).is_none()));
+            assert!(bool::from($field::from_uint(&$uint::MAX).is_none()));
+            assert_eq!($field::reduce(&modulus.resize()), $field::ZERO);
+        }
+    };
+
+    ($field: ident, "str") => {
+        #[test]
+        fn test_str() {
+            use crate::ff_ext::radix::ParseFieldError;
+            use num_bigint::BigUint;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            for _ in 0..1000 {
+                let a = $field::random(&mut rng);
+                let n = BigUint::from_bytes_le(a.to_repr().as_ref());
+                assert_eq!(a.to_string(), n.to_string());
+                assert_eq!(format!("{:x}", a), n.to_str_radix(16));
+                assert_eq!(a.to_string().parse::<$field>(), Ok(a));
+                assert_eq!(format!("{:#x}", a).parse::<$field>(), Ok(a));
+                assert_eq!(format!("{:#066x}", a).parse::<$field>(), Ok(a));
+            }
+
+            assert_eq!($field::ZERO.to_string(), "0");
+            assert_eq!(format!("{:#x}", $field::ZERO), "0x0");
+            assert_eq!(format!("{:>4}", $field::ONE), "   1");
+            assert_eq!(format!("{:+}", $field::from(42u64)), "+42");
+            assert_eq!("0X2a".parse::<$field>(), Ok($field::from(42u64)));
+            assert_eq!("0042".parse::<$field>(), Ok($field::from(42u64)));
+
+            assert_eq!("".parse::<$field>(), Err(ParseFieldError::Empty));
+            assert_eq!("0x".parse::<$field>(), Err(ParseFieldError::Empty));
+            assert_eq!("-1".parse::<$field>(), Err(ParseFieldError::InvalidDigit));
+            assert_eq!("12a".parse::<$field>(), Err(ParseFieldError::InvalidDigit));
+            assert_eq!("0xfg".parse::<$field>(), Err(ParseFieldError::InvalidDigit));
+
+            // The modulus is one more than the canonical form of -1.
+            let modulus = BigUint::from_bytes_le((-$field::ONE).to_repr().as_ref()) + 1u32;
+            assert_eq!(
+                modulus.to_string().parse::<$field>(),
+                Err(ParseFieldError::NotCanonical)
+            );
+            assert_eq!(
+                (&modulus << 512u32).to_string().parse::<$field>(),
+                Err(ParseFieldError::NotCanonical)
+            );
+
+            // Integers of any length are reduced by the `mod_order` constructors.
+            for len in [0, 1, 7, 31, 32, 33, 64, 65, 200] {
+                let mut bytes = vec![0u8; len];
+                rng.fill_bytes(&mut bytes);
+                let n = BigUint::from_bytes_le(&bytes) % &modulus;
+                let mut repr = <$field as PrimeField>::Repr::default();
+                for (dst, src) in repr.as_mut().iter_mut().zip(n.to_bytes_le()) {
+                    *dst = src;
+                }
+                let a = $field::from_repr(repr).unwrap();
+                assert_eq!($field::from_bytes_mod_order(&bytes), a);
+                bytes.reverse();
+                assert_eq!($field::from_be_bytes_mod_order(&bytes), a);
+            }
+            let a = $field::random(&mut rng);
+            let n = BigUint::from_bytes_le(a.to_repr().as_ref()) + &modulus * &modulus * 7u32;
+            assert_eq!($field::from_str_mod_order(&n.to_string()), Ok(a));
+            assert_eq!(
+                $field::from_str_mod_order(&format!("0x{}", n.to_str_radix(16))),
+                Ok(a)
+            );
+            assert_eq!($field::from_str_mod_order("0"), Ok($field::ZERO));
+            assert_eq!(
+                $field::from_str_mod_order("0x"),
+                Err(ParseFieldError::Empty)
+            );
+        }
+    };
+
+    ($field: ident, "zeroize") => {
+        #[test]
+        fn test_zeroize() {
+            use zeroize::Zeroize;
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54,
+                0x06, 0xbc, 0xe5,
+            ]);
+            let mut a = $field::random(&mut rng);
+            a.zeroize();
+            assert_eq!(a, $field::ZERO);
+
+            let mut elements: Vec<_> = (0..4).map(|_| $field::random(&mut rng)).collect();
+            elements.as_mut_slice().zeroize();
+            assert!(elements.iter().all(|e| *e == $field::ZERO));
+        }
     };
 
     ($field: ident, "serialization") => {
@@ -250,6 +446,12 @@ macro_rules! field_testing_suite {
                     a.write_raw(&mut buf).unwrap();
                     let b = $f::read_raw(&mut &buf[..]).unwrap();
                     assert_eq!(a, b);
+                    let b = $f::from_bytes(&a.to_bytes()).unwrap();
+                    assert_eq!(a, b);
+                    let mut bytes = a.to_bytes_be();
+                    assert_eq!($f::from_bytes_be(&bytes).unwrap(), a);
+                    bytes.reverse();
+                    assert_eq!(bytes, a.to_bytes());
                 }
             };
         }
@@ -350,12 +552,13 @@ macro_rules! field_testing_suite {
                 0xbc, 0xe5,
             ]);
             const LIMBS: usize = $field::size() / 8;
+            let modulus = $crate::tests::field::modulus_limbs::<$field, LIMBS>();
             // failure check
             for _ in 0..1000000 {
                 let rand_word = [(); LIMBS].map(|_| rng.next_u64());
-                let a = $field(rand_word);
-                let rand_bytes = a.to_raw_bytes();
-                match is_less_than::<LIMBS>(&rand_word, &MODULUS.0) {
+                let rand_bytes = $crate::tests::field::limbs_to_bytes(&rand_word);
+                let a = $field::from_raw_bytes_unchecked(&rand_bytes);
+                match is_less_than::<LIMBS>(&rand_word, &modulus) {
                     false => {
                         assert!($field::from_raw_bytes(&rand_bytes).is_none());
                     }
@@ -367,6 +570,149 @@ macro_rules! field_testing_suite {
         }
     };
 
+    ($field: ident, "from_be_slice") => {
+        #[test]
+        fn test_from_be_slice() {
+            use crate::serde::FieldDecodingError;
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            for _ in 0..100000 {
+                let a = $field::random(&mut rng);
+                let mut bytes = a.to_bytes();
+                bytes.reverse();
+                assert_eq!($field::from_be_slice(&bytes), Ok(a));
+            }
+
+            // Short inputs are zero-extended.
+            assert_eq!($field::from_be_slice(&[]), Ok($field::zero()));
+            assert_eq!($field::from_be_slice(&[1]), Ok($field::one()));
+            assert_eq!($field::from_be_slice(&[1, 0]), Ok($field::from(256)));
+
+            // Over-length inputs are rejected, even if the extra bytes are zero.
+            assert_eq!(
+                $field::from_be_slice(&vec![0u8; $field::size() + 1]),
+                Err(FieldDecodingError::InvalidLength)
+            );
+
+            // The modulus itself is out of range. `p - 1` is even so adding one to
+            // its least significant byte does not carry.
+            let mut modulus = (-$field::one()).to_bytes();
+            modulus.reverse();
+            *modulus.last_mut().unwrap() += 1;
+            assert_eq!(
+                $field::from_be_slice(&modulus),
+                Err(FieldDecodingError::NotCanonical)
+            );
+        }
+    };
+
+    ($field: ident, "debug_validate") => {
+        #[test]
+        fn test_debug_validate() {
+            use crate::serde::SerdeObject;
+            use ff::Field;
+            use rand_core::OsRng;
+
+            assert!($field::ZERO.debug_validate());
+            assert!($field::ONE.debug_validate());
+            assert!((-$field::ONE).debug_validate());
+            for _ in 0..100 {
+                assert!($field::random(OsRng).debug_validate());
+            }
+
+            let modulus = $crate::tests::field::modulus_limbs::<$field, { $field::size() / 8 }>();
+            let modulus = $crate::tests::field::limbs_to_bytes(&modulus);
+            assert!(!$field::from_raw_bytes_unchecked(&modulus).debug_validate());
+            assert!(!$field::from_raw_bytes_unchecked(&vec![0xff; $field::size()]).debug_validate());
+        }
+
+        #[cfg(all(feature = "debug-validate", debug_assertions))]
+        #[test]
+        #[should_panic]
+        fn test_debug_validate_arithmetic() {
+            use crate::serde::SerdeObject;
+
+            let modulus = $crate::tests::field::modulus_limbs::<$field, { $field::size() / 8 }>();
+            let modulus = $crate::tests::field::limbs_to_bytes(&modulus);
+            let _ = $field::from_raw_bytes_unchecked(&modulus) + <$field as ff::Field>::ONE;
+        }
+    };
+
+    ($field: ident, "balanced_digits") => {
+        #[test]
+        fn test_balanced_digits() {
+            use ff::Field;
+            use rand::SeedableRng;
+            use rand_xorshift::XorShiftRng;
+
+            fn recompose(digits: &[i64], base: u64) -> $field {
+                let base = $field::from(base);
+                digits.iter().rev().fold($field::zero(), |acc, &digit| {
+                    let digit = if digit < 0 {
+                        -$field::from(digit.unsigned_abs())
+                    } else {
+                        $field::from(digit as u64)
+                    };
+                    acc * base + digit
+                })
+            }
+
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06,
+                0xbc, 0xe5,
+            ]);
+            for base in [2, 3, 4, 7, 16, 255, 256, 1 << 16, (1 << 32) + 1, i64::MAX as u64] {
+                assert!($field::zero().to_balanced_digits(base).is_empty());
+                for _ in 0..100 {
+                    let a = $field::random(&mut rng);
+                    let digits = a.to_balanced_digits(base);
+                    for &digit in digits.iter() {
+                        assert!(2 * digit as i128 > -(base as i128));
+                        assert!(2 * digit as i128 <= base as i128);
+                    }
+                    assert_ne!(digits.last(), Some(&0));
+                    assert_eq!(recompose(&digits, base), a);
+                }
+            }
+
+            // Half the base stays positive while one more wraps to negative.
+            assert_eq!($field::from(8).to_balanced_digits(16), vec![8]);
+            assert_eq!($field::from(9).to_balanced_digits(16), vec![-7, 1]);
+            assert_eq!($field::from(15).to_balanced_digits(16), vec![-1, 1]);
+            assert_eq!((-$field::one()).to_balanced_digits(2).len(), $field::NUM_BITS as usize);
+        }
+    };
+
+    ($field: ident, "domain") => {
+        #[test]
+        fn test_domain() {
+            let max = $field::max_fft_size();
+            let root_of_unity = <$field as ff::PrimeField>::ROOT_OF_UNITY;
+            assert_eq!(max, 1 << <$field as ff::PrimeField>::S);
+            assert_eq!(ff::Field::pow(&root_of_unity, [max]), <$field as ff::Field>::ONE);
+            if max > 1 {
+                assert_ne!(ff::Field::pow(&root_of_unity, [max / 2]), <$field as ff::Field>::ONE);
+            }
+
+            assert!($field::supports_domain(1));
+            assert!($field::supports_domain(max));
+            assert!(!$field::supports_domain(0));
+            assert!(!$field::supports_domain(2 * max));
+            assert!(!$field::supports_domain(3));
+
+            assert_eq!($field::smallest_domain_at_least(0), Some(1));
+            assert_eq!($field::smallest_domain_at_least(1), Some(1));
+            assert_eq!($field::smallest_domain_at_least(max), Some(max));
+            assert_eq!($field::smallest_domain_at_least(max + 1), None);
+            assert_eq!($field::smallest_domain_at_least(u64::MAX), None);
+            if max >= 4 {
+                assert_eq!($field::smallest_domain_at_least(3), Some(4));
+            }
+        }
+    };
+
     ($field: ident, "constants", $modulus_str: expr) => {
         #[test]
         fn test_primefield_constants() {
@@ -395,6 +741,8 @@ macro_rules! field_testing_suite {
             use crate::ff_ext::Legendre;
             use rand_core::OsRng;
 
+            assert_eq!($field::ZERO.sqrt().unwrap(), $field::ZERO);
+
             let v = ($field::TWO_INV).square().sqrt().unwrap();
             assert!(v == $field::TWO_INV || (-v) == $field::TWO_INV);
 
@@ -456,7 +804,7 @@ macro_rules! field_testing_suite {
             assert!($test_vectors.len() == N_VECS);
 
             let mut seeded_rng = XorShiftRng::seed_from_u64(0u64);
-            let uniform_bytes = std::iter::from_fn(|| {
+            let uniform_bytes = core::iter::from_fn(|| {
                 let mut bytes = [0u8; 64];
                 seeded_rng.fill_bytes(&mut bytes);
                 Some(bytes)
@@ -707,6 +1055,29 @@ macro_rules! field_testing_suite {
                 assert_eq!(a, b);
             }
         }
+
+        #[test]
+        fn test_cyclotomic_squaring() {
+            let mut rng = XorShiftRng::from_seed([
+                0x59, 0x62, 0xbe, 0x5d, 0x76, 0x3d, 0x31, 0x8d, 0x17, 0xdb, 0x37, 0x32, 0x54, 0x06, 0xbc,
+                0xe5,
+            ]);
+
+            for _ in 0..1000 {
+                // Raise to (p^6 - 1)(p^2 + 1) to land in the cyclotomic subgroup.
+                let f = $ext_field::random(&mut rng);
+                let mut a = f;
+                a.conjugate();
+                a.mul_assign(&f.invert().unwrap());
+                let mut b = a;
+                b.frobenius_map(2);
+                a.mul_assign(&b);
+
+                let mut b = a;
+                b.cyclotomic_square();
+                assert_eq!(b, a.square());
+            }
+        }
     };
 
     ($ext_field: ident, "frobenius", $frobenius_param: expr) => {
@@ -733,3 +1104,19 @@ macro_rules! field_testing_suite {
         }
     };
 }
+
+/// Returns the modulus of `F` as `N` little-endian limbs, parsed from its
+/// hexadecimal `PrimeField::MODULUS`.
+pub(crate) fn modulus_limbs<F: ff::PrimeField, const N: usize>() -> [u64; N] {
+    let hex = F::MODULUS.trim_start_matches("0x");
+    let mut limbs = [0u64; N];
+    for (limb, digits) in limbs.iter_mut().zip(hex.as_bytes().rchunks(16)) {
+        *limb = u64::from_str_radix(core::str::from_utf8(digits).unwrap(), 16).unwrap();
+    }
+    limbs
+}
+
+/// Returns the little-endian bytes of little-endian limbs.
+pub(crate) fn limbs_to_bytes(limbs: &[u64]) -> Vec<u8> {
+    limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect()
+}